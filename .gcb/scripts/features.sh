@@ -73,6 +73,16 @@ done
 cargo clippy --no-deps --package google-cloud-pubsub --all-targets -- --deny warnings
 cargo clippy --no-deps --package google-cloud-pubsub --all-features --all-targets --profile=test -- --deny warnings
 
+cargo clean
+echo "==== google-cloud-bigquery ===="
+for sub in test doc; do
+  cargo "${sub}" --profile=ci --package google-cloud-bigquery --no-default-features
+  cargo "${sub}" --profile=ci --package google-cloud-bigquery --no-default-features --features arrow
+  cargo "${sub}" --profile=ci --package google-cloud-bigquery --all-features
+done
+cargo clippy --no-deps --package google-cloud-bigquery --all-targets -- --deny warnings
+cargo clippy --no-deps --package google-cloud-bigquery --all-features --all-targets --profile=test -- --deny warnings
+
 echo "==== DONE ===="
 
 /workspace/.bin/sccache --show-stats
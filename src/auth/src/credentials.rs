@@ -29,6 +29,7 @@ use std::sync::atomic::{AtomicU64, Ordering};
 pub mod anonymous;
 pub mod api_key_credentials;
 pub(crate) mod crypto_provider;
+pub mod downscoped;
 pub mod external_account;
 pub(crate) mod external_account_sources;
 #[cfg(feature = "gdch")]
@@ -40,6 +41,7 @@ pub(crate) mod internal;
 pub mod mds;
 pub mod service_account;
 pub mod subject_token;
+pub mod token_store;
 pub mod user_account;
 pub(crate) const QUOTA_PROJECT_KEY: &str = "x-goog-user-project";
 
@@ -480,6 +482,7 @@ pub struct Builder {
     quota_project_id: Option<String>,
     scopes: Option<Vec<String>>,
     universe_domain: Option<String>,
+    token_store: Option<Arc<dyn token_store::dynamic::TokenStore>>,
 }
 
 impl Default for Builder {
@@ -499,6 +502,7 @@ impl Default for Builder {
             quota_project_id: None,
             scopes: None,
             universe_domain: None,
+            token_store: None,
         }
     }
 }
@@ -577,6 +581,33 @@ impl Builder {
         self
     }
 
+    /// Sets the [TokenStore] to share access tokens across processes.
+    ///
+    /// Before fetching a new access token, the credentials look for a valid
+    /// token in the store, and save any new tokens to it. Use this with
+    /// short-lived processes, such as command-line tools, to avoid fetching a
+    /// new token on every invocation.
+    ///
+    /// Service account keys create access tokens locally, the store is not
+    /// used with these credentials.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_auth::credentials::Builder;
+    /// # use google_cloud_auth::credentials::token_store::FileTokenStore;
+    /// # fn sample() -> anyhow::Result<()> {
+    /// let credentials = Builder::default()
+    ///     .with_token_store(FileTokenStore::new("/var/cache/my-tool/tokens.json"))
+    ///     .build()?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [TokenStore]: token_store::TokenStore
+    pub fn with_token_store<S: token_store::TokenStore + 'static>(mut self, store: S) -> Self {
+        self.token_store = Some(Arc::new(store));
+        self
+    }
+
     /// Returns a [Credentials] instance with the configured settings.
     ///
     /// # Errors
@@ -631,6 +662,7 @@ impl Builder {
             quota_project_id,
             self.scopes,
             self.universe_domain,
+            self.token_store,
         )
     }
 
@@ -743,15 +775,16 @@ macro_rules! config_common_builder {
     }};
 }
 
-fn build_credentials(
+pub(crate) fn build_credentials(
     json: Option<Value>,
     quota_project_id: Option<String>,
     scopes: Option<Vec<String>>,
     universe_domain: Option<String>,
+    token_store: Option<Arc<dyn token_store::dynamic::TokenStore>>,
 ) -> BuildResult<AccessTokenCredentials> {
     match json {
         None => config_builder!(
-            mds::Builder::from_adc(),
+            mds::Builder::from_adc().maybe_token_store(token_store),
             quota_project_id,
             scopes,
             universe_domain.clone(),
@@ -762,7 +795,7 @@ fn build_credentials(
             match cred_type {
                 "authorized_user" => {
                     config_builder!(
-                        user_account::Builder::new(json),
+                        user_account::Builder::new(json).maybe_token_store(token_store),
                         quota_project_id,
                         scopes,
                         universe_domain.clone(),
@@ -779,7 +812,7 @@ fn build_credentials(
                 ),
                 "impersonated_service_account" => {
                     config_builder!(
-                        impersonated::Builder::new(json),
+                        impersonated::Builder::new(json).maybe_token_store(token_store),
                         quota_project_id,
                         scopes,
                         universe_domain.clone(),
@@ -787,7 +820,7 @@ fn build_credentials(
                     )
                 }
                 "external_account" => config_builder!(
-                    external_account::Builder::new(json),
+                    external_account::Builder::new(json).maybe_token_store(token_store),
                     quota_project_id,
                     scopes,
                     universe_domain.clone(),
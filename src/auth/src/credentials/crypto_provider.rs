@@ -21,16 +21,29 @@ pub(crate) fn get_key_provider() -> &'static dyn rustls::crypto::KeyProvider {
         key_provider.unwrap_or_else(|| rustls::crypto::aws_lc_rs::default_provider().key_provider);
 
     #[cfg(not(feature = "default-rustls-provider"))]
-    let key_provider = key_provider.expect(
-        r###"
+    let key_provider = key_provider.expect(MISSING_PROVIDER);
+
+    key_provider
+}
+
+pub(crate) fn get_secure_random() -> &'static dyn rustls::crypto::SecureRandom {
+    let secure_random = CryptoProvider::get_default().map(|p| p.secure_random);
+    #[cfg(feature = "default-rustls-provider")]
+    let secure_random = secure_random
+        .unwrap_or_else(|| rustls::crypto::aws_lc_rs::default_provider().secure_random);
+
+    #[cfg(not(feature = "default-rustls-provider"))]
+    let secure_random = secure_random.expect(MISSING_PROVIDER);
+
+    secure_random
+}
+
+#[cfg(not(feature = "default-rustls-provider"))]
+const MISSING_PROVIDER: &str = r###"
 The default rustls::CryptoProvider should be configured by the application. The
 `google-cloud-auth` crate was compiled without the `default-rustls-provider`
 feature. Without this feature the crate expects the application to initialize
 the rustls crypto provider using `rustls::CryptoProvider::install_default()`.
 
 Note that the application must use the exact same version of `rustls` as the
-`google-cloud-auth` crate does. Otherwise `install_default()` has no effect."###,
-    );
-
-    key_provider
-}
+`google-cloud-auth` crate does. Otherwise `install_default()` has no effect."###;
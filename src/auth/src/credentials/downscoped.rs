@@ -0,0 +1,785 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Downscoped credentials with [Credential Access Boundaries].
+//!
+//! A Credential Access Boundary restricts the permissions of a short-lived
+//! access token. The boundary lists the resources the token can access, the
+//! upper bound on the permissions available for each resource, and optionally
+//! a [CEL] condition that further restricts the objects within a resource.
+//!
+//! Applications use downscoped credentials to hand out tokens to less trusted
+//! workloads. For example, a token that can only read objects with a given
+//! prefix from a single Cloud Storage bucket.
+//!
+//! The source credentials must have the permissions granted by the boundary,
+//! downscoping never grants new permissions.
+//!
+//! Each downscoped token is obtained from the Security Token Service. The
+//! token expires when the service reports, or with the source token if the
+//! service omits the token lifetime.
+//!
+//! This module does not implement client-side Credential Access Boundaries,
+//! where the application mints many downscoped tokens locally from a single
+//! intermediary token. That variant requires the Tink cryptographic library,
+//! which has no Rust implementation.
+//!
+//! ## Example: Creating downscoped credentials
+//!
+//! ```
+//! # use google_cloud_auth::credentials::{Builder as AdcBuilder, downscoped};
+//! # use google_cloud_auth::credentials::downscoped::{AccessBoundaryRule, AvailabilityCondition};
+//! # use http::Extensions;
+//! # async fn sample() -> anyhow::Result<()> {
+//! let source = AdcBuilder::default().build()?;
+//! let rule = AccessBoundaryRule::new(
+//!     "//storage.googleapis.com/projects/_/buckets/my-bucket",
+//!     ["inRole:roles/storage.objectViewer"],
+//! )
+//! .with_availability_condition(AvailabilityCondition::new(
+//!     "resource.name.startsWith('projects/_/buckets/my-bucket/objects/tenant-1/')",
+//! ));
+//! let credentials = downscoped::Builder::new(source, [rule]).build()?;
+//! let headers = credentials.headers(Extensions::new()).await?;
+//! println!("Headers: {headers:?}");
+//! # Ok(()) }
+//! ```
+//!
+//! [Credential Access Boundaries]: https://cloud.google.com/iam/docs/downscoping-short-lived-credentials
+//! [CEL]: https://cloud.google.com/iam/docs/conditions-overview#cel
+
+use super::internal::sts_exchange::{ExchangeTokenRequest, STSHandler};
+use crate::build_errors::Error as BuilderError;
+use crate::constants::{ACCESS_TOKEN_TYPE, DEFAULT_UNIVERSE_DOMAIN, STS_TOKEN_URL};
+use crate::credentials::dynamic::{AccessTokenCredentialsProvider, CredentialsProvider};
+use crate::credentials::{AccessToken, AccessTokenCredentials, CacheableResource, Credentials};
+use crate::errors::non_retryable_from_str;
+use crate::headers_util::AuthHeadersBuilder;
+use crate::token::{CachedTokenProvider, Token, TokenProvider};
+use crate::token_cache::{TokenCache, TokenExpiration};
+use crate::{BuildResult, Result};
+use async_trait::async_trait;
+use google_cloud_gax::backoff_policy::BackoffPolicyArg;
+use google_cloud_gax::retry_policy::RetryPolicyArg;
+use google_cloud_gax::retry_throttler::RetryThrottlerArg;
+use http::{Extensions, HeaderMap};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::time::Instant;
+
+/// The maximum number of rules in a Credential Access Boundary.
+const MAX_RULES: usize = 10;
+
+/// A rule in a Credential Access Boundary.
+///
+/// Each rule names a resource, the permissions available on the resource,
+/// and an optional condition to further restrict the permissions.
+///
+/// # Example
+/// ```
+/// # use google_cloud_auth::credentials::downscoped::AccessBoundaryRule;
+/// let rule = AccessBoundaryRule::new(
+///     "//storage.googleapis.com/projects/_/buckets/my-bucket",
+///     ["inRole:roles/storage.objectViewer"],
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessBoundaryRule {
+    available_resource: String,
+    available_permissions: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    availability_condition: Option<AvailabilityCondition>,
+}
+
+impl AccessBoundaryRule {
+    /// Creates a new rule.
+    ///
+    /// The `resource` is the full resource name, for example,
+    /// `//storage.googleapis.com/projects/_/buckets/my-bucket`. The
+    /// `permissions` are IAM roles prefixed with `inRole:`, for example,
+    /// `inRole:roles/storage.objectViewer`.
+    pub fn new<R, I, P>(resource: R, permissions: I) -> Self
+    where
+        R: Into<String>,
+        I: IntoIterator<Item = P>,
+        P: Into<String>,
+    {
+        Self {
+            available_resource: resource.into(),
+            available_permissions: permissions.into_iter().map(Into::into).collect(),
+            availability_condition: None,
+        }
+    }
+
+    /// Sets the condition to restrict the permissions in this rule.
+    pub fn with_availability_condition(mut self, v: AvailabilityCondition) -> Self {
+        self.availability_condition = Some(v);
+        self
+    }
+
+    fn validate(&self) -> BuildResult<()> {
+        if self.available_resource.is_empty() {
+            return Err(BuilderError::parsing(
+                "the available resource in an access boundary rule must not be empty",
+            ));
+        }
+        if self.available_permissions.is_empty() {
+            return Err(BuilderError::parsing(
+                "the available permissions in an access boundary rule must not be empty",
+            ));
+        }
+        if let Some(p) = self
+            .available_permissions
+            .iter()
+            .find(|p| !p.starts_with("inRole:"))
+        {
+            return Err(BuilderError::parsing(format!(
+                "available permissions must start with `inRole:`, got `{p}`"
+            )));
+        }
+        if self
+            .availability_condition
+            .as_ref()
+            .is_some_and(|c| c.expression.is_empty())
+        {
+            return Err(BuilderError::parsing(
+                "the expression in an availability condition must not be empty",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// A condition to restrict the permissions in an [AccessBoundaryRule].
+///
+/// The condition is a [CEL] expression. For example, to restrict access to
+/// the objects with a given prefix in a Cloud Storage bucket:
+///
+/// ```
+/// # use google_cloud_auth::credentials::downscoped::AvailabilityCondition;
+/// let condition = AvailabilityCondition::new(
+///     "resource.name.startsWith('projects/_/buckets/my-bucket/objects/tenant-1/')",
+/// )
+/// .with_title("tenant-1 objects only");
+/// ```
+///
+/// [CEL]: https://cloud.google.com/iam/docs/conditions-overview#cel
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AvailabilityCondition {
+    expression: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+impl AvailabilityCondition {
+    /// Creates a new condition with the given CEL expression.
+    pub fn new<T: Into<String>>(expression: T) -> Self {
+        Self {
+            expression: expression.into(),
+            title: None,
+            description: None,
+        }
+    }
+
+    /// Sets the optional title for the condition.
+    pub fn with_title<T: Into<String>>(mut self, v: T) -> Self {
+        self.title = Some(v.into());
+        self
+    }
+
+    /// Sets the optional description for the condition.
+    pub fn with_description<T: Into<String>>(mut self, v: T) -> Self {
+        self.description = Some(v.into());
+        self
+    }
+}
+
+/// A builder for downscoped [Credentials] instances.
+///
+/// # Example
+/// ```
+/// # use google_cloud_auth::credentials::{Builder as AdcBuilder, downscoped};
+/// # use google_cloud_auth::credentials::downscoped::AccessBoundaryRule;
+/// # async fn sample() -> anyhow::Result<()> {
+/// let source = AdcBuilder::default().build()?;
+/// let rule = AccessBoundaryRule::new(
+///     "//storage.googleapis.com/projects/_/buckets/my-bucket",
+///     ["inRole:roles/storage.objectViewer"],
+/// );
+/// let credentials = downscoped::Builder::new(source, [rule]).build()?;
+/// # Ok(()) }
+/// ```
+pub struct Builder {
+    source_credentials: Credentials,
+    rules: Vec<AccessBoundaryRule>,
+    token_url: Option<String>,
+    retry_builder: crate::retry::Builder,
+}
+
+impl Builder {
+    /// Creates a new builder using the source credentials and the rules of the
+    /// Credential Access Boundary.
+    ///
+    /// The source credentials must provide access tokens. Tokens from the
+    /// source credentials are exchanged for downscoped tokens.
+    pub fn new<I>(source_credentials: Credentials, rules: I) -> Self
+    where
+        I: IntoIterator<Item = AccessBoundaryRule>,
+    {
+        Self {
+            source_credentials,
+            rules: rules.into_iter().collect(),
+            token_url: None,
+            retry_builder: crate::retry::Builder::default(),
+        }
+    }
+
+    /// Sets the token URL for the STS token exchange.
+    ///
+    /// If not provided, the Security Token Service in the universe domain of
+    /// the source credentials is used, `https://sts.googleapis.com/v1/token`
+    /// in the default universe domain.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_auth::credentials::{Builder as AdcBuilder, downscoped};
+    /// # use google_cloud_auth::credentials::downscoped::AccessBoundaryRule;
+    /// # async fn sample() -> anyhow::Result<()> {
+    /// # let source = AdcBuilder::default().build()?;
+    /// # let rules: Vec<AccessBoundaryRule> = vec![];
+    /// let builder = downscoped::Builder::new(source, rules)
+    ///     .with_token_url("https://sts.example.com/v1/token");
+    /// # Ok(()) }
+    /// ```
+    pub fn with_token_url<S: Into<String>>(mut self, token_url: S) -> Self {
+        self.token_url = Some(token_url.into());
+        self
+    }
+
+    /// Configure the retry policy for fetching tokens.
+    ///
+    /// The retry policy controls how to handle retries, and sets limits on
+    /// the number of attempts or the total time spent retrying.
+    ///
+    /// ```
+    /// # use google_cloud_auth::credentials::{Builder as AdcBuilder, downscoped};
+    /// # use google_cloud_auth::credentials::downscoped::AccessBoundaryRule;
+    /// # async fn sample() -> anyhow::Result<()> {
+    /// use google_cloud_gax::retry_policy::{AlwaysRetry, RetryPolicyExt};
+    /// # let source = AdcBuilder::default().build()?;
+    /// # let rules: Vec<AccessBoundaryRule> = vec![];
+    /// let credentials = downscoped::Builder::new(source, rules)
+    ///     .with_retry_policy(AlwaysRetry.with_attempt_limit(3))
+    ///     .build()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn with_retry_policy<V: Into<RetryPolicyArg>>(mut self, v: V) -> Self {
+        self.retry_builder = self.retry_builder.with_retry_policy(v.into());
+        self
+    }
+
+    /// Configure the retry backoff policy.
+    ///
+    /// The backoff policy controls how long to wait in between retry attempts.
+    ///
+    /// ```
+    /// # use google_cloud_auth::credentials::{Builder as AdcBuilder, downscoped};
+    /// # use google_cloud_auth::credentials::downscoped::AccessBoundaryRule;
+    /// # async fn sample() -> anyhow::Result<()> {
+    /// use google_cloud_gax::exponential_backoff::ExponentialBackoff;
+    /// # let source = AdcBuilder::default().build()?;
+    /// # let rules: Vec<AccessBoundaryRule> = vec![];
+    /// let credentials = downscoped::Builder::new(source, rules)
+    ///     .with_backoff_policy(ExponentialBackoff::default())
+    ///     .build()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn with_backoff_policy<V: Into<BackoffPolicyArg>>(mut self, v: V) -> Self {
+        self.retry_builder = self.retry_builder.with_backoff_policy(v.into());
+        self
+    }
+
+    /// Configure the retry throttler.
+    ///
+    /// Advanced applications may want to configure a retry throttler to
+    /// [Address Cascading Failures] and when [Handling Overload] conditions.
+    /// The authentication library throttles its retry loop, using a policy to
+    /// control the throttling algorithm. Use this method to fine tune or
+    /// customize the default retry throttler.
+    ///
+    /// [Handling Overload]: https://sre.google/sre-book/handling-overload/
+    /// [Address Cascading Failures]: https://sre.google/sre-book/addressing-cascading-failures/
+    ///
+    /// ```
+    /// # use google_cloud_auth::credentials::{Builder as AdcBuilder, downscoped};
+    /// # use google_cloud_auth::credentials::downscoped::AccessBoundaryRule;
+    /// # async fn sample() -> anyhow::Result<()> {
+    /// use google_cloud_gax::retry_throttler::AdaptiveThrottler;
+    /// # let source = AdcBuilder::default().build()?;
+    /// # let rules: Vec<AccessBoundaryRule> = vec![];
+    /// let credentials = downscoped::Builder::new(source, rules)
+    ///     .with_retry_throttler(AdaptiveThrottler::default())
+    ///     .build()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn with_retry_throttler<V: Into<RetryThrottlerArg>>(mut self, v: V) -> Self {
+        self.retry_builder = self.retry_builder.with_retry_throttler(v.into());
+        self
+    }
+
+    /// Returns a [Credentials] instance with the configured settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Credential Access Boundary is invalid. The
+    /// boundary must have between 1 and 10 rules, each rule must have a
+    /// resource and at least one permission, and all the permissions must
+    /// start with `inRole:`.
+    pub fn build(self) -> BuildResult<Credentials> {
+        Ok(Credentials {
+            inner: Arc::new(self.build_credentials()?),
+        })
+    }
+
+    /// Returns an [AccessTokenCredentials] instance with the configured settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Credential Access Boundary is invalid. The
+    /// boundary must have between 1 and 10 rules, each rule must have a
+    /// resource and at least one permission, and all the permissions must
+    /// start with `inRole:`.
+    pub fn build_access_token_credentials(self) -> BuildResult<AccessTokenCredentials> {
+        Ok(AccessTokenCredentials {
+            inner: Arc::new(self.build_credentials()?),
+        })
+    }
+
+    fn build_credentials(self) -> BuildResult<DownscopedCredentials<TokenCache>> {
+        if self.rules.is_empty() || self.rules.len() > MAX_RULES {
+            return Err(BuilderError::parsing(format!(
+                "a credential access boundary must have between 1 and {MAX_RULES} rules, got {}",
+                self.rules.len()
+            )));
+        }
+        self.rules
+            .iter()
+            .try_for_each(AccessBoundaryRule::validate)?;
+
+        let token_provider = DownscopedTokenProvider {
+            source_credentials: self.source_credentials.clone(),
+            rules: self.rules,
+            token_url: self.token_url,
+        };
+        let token_provider = self.retry_builder.build(token_provider);
+        Ok(DownscopedCredentials {
+            token_provider: TokenCache::new(token_provider),
+            source_credentials: self.source_credentials,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct DownscopedCredentials<T>
+where
+    T: CachedTokenProvider,
+{
+    token_provider: T,
+    source_credentials: Credentials,
+}
+
+#[async_trait]
+impl<T> CredentialsProvider for DownscopedCredentials<T>
+where
+    T: CachedTokenProvider,
+{
+    async fn headers(&self, extensions: Extensions) -> Result<CacheableResource<HeaderMap>> {
+        let token = self.token_provider.token(extensions).await?;
+        AuthHeadersBuilder::new(&token).build()
+    }
+
+    async fn universe_domain(&self) -> Option<String> {
+        self.source_credentials.universe_domain().await
+    }
+}
+
+#[async_trait]
+impl<T> AccessTokenCredentialsProvider for DownscopedCredentials<T>
+where
+    T: CachedTokenProvider,
+{
+    async fn access_token(&self) -> Result<AccessToken> {
+        let token = self.token_provider.token(Extensions::new()).await?;
+        token.into()
+    }
+}
+
+#[derive(Debug)]
+struct DownscopedTokenProvider {
+    source_credentials: Credentials,
+    rules: Vec<AccessBoundaryRule>,
+    token_url: Option<String>,
+}
+
+impl DownscopedTokenProvider {
+    /// Returns the source token, and its expiration time if the source
+    /// credentials report it.
+    async fn source_token(&self) -> Result<(String, Option<Instant>)> {
+        let expiration = TokenExpiration::default();
+        let mut extensions = Extensions::new();
+        extensions.insert(expiration.clone());
+        let headers = match self.source_credentials.headers(extensions).await? {
+            CacheableResource::New { data, .. } => data,
+            CacheableResource::NotModified => {
+                unreachable!("requested source credentials without a caching etag")
+            }
+        };
+        headers
+            .get(http::header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .map(|token| (token.to_string(), expiration.get()))
+            .ok_or_else(|| {
+                non_retryable_from_str("the source credentials must provide a bearer token")
+            })
+    }
+
+    // The universe domain is only available asynchronously, so the URL is
+    // resolved for each token exchange.
+    async fn token_url(&self) -> String {
+        if let Some(url) = &self.token_url {
+            return url.clone();
+        }
+        match self.source_credentials.universe_domain().await {
+            Some(ud) if ud != DEFAULT_UNIVERSE_DOMAIN => format!("https://sts.{ud}/v1/token"),
+            _ => STS_TOKEN_URL.to_string(),
+        }
+    }
+
+    fn options(&self) -> HashMap<String, serde_json::Value> {
+        let boundary = serde_json::json!({ "accessBoundaryRules": self.rules });
+        HashMap::from([("accessBoundary".to_string(), boundary)])
+    }
+}
+
+#[async_trait]
+impl TokenProvider for DownscopedTokenProvider {
+    async fn token(&self) -> Result<Token> {
+        let (source_token, source_expires_at) = self.source_token().await?;
+        let req = ExchangeTokenRequest {
+            url: self.token_url().await,
+            subject_token: source_token,
+            subject_token_type: ACCESS_TOKEN_TYPE.to_string(),
+            extra_options: Some(self.options()),
+            ..ExchangeTokenRequest::default()
+        };
+        let resp = STSHandler::default().exchange_token(req).await?;
+        // Without a lifetime in the response, the downscoped token expires
+        // with the source token.
+        let expires_at = match (resp.expires_at(), source_expires_at) {
+            (Some(e), _) | (None, Some(e)) => e,
+            (None, None) => resp.required_expires_at()?,
+        };
+        Ok(Token {
+            token: resp.access_token,
+            token_type: resp.token_type,
+            expires_at: Some(expires_at),
+            metadata: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::TOKEN_EXCHANGE_GRANT_TYPE;
+    use crate::credentials::tests::{MockCredentials, get_token_from_headers};
+    use crate::token::tests::MockTokenProvider;
+    use httptest::{Expectation, Server, matchers::*, responders::*};
+    use serde_json::json;
+    use test_case::test_case;
+
+    type TestResult = anyhow::Result<()>;
+
+    fn source_credentials(
+        token: &'static str,
+        universe_domain: Option<&'static str>,
+    ) -> Credentials {
+        let mut mock = MockCredentials::new();
+        mock.expect_headers().returning(move |_| {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                http::header::AUTHORIZATION,
+                http::HeaderValue::from_static(token),
+            );
+            Ok(CacheableResource::New {
+                entity_tag: Default::default(),
+                data: headers,
+            })
+        });
+        mock.expect_universe_domain()
+            .returning(move || universe_domain.map(str::to_string));
+        Credentials::from(mock)
+    }
+
+    fn test_rule() -> AccessBoundaryRule {
+        AccessBoundaryRule::new(
+            "//storage.googleapis.com/projects/_/buckets/my-bucket",
+            ["inRole:roles/storage.objectViewer"],
+        )
+        .with_availability_condition(
+            AvailabilityCondition::new(
+                "resource.name.startsWith('projects/_/buckets/my-bucket/objects/tenant-1/')",
+            )
+            .with_title("tenant-1")
+            .with_description("only objects for tenant-1"),
+        )
+    }
+
+    #[test]
+    fn serialize_rule() -> TestResult {
+        let got = serde_json::to_value(test_rule())?;
+        let want = json!({
+            "availableResource": "//storage.googleapis.com/projects/_/buckets/my-bucket",
+            "availablePermissions": ["inRole:roles/storage.objectViewer"],
+            "availabilityCondition": {
+                "expression": "resource.name.startsWith('projects/_/buckets/my-bucket/objects/tenant-1/')",
+                "title": "tenant-1",
+                "description": "only objects for tenant-1",
+            }
+        });
+        assert_eq!(got, want);
+
+        let got = serde_json::to_value(AccessBoundaryRule::new("r", ["inRole:p"]))?;
+        assert_eq!(
+            got,
+            json!({"availableResource": "r", "availablePermissions": ["inRole:p"]})
+        );
+        Ok(())
+    }
+
+    #[test_case(vec![]; "no rules")]
+    #[test_case(vec![test_rule(); 11]; "too many rules")]
+    #[test_case(vec![AccessBoundaryRule::new("", ["inRole:p"])]; "empty resource")]
+    #[test_case(vec![AccessBoundaryRule::new("r", Vec::<String>::new())]; "empty permissions")]
+    #[test_case(vec![AccessBoundaryRule::new("r", ["roles/storage.objectViewer"])]; "bad permission")]
+    #[test_case(vec![AccessBoundaryRule::new("r", ["inRole:p"]).with_availability_condition(AvailabilityCondition::new(""))]; "empty expression")]
+    fn build_invalid(rules: Vec<AccessBoundaryRule>) {
+        let err = Builder::new(source_credentials("Bearer source", None), rules)
+            .build()
+            .unwrap_err();
+        assert!(err.is_parsing(), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn exchange() -> TestResult {
+        let server = Server::run();
+        let options = json!({ "accessBoundary": { "accessBoundaryRules": [test_rule()] } });
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/v1/token"),
+                request::body(url_decoded(contains((
+                    "grant_type",
+                    TOKEN_EXCHANGE_GRANT_TYPE
+                )))),
+                request::body(url_decoded(contains(("subject_token", "source-token")))),
+                request::body(url_decoded(contains((
+                    "subject_token_type",
+                    ACCESS_TOKEN_TYPE
+                )))),
+                request::body(url_decoded(contains((
+                    "requested_token_type",
+                    ACCESS_TOKEN_TYPE
+                )))),
+                request::body(url_decoded(contains(("options", options.to_string())))),
+            ])
+            .times(1)
+            .respond_with(json_encoded(json!({
+                "access_token": "downscoped-token",
+                "issued_token_type": ACCESS_TOKEN_TYPE,
+                "token_type": "Bearer",
+                "expires_in": 3600,
+            }))),
+        );
+
+        let credentials = Builder::new(
+            source_credentials("Bearer source-token", None),
+            [test_rule()],
+        )
+        .with_token_url(server.url("/v1/token").to_string())
+        .build()?;
+        let headers = credentials.headers(Extensions::new()).await?;
+        assert_eq!(
+            get_token_from_headers(headers).as_deref(),
+            Some("downscoped-token")
+        );
+
+        // The token is cached.
+        let headers = credentials.headers(Extensions::new()).await?;
+        assert_eq!(
+            get_token_from_headers(headers).as_deref(),
+            Some("downscoped-token")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn access_token_credentials() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("POST", "/v1/token")).respond_with(
+                json_encoded(json!({
+                    "access_token": "downscoped-token",
+                    "issued_token_type": ACCESS_TOKEN_TYPE,
+                    "token_type": "Bearer",
+                    "expires_in": 3600,
+                })),
+            ),
+        );
+        let credentials = Builder::new(
+            source_credentials("Bearer source-token", None),
+            [test_rule()],
+        )
+        .with_token_url(server.url("/v1/token").to_string())
+        .build_access_token_credentials()?;
+        let token = credentials.access_token().await?;
+        assert_eq!(token.token, "downscoped-token");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exchange_error() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("POST", "/v1/token"))
+                .respond_with(status_code(400).body("bad boundary")),
+        );
+        let credentials = Builder::new(
+            source_credentials("Bearer source-token", None),
+            [test_rule()],
+        )
+        .with_token_url(server.url("/v1/token").to_string())
+        .build()?;
+        let err = credentials.headers(Extensions::new()).await.unwrap_err();
+        assert!(!err.is_transient(), "{err:?}");
+        Ok(())
+    }
+
+    // Returns credentials backed by a `TokenCache`, which report the
+    // expiration time of their token.
+    fn cached_source_credentials(expires_at: Option<Instant>) -> Credentials {
+        let mut mock = MockTokenProvider::new();
+        mock.expect_token().returning(move || {
+            Ok(Token {
+                token: "source-token".to_string(),
+                token_type: "Bearer".to_string(),
+                expires_at,
+                metadata: None,
+            })
+        });
+        Credentials {
+            inner: Arc::new(DownscopedCredentials {
+                token_provider: TokenCache::new(mock),
+                source_credentials: source_credentials("Bearer unused", None),
+            }),
+        }
+    }
+
+    fn exchange_without_expires_in(server: &Server) {
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/v1/token"),
+                request::body(url_decoded(contains(("subject_token", "source-token")))),
+            ])
+            .respond_with(json_encoded(json!({
+                "access_token": "downscoped-token",
+                "issued_token_type": ACCESS_TOKEN_TYPE,
+                "token_type": "Bearer",
+            }))),
+        );
+    }
+
+    #[tokio::test]
+    async fn expiration_from_source() -> TestResult {
+        let server = Server::run();
+        exchange_without_expires_in(&server);
+        let source_expires_at = Instant::now() + std::time::Duration::from_secs(1234);
+        let provider = DownscopedTokenProvider {
+            source_credentials: cached_source_credentials(Some(source_expires_at)),
+            rules: vec![test_rule()],
+            token_url: Some(server.url("/v1/token").to_string()),
+        };
+        let token = provider.token().await?;
+        assert_eq!(token.token, "downscoped-token");
+        assert_eq!(token.expires_at, Some(source_expires_at));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn expiration_unknown() -> TestResult {
+        let server = Server::run();
+        exchange_without_expires_in(&server);
+        let provider = DownscopedTokenProvider {
+            source_credentials: cached_source_credentials(None),
+            rules: vec![test_rule()],
+            token_url: Some(server.url("/v1/token").to_string()),
+        };
+        let err = provider.token().await.unwrap_err();
+        assert!(!err.is_transient(), "{err:?}");
+        assert!(format!("{err:?}").contains("expires_in"), "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn source_without_bearer_token() -> TestResult {
+        let credentials = Builder::new(source_credentials("Basic abc", None), [test_rule()])
+            .with_token_url("http://localhost/v1/token")
+            .build()?;
+        let err = credentials.headers(Extensions::new()).await.unwrap_err();
+        assert!(!err.is_transient(), "{err:?}");
+        assert!(format!("{err:?}").contains("bearer token"), "{err:?}");
+        Ok(())
+    }
+
+    #[test_case(None, STS_TOKEN_URL; "default universe")]
+    #[test_case(Some("googleapis.com"), STS_TOKEN_URL; "explicit default universe")]
+    #[test_case(Some("my-universe.com"), "https://sts.my-universe.com/v1/token"; "custom universe")]
+    #[tokio::test]
+    async fn token_url_from_universe_domain(
+        universe_domain: Option<&'static str>,
+        want: &str,
+    ) -> TestResult {
+        let provider = DownscopedTokenProvider {
+            source_credentials: source_credentials("Bearer source-token", universe_domain),
+            rules: vec![test_rule()],
+            token_url: None,
+        };
+        assert_eq!(provider.token_url().await, want);
+
+        let credentials = Builder::new(
+            source_credentials("Bearer source-token", universe_domain),
+            [test_rule()],
+        )
+        .build()?;
+        assert_eq!(
+            credentials.universe_domain().await.as_deref(),
+            universe_domain
+        );
+        Ok(())
+    }
+}
@@ -23,7 +23,8 @@
 //!
 //! Refer to [Obtain short-lived tokens for Workforce Identity Federation] for
 //! creating configurations that can be used with this library for loading credentials
-//! using various external toke provider sources such as file, URL, or an executable.
+//! using various external toke provider sources such as file, URL, an executable,
+//! or an X.509 certificate.
 //!
 //! ## Example: Creating credentials from a JSON object
 //!
@@ -110,6 +111,7 @@ use super::external_account_sources::aws_sourced::AwsSourcedCredentials;
 use super::external_account_sources::executable_sourced::ExecutableSourcedCredentials;
 use super::external_account_sources::file_sourced::FileSourcedCredentials;
 use super::external_account_sources::url_sourced::UrlSourcedCredentials;
+use super::external_account_sources::x509_sourced::{CertificateConfig, X509SourcedCredentials};
 use super::impersonated;
 use super::internal::sts_exchange::{ClientAuthentication, ExchangeTokenRequest, STSHandler};
 use super::{CacheableResource, Credentials};
@@ -119,6 +121,8 @@ use crate::constants::{DEFAULT_SCOPE, DEFAULT_UNIVERSE_DOMAIN, STS_TOKEN_URL};
 use crate::credentials::dynamic::AccessTokenCredentialsProvider;
 use crate::credentials::external_account_sources::programmatic_sourced::ProgrammaticSourcedCredentials;
 use crate::credentials::subject_token::dynamic;
+use crate::credentials::token_store::dynamic::TokenStore as TokenStoreDyn;
+use crate::credentials::token_store::{PersistentTokenProvider, TokenStore, cache_key};
 use crate::credentials::{AccessToken, AccessTokenCredentials};
 use crate::errors::non_retryable;
 use crate::headers_util::AuthHeadersBuilder;
@@ -134,7 +138,6 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::time::{Duration, Instant};
 
 const IAM_SCOPE: &str = "https://www.googleapis.com/auth/iam";
 
@@ -167,6 +170,9 @@ enum CredentialSourceFile {
     Executable {
         executable: ExecutableConfig,
     },
+    Certificate {
+        certificate: CertificateConfig,
+    },
     Url {
         url: String,
         headers: Option<HashMap<String, String>>,
@@ -252,6 +258,9 @@ impl CredentialSource {
             CredentialSourceFile::File { file, format } => {
                 Self::File(FileSourcedCredentials::new(file, format))
             }
+            CredentialSourceFile::Certificate { certificate } => {
+                Self::X509(X509SourcedCredentials::new(certificate))
+            }
             CredentialSourceFile::Aws {
                 region_url,
                 url,
@@ -389,6 +398,7 @@ enum CredentialSource {
     Executable(ExecutableSourcedCredentials),
     File(FileSourcedCredentials),
     Aws(AwsSourcedCredentials),
+    X509(X509SourcedCredentials),
     Programmatic(ProgrammaticSourcedCredentials),
 }
 
@@ -397,24 +407,59 @@ impl ExternalAccountConfig {
         self,
         quota_project_id: Option<String>,
         retry_builder: RetryTokenProviderBuilder,
+        token_store: Option<Arc<dyn TokenStoreDyn>>,
+        key: String,
     ) -> ExternalAccountCredentials<TokenCache> {
         let config = self.clone();
         match self.credential_source {
-            CredentialSource::Url(source) => {
-                Self::make_credentials_from_source(source, config, quota_project_id, retry_builder)
-            }
-            CredentialSource::Executable(source) => {
-                Self::make_credentials_from_source(source, config, quota_project_id, retry_builder)
-            }
-            CredentialSource::Programmatic(source) => {
-                Self::make_credentials_from_source(source, config, quota_project_id, retry_builder)
-            }
-            CredentialSource::File(source) => {
-                Self::make_credentials_from_source(source, config, quota_project_id, retry_builder)
-            }
-            CredentialSource::Aws(source) => {
-                Self::make_credentials_from_source(source, config, quota_project_id, retry_builder)
-            }
+            CredentialSource::Url(source) => Self::make_credentials_from_source(
+                source,
+                config,
+                quota_project_id,
+                retry_builder,
+                token_store,
+                key,
+            ),
+            CredentialSource::Executable(source) => Self::make_credentials_from_source(
+                source,
+                config,
+                quota_project_id,
+                retry_builder,
+                token_store,
+                key,
+            ),
+            CredentialSource::Programmatic(source) => Self::make_credentials_from_source(
+                source,
+                config,
+                quota_project_id,
+                retry_builder,
+                token_store,
+                key,
+            ),
+            CredentialSource::File(source) => Self::make_credentials_from_source(
+                source,
+                config,
+                quota_project_id,
+                retry_builder,
+                token_store,
+                key,
+            ),
+            CredentialSource::Aws(source) => Self::make_credentials_from_source(
+                source,
+                config,
+                quota_project_id,
+                retry_builder,
+                token_store,
+                key,
+            ),
+            CredentialSource::X509(source) => Self::make_credentials_from_source(
+                source,
+                config,
+                quota_project_id,
+                retry_builder,
+                token_store,
+                key,
+            ),
         }
     }
 
@@ -423,6 +468,8 @@ impl ExternalAccountConfig {
         config: ExternalAccountConfig,
         quota_project_id: Option<String>,
         retry_builder: RetryTokenProviderBuilder,
+        token_store: Option<Arc<dyn TokenStoreDyn>>,
+        key: String,
     ) -> ExternalAccountCredentials<TokenCache>
     where
         T: dynamic::SubjectTokenProvider + 'static,
@@ -433,7 +480,11 @@ impl ExternalAccountConfig {
             config,
         };
         let token_provider_with_retry = retry_builder.build(token_provider);
-        let cache = TokenCache::new(token_provider_with_retry);
+        let cache = TokenCache::new(PersistentTokenProvider::new(
+            token_provider_with_retry,
+            token_store,
+            key,
+        ));
         ExternalAccountCredentials {
             token_provider: cache,
             quota_project_id,
@@ -457,7 +508,15 @@ where
     T: dynamic::SubjectTokenProvider,
 {
     async fn token(&self) -> Result<Token> {
-        let subject_token = self.subject_token_provider.subject_token().await?;
+        // X.509 credentials authenticate the token exchange with the same
+        // workload certificate used as the subject token.
+        let (subject_token, client_certificate) = match &self.config.credential_source {
+            CredentialSource::X509(source) => {
+                let (token, certificate) = source.subject_token_and_certificate()?;
+                (token, Some(certificate))
+            }
+            _ => (self.subject_token_provider.subject_token().await?, None),
+        };
 
         let audience = self.config.audience.clone();
         let subject_token_type = self.config.subject_token_type.clone();
@@ -469,7 +528,7 @@ where
                 let workforce_pool_user_project = self.config.workforce_pool_user_project.clone();
                 workforce_pool_user_project.map(|project| {
                     let mut options = HashMap::new();
-                    options.insert("userProject".to_string(), project);
+                    options.insert("userProject".to_string(), project.into());
                     options
                 })
             } else {
@@ -501,7 +560,10 @@ where
             ..ExchangeTokenRequest::default()
         };
 
-        let token_res = STSHandler::default().exchange_token(req).await?;
+        let token_res = STSHandler::default()
+            .with_client_certificate(client_certificate)
+            .exchange_token(req)
+            .await?;
 
         if let Some(impersonation_url) = &self.config.service_account_impersonation_url {
             let mut headers = HeaderMap::new();
@@ -521,10 +583,11 @@ where
             .await;
         }
 
+        let expires_at = token_res.required_expires_at()?;
         let token = Token {
             token: token_res.access_token,
             token_type: token_res.token_type,
-            expires_at: Some(Instant::now() + Duration::from_secs(token_res.expires_in)),
+            expires_at: Some(expires_at),
             metadata: None,
         };
         Ok(token)
@@ -591,6 +654,7 @@ pub struct Builder {
     universe_domain: Option<String>,
     retry_builder: RetryTokenProviderBuilder,
     iam_endpoint_override: Option<String>,
+    token_store: Option<Arc<dyn TokenStoreDyn>>,
 }
 
 impl Builder {
@@ -605,6 +669,7 @@ impl Builder {
             universe_domain: None,
             retry_builder: RetryTokenProviderBuilder::default(),
             iam_endpoint_override: None,
+            token_store: None,
         }
     }
 
@@ -746,6 +811,36 @@ impl Builder {
         self
     }
 
+    /// Sets the [TokenStore] to share access tokens across processes.
+    ///
+    /// Before fetching a new access token, the credentials look for a valid
+    /// token in the store, and save any new tokens to it. Use this with
+    /// short-lived processes, such as command-line tools, to avoid fetching a
+    /// new token on every invocation.
+    ///
+    /// ```
+    /// # use google_cloud_auth::credentials::external_account::Builder;
+    /// # use google_cloud_auth::credentials::token_store::FileTokenStore;
+    /// # use serde_json::json;
+    /// # async fn sample() -> anyhow::Result<()> {
+    /// let config = json!({ /* add details here */ });
+    /// let credentials = Builder::new(config)
+    ///     .with_token_store(FileTokenStore::new("/var/cache/my-tool/tokens.json"))
+    ///     .build()?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [TokenStore]: crate::credentials::token_store::TokenStore
+    pub fn with_token_store<S: TokenStore + 'static>(mut self, store: S) -> Self {
+        self.token_store = Some(Arc::new(store));
+        self
+    }
+
+    pub(crate) fn maybe_token_store(mut self, store: Option<Arc<dyn TokenStoreDyn>>) -> Self {
+        self.token_store = store.or(self.token_store);
+        self
+    }
+
     #[cfg(all(test, google_cloud_unstable_trust_boundaries))]
     fn maybe_iam_endpoint_override(mut self, iam_endpoint_override: Option<String>) -> Self {
         self.iam_endpoint_override = iam_endpoint_override;
@@ -789,6 +884,13 @@ impl Builder {
     fn build_credentials(
         self,
     ) -> BuildResult<CredentialsWithAccessBoundary<ExternalAccountCredentials<TokenCache>>> {
+        // The configuration identifies the workload, including its credential
+        // source, the audience, and any service account impersonation.
+        let key = cache_key(
+            &format!("external_account:{}", self.external_account_config),
+            self.scopes.as_deref().unwrap_or_default(),
+            self.universe_domain.as_deref(),
+        );
         let mut file: ExternalAccountFile =
             serde_json::from_value(self.external_account_config).map_err(BuilderError::parsing)?;
 
@@ -808,12 +910,21 @@ impl Builder {
             ));
         }
 
+        if let CredentialSourceFile::Certificate { certificate } = &file.credential_source {
+            certificate.validate().map_err(BuilderError::parsing)?;
+        }
+
         let config: ExternalAccountConfig = file.into();
 
         let access_boundary_url =
             external_account_lookup_url(&config.audience, self.iam_endpoint_override.as_deref());
 
-        let creds = config.make_credentials(self.quota_project_id, self.retry_builder);
+        let creds = config.make_credentials(
+            self.quota_project_id,
+            self.retry_builder,
+            self.token_store,
+            key,
+        );
 
         Ok(CredentialsWithAccessBoundary::new(
             creds,
@@ -1453,7 +1564,7 @@ impl ProgrammaticBuilder {
     /// `audience` or `subject_token_type`) have not been set.
     pub fn build(self) -> BuildResult<Credentials> {
         let (config, quota_project_id, retry_builder) = self.build_components()?;
-        let creds = config.make_credentials(quota_project_id, retry_builder);
+        let creds = config.make_credentials(quota_project_id, retry_builder, None, String::new());
         Ok(Credentials {
             inner: Arc::new(creds),
         })
@@ -1546,6 +1657,7 @@ mod tests {
     use std::fmt;
     use test_case::test_case;
     use time::OffsetDateTime;
+    use tokio::time::Duration;
 
     #[derive(Debug)]
     struct TestProviderError;
@@ -1790,6 +1902,103 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn create_external_account_detect_x509_sourced() {
+        let contents = json!({
+            "type": "external_account",
+            "audience": "audience",
+            "subject_token_type": "urn:ietf:params:oauth:token-type:mtls",
+            "token_url": "https://sts.mtls.googleapis.com/v1/token",
+            "credential_source": {
+                "certificate": {
+                    "certificate_config_location": "/foo/certificate_config.json",
+                    "trust_chain_path": "/foo/chain.pem"
+                }
+            }
+        });
+
+        let file: ExternalAccountFile =
+            serde_json::from_value(contents).expect("failed to parse external account config");
+        let config: ExternalAccountConfig = file.into();
+        let source = config.credential_source;
+
+        match source {
+            CredentialSource::X509(source) => {
+                let fmt = format!("{source:?}");
+                assert!(fmt.contains("/foo/certificate_config.json"), "{fmt}");
+                assert!(fmt.contains("/foo/chain.pem"), "{fmt}");
+            }
+            _ => {
+                unreachable!("expected X509 Sourced credential")
+            }
+        }
+    }
+
+    #[test_case(json!({"use_default_certificate_config": true, "certificate_config_location": "/foo"}); "both")]
+    #[test_case(json!({"trust_chain_path": "/foo"}); "neither")]
+    fn create_external_account_x509_invalid(certificate: Value) {
+        let contents = json!({
+            "type": "external_account",
+            "audience": "audience",
+            "subject_token_type": "urn:ietf:params:oauth:token-type:mtls",
+            "token_url": "https://sts.mtls.googleapis.com/v1/token",
+            "credential_source": {
+                "certificate": certificate
+            }
+        });
+        let err = Builder::new(contents).build().unwrap_err();
+        assert!(err.is_parsing(), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn test_external_account_x509_sourced() -> anyhow::Result<()> {
+        let dir = tempfile::TempDir::new()?;
+        let config_path = crate::mtls::tests::write_certificate_config(&dir)?;
+        let sts_server = Server::run();
+        let contents = json!({
+            "type": "external_account",
+            "audience": "audience",
+            "subject_token_type": "urn:ietf:params:oauth:token-type:mtls",
+            "token_url": sts_server.url("/token").to_string(),
+            "credential_source": {
+                "certificate": {
+                    "certificate_config_location": config_path,
+                }
+            }
+        });
+        let subject_token = X509SourcedCredentials::new(CertificateConfig {
+            certificate_config_location: Some(config_path.to_string_lossy().to_string()),
+            ..CertificateConfig::default()
+        })
+        .subject_token()
+        .await?
+        .token;
+
+        sts_server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/token"),
+                request::body(url_decoded(contains(("subject_token", subject_token)))),
+                request::body(url_decoded(contains((
+                    "subject_token_type",
+                    "urn:ietf:params:oauth:token-type:mtls"
+                )))),
+                request::body(url_decoded(contains(("audience", "audience")))),
+            ])
+            .respond_with(json_encoded(json!({
+                "access_token": "sts-only-token",
+                "issued_token_type": "urn:ietf:params:oauth:token-type:access_token",
+                "token_type": "Bearer",
+                "expires_in": 3600,
+            }))),
+        );
+
+        let creds = Builder::new(contents).build()?;
+        let headers = creds.headers(Extensions::new()).await?;
+        let token = get_token_from_headers(headers);
+        assert_eq!(token.as_deref(), Some("sts-only-token"));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_external_account_with_impersonation_success() {
         let subject_token_server = Server::run();
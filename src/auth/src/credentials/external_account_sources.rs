@@ -17,3 +17,4 @@ pub mod executable_sourced;
 pub mod file_sourced;
 pub mod programmatic_sourced;
 pub mod url_sourced;
+pub mod x509_sourced;
@@ -0,0 +1,291 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64::Engine;
+use google_cloud_gax::error::CredentialsError;
+use rustls_pki_types::CertificateDer;
+use rustls_pki_types::pem::PemObject;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::{
+    Result,
+    credentials::subject_token::{
+        Builder as SubjectTokenBuilder, SubjectToken, SubjectTokenProvider,
+    },
+    mtls::{self, ClientCertificate},
+};
+
+/// The `credential_source.certificate` section of an external account
+/// configuration.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub(crate) struct CertificateConfig {
+    #[serde(default)]
+    pub use_default_certificate_config: bool,
+    pub certificate_config_location: Option<String>,
+    pub trust_chain_path: Option<String>,
+}
+
+impl CertificateConfig {
+    /// Returns an error unless exactly one source of the certificate
+    /// configuration is set.
+    pub(crate) fn validate(&self) -> std::result::Result<(), &'static str> {
+        match (
+            self.use_default_certificate_config,
+            &self.certificate_config_location,
+        ) {
+            (true, Some(_)) => Err(
+                "certificate_config_location must not be set when use_default_certificate_config is true",
+            ),
+            (false, None) => Err(
+                "either use_default_certificate_config or certificate_config_location must be set",
+            ),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Subject tokens from an X.509 workload certificate.
+///
+/// The subject token is a JSON array with the base64 encoded (DER)
+/// certificates, starting with the leaf certificate, followed by the optional
+/// trust chain. The STS exchange must use the same certificate to establish
+/// the mTLS connection.
+#[derive(Debug, Clone)]
+pub(crate) struct X509SourcedCredentials {
+    certificate_config_location: Option<String>,
+    trust_chain_path: Option<String>,
+}
+
+impl X509SourcedCredentials {
+    pub(crate) fn new(config: CertificateConfig) -> Self {
+        Self {
+            certificate_config_location: config.certificate_config_location,
+            trust_chain_path: config.trust_chain_path,
+        }
+    }
+
+    /// Loads the workload certificate and its private key.
+    ///
+    /// The certificate is loaded for each token exchange, as workload
+    /// certificates are rotated frequently.
+    fn client_certificate(&self) -> Result<ClientCertificate> {
+        let path = self
+            .certificate_config_location
+            .as_ref()
+            .map(PathBuf::from)
+            .or_else(mtls::certificate_config_path)
+            .ok_or_else(|| {
+                CredentialsError::from_msg(
+                    false,
+                    "cannot find the default certificate configuration location",
+                )
+            })?;
+        mtls::load_client_certificate(&path)
+    }
+
+    /// Loads the workload certificate, and returns the subject token for it.
+    ///
+    /// The STS exchange must use the same certificate that is in the subject
+    /// token, so the certificate is loaded once for both.
+    pub(crate) fn subject_token_and_certificate(
+        &self,
+    ) -> Result<(SubjectToken, ClientCertificate)> {
+        let certificate = self.client_certificate()?;
+        let token = self.subject_token_for(&certificate)?;
+        Ok((token, certificate))
+    }
+
+    fn subject_token_for(&self, certificate: &ClientCertificate) -> Result<SubjectToken> {
+        let leaf = CertificateDer::from_pem_slice(certificate.cert_pem())
+            .map_err(|e| CredentialsError::new(false, "failed to parse workload certificate", e))?;
+        let mut chain = vec![leaf];
+        for (i, cert) in self.trust_chain()?.into_iter().enumerate() {
+            match (i, cert == chain[0]) {
+                // The leaf certificate may be repeated at the start of the
+                // trust chain.
+                (0, true) => continue,
+                (_, true) => {
+                    return Err(CredentialsError::from_msg(
+                        false,
+                        "the workload certificate must be at the start of the trust chain",
+                    ));
+                }
+                _ => chain.push(cert),
+            }
+        }
+        let encoded = chain
+            .iter()
+            .map(|c| base64::engine::general_purpose::STANDARD.encode(c))
+            .collect::<Vec<_>>();
+        let token =
+            serde_json::to_string(&encoded).map_err(|e| CredentialsError::from_source(false, e))?;
+        Ok(SubjectTokenBuilder::new(token).build())
+    }
+
+    fn trust_chain(&self) -> Result<Vec<CertificateDer<'static>>> {
+        let Some(path) = &self.trust_chain_path else {
+            return Ok(Vec::new());
+        };
+        let pem = std::fs::read(path).map_err(|e| {
+            CredentialsError::new(false, format!("failed to read trust chain from {path}"), e)
+        })?;
+        CertificateDer::pem_slice_iter(&pem)
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| {
+                CredentialsError::new(false, format!("failed to parse trust chain from {path}"), e)
+            })
+    }
+}
+
+impl SubjectTokenProvider for X509SourcedCredentials {
+    type Error = CredentialsError;
+    async fn subject_token(&self) -> Result<SubjectToken> {
+        self.subject_token_and_certificate().map(|(token, _)| token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mtls::tests::{TEST_CERT, TEST_INTERMEDIATE, write_certificate_config};
+    use tempfile::TempDir;
+    use test_case::test_case;
+
+    type TestResult = anyhow::Result<()>;
+
+    fn der(pem: &str) -> String {
+        let der = CertificateDer::from_pem_slice(pem.as_bytes()).unwrap();
+        base64::engine::general_purpose::STANDARD.encode(der)
+    }
+
+    fn credentials(
+        dir: &TempDir,
+        trust_chain: Option<&str>,
+    ) -> anyhow::Result<X509SourcedCredentials> {
+        let config = write_certificate_config(dir)?;
+        let trust_chain_path = match trust_chain {
+            None => None,
+            Some(contents) => {
+                let path = dir.path().join("chain.pem");
+                std::fs::write(&path, contents)?;
+                Some(path.to_string_lossy().to_string())
+            }
+        };
+        Ok(X509SourcedCredentials::new(CertificateConfig {
+            certificate_config_location: Some(config.to_string_lossy().to_string()),
+            trust_chain_path,
+            ..CertificateConfig::default()
+        }))
+    }
+
+    #[test_case(true, None, true; "default")]
+    #[test_case(false, Some("path"), true; "location")]
+    #[test_case(true, Some("path"), false; "both")]
+    #[test_case(false, None, false; "neither")]
+    fn validate(use_default: bool, location: Option<&str>, want: bool) {
+        let config = CertificateConfig {
+            use_default_certificate_config: use_default,
+            certificate_config_location: location.map(str::to_string),
+            trust_chain_path: None,
+        };
+        assert_eq!(config.validate().is_ok(), want, "{config:?}");
+    }
+
+    #[tokio::test]
+    async fn leaf_only() -> TestResult {
+        let dir = TempDir::new()?;
+        let token = credentials(&dir, None)?.subject_token().await?;
+        let got: Vec<String> = serde_json::from_str(&token.token)?;
+        assert_eq!(got, vec![der(TEST_CERT)]);
+        Ok(())
+    }
+
+    #[test]
+    fn token_and_certificate_match() -> TestResult {
+        let dir = TempDir::new()?;
+        let credentials = credentials(&dir, None)?;
+        let (token, certificate) = credentials.subject_token_and_certificate()?;
+        let got: Vec<String> = serde_json::from_str(&token.token)?;
+        assert_eq!(got, vec![der(TEST_CERT)]);
+        assert_eq!(certificate.cert_pem(), TEST_CERT.as_bytes());
+
+        // Simulate a certificate rotation.
+        std::fs::write(dir.path().join("cert.pem"), TEST_INTERMEDIATE)?;
+        let (token, certificate) = credentials.subject_token_and_certificate()?;
+        let got: Vec<String> = serde_json::from_str(&token.token)?;
+        assert_eq!(got, vec![der(TEST_INTERMEDIATE)]);
+        assert_eq!(certificate.cert_pem(), TEST_INTERMEDIATE.as_bytes());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn with_trust_chain() -> TestResult {
+        let dir = TempDir::new()?;
+        let token = credentials(&dir, Some(TEST_INTERMEDIATE))?
+            .subject_token()
+            .await?;
+        let got: Vec<String> = serde_json::from_str(&token.token)?;
+        assert_eq!(got, vec![der(TEST_CERT), der(TEST_INTERMEDIATE)]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn trust_chain_starts_with_leaf() -> TestResult {
+        let dir = TempDir::new()?;
+        let chain = format!("{TEST_CERT}{TEST_INTERMEDIATE}");
+        let token = credentials(&dir, Some(&chain))?.subject_token().await?;
+        let got: Vec<String> = serde_json::from_str(&token.token)?;
+        assert_eq!(got, vec![der(TEST_CERT), der(TEST_INTERMEDIATE)]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn trust_chain_with_leaf_in_middle() -> TestResult {
+        let dir = TempDir::new()?;
+        let chain = format!("{TEST_INTERMEDIATE}{TEST_CERT}");
+        let err = credentials(&dir, Some(&chain))?
+            .subject_token()
+            .await
+            .unwrap_err();
+        assert!(!err.is_transient(), "{err:?}");
+        assert!(
+            err.to_string().contains("start of the trust chain"),
+            "{err:?}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn missing_trust_chain() -> TestResult {
+        let dir = TempDir::new()?;
+        let mut credentials = credentials(&dir, None)?;
+        credentials.trust_chain_path = Some("/path/to/missing/chain.pem".to_string());
+        let err = credentials.subject_token().await.unwrap_err();
+        assert!(!err.is_transient(), "{err:?}");
+        assert!(err.to_string().contains("trust chain"), "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn missing_certificate_config() -> TestResult {
+        let credentials = X509SourcedCredentials::new(CertificateConfig {
+            certificate_config_location: Some("/path/to/missing/config.json".to_string()),
+            ..CertificateConfig::default()
+        });
+        let err = credentials.subject_token().await.unwrap_err();
+        assert!(!err.is_transient(), "{err:?}");
+        Ok(())
+    }
+}
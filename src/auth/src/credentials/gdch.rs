@@ -67,7 +67,6 @@ use rustls_pki_types::PrivateKeyDer;
 use rustls_pki_types::pem::PemObject;
 use serde::Deserialize;
 use std::sync::Arc;
-use tokio::time::Instant;
 
 /// Represents a Google Distributed Cloud service account key.
 #[derive(Deserialize, Clone)]
@@ -206,7 +205,7 @@ impl TokenProvider for GdchServiceAccountTokenProvider {
             .exchange_token(req)
             .await?;
 
-        let expires_at = Instant::now() + tokio::time::Duration::from_secs(resp.expires_in);
+        let expires_at = resp.required_expires_at()?;
 
         Ok(Token {
             token: resp.access_token,
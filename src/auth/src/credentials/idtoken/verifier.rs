@@ -12,12 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Verify [OIDC ID tokens].
+//! Verify [OIDC ID tokens] and other Google-signed JWTs.
 //!
 //! [Verifier] is used to validate an OIDC ID token.
 //! This includes verifying the token's signature against the appropriate
 //! JSON Web Key Set (JWKS), and validating its claims, such as audience and issuer.
 //!
+//! The [Builder] also has presets for other JSON Web Tokens (JWTs) commonly
+//! used with Google Cloud:
+//! - [Builder::iap()] verifies the [signed headers] from Identity-Aware Proxy.
+//! - [Builder::firebase()] verifies [Firebase Auth ID tokens].
+//! - [Builder::service_account()] verifies JWTs self-signed by a service
+//!   account, using the service account's public X.509 certificates.
+//!
 //! ## Example: Verifying an ID token
 //!
 //! ```
@@ -34,22 +41,83 @@
 //! #   Ok(())
 //! }
 //! ```
+//!
+//! ## Example: Verifying an IAP JWT into a custom type
+//!
+//! ```
+//! # use google_cloud_auth::credentials::idtoken::verifier::{Builder, Verifier};
+//! #[derive(serde::Deserialize)]
+//! struct IapClaims {
+//!     email: String,
+//!     sub: String,
+//! }
+//!
+//! let audience = "/projects/123456/global/backendServices/987654";
+//! let verifier = Builder::iap([audience]).build();
+//!
+//! async fn verify_iap_header(verifier: &Verifier, header: &str) -> anyhow::Result<()> {
+//!     let claims: IapClaims = verifier.verify_as(header).await?;
+//!     println!("Hello: {} ({})", claims.email, claims.sub);
+//! #   Ok(())
+//! }
+//! ```
 //! [OIDC ID Tokens]: https://cloud.google.com/docs/authentication/token-types#identity-tokens
+//! [signed headers]: https://cloud.google.com/iap/docs/signed-headers-howto
+//! [Firebase Auth ID tokens]: https://firebase.google.com/docs/auth/admin/verify-id-tokens
 
-use crate::credentials::internal::jwk_client::JwkClient;
+use crate::credentials::internal::jwk_client::{
+    FIREBASE_X509_URL, IAP_JWK_URL, JwkClient, KeySource, SERVICE_ACCOUNT_X509_URL,
+};
 use jsonwebtoken::Validation;
+use serde::de::DeserializeOwned;
 /// Represents the claims in an ID token.
 pub use serde_json::Map;
 /// Represents a claim value in an ID token.
 pub use serde_json::Value;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// TODO(#3591): Support TPC/REP that can have different issuers
+const GOOGLE_ISSUERS: [&str; 2] = ["https://accounts.google.com", "accounts.google.com"];
+const IAP_ISSUER: &str = "https://cloud.google.com/iap";
+const FIREBASE_ISSUER: &str = "https://securetoken.google.com";
+
+/// The signing algorithms supported by [Verifier].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Algorithm {
+    /// RSASSA-PKCS1-v1_5 using SHA-256.
+    RS256,
+    /// RSASSA-PKCS1-v1_5 using SHA-384.
+    RS384,
+    /// RSASSA-PKCS1-v1_5 using SHA-512.
+    RS512,
+    /// ECDSA using P-256 and SHA-256.
+    ES256,
+    /// ECDSA using P-384 and SHA-384.
+    ES384,
+}
+
+impl Algorithm {
+    fn as_jwt(self) -> jsonwebtoken::Algorithm {
+        match self {
+            Self::RS256 => jsonwebtoken::Algorithm::RS256,
+            Self::RS384 => jsonwebtoken::Algorithm::RS384,
+            Self::RS512 => jsonwebtoken::Algorithm::RS512,
+            Self::ES256 => jsonwebtoken::Algorithm::ES256,
+            Self::ES384 => jsonwebtoken::Algorithm::ES384,
+        }
+    }
+}
 
 /// Builder is used construct a [Verifier] of id tokens.
 pub struct Builder {
     audiences: Vec<String>,
     email: Option<String>,
-    jwks_url: Option<String>,
+    key_source: KeySource,
     clock_skew: Option<Duration>,
+    issuers: Vec<String>,
+    algorithms: Vec<Algorithm>,
+    firebase: bool,
 }
 
 impl Builder {
@@ -67,11 +135,94 @@ impl Builder {
         Self {
             audiences,
             email: None,
-            jwks_url: None,
+            key_source: KeySource::Default,
             clock_skew: None,
+            issuers: GOOGLE_ISSUERS.map(str::to_string).to_vec(),
+            algorithms: vec![Algorithm::RS256, Algorithm::ES256],
+            firebase: false,
         }
     }
 
+    /// Create a [Verifier] for the JWTs in the `x-goog-iap-jwt-assertion`
+    /// header set by [Identity-Aware Proxy].
+    ///
+    /// The audiences are in the form `/projects/{project_number}/apps/{project_id}`
+    /// for App Engine, or `/projects/{project_number}/global/backendServices/{service_id}`
+    /// for Compute Engine and GKE.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use google_cloud_auth::credentials::idtoken::verifier::Builder;
+    /// let verifier = Builder::iap(["/projects/123456/apps/my-project"]).build();
+    /// ```
+    ///
+    /// [Identity-Aware Proxy]: https://cloud.google.com/iap/docs/signed-headers-howto
+    pub fn iap<I, S>(audiences: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::new(audiences)
+            .with_jwks_url(IAP_JWK_URL)
+            .with_issuers([IAP_ISSUER])
+            .with_algorithms([Algorithm::ES256])
+    }
+
+    /// Create a [Verifier] for [Firebase Auth ID tokens] issued for
+    /// `project_id`.
+    ///
+    /// In addition to the signature, audience, issuer, and expiration, the
+    /// verifier checks that the `sub` claim is a non-empty string of at most
+    /// 128 characters, and that the `auth_time` claim is in the past.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use google_cloud_auth::credentials::idtoken::verifier::Builder;
+    /// let verifier = Builder::firebase("my-project").build();
+    /// ```
+    ///
+    /// [Firebase Auth ID tokens]: https://firebase.google.com/docs/auth/admin/verify-id-tokens
+    pub fn firebase<S: Into<String>>(project_id: S) -> Self {
+        let project_id = project_id.into();
+        let mut builder = Self::new([project_id.as_str()])
+            .with_x509_url(FIREBASE_X509_URL)
+            .with_issuers([format!("{FIREBASE_ISSUER}/{project_id}")])
+            .with_algorithms([Algorithm::RS256]);
+        builder.firebase = true;
+        builder
+    }
+
+    /// Create a [Verifier] for JWTs self-signed by the service account
+    /// `client_email`.
+    ///
+    /// The token signature is verified using the public X.509 certificates of
+    /// the service account, and the token issuer must be the service account.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use google_cloud_auth::credentials::idtoken::verifier::Builder;
+    /// let verifier = Builder::service_account(
+    ///     "partner@partner-project.iam.gserviceaccount.com",
+    ///     ["https://my-service.example.com"],
+    /// )
+    /// .build();
+    /// ```
+    pub fn service_account<E, I, S>(client_email: E, audiences: I) -> Self
+    where
+        E: Into<String>,
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let client_email = client_email.into();
+        Self::new(audiences)
+            .with_x509_url(format!("{SERVICE_ACCOUNT_X509_URL}/{client_email}"))
+            .with_issuers([client_email])
+            .with_algorithms([Algorithm::RS256])
+    }
+
     /// The email address of the service account that signed the ID token.
     ///
     /// If provided, the verifier will check that the `email` claim in the
@@ -104,7 +255,27 @@ impl Builder {
     ///     .build();
     /// ```
     pub fn with_jwks_url<S: Into<String>>(mut self, jwks_url: S) -> Self {
-        self.jwks_url = Some(jwks_url.into());
+        self.key_source = KeySource::Jwks(jwks_url.into());
+        self
+    }
+
+    /// The URL of a JSON object mapping key ids to X.509 certificates in PEM
+    /// format.
+    ///
+    /// Use this instead of [with_jwks_url()][Builder::with_jwks_url] if the
+    /// public keys are published as X.509 certificates, such as the keys of a
+    /// service account.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use google_cloud_auth::credentials::idtoken::verifier::Builder;
+    /// let verifier = Builder::new(["https://my-service.a.run.app"])
+    ///     .with_x509_url("https://www.googleapis.com/robot/v1/metadata/x509/my-sa@my-project.iam.gserviceaccount.com")
+    ///     .build();
+    /// ```
+    pub fn with_x509_url<S: Into<String>>(mut self, x509_url: S) -> Self {
+        self.key_source = KeySource::X509(x509_url.into());
         self
     }
 
@@ -127,14 +298,61 @@ impl Builder {
         self
     }
 
+    /// The accepted values for the `iss` claim.
+    ///
+    /// Replaces the issuers set by the constructor. For Google ID tokens the
+    /// defaults are `https://accounts.google.com` and `accounts.google.com`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use google_cloud_auth::credentials::idtoken::verifier::Builder;
+    /// let verifier = Builder::new(["https://my-service.a.run.app"])
+    ///     .with_issuers(["https://accounts.google.com"])
+    ///     .build();
+    /// ```
+    pub fn with_issuers<I, S>(mut self, issuers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.issuers = issuers.into_iter().map(|s| s.into()).collect();
+        self
+    }
+
+    /// The accepted signing algorithms.
+    ///
+    /// Replaces the algorithms set by the constructor. Tokens signed with any
+    /// other algorithm are rejected before fetching any keys. For Google ID
+    /// tokens the defaults are [Algorithm::RS256] and [Algorithm::ES256].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use google_cloud_auth::credentials::idtoken::verifier::{Algorithm, Builder};
+    /// let verifier = Builder::new(["https://my-service.a.run.app"])
+    ///     .with_algorithms([Algorithm::RS256])
+    ///     .build();
+    /// ```
+    pub fn with_algorithms<I>(mut self, algorithms: I) -> Self
+    where
+        I: IntoIterator<Item = Algorithm>,
+    {
+        self.algorithms = algorithms.into_iter().collect();
+        self
+    }
+
     /// Returns a [Verifier] instance with the configured settings.
     pub fn build(self) -> Verifier {
         Verifier {
             jwk_client: JwkClient::new(),
-            audiences: self.audiences.clone(),
-            email: self.email.clone(),
-            jwks_url: self.jwks_url.clone(),
+            audiences: self.audiences,
+            email: self.email,
+            key_source: self.key_source,
             clock_skew: self.clock_skew.unwrap_or_else(|| Duration::from_secs(10)),
+            issuers: self.issuers,
+            algorithms: self.algorithms,
+            firebase: self.firebase,
         }
     }
 }
@@ -158,8 +376,11 @@ pub struct Verifier {
     jwk_client: JwkClient,
     audiences: Vec<String>,
     email: Option<String>,
-    jwks_url: Option<String>,
+    key_source: KeySource,
     clock_skew: Duration,
+    issuers: Vec<String>,
+    algorithms: Vec<Algorithm>,
+    firebase: bool,
 }
 
 impl Verifier {
@@ -167,22 +388,28 @@ impl Verifier {
     pub async fn verify(&self, token: &str) -> std::result::Result<Map<String, Value>, Error> {
         let header = jsonwebtoken::decode_header(token).map_err(Error::decode)?;
 
+        if !self.algorithms.iter().any(|a| a.as_jwt() == header.alg) {
+            let err_msg = format!(
+                "expected one of {:?}, but found `{:?}`",
+                self.algorithms, header.alg
+            );
+            return Err(Error::invalid_field("alg", err_msg));
+        }
+
         let key_id = header
             .kid
             .ok_or_else(|| Error::invalid_field("kid", "kid header is missing"))?;
 
         let mut validation = Validation::new(header.alg);
         validation.leeway = self.clock_skew.as_secs();
-        // TODO(#3591): Support TPC/REP that can have different issuers
-        validation.set_issuer(&["https://accounts.google.com", "accounts.google.com"]);
+        validation.set_issuer(&self.issuers);
         validation.set_audience(&self.audiences);
 
         let expected_email = self.email.clone();
-        let jwks_url = self.jwks_url.clone();
 
         let cert = self
             .jwk_client
-            .get_or_load_cert(key_id, header.alg, jwks_url)
+            .get_or_load_cert(key_id, header.alg, &self.key_source)
             .await
             .map_err(Error::load_cert)?;
 
@@ -219,8 +446,68 @@ impl Verifier {
             }
         }
 
+        if self.firebase {
+            self.verify_firebase_claims(&claims)?;
+        }
+
         Ok(claims)
     }
+
+    /// Verifies the claims required for Firebase Auth ID tokens.
+    fn verify_firebase_claims(
+        &self,
+        claims: &Map<String, Value>,
+    ) -> std::result::Result<(), Error> {
+        match claims.get("sub").and_then(Value::as_str) {
+            Some(sub) if !sub.is_empty() && sub.chars().count() <= 128 => {}
+            _ => {
+                return Err(Error::invalid_field(
+                    "sub",
+                    "sub claim must be a non-empty string of at most 128 characters",
+                ));
+            }
+        }
+        let auth_time = claims
+            .get("auth_time")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| Error::invalid_field("auth_time", "auth_time claim is missing"))?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        if Duration::from_secs(auth_time) > now + self.clock_skew {
+            return Err(Error::invalid_field(
+                "auth_time",
+                "auth_time claim is in the future",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Verifies the token and deserializes its claims into `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use google_cloud_auth::credentials::idtoken::verifier::Verifier;
+    /// #[derive(serde::Deserialize)]
+    /// struct FirebaseClaims {
+    ///     sub: String,
+    ///     email: Option<String>,
+    /// }
+    ///
+    /// async fn verify_firebase_token(verifier: &Verifier, token: &str) -> anyhow::Result<()> {
+    ///     let claims: FirebaseClaims = verifier.verify_as(token).await?;
+    ///     println!("Hello: {} {:?}", claims.sub, claims.email);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn verify_as<T>(&self, token: &str) -> std::result::Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let claims = self.verify(token).await?;
+        serde_json::from_value(Value::Object(claims)).map_err(Error::decode)
+    }
 }
 
 type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
@@ -295,10 +582,12 @@ enum ErrorKind {
 pub(crate) mod tests {
     use super::*;
     use crate::credentials::idtoken::tests::{
-        generate_test_id_token, generate_test_id_token_es256, generate_test_id_token_with_claims,
+        generate_test_id_token, generate_test_id_token_es256,
+        generate_test_id_token_es256_with_claims, generate_test_id_token_with_claims,
     };
     use crate::credentials::internal::jwk_client::tests::{
-        create_es256_jwk_set_response, create_rsa256_jwk_set_response,
+        create_es256_jwk_set_response, create_rsa256_jwk_set_response, create_x509_certs_response,
+        create_x509_es256_certs_response,
     };
     use httptest::matchers::{all_of, request};
     use httptest::responders::{json_encoded, status_code};
@@ -630,4 +919,339 @@ pub(crate) mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_verify_iap_success() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![request::path("/iap"),])
+                .times(1)
+                .respond_with(json_encoded(create_es256_jwk_set_response())),
+        );
+
+        let audience = "/projects/123456/apps/test-project";
+        let mut claims = HashMap::new();
+        claims.insert("iss", IAP_ISSUER.into());
+        claims.insert("email", "user@example.com".into());
+        let token = generate_test_id_token_es256_with_claims(audience, claims);
+
+        let verifier = Builder::iap([audience])
+            .with_jwks_url(format!("http://{}/iap", server.addr()))
+            .build();
+
+        let claims = verifier.verify(&token).await?;
+        assert_eq!(claims["email"], "user@example.com");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_verify_iap_rejects_other_algorithms() -> TestResult {
+        // No keys are fetched for tokens with unexpected algorithms.
+        let server = Server::run();
+
+        let audience = "/projects/123456/apps/test-project";
+        let mut claims = HashMap::new();
+        claims.insert("iss", IAP_ISSUER.into());
+        let token = generate_test_id_token_with_claims(audience, claims);
+
+        let verifier = Builder::iap([audience])
+            .with_jwks_url(format!("http://{}/iap", server.addr()))
+            .build();
+
+        let err = verifier.verify(&token).await.unwrap_err();
+        assert!(err.is_invalid(), "{err:?}");
+        assert!(err.to_string().contains("`alg`"), "{err}");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_verify_iap_rejects_google_issuer() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![request::path("/iap"),])
+                .times(1)
+                .respond_with(json_encoded(create_es256_jwk_set_response())),
+        );
+
+        let audience = "/projects/123456/apps/test-project";
+        let token = generate_test_id_token_es256(audience);
+
+        let verifier = Builder::iap([audience])
+            .with_jwks_url(format!("http://{}/iap", server.addr()))
+            .build();
+
+        let err = verifier.verify(&token).await.unwrap_err();
+        assert!(err.is_invalid(), "{err:?}");
+        assert!(err.to_string().contains("`iss`"), "{err}");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_verify_x509_wrong_alg_does_not_poison_cache() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![request::path("/x509"),])
+                .times(1)
+                .respond_with(json_encoded(create_x509_certs_response())),
+        );
+
+        let audience = "https://example.com";
+        let verifier = Builder::new([audience])
+            .with_x509_url(format!("http://{}/x509", server.addr()))
+            .build();
+
+        // A forged token claiming ES256 for the RSA key is rejected.
+        let forged = generate_test_id_token_es256(audience);
+        let err = verifier.verify(&forged).await.unwrap_err();
+        assert!(err.is_invalid(), "{err:?}");
+
+        // Valid tokens are still accepted, using the cached key.
+        let token = generate_test_id_token(audience);
+        let claims = verifier.verify(&token).await?;
+        assert_eq!(claims["aud"], audience);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_verify_x509_es256() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![request::path("/x509"),])
+                .times(1)
+                .respond_with(json_encoded(create_x509_es256_certs_response())),
+        );
+
+        let audience = "https://example.com";
+        let verifier = Builder::new([audience])
+            .with_x509_url(format!("http://{}/x509", server.addr()))
+            .build();
+        let claims = verifier
+            .verify(&generate_test_id_token_es256(audience))
+            .await?;
+        assert_eq!(claims["aud"], audience);
+
+        let err = verifier
+            .verify(&generate_test_id_token(audience))
+            .await
+            .unwrap_err();
+        assert!(err.is_invalid(), "{err:?}");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_verify_firebase_success() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![request::path("/x509"),])
+                .times(2)
+                .respond_with(json_encoded(create_x509_certs_response())),
+        );
+
+        let project = "test-project";
+        let mut claims = HashMap::new();
+        claims.insert("iss", format!("{FIREBASE_ISSUER}/{project}").into());
+        claims.insert("sub", "test-user".into());
+        claims.insert("auth_time", now_secs().into());
+        let token = generate_test_id_token_with_claims(project, claims);
+
+        let verifier = Builder::firebase(project)
+            .with_x509_url(format!("http://{}/x509", server.addr()))
+            .build();
+
+        let claims = verifier.verify(&token).await?;
+        assert_eq!(claims["sub"], "test-user");
+
+        // Tokens for other projects are rejected.
+        let verifier = Builder::firebase("other-project")
+            .with_x509_url(format!("http://{}/x509", server.addr()))
+            .build();
+        let err = verifier.verify(&token).await.unwrap_err();
+        assert!(err.is_invalid(), "{err:?}");
+
+        Ok(())
+    }
+
+    fn now_secs() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("now is after the epoch")
+            .as_secs()
+    }
+
+    #[tokio::test]
+    async fn test_verify_firebase_claims() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![request::path("/x509"),])
+                .times(1)
+                .respond_with(json_encoded(create_x509_certs_response())),
+        );
+        let project = "test-project";
+        let verifier = Builder::firebase(project)
+            .with_x509_url(format!("http://{}/x509", server.addr()))
+            .build();
+
+        let now = now_secs();
+        let cases: [(&str, Option<Value>, Option<Value>); 5] = [
+            ("sub", None, Some(now.into())),
+            ("sub", Some("".into()), Some(now.into())),
+            ("sub", Some("a".repeat(129).into()), Some(now.into())),
+            ("auth_time", Some("test-user".into()), None),
+            (
+                "auth_time",
+                Some("test-user".into()),
+                Some((now + 3600).into()),
+            ),
+        ];
+        for (field, sub, auth_time) in cases {
+            let mut claims = HashMap::new();
+            claims.insert("iss", format!("{FIREBASE_ISSUER}/{project}").into());
+            claims.insert("sub", sub.clone().unwrap_or(Value::Null));
+            if let Some(auth_time) = auth_time.clone() {
+                claims.insert("auth_time", auth_time);
+            }
+            let token = generate_test_id_token_with_claims(project, claims);
+            let err = verifier.verify(&token).await.unwrap_err();
+            assert!(err.is_invalid(), "{sub:?} {auth_time:?} {err:?}");
+            assert!(
+                err.to_string().contains(&format!("`{field}`")),
+                "{sub:?} {auth_time:?} {err}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_verify_service_account_success() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![request::path("/x509"),])
+                .times(2)
+                .respond_with(json_encoded(create_x509_certs_response())),
+        );
+
+        let email = "test-sa@test-project.iam.gserviceaccount.com";
+        let audience = "https://example.com";
+        let mut claims = HashMap::new();
+        claims.insert("iss", email.into());
+        let token = generate_test_id_token_with_claims(audience, claims);
+
+        let verifier = Builder::service_account(email, [audience])
+            .with_x509_url(format!("http://{}/x509", server.addr()))
+            .build();
+        let _claims = verifier.verify(&token).await?;
+
+        // Tokens issued by other service accounts are rejected.
+        let verifier = Builder::service_account("other@example.com", [audience])
+            .with_x509_url(format!("http://{}/x509", server.addr()))
+            .build();
+        let err = verifier.verify(&token).await.unwrap_err();
+        assert!(err.is_invalid(), "{err:?}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_presets() {
+        let verifier = Builder::iap(["aud"]).build();
+        assert_eq!(
+            verifier.key_source,
+            KeySource::Jwks(IAP_JWK_URL.to_string())
+        );
+        assert_eq!(verifier.issuers, [IAP_ISSUER]);
+        assert_eq!(verifier.algorithms, [super::Algorithm::ES256]);
+
+        let verifier = Builder::firebase("test-project").build();
+        assert_eq!(
+            verifier.key_source,
+            KeySource::X509(FIREBASE_X509_URL.to_string())
+        );
+        assert_eq!(verifier.audiences, ["test-project"]);
+        assert_eq!(
+            verifier.issuers,
+            ["https://securetoken.google.com/test-project"]
+        );
+        assert_eq!(verifier.algorithms, [super::Algorithm::RS256]);
+
+        let verifier = Builder::service_account("sa@example.com", ["aud"]).build();
+        assert_eq!(
+            verifier.key_source,
+            KeySource::X509(
+                "https://www.googleapis.com/robot/v1/metadata/x509/sa@example.com".to_string()
+            )
+        );
+        assert_eq!(verifier.issuers, ["sa@example.com"]);
+        assert_eq!(verifier.algorithms, [super::Algorithm::RS256]);
+    }
+
+    #[tokio::test]
+    async fn test_verify_custom_issuers() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![request::path("/certs"),])
+                .times(1)
+                .respond_with(json_encoded(create_rsa256_jwk_set_response())),
+        );
+
+        let audience = "https://example.com";
+        let mut claims = HashMap::new();
+        claims.insert("iss", "https://issuer.example.com".into());
+        let token = generate_test_id_token_with_claims(audience, claims);
+
+        let verifier = Builder::new([audience])
+            .with_jwks_url(format!("http://{}/certs", server.addr()))
+            .with_issuers(["https://other.example.com", "https://issuer.example.com"])
+            .build();
+        let _claims = verifier.verify(&token).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_verify_as() -> TestResult {
+        #[derive(Debug, serde::Deserialize)]
+        struct Claims {
+            aud: String,
+            email: String,
+        }
+        #[derive(Debug, serde::Deserialize)]
+        #[allow(dead_code)]
+        struct MissingClaims {
+            hd: String,
+        }
+
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![request::path("/certs"),])
+                .times(1)
+                .respond_with(json_encoded(create_rsa256_jwk_set_response())),
+        );
+
+        let audience = "https://example.com";
+        let mut claims = HashMap::new();
+        claims.insert("email", "test@example.com".into());
+        let token = generate_test_id_token_with_claims(audience, claims);
+
+        let verifier = Builder::new([audience])
+            .with_jwks_url(format!("http://{}/certs", server.addr()))
+            .build();
+
+        let claims = verifier.verify_as::<Claims>(&token).await?;
+        assert_eq!(claims.aud, audience);
+        assert_eq!(claims.email, "test@example.com");
+
+        let err = verifier
+            .verify_as::<MissingClaims>(&token)
+            .await
+            .unwrap_err();
+        assert!(err.is_decode(), "{err:?}");
+
+        Ok(())
+    }
 }
@@ -93,6 +93,8 @@ use crate::access_boundary::CredentialsWithAccessBoundary;
 use crate::build_errors::Error as BuilderError;
 use crate::constants::DEFAULT_SCOPE;
 use crate::credentials::dynamic::{AccessTokenCredentialsProvider, CredentialsProvider};
+use crate::credentials::token_store::dynamic::TokenStore as TokenStoreDyn;
+use crate::credentials::token_store::{PersistentTokenProvider, TokenStore, cache_key};
 use crate::credentials::{
     AccessToken, AccessTokenCredentials, CacheableResource, Credentials, build_credentials,
     extract_credential_type,
@@ -164,6 +166,7 @@ pub struct Builder {
     retry_builder: RetryTokenProviderBuilder,
     iam_endpoint_override: Option<String>,
     is_access_boundary_enabled: bool,
+    token_store: Option<Arc<dyn TokenStoreDyn>>,
 }
 
 #[derive(Debug, Clone)]
@@ -262,6 +265,7 @@ impl Builder {
             retry_builder: RetryTokenProviderBuilder::default(),
             iam_endpoint_override: None,
             is_access_boundary_enabled: true,
+            token_store: None,
         }
     }
 
@@ -293,6 +297,7 @@ impl Builder {
             retry_builder: RetryTokenProviderBuilder::default(),
             iam_endpoint_override: None,
             is_access_boundary_enabled: true,
+            token_store: None,
         }
     }
 
@@ -519,6 +524,36 @@ impl Builder {
         self
     }
 
+    /// Sets the [TokenStore] to share access tokens across processes.
+    ///
+    /// Before fetching a new access token, the credentials look for a valid
+    /// token in the store, and save any new tokens to it. Use this with
+    /// short-lived processes, such as command-line tools, to avoid fetching a
+    /// new token on every invocation.
+    ///
+    /// ```
+    /// # use google_cloud_auth::credentials::impersonated::Builder;
+    /// # use google_cloud_auth::credentials::token_store::FileTokenStore;
+    /// # use serde_json::json;
+    /// # async fn sample() -> anyhow::Result<()> {
+    /// let impersonated_credential = json!({ /* add details here */ });
+    /// let credentials = Builder::new(impersonated_credential)
+    ///     .with_token_store(FileTokenStore::new("/var/cache/my-tool/tokens.json"))
+    ///     .build()?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [TokenStore]: crate::credentials::token_store::TokenStore
+    pub fn with_token_store<S: TokenStore + 'static>(mut self, store: S) -> Self {
+        self.token_store = Some(Arc::new(store));
+        self
+    }
+
+    pub(crate) fn maybe_token_store(mut self, store: Option<Arc<dyn TokenStoreDyn>>) -> Self {
+        self.token_store = store.or(self.token_store);
+        self
+    }
+
     /// Returns a [Credentials] instance with the configured settings.
     ///
     /// # Errors
@@ -591,7 +626,26 @@ impl Builder {
         let client_email = impersonation_url.client_email()?;
         let iam_endpoint_override = self.iam_endpoint_override.clone();
         let universe_domain_override = self.universe_domain.clone();
+        let token_store = self.token_store.clone();
+        // The tokens are for the target principal. Include the source
+        // credentials in the key when known, so a process never uses a token
+        // its own source credentials could not create.
+        let source = match &self.source {
+            BuilderSource::FromJson(json) => json
+                .get("source_credentials")
+                .map(source_identity)
+                .unwrap_or_default(),
+            BuilderSource::FromCredentials(_) => String::new(),
+        };
         let (token_provider, quota_project_id, source_credentials) = self.build_components()?;
+        let delegates = serde_json::to_string(&token_provider.inner.delegates)
+            .map_err(BuilderError::parsing)?;
+        let key = cache_key(
+            &format!("impersonated:{client_email}:{delegates}:{source}"),
+            &token_provider.inner.scopes,
+            universe_domain_override.as_deref(),
+        );
+        let token_provider = PersistentTokenProvider::new(token_provider, token_store, key);
         let access_boundary_url = crate::access_boundary::service_account_lookup_url(
             &client_email,
             iam_endpoint_override.as_deref(),
@@ -730,6 +784,29 @@ fn config_from_json(json: Value) -> BuildResult<ImpersonatedConfig> {
     serde_json::from_value::<ImpersonatedConfig>(json).map_err(BuilderError::parsing)
 }
 
+/// Returns the identity of the source credentials, for the token cache key.
+///
+/// Uses the fields that identify the principal and its secret, so tokens are
+/// shared only by processes using the same source credentials.
+fn source_identity(source: &Value) -> String {
+    let field = |name: &str| source.get(name).and_then(Value::as_str).unwrap_or_default();
+    match field("type") {
+        "service_account" => format!(
+            "service_account:{}:{}",
+            field("client_email"),
+            field("private_key_id")
+        ),
+        "authorized_user" => format!(
+            "authorized_user:{}:{}",
+            field("client_id"),
+            field("refresh_token")
+        ),
+        // Other credential types, such as external accounts, are identified
+        // by their full configuration.
+        t => format!("{t}:{source}"),
+    }
+}
+
 pub(crate) fn build_components_from_json(
     json: Value,
 ) -> BuildResult<ImpersonatedCredentialComponents> {
@@ -749,7 +826,7 @@ pub(crate) fn build_components_from_json(
     // If user does want some specific scopes or quota, they can build using the
     // from_source_credentials method.
     let source_credentials =
-        build_credentials(Some(config.source_credentials), None, None, None)?.into();
+        build_credentials(Some(config.source_credentials), None, None, None, None)?.into();
 
     Ok(ImpersonatedCredentialComponents {
         source_credentials,
@@ -1136,6 +1213,42 @@ mod tests {
         }
     }
 
+    #[test]
+    #[parallel]
+    fn source_identity_uses_principal_fields() {
+        let sa = json!({
+            "type": "service_account",
+            "client_email": "sa@example.com",
+            "private_key_id": "key-1",
+            "private_key": "secret",
+            "project_id": "p1",
+        });
+        let mut other_project = sa.clone();
+        other_project["project_id"] = json!("p2");
+        assert_eq!(source_identity(&sa), source_identity(&other_project));
+        let mut other_key = sa.clone();
+        other_key["private_key_id"] = json!("key-2");
+        assert_ne!(source_identity(&sa), source_identity(&other_key));
+
+        let user = json!({
+            "type": "authorized_user",
+            "client_id": "client",
+            "client_secret": "secret",
+            "refresh_token": "token-1",
+        });
+        let mut other_token = user.clone();
+        other_token["refresh_token"] = json!("token-2");
+        assert_ne!(source_identity(&user), source_identity(&other_token));
+
+        let external = json!({
+            "type": "external_account",
+            "audience": "audience-1",
+        });
+        let mut other_audience = external.clone();
+        other_audience["audience"] = json!("audience-2");
+        assert_ne!(source_identity(&external), source_identity(&other_audience));
+    }
+
     #[tokio::test]
     #[parallel]
     async fn test_generate_access_token_client_retry_success() -> TestResult {
@@ -15,16 +15,40 @@
 use crate::Result;
 use crate::errors::CredentialsError;
 use jsonwebtoken::{Algorithm, DecodingKey, jwk::JwkSet};
+use reqwest::header::{CACHE_CONTROL, HeaderMap};
+use rustls_pki_types::CertificateDer;
+use rustls_pki_types::pem::PemObject;
+use serde::de::DeserializeOwned;
+use spki::ObjectIdentifier;
 use std::{
     collections::HashMap,
     sync::Arc,
-    time::{Duration, Instant},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 use tokio::sync::RwLock;
 
-const IAP_JWK_URL: &str = "https://www.gstatic.com/iap/verify/public_key-jwk";
-const OAUTH2_JWK_URL: &str = "https://www.googleapis.com/oauth2/v3/certs";
+pub(crate) const IAP_JWK_URL: &str = "https://www.gstatic.com/iap/verify/public_key-jwk";
+pub(crate) const OAUTH2_JWK_URL: &str = "https://www.googleapis.com/oauth2/v3/certs";
+pub(crate) const FIREBASE_X509_URL: &str =
+    "https://www.googleapis.com/robot/v1/metadata/x509/securetoken@system.gserviceaccount.com";
+pub(crate) const SERVICE_ACCOUNT_X509_URL: &str =
+    "https://www.googleapis.com/robot/v1/metadata/x509";
 const CACHE_TTL: Duration = Duration::from_secs(3600);
+// Upper bound for the `max-age` directive, so a misconfigured server cannot
+// pin a key in the cache after it is rotated.
+const MAX_CACHE_TTL: Duration = Duration::from_secs(24 * 3600);
+const FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Where to find the public keys used to verify a token signature.
+#[derive(Clone, Debug, PartialEq)]
+pub enum KeySource {
+    /// The default Google JWKS for the signing algorithm.
+    Default,
+    /// A JSON Web Key Set (JWKS).
+    Jwks(String),
+    /// A JSON object mapping key ids to X.509 certificates in PEM format.
+    X509(String),
+}
 
 #[derive(Clone, Debug)]
 struct CacheEntry {
@@ -50,40 +74,54 @@ impl JwkClient {
         &self,
         key_id: String,
         alg: Algorithm,
-        jwks_url: Option<String>,
+        source: &KeySource,
     ) -> Result<DecodingKey> {
-        let key_id_str = key_id.as_str();
-        let mut cache = self.cache.try_write().map_err(|_e| {
-            CredentialsError::from_msg(false, "failed to obtain lock to read certificate cache")
-        })?;
-        if let Some(entry) = cache.get(key_id_str)
+        if let Some(entry) = self.cache.read().await.get(&key_id)
             && entry.expires_at > Instant::now()
         {
             return Ok(entry.key.clone());
         }
 
-        let jwks_url = self.resolve_jwks_url(alg, jwks_url)?;
-        let jwk_set: JwkSet = self.fetch_certs(jwks_url).await?;
-        let jwk = jwk_set.find(key_id_str).ok_or_else(|| {
-            CredentialsError::from_msg(false, "JWKS did not contain a matching `kid`")
-        })?;
-
-        let key = DecodingKey::from_jwk(jwk)
-            .map_err(|e| CredentialsError::new(false, "failed to parse JWK", e))?;
-
-        let entry = CacheEntry {
-            key: key.clone(),
-            expires_at: Instant::now() + self.ttl,
+        // Fetch the keys without holding the lock, a slow server must not
+        // block the verification of tokens signed with cached keys.
+        let (keys, max_age) = match source {
+            KeySource::Default => self.load_jwks(&self.resolve_jwks_url(alg)?).await?,
+            KeySource::Jwks(url) => self.load_jwks(url).await?,
+            KeySource::X509(url) => self.load_x509(url).await?,
+        };
+        let key = match keys.get(&key_id) {
+            None => {
+                return Err(CredentialsError::from_msg(
+                    false,
+                    "JWKS did not contain a matching `kid`",
+                ));
+            }
+            Some(Err(e)) => return Err(e.clone()),
+            Some(Ok(key)) => key.key.clone(),
         };
-        cache.insert(key_id_str.to_string(), entry);
+
+        // Cache all the keys in the set, tokens signed with the other keys
+        // are likely to show up soon.
+        let now = Instant::now();
+        let ttl = max_age.map_or(self.ttl, |age| age.min(MAX_CACHE_TTL));
+        let mut cache = self.cache.write().await;
+        cache.retain(|_, entry| entry.expires_at > now);
+        cache.extend(keys.into_iter().filter_map(|(kid, key)| {
+            let key = key.ok()?;
+            let expires_at = now + key.valid_for.map_or(ttl, |v| v.min(ttl));
+            Some((
+                kid,
+                CacheEntry {
+                    key: key.key,
+                    expires_at,
+                },
+            ))
+        }));
 
         Ok(key)
     }
 
-    fn resolve_jwks_url(&self, alg: Algorithm, jwks_url: Option<String>) -> Result<String> {
-        if let Some(jwks_url) = jwks_url {
-            return Ok(jwks_url);
-        }
+    fn resolve_jwks_url(&self, alg: Algorithm) -> Result<String> {
         match alg {
             Algorithm::RS256 => Ok(OAUTH2_JWK_URL.to_string()),
             Algorithm::ES256 => Ok(IAP_JWK_URL.to_string()),
@@ -96,27 +134,217 @@ impl JwkClient {
         }
     }
 
-    async fn fetch_certs(&self, jwks_url: String) -> Result<JwkSet> {
+    async fn load_jwks(&self, url: &str) -> Result<(ParsedKeys, Option<Duration>)> {
+        let (jwk_set, max_age) = self
+            .fetch::<JwkSet>(url, "failed to fetch JWK set", "failed to parse JWK set")
+            .await?;
+        let keys = jwk_set
+            .keys
+            .iter()
+            .filter_map(|jwk| {
+                let kid = jwk.common.key_id.clone()?;
+                let key = DecodingKey::from_jwk(jwk)
+                    .map(|key| ParsedKey {
+                        key,
+                        valid_for: None,
+                    })
+                    .map_err(parse_error);
+                Some((kid, key))
+            })
+            .collect();
+        Ok((keys, max_age))
+    }
+
+    // The key type comes from each certificate, never from the (unverified)
+    // token header. Otherwise a forged token could cache the keys with the
+    // wrong type, and valid tokens would fail until the cache expires.
+    async fn load_x509(&self, url: &str) -> Result<(ParsedKeys, Option<Duration>)> {
+        let (certs, max_age) = self
+            .fetch::<HashMap<String, String>>(
+                url,
+                "failed to fetch X.509 certificates",
+                "failed to parse X.509 certificates",
+            )
+            .await?;
+        let now = SystemTime::now();
+        let keys = certs
+            .into_iter()
+            .map(|(kid, pem)| {
+                let key = public_key_from_pem(&pem, now).map(|(key, valid_for)| ParsedKey {
+                    key,
+                    valid_for: Some(valid_for),
+                });
+                (kid, key)
+            })
+            .collect();
+        Ok((keys, max_age))
+    }
+
+    async fn fetch<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        fetch_msg: &'static str,
+        parse_msg: &'static str,
+    ) -> Result<(T, Option<Duration>)> {
         let client = reqwest::Client::new();
         // TODO(#3592): add retries
         let response = client
-            .get(jwks_url)
+            .get(url)
+            .timeout(FETCH_TIMEOUT)
             .send()
             .await
-            .map_err(|e| crate::errors::from_http_error(e, "failed to fetch JWK set"))?;
+            .map_err(|e| crate::errors::from_http_error(e, fetch_msg))?;
 
         if !response.status().is_success() {
-            let err = crate::errors::from_http_response(response, "failed to fetch JWK set").await;
+            let err = crate::errors::from_http_response(response, fetch_msg).await;
             return Err(err);
         }
 
-        let jwk_set: JwkSet = response
+        let max_age = max_age(response.headers());
+        let body: T = response
             .json()
             .await
-            .map_err(|e| CredentialsError::new(!e.is_decode(), "failed to parse JWK set", e))?;
+            .map_err(|e| CredentialsError::new(!e.is_decode(), parse_msg, e))?;
+
+        Ok((body, max_age))
+    }
+}
+
+/// A key loaded from a key set.
+struct ParsedKey {
+    key: DecodingKey,
+    /// How long until the certificate for the key expires, if known.
+    valid_for: Option<Duration>,
+}
+
+type ParsedKeys = HashMap<String, Result<ParsedKey>>;
+
+fn parse_error(e: jsonwebtoken::errors::Error) -> CredentialsError {
+    CredentialsError::new(false, "failed to parse JWK", e)
+}
 
-        Ok(jwk_set)
+/// Extracts the public key from a PEM-encoded X.509 certificate.
+///
+/// The key type is determined by the algorithm in the certificate's
+/// `subjectPublicKeyInfo`. Also returns how long until the certificate
+/// expires.
+///
+/// The certificate signature is not verified, the certificates are trusted
+/// because they are downloaded over TLS from a Google endpoint. The
+/// certificate must be valid at `now`.
+fn public_key_from_pem(pem: &str, now: SystemTime) -> Result<(DecodingKey, Duration)> {
+    const RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+    const EC_PUBLIC_KEY: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+
+    let der = CertificateDer::from_pem_slice(pem.as_bytes())
+        .map_err(|e| CredentialsError::new(false, "failed to parse X.509 certificate", e))?;
+    let cert = parse_certificate(der.as_ref())
+        .map_err(|e| CredentialsError::new(false, "failed to parse X.509 certificate", e))?;
+    let key = match cert.algorithm {
+        RSA_ENCRYPTION => DecodingKey::from_rsa_der(&cert.public_key),
+        EC_PUBLIC_KEY => DecodingKey::from_ec_der(&cert.public_key),
+        oid => {
+            return Err(CredentialsError::from_msg(
+                false,
+                format!("unsupported public key algorithm in X.509 certificate: {oid}"),
+            ));
+        }
+    };
+    let ParsedCertificate {
+        not_before,
+        not_after,
+        ..
+    } = cert;
+    let now = now.duration_since(UNIX_EPOCH).unwrap_or_default();
+    if now < not_before || now > not_after {
+        return Err(CredentialsError::from_msg(
+            false,
+            "the X.509 certificate is not valid at the current time",
+        ));
     }
+    Ok((key, not_after - now))
+}
+
+/// The fields of an X.509 certificate used to verify tokens.
+struct ParsedCertificate {
+    /// The start of the validity period, as a duration since the Unix epoch.
+    not_before: Duration,
+    /// The end of the validity period, as a duration since the Unix epoch.
+    not_after: Duration,
+    /// The algorithm of the subject public key.
+    algorithm: ObjectIdentifier,
+    /// The contents of the `subjectPublicKey` field, that is, a PKCS#1
+    /// `RSAPublicKey` for RSA keys, and the uncompressed point for EC keys.
+    public_key: Vec<u8>,
+}
+
+/// Parses a DER-encoded X.509 certificate.
+///
+/// See RFC 5280 section 4.1 for the certificate format.
+fn parse_certificate(cert: &[u8]) -> der::Result<ParsedCertificate> {
+    use der::asn1::{AnyRef, ContextSpecific, GeneralizedTime, UtcTime};
+    use der::{Decode, Reader, SliceReader, Tag, TagNumber, Tagged};
+
+    fn time(any: AnyRef<'_>) -> der::Result<Duration> {
+        match any.tag() {
+            Tag::UtcTime => Ok(UtcTime::try_from(any)?.to_unix_duration()),
+            Tag::GeneralizedTime => Ok(GeneralizedTime::try_from(any)?.to_unix_duration()),
+            tag => Err(tag.unexpected_error(None)),
+        }
+    }
+
+    let mut reader = SliceReader::new(cert)?;
+    let parsed = reader.sequence(|cert| {
+        let parsed = cert.sequence(|tbs| {
+            let _version = ContextSpecific::<u8>::decode_explicit(tbs, TagNumber::N0)?;
+            let _serial_number = AnyRef::decode(tbs)?;
+            let _signature = AnyRef::decode(tbs)?;
+            let _issuer = AnyRef::decode(tbs)?;
+            let (not_before, not_after) = tbs.sequence(|validity| {
+                Ok((
+                    time(AnyRef::decode(validity)?)?,
+                    time(AnyRef::decode(validity)?)?,
+                ))
+            })?;
+            let _subject = AnyRef::decode(tbs)?;
+            let spki = spki::SubjectPublicKeyInfoRef::decode(tbs)?;
+            // Skip the optional unique identifiers and extensions.
+            while !tbs.is_finished() {
+                AnyRef::decode(tbs)?;
+            }
+            let public_key = spki
+                .subject_public_key
+                .as_bytes()
+                .ok_or_else(|| Tag::BitString.value_error())?;
+            Ok(ParsedCertificate {
+                not_before,
+                not_after,
+                algorithm: spki.algorithm.oid,
+                public_key: public_key.to_vec(),
+            })
+        })?;
+        let _signature_algorithm = AnyRef::decode(cert)?;
+        let _signature_value = AnyRef::decode(cert)?;
+        Ok(parsed)
+    })?;
+    reader.finish(parsed)
+}
+
+/// Returns the `max-age` directive from the `Cache-Control` header, if any.
+fn max_age(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get_all(CACHE_CONTROL)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .find_map(|directive| {
+            let (name, value) = directive.trim().split_once('=')?;
+            if !name.trim().eq_ignore_ascii_case("max-age") {
+                return None;
+            }
+            value.trim().trim_matches('"').parse().ok()
+        })
+        .map(Duration::from_secs)
 }
 
 #[cfg(test)]
@@ -124,12 +352,14 @@ pub(crate) mod tests {
     use super::*;
     use base64::Engine;
     use httptest::matchers::{all_of, request};
-    use httptest::responders::json_encoded;
+    use httptest::responders::{delay_and_then, json_encoded, status_code};
     use httptest::{Expectation, Server};
     use jsonwebtoken::Algorithm;
     use p256::elliptic_curve::sec1::ToEncodedPoint;
+    use rsa::pkcs1::EncodeRsaPublicKey;
     use rsa::traits::PublicKeyParts;
     use serial_test::parallel;
+    use test_case::test_case;
 
     type TestResult = anyhow::Result<()>;
 
@@ -144,6 +374,48 @@ pub(crate) mod tests {
 
     const TEST_KEY_ID: &str = "test-key-id";
 
+    /// A self-signed certificate for `RSA_PRIVATE_KEY`.
+    const TEST_CERT: &str = "\
+-----BEGIN CERTIFICATE-----\n\
+MIIDITCCAgmgAwIBAgIUBJXbRbVNzyd4hiCh6owEnOCxr30wDQYJKoZIhvcNAQEL\n\
+BQAwHzEdMBsGA1UEAwwUdGVzdC1zZXJ2aWNlLWFjY291bnQwIBcNMjYxMDE4MDMy\n\
+ODE2WhgPMjEyNjA5MjQwMzI4MTZaMB8xHTAbBgNVBAMMFHRlc3Qtc2VydmljZS1h\n\
+Y2NvdW50MIIBIjANBgkqhkiG9w0BAQEFAAOCAQ8AMIIBCgKCAQEAyOVVaWK8KRwS\n\
+RYC9cQEgVbY3Ab+SLBaze1UlGsserdDADNlG0SHWkm/S+IrxnjWeOc7Vl8EtqNmL\n\
+bp7fzK7CMC5bKwVuQ3BOQ4KSd+0Bz5dm90fGK6+lz3ZvI22b8jeE1pbYET0zoOAL\n\
+CnvW4A8uyEjKKgmo+4zHwkISH2agJlmIUptzi82J1XfOD4Le2MizJv/c2s7q/bfA\n\
+iyNh9a3jNznB4w6FJaUvOP55zy7OfrmAgeXM0yXJ2nl9drDtTxjeGy5AimNttzvw\n\
+EvNmejBuvGFcq7yB7Wk9xvDGjef1VsSBHjrHOnGw+gXcTCOe+E59JGvqENlWRRlv\n\
+3iy/53TskQIDAQABo1MwUTAdBgNVHQ4EFgQUnB9DbePcJy8iMeEfAa3m1IN5raAw\n\
+HwYDVR0jBBgwFoAUnB9DbePcJy8iMeEfAa3m1IN5raAwDwYDVR0TAQH/BAUwAwEB\n\
+/zANBgkqhkiG9w0BAQsFAAOCAQEAO+MoTi4j17omIX1bwgoZK7IcXwIeAoc/DvjQ\n\
+AhNWoSIffpUpzBrZ0e5SGayu6jlTavWoOdIsQXc1+DuQrW7Y7Qs3ms1xl6szXQ1p\n\
+n6qsOrwZ1XCFTMgHuSEQcu7Uqt4sDPUCREI7j54ORJEZ/Eg4PeS+2oBUus8qzFpZ\n\
+NHzFst06zMqFBttcUPKy+C7o/lxKZI1HP0T+4NDsEb8fXjspbdBRchS0ALaFXbnU\n\
+f0IKyBVgO+7JbupjMaU2fKM04ABuLhpy8AOkcdOu4I/V8YEkkDkyvQ/O/T8RwCoY\n\
++bvbQtT1NmQk3/Jlc7tO4hMVmvuikAhSMVEhp2uafNIeMl5nQg==\n\
+-----END CERTIFICATE-----\n";
+
+    /// A self-signed certificate for `ES256_PRIVATE_KEY`.
+    const TEST_EC_CERT: &str = "\
+-----BEGIN CERTIFICATE-----\n\
+MIIBLjCB1qADAgECAgQBI0VnMAoGCCqGSM49BAMCMB8xHTAbBgNVBAMMFHRlc3Qt\n\
+c2VydmljZS1hY2NvdW50MCAXDTI2MTAxODAwMDAwMFoYDzIxMjYwOTI0MDAwMDAw\n\
+WjAfMR0wGwYDVQQDDBR0ZXN0LXNlcnZpY2UtYWNjb3VudDBZMBMGByqGSM49AgEG\n\
+CCqGSM49AwEHA0IABOY7ON5SIWpgEsNHMF7WcQh6uAwZHu6busR5CI3Qhl+u7aW7\n\
+rn3KjyjQ7J9WSxkS+4bkgE1l4qDSsfGN8a+AOsYwCgYIKoZIzj0EAwIDRwAwRAIg\n\
+IoE8fS5coWdxboWGIpPOgPeXOLh5aTufSDZLObTrLxMCIH/BQTvRWVQrvVxmZP4J\n\
+fTHO7m3Z+62cE6sp+jSP5jCg\n\
+-----END CERTIFICATE-----\n";
+
+    pub(crate) fn create_x509_certs_response() -> serde_json::Value {
+        serde_json::json!({ TEST_KEY_ID: TEST_CERT })
+    }
+
+    pub(crate) fn create_x509_es256_certs_response() -> serde_json::Value {
+        serde_json::json!({ TEST_KEY_ID: TEST_EC_CERT })
+    }
+
     pub(crate) fn create_rsa256_jwk_set_response() -> serde_json::Value {
         let pub_cert = crate::credentials::tests::RSA_PRIVATE_KEY.to_public_key();
         serde_json::json!({
@@ -197,13 +469,17 @@ pub(crate) mod tests {
             .get_or_load_cert(
                 TEST_KEY_ID.to_string(),
                 Algorithm::RS256,
-                Some(jwks_url.clone()),
+                &KeySource::Jwks(jwks_url.clone()),
             )
             .await?;
 
         // Second call, should use cache
         let _key = client
-            .get_or_load_cert(TEST_KEY_ID.to_string(), Algorithm::RS256, Some(jwks_url))
+            .get_or_load_cert(
+                TEST_KEY_ID.to_string(),
+                Algorithm::RS256,
+                &KeySource::Jwks(jwks_url),
+            )
             .await?;
 
         Ok(())
@@ -224,7 +500,11 @@ pub(crate) mod tests {
         let jwks_url = format!("http://{}/certs", server.addr());
 
         let result = client
-            .get_or_load_cert("unknown-kid".to_string(), Algorithm::RS256, Some(jwks_url))
+            .get_or_load_cert(
+                "unknown-kid".to_string(),
+                Algorithm::RS256,
+                &KeySource::Jwks(jwks_url),
+            )
             .await;
 
         assert!(result.is_err(), "{result:?}");
@@ -251,7 +531,11 @@ pub(crate) mod tests {
         let jwks_url = format!("http://{}/certs", server.addr());
 
         let result = client
-            .get_or_load_cert(TEST_KEY_ID.to_string(), Algorithm::RS256, Some(jwks_url))
+            .get_or_load_cert(
+                TEST_KEY_ID.to_string(),
+                Algorithm::RS256,
+                &KeySource::Jwks(jwks_url),
+            )
             .await;
 
         assert!(result.is_err(), "{result:?}");
@@ -266,29 +550,20 @@ pub(crate) mod tests {
     fn test_resolve_jwks_url() -> TestResult {
         let client = JwkClient::new();
 
-        // Custom URL
-        let url = "https://example.com/jwks".to_string();
-        assert_eq!(
-            client
-                .resolve_jwks_url(Algorithm::RS256, Some(url.clone()))
-                .unwrap(),
-            url
-        );
-
         // Default for RS256
         assert_eq!(
-            client.resolve_jwks_url(Algorithm::RS256, None).unwrap(),
+            client.resolve_jwks_url(Algorithm::RS256).unwrap(),
             OAUTH2_JWK_URL
         );
 
         // Default for ES256
         assert_eq!(
-            client.resolve_jwks_url(Algorithm::ES256, None).unwrap(),
+            client.resolve_jwks_url(Algorithm::ES256).unwrap(),
             IAP_JWK_URL
         );
 
         // Unsupported algorithm
-        let result = client.resolve_jwks_url(Algorithm::HS256, None);
+        let result = client.resolve_jwks_url(Algorithm::HS256);
         assert!(result.is_err(), "{result:?}");
 
         Ok(())
@@ -313,7 +588,7 @@ pub(crate) mod tests {
             .get_or_load_cert(
                 TEST_KEY_ID.to_string(),
                 Algorithm::RS256,
-                Some(jwks_url.clone()),
+                &KeySource::Jwks(jwks_url.clone()),
             )
             .await?;
 
@@ -322,7 +597,7 @@ pub(crate) mod tests {
             .get_or_load_cert(
                 TEST_KEY_ID.to_string(),
                 Algorithm::RS256,
-                Some(jwks_url.clone()),
+                &KeySource::Jwks(jwks_url.clone()),
             )
             .await?;
 
@@ -331,7 +606,11 @@ pub(crate) mod tests {
 
         // This call should fetch from URL again.
         let _key = client
-            .get_or_load_cert(TEST_KEY_ID.to_string(), Algorithm::RS256, Some(jwks_url))
+            .get_or_load_cert(
+                TEST_KEY_ID.to_string(),
+                Algorithm::RS256,
+                &KeySource::Jwks(jwks_url),
+            )
             .await?;
 
         Ok(())
@@ -356,15 +635,274 @@ pub(crate) mod tests {
             .get_or_load_cert(
                 TEST_KEY_ID.to_string(),
                 Algorithm::ES256,
-                Some(jwks_url.clone()),
+                &KeySource::Jwks(jwks_url.clone()),
             )
             .await?;
 
         // Second call, should use cache
         let _key = client
-            .get_or_load_cert(TEST_KEY_ID.to_string(), Algorithm::ES256, Some(jwks_url))
+            .get_or_load_cert(
+                TEST_KEY_ID.to_string(),
+                Algorithm::ES256,
+                &KeySource::Jwks(jwks_url),
+            )
             .await?;
 
         Ok(())
     }
+
+    #[tokio::test]
+    #[parallel]
+    async fn test_get_or_load_cert_x509_success() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![request::path("/x509"),])
+                .times(1)
+                .respond_with(json_encoded(create_x509_certs_response())),
+        );
+
+        let client = JwkClient::new();
+        let source = KeySource::X509(format!("http://{}/x509", server.addr()));
+
+        let _key = client
+            .get_or_load_cert(TEST_KEY_ID.to_string(), Algorithm::RS256, &source)
+            .await?;
+        let _key = client
+            .get_or_load_cert(TEST_KEY_ID.to_string(), Algorithm::RS256, &source)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn test_get_or_load_cert_x509_bad_cert() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![request::path("/x509"),])
+                .times(1)
+                .respond_with(json_encoded(serde_json::json!({TEST_KEY_ID: "not-a-cert"}))),
+        );
+
+        let client = JwkClient::new();
+        let source = KeySource::X509(format!("http://{}/x509", server.addr()));
+
+        let result = client
+            .get_or_load_cert(TEST_KEY_ID.to_string(), Algorithm::RS256, &source)
+            .await;
+        let err = result.unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("failed to parse X.509 certificate"),
+            "{err}"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn test_get_or_load_cert_caches_key_set() -> TestResult {
+        let server = Server::run();
+        let mut response = create_rsa256_jwk_set_response();
+        let mut other = response["keys"][0].clone();
+        other["kid"] = "other-key-id".into();
+        response["keys"].as_array_mut().unwrap().push(other);
+        server.expect(
+            Expectation::matching(all_of![request::path("/certs"),])
+                .times(1)
+                .respond_with(json_encoded(response)),
+        );
+
+        let client = JwkClient::new();
+        let source = KeySource::Jwks(format!("http://{}/certs", server.addr()));
+
+        let _key = client
+            .get_or_load_cert(TEST_KEY_ID.to_string(), Algorithm::RS256, &source)
+            .await?;
+        // The second key was cached with the first request.
+        let _key = client
+            .get_or_load_cert("other-key-id".to_string(), Algorithm::RS256, &source)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn test_get_or_load_cert_cache_control() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![request::path("/certs"),])
+                .times(2)
+                .respond_with(
+                    status_code(200)
+                        .append_header("Content-Type", "application/json")
+                        .append_header("Cache-Control", "public, max-age=1, must-revalidate")
+                        .body(create_rsa256_jwk_set_response().to_string()),
+                ),
+        );
+
+        // The `max-age` directive takes precedence over the default TTL.
+        let client = JwkClient::new();
+        let source = KeySource::Jwks(format!("http://{}/certs", server.addr()));
+
+        let _key = client
+            .get_or_load_cert(TEST_KEY_ID.to_string(), Algorithm::RS256, &source)
+            .await?;
+        let _key = client
+            .get_or_load_cert(TEST_KEY_ID.to_string(), Algorithm::RS256, &source)
+            .await?;
+
+        tokio::time::sleep(Duration::from_secs(2)).await;
+
+        let _key = client
+            .get_or_load_cert(TEST_KEY_ID.to_string(), Algorithm::RS256, &source)
+            .await?;
+
+        Ok(())
+    }
+
+    #[test]
+    #[parallel]
+    fn test_public_key_from_pem() -> TestResult {
+        let now = SystemTime::now();
+        let (_, valid_for) = public_key_from_pem(TEST_CERT, now)?;
+        let der = CertificateDer::from_pem_slice(TEST_CERT.as_bytes())?;
+        let got = parse_certificate(der.as_ref())?;
+        let want = crate::credentials::tests::RSA_PRIVATE_KEY
+            .to_public_key()
+            .to_pkcs1_der()
+            .expect("the test key can be encoded");
+        assert_eq!(got.public_key, want.as_bytes());
+        assert_eq!(got.algorithm.to_string(), "1.2.840.113549.1.1.1");
+        // The test certificate is valid for 100 years.
+        assert!(
+            valid_for > Duration::from_secs(90 * 365 * 86400),
+            "{valid_for:?}"
+        );
+
+        assert!(public_key_from_pem("not-a-cert", now).is_err());
+        for len in [0, 1, 64, der.as_ref().len() - 1] {
+            assert!(parse_certificate(&der.as_ref()[..len]).is_err(), "{len}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[parallel]
+    fn test_public_key_from_pem_ec() -> TestResult {
+        let _ = public_key_from_pem(TEST_EC_CERT, SystemTime::now())?;
+        let der = CertificateDer::from_pem_slice(TEST_EC_CERT.as_bytes())?;
+        let got = parse_certificate(der.as_ref())?;
+        let want = crate::credentials::tests::ES256_PRIVATE_KEY
+            .public_key()
+            .to_encoded_point(false);
+        assert_eq!(got.public_key, want.as_bytes());
+        assert_eq!(got.algorithm.to_string(), "1.2.840.10045.2.1");
+        Ok(())
+    }
+
+    #[test]
+    #[parallel]
+    fn test_public_key_from_pem_validity() -> TestResult {
+        // The test certificate is valid from 2026-10-18 until 2126-09-24.
+        let before = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let err = public_key_from_pem(TEST_CERT, before).unwrap_err();
+        assert!(err.to_string().contains("not valid"), "{err}");
+        let after = UNIX_EPOCH + Duration::from_secs(5_000_000_000);
+        let err = public_key_from_pem(TEST_CERT, after).unwrap_err();
+        assert!(err.to_string().contains("not valid"), "{err}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn test_get_or_load_cert_clamps_max_age() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![request::path("/certs"),])
+                .times(1)
+                .respond_with(
+                    status_code(200)
+                        .append_header("Content-Type", "application/json")
+                        .append_header("Cache-Control", format!("max-age={}", u64::MAX))
+                        .body(create_rsa256_jwk_set_response().to_string()),
+                ),
+        );
+
+        let client = JwkClient::new();
+        let source = KeySource::Jwks(format!("http://{}/certs", server.addr()));
+        let _key = client
+            .get_or_load_cert(TEST_KEY_ID.to_string(), Algorithm::RS256, &source)
+            .await?;
+        let expires_at = client.cache.read().await[TEST_KEY_ID].expires_at;
+        assert!(
+            expires_at <= Instant::now() + MAX_CACHE_TTL,
+            "{expires_at:?}"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn test_get_or_load_cert_fetch_does_not_block_cache() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![request::path("/certs"),])
+                .times(1)
+                .respond_with(json_encoded(create_rsa256_jwk_set_response())),
+        );
+        server.expect(
+            Expectation::matching(all_of![request::path("/slow"),])
+                .times(1)
+                .respond_with(delay_and_then(
+                    Duration::from_secs(5),
+                    json_encoded(create_rsa256_jwk_set_response()),
+                )),
+        );
+
+        let client = JwkClient::new();
+        let source = KeySource::Jwks(format!("http://{}/certs", server.addr()));
+        let _key = client
+            .get_or_load_cert(TEST_KEY_ID.to_string(), Algorithm::RS256, &source)
+            .await?;
+
+        let slow = {
+            let client = client.clone();
+            let source = KeySource::Jwks(format!("http://{}/slow", server.addr()));
+            tokio::spawn(async move {
+                client
+                    .get_or_load_cert("other-key-id".to_string(), Algorithm::RS256, &source)
+                    .await
+            })
+        };
+        // Give the slow request time to start.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(!slow.is_finished());
+        let _key = tokio::time::timeout(
+            Duration::from_secs(1),
+            client.get_or_load_cert(TEST_KEY_ID.to_string(), Algorithm::RS256, &source),
+        )
+        .await??;
+        let err = slow.await?.unwrap_err();
+        assert!(err.to_string().contains("`kid`"), "{err}");
+
+        Ok(())
+    }
+
+    #[test_case(None, None; "missing")]
+    #[test_case(Some("max-age=300"), Some(300); "simple")]
+    #[test_case(Some("public, max-age=19800, must-revalidate, no-transform"), Some(19800); "google")]
+    #[test_case(Some("Max-Age = \"60\""), Some(60); "quoted")]
+    #[test_case(Some("no-cache"), None; "no max-age")]
+    #[test_case(Some("max-age=abc"), None; "invalid")]
+    fn test_max_age(header: Option<&str>, want: Option<u64>) {
+        let mut headers = HeaderMap::new();
+        if let Some(h) = header {
+            headers.insert(CACHE_CONTROL, h.parse().unwrap());
+        }
+        assert_eq!(max_age(&headers), want.map(Duration::from_secs));
+    }
 }
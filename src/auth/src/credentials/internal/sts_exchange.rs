@@ -15,10 +15,12 @@
 use crate::{
     constants::{ACCESS_TOKEN_TYPE, TOKEN_EXCHANGE_GRANT_TYPE},
     credentials::errors::{self, CredentialsError},
+    mtls::ClientCertificate,
 };
 use base64::Engine;
 use serde::Deserialize;
 use std::collections::HashMap;
+use tokio::time::{Duration, Instant};
 
 type Result<T> = std::result::Result<T, CredentialsError>;
 
@@ -28,6 +30,7 @@ type Result<T> = std::result::Result<T, CredentialsError>;
 pub struct STSHandler {
     body_encoding: BodyEncoding,
     ca_cert_path: Option<String>,
+    client_certificate: Option<ClientCertificate>,
 }
 
 /// Defines the type of body serialization to use for requests to the STS endpoint.
@@ -56,6 +59,15 @@ impl STSHandler {
         self
     }
 
+    /// Configures a client certificate to authenticate the exchange with mTLS.
+    pub(crate) fn with_client_certificate(
+        mut self,
+        client_certificate: Option<ClientCertificate>,
+    ) -> Self {
+        self.client_certificate = client_certificate;
+        self
+    }
+
     /// Performs an oauth2 token exchange with the provided [ExchangeTokenRequest] information.
     pub(crate) async fn exchange_token(self, req: ExchangeTokenRequest) -> Result<TokenResponse> {
         let mut params = HashMap::new();
@@ -109,6 +121,13 @@ impl STSHandler {
         if let Some(path) = self.ca_cert_path {
             client_builder = add_root_cert(client_builder, path).await?;
         }
+        if let Some(certificate) = self.client_certificate {
+            let identity =
+                reqwest::Identity::from_pem(&certificate.identity_pem()).map_err(|e| {
+                    CredentialsError::new(false, "failed to load the client certificate", e)
+                })?;
+            client_builder = client_builder.identity(identity);
+        }
 
         let client = client_builder
             .build()
@@ -165,11 +184,30 @@ pub struct TokenResponse {
     pub access_token: String,
     pub issued_token_type: String,
     pub token_type: String,
-    pub expires_in: u64,
+    /// The lifetime of the token in seconds. The field is optional in
+    /// [RFC 8693](https://datatracker.ietf.org/doc/html/rfc8693#section-2.2.1).
+    pub expires_in: Option<u64>,
     pub scope: Option<String>,
     pub refresh_token: Option<String>,
 }
 
+impl TokenResponse {
+    /// Returns the expiration time of the token, if the response includes
+    /// its lifetime.
+    pub(crate) fn expires_at(&self) -> Option<Instant> {
+        self.expires_in
+            .map(|secs| Instant::now() + Duration::from_secs(secs))
+    }
+
+    /// Returns the expiration time of the token, or an error if the response
+    /// does not include its lifetime.
+    pub(crate) fn required_expires_at(&self) -> Result<Instant> {
+        self.expires_at().ok_or_else(|| {
+            CredentialsError::from_msg(false, "the token exchange response is missing `expires_in`")
+        })
+    }
+}
+
 /// ClientAuthentication represents an OAuth client ID and secret and the
 /// mechanism for passing these credentials as stated
 /// in https://datatracker.ietf.org/doc/html/rfc6749#section-2.3.1.
@@ -210,7 +248,7 @@ pub struct ExchangeTokenRequest {
     pub scope: Vec<String>,
     pub actor_token: Option<String>,
     pub actor_token_type: Option<String>,
-    pub extra_options: Option<HashMap<String, String>>,
+    pub extra_options: Option<HashMap<String, serde_json::Value>>,
     pub grant_type: Option<String>,
 }
 
@@ -302,7 +340,7 @@ mod tests {
                 refresh_token: None,
                 issued_token_type: ACCESS_TOKEN_TYPE.to_string(),
                 token_type: "Bearer".to_string(),
-                expires_in: 3600,
+                expires_in: Some(3600),
                 scope: Some(DEFAULT_SCOPE.to_string()),
             }
         );
@@ -310,6 +348,20 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn token_response_without_expires_in() -> TestResult {
+        let resp: TokenResponse = serde_json::from_value(json!({
+            "access_token": "an_example_token",
+            "issued_token_type": ACCESS_TOKEN_TYPE,
+            "token_type": "Bearer",
+        }))?;
+        assert_eq!(resp.expires_in, None);
+        assert_eq!(resp.expires_at(), None);
+        let err = resp.required_expires_at().unwrap_err();
+        assert!(!err.is_transient(), "{err:?}");
+        Ok(())
+    }
+
     #[tokio::test]
     async fn exchange_token_err() -> TestResult {
         let authentication = ClientAuthentication {
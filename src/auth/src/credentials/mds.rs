@@ -74,6 +74,8 @@
 
 use crate::access_boundary::CredentialsWithAccessBoundary;
 use crate::credentials::dynamic::{AccessTokenCredentialsProvider, CredentialsProvider};
+use crate::credentials::token_store::dynamic::TokenStore as TokenStoreDyn;
+use crate::credentials::token_store::{PersistentTokenProvider, TokenStore, cache_key};
 use crate::credentials::{AccessToken, AccessTokenCredentials, CacheableResource, Credentials};
 use crate::headers_util::AuthHeadersBuilder;
 use crate::mds::client::Client as MDSClient;
@@ -135,6 +137,7 @@ pub struct Builder {
     retry_builder: RetryTokenProviderBuilder,
     iam_endpoint_override: Option<String>,
     is_access_boundary_enabled: bool,
+    token_store: Option<Arc<dyn TokenStoreDyn>>,
 }
 
 impl Default for Builder {
@@ -148,6 +151,7 @@ impl Default for Builder {
             retry_builder: RetryTokenProviderBuilder::default(),
             iam_endpoint_override: None,
             is_access_boundary_enabled: true,
+            token_store: None,
         }
     }
 }
@@ -286,6 +290,34 @@ impl Builder {
         self
     }
 
+    /// Sets the [TokenStore] to share access tokens across processes.
+    ///
+    /// Before fetching a new access token, the credentials look for a valid
+    /// token in the store, and save any new tokens to it. Use this with
+    /// short-lived processes, such as command-line tools, to avoid fetching a
+    /// new token on every invocation.
+    ///
+    /// ```
+    /// # use google_cloud_auth::credentials::mds::Builder;
+    /// # use google_cloud_auth::credentials::token_store::FileTokenStore;
+    /// # async fn sample() -> anyhow::Result<()> {
+    /// let credentials = Builder::default()
+    ///     .with_token_store(FileTokenStore::new("/var/cache/my-tool/tokens.json"))
+    ///     .build()?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [TokenStore]: crate::credentials::token_store::TokenStore
+    pub fn with_token_store<S: TokenStore + 'static>(mut self, store: S) -> Self {
+        self.token_store = Some(Arc::new(store));
+        self
+    }
+
+    pub(crate) fn maybe_token_store(mut self, store: Option<Arc<dyn TokenStoreDyn>>) -> Self {
+        self.token_store = store.or(self.token_store);
+        self
+    }
+
     // This method is used to build mds credentials from ADC
     pub(crate) fn from_adc() -> Self {
         Self {
@@ -335,11 +367,21 @@ impl Builder {
         let mds_client = MDSClient::new(self.endpoint.clone());
         let retry_builder = self.retry_builder.clone();
         let (backoff_policy, retry_throttler, retry_policy) = retry_builder.resolve();
+        let key = cache_key(
+            &format!("mds:{}", self.endpoint.as_deref().unwrap_or_default()),
+            self.scopes.as_deref().unwrap_or_default(),
+            self.universe_domain.as_deref(),
+        );
+        let quota_project_id = self.quota_project_id.clone();
+        let universe_domain_override = self.universe_domain.clone();
+        let token_store = self.token_store.clone();
+        let token_provider =
+            PersistentTokenProvider::new(self.build_token_provider(), token_store, key);
         let mdsc = MDSCredentials {
-            quota_project_id: self.quota_project_id.clone(),
-            universe_domain_override: self.universe_domain.clone(),
+            quota_project_id,
+            universe_domain_override,
             universe_domain: OnceLock::new(),
-            token_provider: TokenCache::new(self.build_token_provider()),
+            token_provider: TokenCache::new(token_provider),
             mds_client: mds_client.clone(),
             backoff_policy,
             retry_throttler,
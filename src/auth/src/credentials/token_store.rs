@@ -0,0 +1,633 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Persistent storage for access tokens.
+//!
+//! Each [Credentials] instance caches its access tokens in memory. Short-lived
+//! processes, such as command-line tools, lose this cache when they exit, and
+//! fetch new tokens on every invocation. Many parallel processes using the
+//! same credentials may exceed the quota of the token endpoint, for example,
+//! when impersonating a service account.
+//!
+//! With a [TokenStore] the credentials share access tokens across processes.
+//! Before fetching a new access token, the credentials look for a valid token
+//! in the store. New tokens are saved to the store. The tokens are keyed by the
+//! credential identity (e.g. the service account or the refresh token), the
+//! scopes, and the universe domain.
+//!
+//! [FileTokenStore] saves the tokens in a file. Applications can implement
+//! [TokenStore] to save the tokens elsewhere, for example, in the operating
+//! system keychain.
+//!
+//! The store is a cache, errors loading or saving tokens are ignored and the
+//! credentials fetch new tokens from the token endpoint.
+//!
+//! ## Example: Sharing tokens across processes
+//!
+//! ```
+//! # use google_cloud_auth::credentials::Builder;
+//! # use google_cloud_auth::credentials::token_store::FileTokenStore;
+//! # async fn sample() -> anyhow::Result<()> {
+//! let credentials = Builder::default()
+//!     .with_token_store(FileTokenStore::new("/var/cache/my-tool/tokens.json"))
+//!     .build()?;
+//! # Ok(()) }
+//! ```
+//!
+//! [Credentials]: crate::credentials::Credentials
+
+use crate::Result;
+use crate::errors::CredentialsError;
+use crate::token::{Token, TokenProvider};
+use crate::token_cache::NORMAL_REFRESH_SLACK;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::future::Future;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+use tokio::time::Instant;
+
+/// An access token saved in a [TokenStore].
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoredToken {
+    /// The access token.
+    pub token: String,
+
+    /// The type of the token, typically `"Bearer"`.
+    pub token_type: String,
+
+    /// The time at which the token expires.
+    pub expires_at: SystemTime,
+}
+
+impl std::fmt::Debug for StoredToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StoredToken")
+            .field("token", &"[censored]")
+            .field("token_type", &self.token_type)
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+impl StoredToken {
+    /// Converts a token to its persistent form.
+    ///
+    /// Returns `None` for tokens that do not expire, there is no need to
+    /// persist them.
+    fn from_token(token: &Token) -> Option<Self> {
+        let remaining = token.expires_at?.saturating_duration_since(Instant::now());
+        Some(Self {
+            token: token.token.clone(),
+            token_type: token.token_type.clone(),
+            expires_at: SystemTime::now() + remaining,
+        })
+    }
+
+    /// Converts the stored token to a token, if it is valid long enough.
+    ///
+    /// Tokens that expire within the refresh slack are not used. The token
+    /// cache would immediately try to refresh them.
+    fn into_token(self) -> Option<Token> {
+        let remaining = self.expires_at.duration_since(SystemTime::now()).ok()?;
+        if remaining <= NORMAL_REFRESH_SLACK {
+            return None;
+        }
+        Some(Token {
+            token: self.token,
+            token_type: self.token_type,
+            expires_at: Some(Instant::now() + remaining),
+            metadata: None,
+        })
+    }
+}
+
+/// An interface to persist access tokens.
+///
+/// Implement this trait to save access tokens in a custom location. The
+/// implementation must be safe to use from multiple processes, if the
+/// application shares the store across processes.
+///
+/// # Example
+/// ```
+/// # use google_cloud_auth::credentials::token_store::{StoredToken, TokenStore};
+/// # use google_cloud_auth::errors::CredentialsError;
+/// # use std::collections::HashMap;
+/// # use std::sync::Mutex;
+/// #[derive(Debug, Default)]
+/// struct MyStore(Mutex<HashMap<String, StoredToken>>);
+///
+/// impl TokenStore for MyStore {
+///     async fn load(&self, key: &str) -> Result<Option<StoredToken>, CredentialsError> {
+///         Ok(self.0.lock().unwrap().get(key).cloned())
+///     }
+///     async fn store(&self, key: &str, token: StoredToken) -> Result<(), CredentialsError> {
+///         self.0.lock().unwrap().insert(key.to_string(), token);
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait TokenStore: std::fmt::Debug + Send + Sync {
+    /// Loads the token saved with `key`, if any.
+    ///
+    /// The `key` is an opaque string. It identifies the credentials, the
+    /// scopes, and the universe domain of the token.
+    fn load(&self, key: &str) -> impl Future<Output = Result<Option<StoredToken>>> + Send;
+
+    /// Saves `token` with the given `key`, replacing any existing token.
+    fn store(&self, key: &str, token: StoredToken) -> impl Future<Output = Result<()>> + Send;
+}
+
+pub(crate) mod dynamic {
+    use super::StoredToken;
+    use crate::Result;
+
+    #[async_trait::async_trait]
+    pub trait TokenStore: std::fmt::Debug + Send + Sync {
+        async fn load(&self, key: &str) -> Result<Option<StoredToken>>;
+        async fn store(&self, key: &str, token: StoredToken) -> Result<()>;
+    }
+
+    #[async_trait::async_trait]
+    impl<T> TokenStore for T
+    where
+        T: super::TokenStore,
+    {
+        async fn load(&self, key: &str) -> Result<Option<StoredToken>> {
+            T::load(self, key).await
+        }
+        async fn store(&self, key: &str, token: StoredToken) -> Result<()> {
+            T::store(self, key, token).await
+        }
+    }
+}
+
+/// A [TokenStore] saving the tokens in a file.
+///
+/// The file is only readable by the owner on Unix-like systems. Access to the
+/// file is serialized with an advisory lock on a `.lock` file next to it, so
+/// multiple processes can safely share the store. Expired tokens are removed
+/// when new tokens are saved.
+///
+/// # Example
+/// ```
+/// # use google_cloud_auth::credentials::mds;
+/// # use google_cloud_auth::credentials::token_store::FileTokenStore;
+/// # async fn sample() -> anyhow::Result<()> {
+/// let credentials = mds::Builder::default()
+///     .with_token_store(FileTokenStore::new("/var/cache/my-tool/tokens.json"))
+///     .build()?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FileTokenStore {
+    path: PathBuf,
+}
+
+impl FileTokenStore {
+    /// Creates a store saving the tokens in `path`.
+    ///
+    /// The file, and any missing parent directories, are created when the
+    /// first token is saved.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+
+    fn load_blocking(&self, key: &str) -> Result<Option<StoredToken>> {
+        let Some(parent) = self.path.parent() else {
+            return Ok(None);
+        };
+        if !parent.exists() {
+            return Ok(None);
+        }
+        let lock = self.lock_file()?;
+        lock.lock_shared()
+            .map_err(|e| io_error("lock", &self.path, e))?;
+        Ok(self.read_tokens()?.remove(key))
+    }
+
+    fn store_blocking(&self, key: &str, token: StoredToken) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| io_error("create", parent, e))?;
+        }
+        let lock = self.lock_file()?;
+        lock.lock().map_err(|e| io_error("lock", &self.path, e))?;
+        let now = SystemTime::now();
+        let mut tokens = self.read_tokens()?;
+        tokens.retain(|_, t| t.expires_at > now);
+        tokens.insert(key.to_string(), token);
+        let contents = serde_json::to_vec(&tokens)
+            .map_err(|e| CredentialsError::new(false, "cannot serialize the stored tokens", e))?;
+        // Write to a temporary file and rename it, so concurrent readers that
+        // do not use the lock never observe a partially written file.
+        let tmp = sibling(&self.path, "tmp");
+        private_file(&tmp, true)
+            .and_then(|mut f| f.write_all(&contents))
+            .map_err(|e| io_error("write", &tmp, e))?;
+        std::fs::rename(&tmp, &self.path).map_err(|e| io_error("write", &self.path, e))
+    }
+
+    fn lock_file(&self) -> Result<File> {
+        let path = sibling(&self.path, "lock");
+        private_file(&path, false).map_err(|e| io_error("open", &path, e))
+    }
+
+    fn read_tokens(&self) -> Result<HashMap<String, StoredToken>> {
+        let contents = match std::fs::read(&self.path) {
+            Ok(c) => c,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(HashMap::new()),
+            Err(e) => return Err(io_error("read", &self.path, e)),
+        };
+        // A corrupted file is replaced on the next write.
+        Ok(serde_json::from_slice(&contents).unwrap_or_default())
+    }
+}
+
+impl TokenStore for FileTokenStore {
+    async fn load(&self, key: &str) -> Result<Option<StoredToken>> {
+        let (store, key) = (self.clone(), key.to_string());
+        tokio::task::spawn_blocking(move || store.load_blocking(&key))
+            .await
+            .map_err(|e| CredentialsError::from_source(false, e))?
+    }
+
+    async fn store(&self, key: &str, token: StoredToken) -> Result<()> {
+        let (store, key) = (self.clone(), key.to_string());
+        tokio::task::spawn_blocking(move || store.store_blocking(&key, token))
+            .await
+            .map_err(|e| CredentialsError::from_source(false, e))?
+    }
+}
+
+fn sibling(path: &Path, extension: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(extension);
+    PathBuf::from(name)
+}
+
+fn private_file(path: &Path, truncate: bool) -> std::io::Result<File> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(truncate);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let file = options.open(path)?;
+    // The mode only applies to new files, restrict existing files too.
+    #[cfg(unix)]
+    file.set_permissions(std::os::unix::fs::PermissionsExt::from_mode(0o600))?;
+    Ok(file)
+}
+
+fn io_error(op: &str, path: &Path, e: std::io::Error) -> CredentialsError {
+    CredentialsError::new(
+        false,
+        format!("cannot {op} token store file {}", path.display()),
+        e,
+    )
+}
+
+/// Returns the key for tokens with the given identity, scopes, and universe
+/// domain.
+///
+/// The identity may contain secrets, such as a refresh token. The key is a
+/// hash, so it is safe to save in plain text.
+pub(crate) fn cache_key(
+    identity: &str,
+    scopes: &[String],
+    universe_domain: Option<&str>,
+) -> String {
+    let mut scopes = scopes.iter().map(String::as_str).collect::<Vec<_>>();
+    scopes.sort_unstable();
+    scopes.dedup();
+    let mut hasher = Sha256::new();
+    for part in [
+        identity,
+        &scopes.join(" "),
+        universe_domain.unwrap_or_default(),
+    ] {
+        hasher.update(part.as_bytes());
+        hasher.update([0_u8]);
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// A [TokenProvider] consulting a [TokenStore] before fetching new tokens.
+#[derive(Debug)]
+pub(crate) struct PersistentTokenProvider<T> {
+    inner: T,
+    store: Option<(Arc<dyn dynamic::TokenStore>, String)>,
+}
+
+impl<T> PersistentTokenProvider<T> {
+    pub(crate) fn new(inner: T, store: Option<Arc<dyn dynamic::TokenStore>>, key: String) -> Self {
+        Self {
+            inner,
+            store: store.map(|s| (s, key)),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> TokenProvider for PersistentTokenProvider<T>
+where
+    T: TokenProvider,
+{
+    async fn token(&self) -> Result<Token> {
+        let Some((store, key)) = &self.store else {
+            return self.inner.token().await;
+        };
+        if let Some(token) = store
+            .load(key)
+            .await
+            .ok()
+            .flatten()
+            .and_then(StoredToken::into_token)
+        {
+            return Ok(token);
+        }
+        let token = self.inner.token().await?;
+        if let Some(stored) = StoredToken::from_token(&token) {
+            // The store is a cache, the token is usable even if saving fails.
+            let _ = store.store(key, stored).await;
+        }
+        Ok(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token::tests::MockTokenProvider;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    type TestResult = anyhow::Result<()>;
+
+    fn stored(token: &str, valid_for: Duration) -> StoredToken {
+        StoredToken {
+            token: token.to_string(),
+            token_type: "Bearer".to_string(),
+            expires_at: SystemTime::now() + valid_for,
+        }
+    }
+
+    fn token(token: &str, valid_for: Duration) -> Token {
+        Token {
+            token: token.to_string(),
+            token_type: "Bearer".to_string(),
+            expires_at: Some(Instant::now() + valid_for),
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn debug() {
+        let got = format!("{:?}", stored("secret-token", Duration::from_secs(60)));
+        assert!(!got.contains("secret-token"), "{got}");
+    }
+
+    #[test]
+    fn keys() {
+        let scopes = |s: &[&str]| s.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let key = cache_key("id", &scopes(&["a", "b"]), None);
+        assert_eq!(key.len(), 64, "{key}");
+        assert_eq!(key, cache_key("id", &scopes(&["b", "a", "a"]), None));
+        assert_ne!(key, cache_key("other", &scopes(&["a", "b"]), None));
+        assert_ne!(key, cache_key("id", &scopes(&["a"]), None));
+        assert_ne!(
+            key,
+            cache_key("id", &scopes(&["a", "b"]), Some("example.com"))
+        );
+        // The separators prevent collisions between the parts.
+        assert_ne!(
+            cache_key("ab", &scopes(&["c"]), None),
+            cache_key("a", &scopes(&["bc"]), None)
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        let got = StoredToken::from_token(&token("t", Duration::from_secs(3600))).unwrap();
+        let remaining = got.expires_at.duration_since(SystemTime::now()).unwrap();
+        assert!(remaining > Duration::from_secs(3590), "{remaining:?}");
+
+        let mut never = token("t", Duration::ZERO);
+        never.expires_at = None;
+        assert!(StoredToken::from_token(&never).is_none());
+
+        let got = stored("t", Duration::from_secs(3600)).into_token().unwrap();
+        assert_eq!(got.token, "t");
+        let remaining = got.expires_at.unwrap() - Instant::now();
+        assert!(remaining > Duration::from_secs(3590), "{remaining:?}");
+
+        // Tokens about to expire are not used.
+        assert!(stored("t", Duration::from_secs(60)).into_token().is_none());
+        assert!(stored("t", Duration::ZERO).into_token().is_none());
+    }
+
+    #[tokio::test]
+    async fn file_store() -> TestResult {
+        let dir = TempDir::new()?;
+        let path = dir.path().join("nested/tokens.json");
+        let store = FileTokenStore::new(&path);
+        assert!(store.load("k1").await?.is_none());
+
+        let t1 = stored("t1", Duration::from_secs(3600));
+        store.store("k1", t1.clone()).await?;
+        store
+            .store("k2", stored("t2", Duration::from_secs(3600)))
+            .await?;
+        assert_eq!(store.load("k1").await?, Some(t1));
+        assert_eq!(
+            store.load("k2").await?.map(|t| t.token).as_deref(),
+            Some("t2")
+        );
+        assert!(store.load("k3").await?.is_none());
+
+        // A new instance, e.g. in a different process, sees the same tokens.
+        let other = FileTokenStore::new(&path);
+        assert_eq!(
+            other.load("k2").await?.map(|t| t.token).as_deref(),
+            Some("t2")
+        );
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path)?.permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn file_store_restricts_existing_files() -> TestResult {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = TempDir::new()?;
+        let path = dir.path().join("tokens.json");
+        // Files left behind, for example, by a process that crashed.
+        for extension in ["tmp", "lock"] {
+            let file = sibling(&path, extension);
+            std::fs::write(&file, "")?;
+            std::fs::set_permissions(&file, std::fs::Permissions::from_mode(0o644))?;
+        }
+        let store = FileTokenStore::new(&path);
+        store
+            .store("k1", stored("t1", Duration::from_secs(3600)))
+            .await?;
+        for file in [path.clone(), sibling(&path, "lock")] {
+            let mode = std::fs::metadata(&file)?.permissions().mode();
+            assert_eq!(mode & 0o777, 0o600, "{file:?}");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn file_store_removes_expired() -> TestResult {
+        let dir = TempDir::new()?;
+        let store = FileTokenStore::new(dir.path().join("tokens.json"));
+        store.store("expired", stored("t1", Duration::ZERO)).await?;
+        store
+            .store("valid", stored("t2", Duration::from_secs(3600)))
+            .await?;
+        assert!(store.load("expired").await?.is_none());
+        assert!(store.load("valid").await?.is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn file_store_corrupted() -> TestResult {
+        let dir = TempDir::new()?;
+        let path = dir.path().join("tokens.json");
+        std::fs::write(&path, "not json")?;
+        let store = FileTokenStore::new(&path);
+        assert!(store.load("k1").await?.is_none());
+        store
+            .store("k1", stored("t1", Duration::from_secs(3600)))
+            .await?;
+        assert!(store.load("k1").await?.is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn file_store_concurrent() -> TestResult {
+        let dir = TempDir::new()?;
+        let path = dir.path().join("tokens.json");
+        let tasks = (0..16)
+            .map(|i| {
+                let store = FileTokenStore::new(&path);
+                tokio::spawn(async move {
+                    store
+                        .store(&format!("k{i}"), stored("t", Duration::from_secs(3600)))
+                        .await
+                })
+            })
+            .collect::<Vec<_>>();
+        for t in tasks {
+            t.await??;
+        }
+        let store = FileTokenStore::new(&path);
+        for i in 0..16 {
+            assert!(store.load(&format!("k{i}")).await?.is_some(), "k{i}");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn provider_without_store() -> TestResult {
+        let mut mock = MockTokenProvider::new();
+        mock.expect_token()
+            .times(2)
+            .returning(|| Ok(token("fresh", Duration::from_secs(3600))));
+        let provider = PersistentTokenProvider::new(mock, None, "key".to_string());
+        assert_eq!(provider.token().await?.token, "fresh");
+        assert_eq!(provider.token().await?.token, "fresh");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn provider_uses_store() -> TestResult {
+        let dir = TempDir::new()?;
+        let store: Arc<dyn dynamic::TokenStore> =
+            Arc::new(FileTokenStore::new(dir.path().join("tokens.json")));
+
+        let mut mock = MockTokenProvider::new();
+        mock.expect_token()
+            .times(1)
+            .returning(|| Ok(token("fresh", Duration::from_secs(3600))));
+        let provider = PersistentTokenProvider::new(mock, Some(store.clone()), "key".to_string());
+        assert_eq!(provider.token().await?.token, "fresh");
+
+        // A second provider, e.g. in another process, uses the stored token.
+        let mut mock = MockTokenProvider::new();
+        mock.expect_token().never();
+        let provider = PersistentTokenProvider::new(mock, Some(store.clone()), "key".to_string());
+        let got = provider.token().await?;
+        assert_eq!(got.token, "fresh");
+        let remaining = got.expires_at.unwrap() - Instant::now();
+        assert!(remaining > Duration::from_secs(3590), "{remaining:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn provider_refreshes_stale_tokens() -> TestResult {
+        let dir = TempDir::new()?;
+        let store: Arc<dyn dynamic::TokenStore> =
+            Arc::new(FileTokenStore::new(dir.path().join("tokens.json")));
+        store
+            .store("key", stored("stale", Duration::from_secs(60)))
+            .await?;
+
+        let mut mock = MockTokenProvider::new();
+        mock.expect_token()
+            .times(1)
+            .returning(|| Ok(token("fresh", Duration::from_secs(3600))));
+        let provider = PersistentTokenProvider::new(mock, Some(store.clone()), "key".to_string());
+        assert_eq!(provider.token().await?.token, "fresh");
+        assert_eq!(
+            store.load("key").await?.map(|t| t.token).as_deref(),
+            Some("fresh")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn provider_ignores_store_errors() -> TestResult {
+        let dir = TempDir::new()?;
+        // The parent of the token file is a regular file, all operations fail.
+        let parent = dir.path().join("file");
+        std::fs::write(&parent, "")?;
+        let store: Arc<dyn dynamic::TokenStore> =
+            Arc::new(FileTokenStore::new(parent.join("tokens.json")));
+        assert!(
+            store
+                .store("key", stored("t", Duration::from_secs(3600)))
+                .await
+                .is_err()
+        );
+
+        let mut mock = MockTokenProvider::new();
+        mock.expect_token()
+            .times(1)
+            .returning(|| Ok(token("fresh", Duration::from_secs(3600))));
+        let provider = PersistentTokenProvider::new(mock, Some(store), "key".to_string());
+        assert_eq!(provider.token().await?.token, "fresh");
+        Ok(())
+    }
+}
@@ -22,9 +22,9 @@
 //! information, specifically utilizing an OAuth 2.0 refresh token.
 //!
 //! This module is designed for refresh tokens obtained via the standard
-//! [Authorization Code grant]. Acquiring the initial refresh token (e.g., through
-//! user consent) is outside the scope of this library.
-//! See [RFC 6749 Section 4.1] for flow details.
+//! [Authorization Code grant]. See [RFC 6749 Section 4.1] for flow details.
+//! Applications can acquire the initial refresh token (e.g., through user
+//! consent) using the interactive flows in the [login] module.
 //!
 //! The Google Cloud client libraries for Rust will typically find and use these
 //! credentials automatically if a credentials file exists in the
@@ -95,6 +95,8 @@
 use crate::build_errors::Error as BuilderError;
 use crate::constants::OAUTH2_TOKEN_SERVER_URL;
 use crate::credentials::dynamic::{AccessTokenCredentialsProvider, CredentialsProvider};
+use crate::credentials::token_store::dynamic::TokenStore as TokenStoreDyn;
+use crate::credentials::token_store::{PersistentTokenProvider, TokenStore, cache_key};
 use crate::credentials::{AccessToken, AccessTokenCredentials, CacheableResource, Credentials};
 use crate::errors::{self, CredentialsError};
 use crate::headers_util::AuthHeadersBuilder;
@@ -109,9 +111,13 @@ use http::header::CONTENT_TYPE;
 use http::{Extensions, HeaderMap, HeaderValue};
 use reqwest::{Client, Method};
 use serde_json::Value;
+use std::io::Write;
+use std::path::Path;
 use std::sync::Arc;
 use tokio::time::{Duration, Instant};
 
+pub mod login;
+
 /// A builder for constructing `user_account` [Credentials] instance.
 ///
 /// # Example
@@ -128,6 +134,7 @@ pub struct Builder {
     quota_project_id: Option<String>,
     token_uri: Option<String>,
     retry_builder: RetryTokenProviderBuilder,
+    token_store: Option<Arc<dyn TokenStoreDyn>>,
 }
 
 impl Builder {
@@ -144,6 +151,7 @@ impl Builder {
             quota_project_id: None,
             token_uri: None,
             retry_builder: RetryTokenProviderBuilder::default(),
+            token_store: None,
         }
     }
 
@@ -305,6 +313,71 @@ impl Builder {
         self
     }
 
+    /// Sets the [TokenStore] to share access tokens across processes.
+    ///
+    /// Before fetching a new access token, the credentials look for a valid
+    /// token in the store, and save any new tokens to it. Use this with
+    /// short-lived processes, such as command-line tools, to avoid fetching a
+    /// new token on every invocation.
+    ///
+    /// ```
+    /// # use google_cloud_auth::credentials::user_account::Builder;
+    /// # use google_cloud_auth::credentials::token_store::FileTokenStore;
+    /// # async fn sample() -> anyhow::Result<()> {
+    /// let authorized_user = serde_json::json!({ /* add details here */ });
+    /// let credentials = Builder::new(authorized_user)
+    ///     .with_token_store(FileTokenStore::new("/var/cache/my-tool/tokens.json"))
+    ///     .build()?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [TokenStore]: crate::credentials::token_store::TokenStore
+    pub fn with_token_store<S: TokenStore + 'static>(mut self, store: S) -> Self {
+        self.token_store = Some(Arc::new(store));
+        self
+    }
+
+    pub(crate) fn maybe_token_store(mut self, store: Option<Arc<dyn TokenStoreDyn>>) -> Self {
+        self.token_store = store.or(self.token_store);
+        self
+    }
+
+    /// Writes the `authorized_user` JSON to a file.
+    ///
+    /// Use this function to persist the credentials obtained with one of the
+    /// [login] flows. The file can be loaded later with [Builder::new], or
+    /// used as [application-default credentials] by setting the
+    /// `GOOGLE_APPLICATION_CREDENTIALS` environment variable.
+    ///
+    /// The file contains a refresh token, on Unix-like systems it is only
+    /// readable by the owner.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_auth::credentials::user_account::Builder;
+    /// # fn sample() -> anyhow::Result<()> {
+    /// let authorized_user = serde_json::json!({ /* add details here */ });
+    /// let builder = Builder::new(authorized_user);
+    /// builder.write_authorized_user("authorized_user.json")?;
+    /// let credentials = builder.build()?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [application-default credentials]: https://cloud.google.com/docs/authentication/application-default-credentials
+    pub fn write_authorized_user<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        let contents = serde_json::to_vec_pretty(&self.authorized_user)?;
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options.open(path)?;
+        // The mode only applies to new files, restrict existing files before
+        // writing the refresh token.
+        #[cfg(unix)]
+        file.set_permissions(std::os::unix::fs::PermissionsExt::from_mode(0o600))?;
+        file.write_all(&contents)
+    }
+
     /// Returns a [Credentials] instance with the configured settings.
     ///
     /// # Errors
@@ -380,6 +453,14 @@ impl Builder {
             .unwrap_or(OAUTH2_TOKEN_SERVER_URL.to_string());
         let quota_project_id = self.quota_project_id.or(authorized_user.quota_project_id);
 
+        let key = cache_key(
+            &format!(
+                "authorized_user:{endpoint}:{}:{}",
+                authorized_user.client_id, authorized_user.refresh_token
+            ),
+            self.scopes.as_deref().unwrap_or_default(),
+            None,
+        );
         let token_provider = UserTokenProvider {
             client_id: authorized_user.client_id,
             client_secret: authorized_user.client_secret,
@@ -389,7 +470,12 @@ impl Builder {
             source: UserTokenSource::AccessToken,
         };
 
-        let token_provider = TokenCache::new(self.retry_builder.build(token_provider));
+        let token_provider = self.retry_builder.build(token_provider);
+        let token_provider = TokenCache::new(PersistentTokenProvider::new(
+            token_provider,
+            self.token_store,
+            key,
+        ));
 
         Ok(UserCredentials {
             token_provider,
@@ -693,6 +779,24 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn write_authorized_user_restricts_existing_file() -> TestResult {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::TempDir::new()?;
+        let path = dir.path().join("authorized_user.json");
+        std::fs::write(&path, "{}")?;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644))?;
+
+        let builder = Builder::new(authorized_user_json("test-token-uri".to_string()));
+        builder.write_authorized_user(&path)?;
+        let mode = std::fs::metadata(&path)?.permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        let contents: Value = serde_json::from_slice(&std::fs::read(&path)?)?;
+        assert_eq!(contents, authorized_user_json("test-token-uri".to_string()));
+        Ok(())
+    }
+
     #[test]
     fn debug_token_provider() {
         let expected = UserTokenProvider {
@@ -1377,4 +1481,34 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn token_store_shared_across_credentials() -> TestResult {
+        let server = Server::run();
+        let response = Oauth2RefreshResponse {
+            access_token: "test-access-token".to_string(),
+            id_token: None,
+            expires_in: Some(3600),
+            refresh_token: None,
+            scope: None,
+            token_type: "Bearer".to_string(),
+        };
+        server.expect(
+            Expectation::matching(request::path("/token"))
+                .times(1)
+                .respond_with(json_encoded(response)),
+        );
+
+        let dir = tempfile::TempDir::new()?;
+        let path = dir.path().join("tokens.json");
+        for _ in 0..2 {
+            let credentials = Builder::new(authorized_user_json(server.url("/token").to_string()))
+                .with_token_store(crate::credentials::token_store::FileTokenStore::new(&path))
+                .build()?;
+            let headers = credentials.headers(Extensions::new()).await?;
+            let token = get_token_from_headers(headers).unwrap();
+            assert_eq!(token, "test-access-token");
+        }
+        Ok(())
+    }
 }
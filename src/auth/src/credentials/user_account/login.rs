@@ -0,0 +1,1108 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Interactive login flows for [user account] credentials.
+//!
+//! Command-line tools and desktop applications can use these flows to obtain
+//! a refresh token for the user, without requiring the Google Cloud CLI. Both
+//! flows require an [OAuth 2.0 client] for the application.
+//!
+//! * [LoopbackFlow] implements the [installed application] flow. The user
+//!   opens the authorization URL in a browser on the same machine, and the
+//!   browser redirects to a local HTTP server with the authorization code.
+//!   The flow uses [PKCE] to protect the authorization code.
+//! * [DeviceFlow] implements the [device authorization] flow. The user enters
+//!   a code on a verification page, using any device with a browser. Use this
+//!   flow when the application cannot receive a redirect from the browser,
+//!   for example, in a remote shell.
+//!
+//! Both flows return a [Builder] for the user account [Credentials]. The
+//! builder can [write][Builder::write_authorized_user] the `authorized_user`
+//! JSON to a file, to reuse the credentials without logging in again.
+//!
+//! ## Example: Login with a loopback redirect
+//!
+//! ```no_run
+//! # use google_cloud_auth::credentials::user_account::login::LoopbackFlow;
+//! # async fn sample() -> anyhow::Result<()> {
+//! let authorization = LoopbackFlow::new("YOUR_CLIENT_ID", "YOUR_CLIENT_SECRET")
+//!     .start()
+//!     .await?;
+//! println!("Open this URL in your browser: {}", authorization.authorization_url());
+//! let builder = authorization.finish().await?;
+//! builder.write_authorized_user("authorized_user.json")?;
+//! let credentials = builder.build()?;
+//! # Ok(()) }
+//! ```
+//!
+//! ## Example: Login with a device code
+//!
+//! ```no_run
+//! # use google_cloud_auth::credentials::user_account::login::DeviceFlow;
+//! # async fn sample() -> anyhow::Result<()> {
+//! let authorization = DeviceFlow::new("YOUR_CLIENT_ID", "YOUR_CLIENT_SECRET")
+//!     .start()
+//!     .await?;
+//! println!(
+//!     "Go to {} and enter the code {}",
+//!     authorization.verification_uri(),
+//!     authorization.user_code()
+//! );
+//! let credentials = authorization.finish().await?.build()?;
+//! # Ok(()) }
+//! ```
+//!
+//! [user account]: super
+//! [Credentials]: crate::credentials::Credentials
+//! [OAuth 2.0 client]: https://support.google.com/cloud/answer/15549257
+//! [installed application]: https://developers.google.com/identity/protocols/oauth2/native-app
+//! [device authorization]: https://developers.google.com/identity/protocols/oauth2/limited-input-device
+//! [PKCE]: https://datatracker.ietf.org/doc/html/rfc7636
+
+use super::{Builder, Oauth2RefreshResponse};
+use crate::Result;
+use crate::constants::{DEFAULT_SCOPE, OAUTH2_TOKEN_SERVER_URL};
+use crate::errors::{self, CredentialsError};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use reqwest::Client;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::time::{Duration, Instant};
+
+const AUTH_URI: &str = "https://accounts.google.com/o/oauth2/auth";
+const DEVICE_AUTHORIZATION_URI: &str = "https://oauth2.googleapis.com/device/code";
+const DEVICE_CODE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+const MSG: &str = "failed to complete the user login";
+
+// The defaults from RFC 8628.
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+const SLOW_DOWN_INCREMENT: Duration = Duration::from_secs(5);
+
+// The largest redirect request accepted by the loopback server.
+const MAX_REQUEST_SIZE: usize = 16 * 1024;
+// How long the loopback server waits for each request.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The installed application login flow, using a loopback redirect.
+///
+/// # Example
+/// ```no_run
+/// # use google_cloud_auth::credentials::user_account::login::LoopbackFlow;
+/// # async fn sample() -> anyhow::Result<()> {
+/// let authorization = LoopbackFlow::new("YOUR_CLIENT_ID", "YOUR_CLIENT_SECRET")
+///     .with_scopes(["https://www.googleapis.com/auth/cloud-platform"])
+///     .start()
+///     .await?;
+/// println!("Open this URL in your browser: {}", authorization.authorization_url());
+/// let credentials = authorization.finish().await?.build()?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct LoopbackFlow {
+    client: OAuthClient,
+    scopes: Vec<String>,
+    auth_uri: String,
+    port: u16,
+}
+
+impl LoopbackFlow {
+    /// Creates a new flow for the given OAuth 2.0 client.
+    ///
+    /// The client must be of the "Desktop app" type.
+    pub fn new<I, S>(client_id: I, client_secret: S) -> Self
+    where
+        I: Into<String>,
+        S: Into<String>,
+    {
+        Self {
+            client: OAuthClient::new(client_id.into(), client_secret.into()),
+            scopes: vec![DEFAULT_SCOPE.to_string()],
+            auth_uri: AUTH_URI.to_string(),
+            port: 0,
+        }
+    }
+
+    /// Sets the [scopes] requested in the login.
+    ///
+    /// Defaults to `https://www.googleapis.com/auth/cloud-platform`.
+    ///
+    /// [scopes]: https://developers.google.com/identity/protocols/oauth2/scopes
+    pub fn with_scopes<I, S>(mut self, scopes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.scopes = scopes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the URI of the authorization endpoint.
+    ///
+    /// Defaults to `https://accounts.google.com/o/oauth2/auth`.
+    pub fn with_auth_uri<S: Into<String>>(mut self, auth_uri: S) -> Self {
+        self.auth_uri = auth_uri.into();
+        self
+    }
+
+    /// Sets the URI of the token endpoint.
+    ///
+    /// Defaults to `https://oauth2.googleapis.com/token`. The resulting
+    /// credentials use the same endpoint to refresh access tokens.
+    pub fn with_token_uri<S: Into<String>>(mut self, token_uri: S) -> Self {
+        self.client.token_uri = token_uri.into();
+        self
+    }
+
+    /// Sets the local port to receive the redirect.
+    ///
+    /// Defaults to `0`, which picks any available port.
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    /// Starts the login.
+    ///
+    /// Starts a local HTTP server to receive the authorization code, and
+    /// returns the URL the user must open in a browser.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the local server cannot be started, or if the
+    /// authorization URI is invalid.
+    pub async fn start(self) -> Result<LoopbackAuthorization> {
+        let listener = TcpListener::bind(("127.0.0.1", self.port))
+            .await
+            .map_err(errors::non_retryable)?;
+        let port = listener.local_addr().map_err(errors::non_retryable)?.port();
+        let redirect_uri = format!("http://127.0.0.1:{port}");
+        let state = random_string()?;
+        let code_verifier = random_string()?;
+        let scope = self.scopes.join(" ");
+        let challenge = code_challenge(&code_verifier);
+        let params = [
+            ("client_id", self.client.client_id.as_str()),
+            ("redirect_uri", redirect_uri.as_str()),
+            ("response_type", "code"),
+            ("scope", scope.as_str()),
+            ("state", state.as_str()),
+            ("code_challenge", challenge.as_str()),
+            ("code_challenge_method", "S256"),
+            ("access_type", "offline"),
+        ];
+        let authorization_url = url::Url::parse_with_params(&self.auth_uri, params)
+            .map_err(errors::non_retryable)?
+            .to_string();
+        Ok(LoopbackAuthorization {
+            client: self.client,
+            listener,
+            authorization_url,
+            redirect_uri,
+            state,
+            code_verifier,
+        })
+    }
+}
+
+/// A login started with a [LoopbackFlow].
+pub struct LoopbackAuthorization {
+    client: OAuthClient,
+    listener: TcpListener,
+    authorization_url: String,
+    redirect_uri: String,
+    state: String,
+    code_verifier: String,
+}
+
+impl std::fmt::Debug for LoopbackAuthorization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoopbackAuthorization")
+            .field("client", &self.client)
+            .field("authorization_url", &self.authorization_url)
+            .field("redirect_uri", &self.redirect_uri)
+            .field("state", &"[censored]")
+            .field("code_verifier", &"[censored]")
+            .finish()
+    }
+}
+
+impl LoopbackAuthorization {
+    /// The URL the user must open in a browser to login.
+    pub fn authorization_url(&self) -> &str {
+        &self.authorization_url
+    }
+
+    /// The URI where the browser sends the authorization code.
+    pub fn redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    /// Waits for the user to login, and returns a builder for the credentials.
+    ///
+    /// This function waits until the browser redirects to the local server.
+    /// Use [tokio::time::timeout] to limit how long to wait for the user.
+    ///
+    /// The local server handles each connection independently, and ignores
+    /// requests that are not a valid redirect for this login.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user denies access, or if exchanging the
+    /// authorization code fails.
+    pub async fn finish(self) -> Result<Builder> {
+        let handler = RedirectHandler {
+            redirect_uri: self.redirect_uri.clone(),
+            state: self.state.clone(),
+        };
+        let mut requests = tokio::task::JoinSet::new();
+        let code = loop {
+            tokio::select! {
+                accepted = self.listener.accept() => {
+                    let (stream, _) = accepted.map_err(errors::non_retryable)?;
+                    let handler = handler.clone();
+                    requests.spawn(async move {
+                        // A stalled connection must not block the login.
+                        tokio::time::timeout(REQUEST_TIMEOUT, handler.handle(stream))
+                            .await
+                            .unwrap_or(Ok(None))
+                    });
+                }
+                Some(result) = requests.join_next() => {
+                    match result.map_err(errors::non_retryable)? {
+                        Ok(Some(code)) => break code,
+                        Ok(None) => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+            }
+        };
+        let form = [
+            ("grant_type", "authorization_code"),
+            ("code", code.as_str()),
+            ("redirect_uri", self.redirect_uri.as_str()),
+            ("code_verifier", self.code_verifier.as_str()),
+        ];
+        match self.client.request_token(&form).await? {
+            TokenResponse::Token(response) => self.client.builder(response),
+            TokenResponse::AuthorizationPending | TokenResponse::SlowDown => {
+                Err(errors::non_retryable_from_str(format!(
+                    "{MSG}, unexpected response to the authorization code exchange"
+                )))
+            }
+        }
+    }
+}
+
+/// Validates the redirects received by the loopback server.
+#[derive(Clone)]
+struct RedirectHandler {
+    redirect_uri: String,
+    state: String,
+}
+
+impl RedirectHandler {
+    /// Handles a single request to the loopback server.
+    ///
+    /// Returns `Ok(None)` for requests that are not the redirect for this
+    /// login, for example, a browser asking for the `favicon.ico`, or a
+    /// redirect with the wrong `state` parameter.
+    async fn handle(self, mut stream: TcpStream) -> Result<Option<String>> {
+        let Ok(target) = read_request_target(&mut stream).await else {
+            return Ok(None);
+        };
+        let Some(params) = target
+            .as_deref()
+            .and_then(|t| url::Url::parse(&format!("{}{t}", self.redirect_uri)).ok())
+        else {
+            respond(&mut stream, "400 Bad Request", "Invalid request.").await;
+            return Ok(None);
+        };
+        let param = |name: &str| {
+            params
+                .query_pairs()
+                .find(|(k, _)| k == name)
+                .map(|(_, v)| v.into_owned())
+        };
+        let (code, error) = (param("code"), param("error"));
+        if code.is_none() && error.is_none() {
+            respond(&mut stream, "404 Not Found", "Not found.").await;
+            return Ok(None);
+        }
+        // The redirect may come from a different login, or from another
+        // application. Keep waiting for the redirect of this login.
+        if param("state").as_deref() != Some(self.state.as_str()) {
+            respond(&mut stream, "400 Bad Request", "Invalid state parameter.").await;
+            return Ok(None);
+        }
+        if let Some(error) = error {
+            respond(
+                &mut stream,
+                "200 OK",
+                "Login failed. You can close this window.",
+            )
+            .await;
+            return Err(errors::non_retryable_from_str(format!(
+                "{MSG}, the authorization server returned an error: {error}"
+            )));
+        }
+        respond(
+            &mut stream,
+            "200 OK",
+            "Login complete. You can close this window.",
+        )
+        .await;
+        Ok(code)
+    }
+}
+
+/// The device authorization login flow.
+///
+/// # Example
+/// ```no_run
+/// # use google_cloud_auth::credentials::user_account::login::DeviceFlow;
+/// # async fn sample() -> anyhow::Result<()> {
+/// let authorization = DeviceFlow::new("YOUR_CLIENT_ID", "YOUR_CLIENT_SECRET")
+///     .with_scopes(["https://www.googleapis.com/auth/cloud-platform"])
+///     .start()
+///     .await?;
+/// println!(
+///     "Go to {} and enter the code {}",
+///     authorization.verification_uri(),
+///     authorization.user_code()
+/// );
+/// let credentials = authorization.finish().await?.build()?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct DeviceFlow {
+    client: OAuthClient,
+    scopes: Vec<String>,
+    device_authorization_uri: String,
+}
+
+impl DeviceFlow {
+    /// Creates a new flow for the given OAuth 2.0 client.
+    ///
+    /// The client must be of the "TVs and Limited Input devices" type.
+    pub fn new<I, S>(client_id: I, client_secret: S) -> Self
+    where
+        I: Into<String>,
+        S: Into<String>,
+    {
+        Self {
+            client: OAuthClient::new(client_id.into(), client_secret.into()),
+            scopes: vec![DEFAULT_SCOPE.to_string()],
+            device_authorization_uri: DEVICE_AUTHORIZATION_URI.to_string(),
+        }
+    }
+
+    /// Sets the [scopes] requested in the login.
+    ///
+    /// Defaults to `https://www.googleapis.com/auth/cloud-platform`. Note that
+    /// the device flow only supports [some scopes].
+    ///
+    /// [scopes]: https://developers.google.com/identity/protocols/oauth2/scopes
+    /// [some scopes]: https://developers.google.com/identity/protocols/oauth2/limited-input-device#allowedscopes
+    pub fn with_scopes<I, S>(mut self, scopes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.scopes = scopes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the URI of the device authorization endpoint.
+    ///
+    /// Defaults to `https://oauth2.googleapis.com/device/code`.
+    pub fn with_device_authorization_uri<S: Into<String>>(mut self, uri: S) -> Self {
+        self.device_authorization_uri = uri.into();
+        self
+    }
+
+    /// Sets the URI of the token endpoint.
+    ///
+    /// Defaults to `https://oauth2.googleapis.com/token`. The resulting
+    /// credentials use the same endpoint to refresh access tokens.
+    pub fn with_token_uri<S: Into<String>>(mut self, token_uri: S) -> Self {
+        self.client.token_uri = token_uri.into();
+        self
+    }
+
+    /// Starts the login.
+    ///
+    /// Requests a device code, and returns the verification URI and the code
+    /// the user must enter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the device authorization request fails.
+    pub async fn start(self) -> Result<DeviceAuthorization> {
+        let scope = self.scopes.join(" ");
+        let form = [
+            ("client_id", self.client.client_id.as_str()),
+            ("scope", scope.as_str()),
+        ];
+        let response = Client::new()
+            .post(&self.device_authorization_uri)
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| errors::from_http_error(e, MSG))?;
+        if !response.status().is_success() {
+            return Err(errors::from_http_response(response, MSG).await);
+        }
+        let response = response
+            .json::<DeviceAuthorizationResponse>()
+            .await
+            .map_err(|e| CredentialsError::new(!e.is_decode(), MSG, e))?;
+        Ok(DeviceAuthorization {
+            client: self.client,
+            device_code: response.device_code,
+            user_code: response.user_code,
+            verification_uri: response.verification_uri,
+            verification_uri_complete: response.verification_uri_complete,
+            expires_at: Instant::now() + Duration::from_secs(response.expires_in),
+            interval: response
+                .interval
+                .map(Duration::from_secs)
+                .unwrap_or(DEFAULT_INTERVAL),
+        })
+    }
+}
+
+/// A login started with a [DeviceFlow].
+pub struct DeviceAuthorization {
+    client: OAuthClient,
+    device_code: String,
+    user_code: String,
+    verification_uri: String,
+    verification_uri_complete: Option<String>,
+    expires_at: Instant,
+    interval: Duration,
+}
+
+impl std::fmt::Debug for DeviceAuthorization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeviceAuthorization")
+            .field("client", &self.client)
+            .field("device_code", &"[censored]")
+            .field("user_code", &self.user_code)
+            .field("verification_uri", &self.verification_uri)
+            .field("verification_uri_complete", &self.verification_uri_complete)
+            .field("expires_at", &self.expires_at)
+            .field("interval", &self.interval)
+            .finish()
+    }
+}
+
+impl DeviceAuthorization {
+    /// The code the user must enter in the verification page.
+    pub fn user_code(&self) -> &str {
+        &self.user_code
+    }
+
+    /// The URI of the verification page.
+    pub fn verification_uri(&self) -> &str {
+        &self.verification_uri
+    }
+
+    /// The URI of the verification page, including the user code.
+    ///
+    /// Not all authorization servers provide this URI.
+    pub fn verification_uri_complete(&self) -> Option<&str> {
+        self.verification_uri_complete.as_deref()
+    }
+
+    /// Waits for the user to login, and returns a builder for the credentials.
+    ///
+    /// This function polls the token endpoint until the user completes the
+    /// login, or until the device code expires.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user denies access, if the device code expires,
+    /// or if polling the token endpoint fails.
+    pub async fn finish(self) -> Result<Builder> {
+        let mut interval = self.interval;
+        let form = [
+            ("grant_type", DEVICE_CODE_GRANT_TYPE),
+            ("device_code", self.device_code.as_str()),
+        ];
+        loop {
+            if Instant::now() + interval >= self.expires_at {
+                return Err(errors::non_retryable_from_str(format!(
+                    "{MSG}, the device code expired before the user completed the login"
+                )));
+            }
+            tokio::time::sleep(interval).await;
+            match self.client.request_token(&form).await? {
+                TokenResponse::Token(response) => return self.client.builder(response),
+                TokenResponse::AuthorizationPending => {}
+                TokenResponse::SlowDown => interval += SLOW_DOWN_INCREMENT,
+            }
+        }
+    }
+}
+
+/// The OAuth 2.0 client used in the login flows.
+struct OAuthClient {
+    client_id: String,
+    client_secret: String,
+    token_uri: String,
+}
+
+impl std::fmt::Debug for OAuthClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OAuthClient")
+            .field("client_id", &self.client_id)
+            .field("client_secret", &"[censored]")
+            .field("token_uri", &self.token_uri)
+            .finish()
+    }
+}
+
+impl OAuthClient {
+    fn new(client_id: String, client_secret: String) -> Self {
+        Self {
+            client_id,
+            client_secret,
+            token_uri: OAUTH2_TOKEN_SERVER_URL.to_string(),
+        }
+    }
+
+    async fn request_token(&self, form: &[(&str, &str)]) -> Result<TokenResponse> {
+        let credentials = [
+            ("client_id", self.client_id.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+        ];
+        let form = credentials.iter().chain(form).collect::<Vec<_>>();
+        let response = Client::new()
+            .post(&self.token_uri)
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| errors::from_http_error(e, MSG))?;
+        if response.status().is_success() {
+            let response = response
+                .json::<Oauth2RefreshResponse>()
+                .await
+                .map_err(|e| CredentialsError::new(!e.is_decode(), MSG, e))?;
+            return Ok(TokenResponse::Token(response));
+        }
+        let err = response
+            .error_for_status_ref()
+            .expect_err("the response status is not successful");
+        let body = response
+            .text()
+            .await
+            .map_err(|e| errors::from_http_error(e, MSG))?;
+        let error = serde_json::from_str::<ErrorResponse>(&body).map(|e| e.error);
+        match error.as_deref() {
+            Ok("authorization_pending") => Ok(TokenResponse::AuthorizationPending),
+            Ok("slow_down") => Ok(TokenResponse::SlowDown),
+            _ => Err(CredentialsError::new(
+                errors::is_retryable(&err),
+                format!("{MSG}, body=<{body}>"),
+                err,
+            )),
+        }
+    }
+
+    fn builder(&self, response: Oauth2RefreshResponse) -> Result<Builder> {
+        let refresh_token = response.refresh_token.ok_or_else(|| {
+            errors::non_retryable_from_str(format!(
+                "{MSG}, the token response does not include a refresh token"
+            ))
+        })?;
+        let mut authorized_user = serde_json::json!({
+            "type": "authorized_user",
+            "client_id": self.client_id,
+            "client_secret": self.client_secret,
+            "refresh_token": refresh_token,
+        });
+        if self.token_uri != OAUTH2_TOKEN_SERVER_URL {
+            authorized_user["token_uri"] = self.token_uri.clone().into();
+        }
+        Ok(Builder::new(authorized_user))
+    }
+}
+
+enum TokenResponse {
+    Token(Oauth2RefreshResponse),
+    AuthorizationPending,
+    SlowDown,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+#[derive(Deserialize)]
+struct DeviceAuthorizationResponse {
+    device_code: String,
+    user_code: String,
+    // Google's endpoint predates RFC 8628 and uses `verification_url`.
+    #[serde(alias = "verification_url")]
+    verification_uri: String,
+    verification_uri_complete: Option<String>,
+    expires_in: u64,
+    interval: Option<u64>,
+}
+
+/// Returns a random, URL-safe, string with 256 bits of entropy.
+///
+/// The string is suitable as a PKCE code verifier and as the `state`
+/// parameter.
+fn random_string() -> Result<String> {
+    let mut bytes = [0_u8; 32];
+    crate::credentials::crypto_provider::get_secure_random()
+        .fill(&mut bytes)
+        .map_err(|_| errors::non_retryable_from_str("failed to generate random bytes"))?;
+    Ok(URL_SAFE_NO_PAD.encode(bytes))
+}
+
+fn code_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+/// Reads the request line, and returns the request target for `GET` requests.
+async fn read_request_target(stream: &mut TcpStream) -> std::io::Result<Option<String>> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0_u8; 1024];
+    while !buffer.windows(4).any(|w| w == b"\r\n\r\n") && buffer.len() < MAX_REQUEST_SIZE {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            break;
+        }
+        buffer.extend_from_slice(&chunk[..n]);
+    }
+    let request = String::from_utf8_lossy(&buffer);
+    let mut parts = request
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .split_whitespace();
+    match (parts.next(), parts.next()) {
+        (Some("GET"), Some(target)) if target.starts_with('/') => Ok(Some(target.to_string())),
+        _ => Ok(None),
+    }
+}
+
+async fn respond(stream: &mut TcpStream, status: &str, message: &str) {
+    let body = format!("<html><body><p>{message}</p></body></html>");
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    // The login does not depend on the browser receiving the response.
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::credentials::tests::get_token_from_headers;
+    use http::Extensions;
+    use httptest::{Expectation, Server, cycle, matchers::*, responders::*};
+    use serde_json::{Value, json};
+    use std::collections::HashMap;
+
+    type TestResult = anyhow::Result<()>;
+
+    fn query(url: &str) -> anyhow::Result<HashMap<String, String>> {
+        Ok(url::Url::parse(url)?.query_pairs().into_owned().collect())
+    }
+
+    fn token_response() -> Value {
+        json!({
+            "access_token": "test-access-token",
+            "refresh_token": "test-refresh-token",
+            "expires_in": 3600,
+            "token_type": "Bearer",
+        })
+    }
+
+    #[test]
+    fn pkce_code_challenge() {
+        // The example in RFC 7636 Appendix B.
+        let got = code_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk");
+        assert_eq!(got, "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM");
+    }
+
+    #[test]
+    fn random_strings() -> TestResult {
+        let (a, b) = (random_string()?, random_string()?);
+        assert_eq!(a.len(), 43, "{a}");
+        assert_ne!(a, b);
+        Ok(())
+    }
+
+    #[test]
+    fn debug() {
+        let flow = LoopbackFlow::new("test-client-id", "test-client-secret");
+        let got = format!("{flow:?}");
+        assert!(got.contains("test-client-id"), "{got}");
+        assert!(!got.contains("test-client-secret"), "{got}");
+    }
+
+    #[tokio::test]
+    async fn loopback() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/token"),
+                request::body(url_decoded(contains(("grant_type", "authorization_code")))),
+                request::body(url_decoded(contains(("code", "test-code")))),
+                request::body(url_decoded(contains(("client_id", "test-client-id")))),
+                request::body(url_decoded(contains((
+                    "client_secret",
+                    "test-client-secret"
+                )))),
+                request::body(url_decoded(contains(key("code_verifier")))),
+                request::body(url_decoded(contains(key("redirect_uri")))),
+            ])
+            .times(1)
+            .respond_with(json_encoded(token_response())),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/token"),
+                request::body(matches("\"refresh_token\":\"test-refresh-token\"")),
+            ])
+            .times(1)
+            .respond_with(json_encoded(token_response())),
+        );
+
+        let token_uri = server.url("/token").to_string();
+        let authorization = LoopbackFlow::new("test-client-id", "test-client-secret")
+            .with_auth_uri("https://auth.example.com/auth")
+            .with_token_uri(&token_uri)
+            .with_scopes(["scope1", "scope2"])
+            .start()
+            .await?;
+        let params = query(authorization.authorization_url())?;
+        assert_eq!(
+            params.get("client_id").map(String::as_str),
+            Some("test-client-id")
+        );
+        assert_eq!(
+            params.get("redirect_uri").map(String::as_str),
+            Some(authorization.redirect_uri())
+        );
+        assert_eq!(
+            params.get("scope").map(String::as_str),
+            Some("scope1 scope2")
+        );
+        assert_eq!(
+            params.get("code_challenge_method").map(String::as_str),
+            Some("S256")
+        );
+        assert!(params.contains_key("code_challenge"), "{params:?}");
+        let state = params.get("state").expect("state is set").clone();
+
+        // Simulate the browser, including an unrelated request.
+        let redirect_uri = authorization.redirect_uri().to_string();
+        let browser = tokio::spawn(async move {
+            let favicon = reqwest::get(format!("{redirect_uri}/favicon.ico")).await?;
+            assert_eq!(favicon.status(), reqwest::StatusCode::NOT_FOUND);
+            let response =
+                reqwest::get(format!("{redirect_uri}/?code=test-code&state={state}")).await?;
+            assert!(response.status().is_success(), "{response:?}");
+            anyhow::Ok(())
+        });
+
+        let builder = authorization.finish().await?;
+        browser.await??;
+
+        let dir = tempfile::TempDir::new()?;
+        let path = dir.path().join("authorized_user.json");
+        builder.write_authorized_user(&path)?;
+        let contents: Value = serde_json::from_slice(&std::fs::read(&path)?)?;
+        assert_eq!(
+            contents,
+            json!({
+                "type": "authorized_user",
+                "client_id": "test-client-id",
+                "client_secret": "test-client-secret",
+                "refresh_token": "test-refresh-token",
+                "token_uri": token_uri,
+            })
+        );
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path)?.permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        let credentials = builder.build()?;
+        let headers = credentials.headers(Extensions::new()).await?;
+        assert_eq!(
+            get_token_from_headers(headers).as_deref(),
+            Some("test-access-token")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn loopback_state_mismatch() -> TestResult {
+        let authorization = LoopbackFlow::new("test-client-id", "test-client-secret")
+            .with_token_uri("http://127.0.0.1:1/token")
+            .start()
+            .await?;
+        let state = query(authorization.authorization_url())?
+            .remove("state")
+            .expect("state is set");
+        let redirect_uri = authorization.redirect_uri().to_string();
+        let browser = tokio::spawn(async move {
+            let response =
+                reqwest::get(format!("{redirect_uri}/?code=test-code&state=bad-state")).await?;
+            assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+            // The login keeps waiting for a valid redirect.
+            reqwest::get(format!("{redirect_uri}/?error=access_denied&state={state}")).await?;
+            anyhow::Ok(())
+        });
+        let err = authorization
+            .finish()
+            .await
+            .err()
+            .expect("login should fail");
+        assert!(err.to_string().contains("access_denied"), "{err:?}");
+        browser.await??;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn loopback_stalled_connection() -> TestResult {
+        let authorization = LoopbackFlow::new("test-client-id", "test-client-secret")
+            .start()
+            .await?;
+        let state = query(authorization.authorization_url())?
+            .remove("state")
+            .expect("state is set");
+        let redirect_uri = authorization.redirect_uri().to_string();
+        let address = redirect_uri.trim_start_matches("http://").to_string();
+        let browser = tokio::spawn(async move {
+            // A connection that never sends a request does not block the
+            // redirect.
+            let _stalled = TcpStream::connect(address).await?;
+            reqwest::get(format!("{redirect_uri}/?error=access_denied&state={state}")).await?;
+            anyhow::Ok(())
+        });
+        let err = tokio::time::timeout(REQUEST_TIMEOUT / 2, authorization.finish())
+            .await?
+            .err()
+            .expect("login should fail");
+        assert!(err.to_string().contains("access_denied"), "{err:?}");
+        browser.await??;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn loopback_access_denied() -> TestResult {
+        let authorization = LoopbackFlow::new("test-client-id", "test-client-secret")
+            .start()
+            .await?;
+        let state = query(authorization.authorization_url())?
+            .remove("state")
+            .expect("state is set");
+        let redirect_uri = authorization.redirect_uri().to_string();
+        let browser = tokio::spawn(async move {
+            reqwest::get(format!("{redirect_uri}/?error=access_denied&state={state}")).await
+        });
+        let err = authorization
+            .finish()
+            .await
+            .err()
+            .expect("login should fail");
+        assert!(!err.is_transient(), "{err:?}");
+        assert!(err.to_string().contains("access_denied"), "{err:?}");
+        browser.await??;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn loopback_missing_refresh_token() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("POST", "/token")).respond_with(
+                json_encoded(json!({
+                    "access_token": "test-access-token",
+                    "expires_in": 3600,
+                    "token_type": "Bearer",
+                })),
+            ),
+        );
+        let authorization = LoopbackFlow::new("test-client-id", "test-client-secret")
+            .with_token_uri(server.url("/token").to_string())
+            .start()
+            .await?;
+        let state = query(authorization.authorization_url())?
+            .remove("state")
+            .expect("state is set");
+        let redirect_uri = authorization.redirect_uri().to_string();
+        let browser = tokio::spawn(async move {
+            reqwest::get(format!("{redirect_uri}/?code=test-code&state={state}")).await
+        });
+        let err = authorization
+            .finish()
+            .await
+            .err()
+            .expect("login should fail");
+        assert!(err.to_string().contains("refresh token"), "{err:?}");
+        browser.await??;
+        Ok(())
+    }
+
+    fn device_code_response(server: &Server, interval: u64, expires_in: u64) -> Value {
+        json!({
+            "device_code": "test-device-code",
+            "user_code": "ABCD-EFGH",
+            "verification_url": server.url("/device").to_string(),
+            "expires_in": expires_in,
+            "interval": interval,
+        })
+    }
+
+    #[tokio::test]
+    async fn device() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/device/code"),
+                request::body(url_decoded(contains(("client_id", "test-client-id")))),
+                request::body(url_decoded(contains(("scope", "scope1 scope2")))),
+            ])
+            .times(1)
+            .respond_with(json_encoded(device_code_response(&server, 0, 60))),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/token"),
+                request::body(url_decoded(contains((
+                    "grant_type",
+                    DEVICE_CODE_GRANT_TYPE
+                )))),
+                request::body(url_decoded(contains(("device_code", "test-device-code")))),
+                request::body(url_decoded(contains((
+                    "client_secret",
+                    "test-client-secret"
+                )))),
+            ])
+            .times(3)
+            .respond_with(cycle![
+                status_code(428).body(json!({"error": "authorization_pending"}).to_string()),
+                status_code(400).body(json!({"error": "authorization_pending"}).to_string()),
+                json_encoded(token_response()),
+            ]),
+        );
+
+        let authorization = DeviceFlow::new("test-client-id", "test-client-secret")
+            .with_device_authorization_uri(server.url("/device/code").to_string())
+            .with_token_uri(server.url("/token").to_string())
+            .with_scopes(["scope1", "scope2"])
+            .start()
+            .await?;
+        assert_eq!(authorization.user_code(), "ABCD-EFGH");
+        assert_eq!(
+            authorization.verification_uri(),
+            server.url("/device").to_string()
+        );
+        assert_eq!(authorization.verification_uri_complete(), None);
+        let got = format!("{authorization:?}");
+        assert!(!got.contains("test-device-code"), "{got}");
+
+        let builder = authorization.finish().await?;
+        let credentials = builder.build_access_token_credentials()?;
+        server.expect(
+            Expectation::matching(request::method_path("POST", "/token"))
+                .times(1)
+                .respond_with(json_encoded(token_response())),
+        );
+        let token = credentials.access_token().await?;
+        assert_eq!(token.token, "test-access-token");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn device_access_denied() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("POST", "/device/code"))
+                .respond_with(json_encoded(device_code_response(&server, 0, 60))),
+        );
+        server.expect(
+            Expectation::matching(request::method_path("POST", "/token"))
+                .respond_with(status_code(403).body(json!({"error": "access_denied"}).to_string())),
+        );
+        let authorization = DeviceFlow::new("test-client-id", "test-client-secret")
+            .with_device_authorization_uri(server.url("/device/code").to_string())
+            .with_token_uri(server.url("/token").to_string())
+            .start()
+            .await?;
+        let err = authorization
+            .finish()
+            .await
+            .err()
+            .expect("login should fail");
+        assert!(!err.is_transient(), "{err:?}");
+        assert!(err.to_string().contains("access_denied"), "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn device_expired() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("POST", "/device/code"))
+                .respond_with(json_encoded(device_code_response(&server, 5, 1))),
+        );
+        let authorization = DeviceFlow::new("test-client-id", "test-client-secret")
+            .with_device_authorization_uri(server.url("/device/code").to_string())
+            .with_token_uri(server.url("/token").to_string())
+            .start()
+            .await?;
+        let err = authorization
+            .finish()
+            .await
+            .err()
+            .expect("login should fail");
+        assert!(!err.is_transient(), "{err:?}");
+        assert!(err.to_string().contains("expired"), "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn device_start_error() -> TestResult {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("POST", "/device/code"))
+                .respond_with(status_code(400).body("invalid_client")),
+        );
+        let err = DeviceFlow::new("test-client-id", "test-client-secret")
+            .with_device_authorization_uri(server.url("/device/code").to_string())
+            .start()
+            .await
+            .unwrap_err();
+        assert!(!err.is_transient(), "{err:?}");
+        assert!(err.to_string().contains("invalid_client"), "{err:?}");
+        Ok(())
+    }
+}
@@ -125,7 +125,7 @@ pub(crate) fn is_gax_error_retryable(err: &GaxError) -> bool {
     false
 }
 
-fn is_retryable(err: &reqwest::Error) -> bool {
+pub(crate) fn is_retryable(err: &reqwest::Error) -> bool {
     if err.is_connect() {
         // Connection errors are transient more often than not. A bad
         // configuration can point to a non-existing service, and that will
@@ -60,6 +60,8 @@ pub mod credentials;
 pub mod errors;
 pub(crate) mod headers_util;
 pub(crate) mod mds;
+#[doc(hidden)]
+pub mod mtls;
 pub(crate) mod retry;
 pub mod signer;
 pub(crate) mod token;
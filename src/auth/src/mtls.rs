@@ -0,0 +1,289 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Client certificates for mutual TLS.
+//!
+//! Workloads with a [managed workload identity] receive an X.509 certificate
+//! and its private key. The location of these files is recorded in a
+//! certificate configuration file (`certificate_config.json`). The
+//! certificate authenticates the workload in [X.509 workload identity
+//! federation], and in connections to the mTLS endpoints of Google Cloud
+//! services.
+//!
+//! This module is mainly relevant to other `google-cloud-*` crates.
+//!
+//! [managed workload identity]: https://cloud.google.com/iam/docs/managed-workload-identity
+//! [X.509 workload identity federation]: https://cloud.google.com/iam/docs/workload-identity-federation-with-x509-certificates
+
+use crate::Result;
+use crate::errors::{non_retryable, non_retryable_from_str};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// Enables mutual TLS in the client libraries when set to `true`.
+pub const USE_CLIENT_CERTIFICATE_VAR: &str = "GOOGLE_API_USE_CLIENT_CERTIFICATE";
+
+/// Overrides the location of the certificate configuration file.
+pub const CERTIFICATE_CONFIG_VAR: &str = "GOOGLE_API_CERTIFICATE_CONFIG";
+
+/// Returns true if the environment enables mutual TLS.
+pub fn use_client_certificate() -> bool {
+    std::env::var(USE_CLIENT_CERTIFICATE_VAR).is_ok_and(|v| v.eq_ignore_ascii_case("true"))
+}
+
+/// A client certificate and its private key, both PEM encoded.
+#[derive(Clone)]
+pub struct ClientCertificate {
+    cert_pem: Vec<u8>,
+    key_pem: Vec<u8>,
+}
+
+impl ClientCertificate {
+    /// The PEM encoded certificate chain, leaf certificate first.
+    pub fn cert_pem(&self) -> &[u8] {
+        &self.cert_pem
+    }
+
+    /// The PEM encoded private key.
+    pub fn key_pem(&self) -> &[u8] {
+        &self.key_pem
+    }
+
+    /// The certificate and the private key, concatenated.
+    ///
+    /// This is the format expected by `reqwest::Identity::from_pem()`.
+    pub fn identity_pem(&self) -> Vec<u8> {
+        let mut pem = self.cert_pem.clone();
+        if !pem.ends_with(b"\n") {
+            pem.push(b'\n');
+        }
+        pem.extend_from_slice(&self.key_pem);
+        pem
+    }
+}
+
+impl std::fmt::Debug for ClientCertificate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientCertificate")
+            .field("cert_pem", &String::from_utf8_lossy(&self.cert_pem))
+            .field("key_pem", &"[censored]")
+            .finish()
+    }
+}
+
+/// Loads the workload certificate from the default configuration file.
+///
+/// Returns `Ok(None)` if there is no configuration file in the default
+/// location, and no location is set in the environment.
+pub fn default_client_certificate() -> Result<Option<ClientCertificate>> {
+    if let Some(path) = std::env::var_os(CERTIFICATE_CONFIG_VAR) {
+        return load_client_certificate(Path::new(&path)).map(Some);
+    }
+    match certificate_config_well_known_path() {
+        Some(path) if path.exists() => load_client_certificate(&path).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Loads the workload certificate referenced by a configuration file.
+pub fn load_client_certificate(config_path: &Path) -> Result<ClientCertificate> {
+    let contents = std::fs::read_to_string(config_path).map_err(|e| {
+        crate::errors::CredentialsError::new(
+            false,
+            format!(
+                "failed to read certificate configuration from {}",
+                config_path.display()
+            ),
+            e,
+        )
+    })?;
+    let config: CertificateConfig = serde_json::from_str(&contents).map_err(non_retryable)?;
+    let workload = config.cert_configs.workload.ok_or_else(|| {
+        non_retryable_from_str(format!(
+            "missing `cert_configs.workload` in certificate configuration {}",
+            config_path.display()
+        ))
+    })?;
+    Ok(ClientCertificate {
+        cert_pem: read_pem(&workload.cert_path, "certificate")?,
+        key_pem: read_pem(&workload.key_path, "private key")?,
+    })
+}
+
+/// Returns the path to the certificate configuration file.
+///
+/// The path set in the environment takes precedence over the well-known
+/// location.
+pub(crate) fn certificate_config_path() -> Option<PathBuf> {
+    std::env::var_os(CERTIFICATE_CONFIG_VAR)
+        .map(PathBuf::from)
+        .or_else(certificate_config_well_known_path)
+}
+
+/// The well-known path to the certificate configuration on Windows.
+#[cfg(target_os = "windows")]
+fn certificate_config_well_known_path() -> Option<PathBuf> {
+    std::env::var_os("APPDATA")
+        .map(|root| PathBuf::from(root).join("gcloud/certificate_config.json"))
+}
+
+/// The well-known path to the certificate configuration on Linux and Mac.
+#[cfg(not(target_os = "windows"))]
+fn certificate_config_well_known_path() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .map(|root| PathBuf::from(root).join(".config/gcloud/certificate_config.json"))
+}
+
+fn read_pem(path: &str, what: &str) -> Result<Vec<u8>> {
+    std::fs::read(path).map_err(|e| {
+        crate::errors::CredentialsError::new(
+            false,
+            format!("failed to read workload {what} from {path}"),
+            e,
+        )
+    })
+}
+
+#[derive(Deserialize)]
+struct CertificateConfig {
+    cert_configs: CertConfigs,
+}
+
+#[derive(Deserialize)]
+struct CertConfigs {
+    workload: Option<WorkloadConfig>,
+}
+
+#[derive(Deserialize)]
+struct WorkloadConfig {
+    cert_path: String,
+    key_path: String,
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use scoped_env::ScopedEnv;
+    use serde_json::json;
+    use std::error::Error as _;
+    use tempfile::TempDir;
+
+    type TestResult = anyhow::Result<()>;
+
+    pub(crate) const TEST_CERT: &str = include_str!("../../../testdata/mtls/workload_cert.pem");
+    pub(crate) const TEST_KEY: &str = include_str!("../../../testdata/mtls/workload_key.pem");
+    /// An intermediate certificate for the trust chain.
+    pub(crate) const TEST_INTERMEDIATE: &str =
+        include_str!("../../../testdata/mtls/intermediate_cert.pem");
+
+    /// Creates a certificate configuration, and the files it references.
+    pub(crate) fn write_certificate_config(dir: &TempDir) -> anyhow::Result<PathBuf> {
+        let cert_path = dir.path().join("cert.pem");
+        let key_path = dir.path().join("key.pem");
+        std::fs::write(&cert_path, TEST_CERT)?;
+        std::fs::write(&key_path, TEST_KEY)?;
+        let config = json!({
+            "cert_configs": {
+                "workload": {
+                    "cert_path": cert_path,
+                    "key_path": key_path,
+                }
+            }
+        });
+        let config_path = dir.path().join("certificate_config.json");
+        std::fs::write(&config_path, config.to_string())?;
+        Ok(config_path)
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn use_client_certificate_from_env() {
+        let _e = ScopedEnv::remove(USE_CLIENT_CERTIFICATE_VAR);
+        assert!(!use_client_certificate());
+        let _e = ScopedEnv::set(USE_CLIENT_CERTIFICATE_VAR, "true");
+        assert!(use_client_certificate());
+        let _e = ScopedEnv::set(USE_CLIENT_CERTIFICATE_VAR, "TRUE");
+        assert!(use_client_certificate());
+        let _e = ScopedEnv::set(USE_CLIENT_CERTIFICATE_VAR, "false");
+        assert!(!use_client_certificate());
+    }
+
+    #[test]
+    fn load() -> TestResult {
+        let dir = TempDir::new()?;
+        let path = write_certificate_config(&dir)?;
+        let cert = load_client_certificate(&path)?;
+        assert_eq!(cert.cert_pem(), TEST_CERT.as_bytes());
+        assert_eq!(cert.key_pem(), TEST_KEY.as_bytes());
+        assert_eq!(
+            cert.identity_pem(),
+            format!("{TEST_CERT}{TEST_KEY}").as_bytes()
+        );
+        let fmt = format!("{cert:?}");
+        assert!(!fmt.contains("PRIVATE KEY"), "{fmt}");
+        Ok(())
+    }
+
+    #[test]
+    fn load_missing_config() {
+        let err = load_client_certificate(Path::new("/path/to/missing/config.json")).unwrap_err();
+        assert!(!err.is_transient(), "{err:?}");
+        assert!(err.source().is_some(), "{err:?}");
+    }
+
+    #[test]
+    fn load_missing_workload() -> TestResult {
+        let dir = TempDir::new()?;
+        let path = dir.path().join("certificate_config.json");
+        std::fs::write(&path, json!({"cert_configs": {}}).to_string())?;
+        let err = load_client_certificate(&path).unwrap_err();
+        assert!(!err.is_transient(), "{err:?}");
+        assert!(err.to_string().contains("cert_configs.workload"), "{err:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn load_missing_key() -> TestResult {
+        let dir = TempDir::new()?;
+        let path = write_certificate_config(&dir)?;
+        std::fs::remove_file(dir.path().join("key.pem"))?;
+        let err = load_client_certificate(&path).unwrap_err();
+        assert!(err.to_string().contains("private key"), "{err:?}");
+        Ok(())
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn default_from_env() -> TestResult {
+        let dir = TempDir::new()?;
+        let path = write_certificate_config(&dir)?;
+        let _e = ScopedEnv::set(CERTIFICATE_CONFIG_VAR, path.to_str().unwrap());
+        assert_eq!(certificate_config_path(), Some(path.clone()));
+        let cert = default_client_certificate()?;
+        assert!(cert.is_some(), "{cert:?}");
+        Ok(())
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn default_missing() -> TestResult {
+        let dir = TempDir::new()?;
+        let _e = ScopedEnv::remove(CERTIFICATE_CONFIG_VAR);
+        let _home = ScopedEnv::set("HOME", dir.path().to_str().unwrap());
+        let _appdata = ScopedEnv::set("APPDATA", dir.path().to_str().unwrap());
+        let cert = default_client_certificate()?;
+        assert!(cert.is_none(), "{cert:?}");
+        Ok(())
+    }
+}
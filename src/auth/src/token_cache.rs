@@ -26,9 +26,27 @@ use tokio::time::{Duration, Instant, sleep};
 // determine when to refresh a token. Most MDS' refresh token 5 mins before
 // expiry, except for Serverless which refresh tokens 4 mins before
 // expiry. So we are using 4 mins as the staleness limit for our refresh logic.
-const NORMAL_REFRESH_SLACK: Duration = Duration::from_secs(240);
+pub(crate) const NORMAL_REFRESH_SLACK: Duration = Duration::from_secs(240);
 const SHORT_REFRESH_SLACK: Duration = Duration::from_secs(10);
 
+/// Receives the expiration time of the token returned by a [TokenCache].
+///
+/// Credentials built on top of other credentials insert this in the
+/// extensions of the `headers()` request, to learn when the source token
+/// expires. Credentials that do not use a [TokenCache] ignore it.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TokenExpiration(Arc<std::sync::Mutex<Option<Instant>>>);
+
+impl TokenExpiration {
+    pub(crate) fn get(&self) -> Option<Instant> {
+        *self.0.lock().expect("never poisoned")
+    }
+
+    fn set(&self, v: Option<Instant>) {
+        *self.0.lock().expect("never poisoned") = v;
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct TokenCache {
     rx_token: watch::Receiver<Option<Result<(Token, EntityTag)>>>,
@@ -77,6 +95,9 @@ impl TokenCache {
 impl CachedTokenProvider for TokenCache {
     async fn token(&self, extensions: Extensions) -> Result<CacheableResource<Token>> {
         let (data, entity_tag) = self.latest_token_and_entity_tag().await?;
+        if let Some(expiration) = extensions.get::<TokenExpiration>() {
+            expiration.set(data.expires_at);
+        }
         match extensions.get::<EntityTag>() {
             Some(tag) if entity_tag.eq(tag) => Ok(CacheableResource::NotModified),
             _ => Ok(CacheableResource::New { entity_tag, data }),
@@ -266,6 +287,29 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn reports_expiration() -> TestResult {
+        let expires_at = Instant::now() + TOKEN_VALID_DURATION;
+        let mut mock = MockTokenProvider::new();
+        mock.expect_token().times(1).return_once(move || {
+            Ok(Token {
+                token: "test-token".to_string(),
+                token_type: "Bearer".to_string(),
+                expires_at: Some(expires_at),
+                metadata: None,
+            })
+        });
+        let cache = TokenCache::new(mock);
+
+        let expiration = TokenExpiration::default();
+        assert_eq!(expiration.get(), None);
+        let mut extensions = Extensions::new();
+        extensions.insert(expiration.clone());
+        let _ = cache.token(extensions).await?;
+        assert_eq!(expiration.get(), Some(expires_at));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn initial_token_failure() {
         let mut mock = MockTokenProvider::new();
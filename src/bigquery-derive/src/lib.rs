@@ -160,6 +160,74 @@ pub fn derive_from_sql(input: TokenStream) -> TokenStream {
     expanded.into()
 }
 
+/// Derives `ToSql` for converting a struct into a BigQuery `STRUCT` query
+/// parameter.
+///
+/// Supports renaming attributes via `#[bigquery(rename = "new_name")]`.
+#[proc_macro_derive(ToSql, attributes(bigquery))]
+pub fn derive_to_sql(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident;
+
+    let fields = match input.data {
+        Data::Struct(data) => match data.fields {
+            Fields::Named(fields) => fields.named,
+            _ => {
+                return syn::Error::new_spanned(
+                    name,
+                    "ToSql can only be derived for structs with named fields",
+                )
+                .to_compile_error()
+                .into();
+            }
+        },
+        _ => {
+            return syn::Error::new_spanned(name, "ToSql can only be derived for structs")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let field_types = fields.iter().map(|f| {
+        let field_type = &f.ty;
+        let db_column_name = get_field_name(f);
+        quote! {
+            google_cloud_bigquery::model::QueryParameterStructType::new()
+                .set_name(#db_column_name)
+                .set_type(<#field_type as google_cloud_bigquery::ToSql>::sql_type())
+        }
+    });
+
+    let field_values = fields.iter().map(|f| {
+        let field_name = f.ident.as_ref().expect("named field must have identifier");
+        let db_column_name = get_field_name(f);
+        quote! {
+            (#db_column_name, google_cloud_bigquery::ToSql::to_sql(&self.#field_name))
+        }
+    });
+
+    let expanded = quote! {
+        impl google_cloud_bigquery::ToSql for #name {
+            fn sql_type() -> google_cloud_bigquery::model::QueryParameterType {
+                google_cloud_bigquery::model::QueryParameterType::new()
+                    .set_type("STRUCT")
+                    .set_struct_types([
+                        #( #field_types, )*
+                    ])
+            }
+
+            fn to_sql(&self) -> google_cloud_bigquery::model::QueryParameterValue {
+                google_cloud_bigquery::model::QueryParameterValue::new()
+                    .set_struct_values([
+                        #( #field_values, )*
+                    ])
+            }
+        }
+    };
+
+    expanded.into()
+}
+
 fn get_field_name(field: &syn::Field) -> String {
     for attr in &field.attrs {
         if attr.path().is_ident("bigquery") {
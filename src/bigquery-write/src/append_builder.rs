@@ -13,14 +13,14 @@
 // limitations under the License.
 
 use crate::Error;
-use crate::append_response::to_result;
+use crate::append_response::{map_offset_error, to_result};
 use crate::error::{AppendError, AppendResult};
 use crate::model::{AppendResponse, AppendRowsRequest};
 use crate::runner::WriteRequest;
 use gaxi::prost::{FromProto, ToProto};
 use tokio::sync::{mpsc, oneshot};
 
-/// A request builder for appending rows on the default stream.
+/// A request builder for appending rows to a stream.
 #[derive(Clone, Debug)]
 pub struct Append {
     req_tx: mpsc::UnboundedSender<WriteRequest>,
@@ -33,19 +33,30 @@ impl Append {
     }
 
     /// Append rows to the stream.
+    ///
+    /// If the request has an offset, errors for appends at the wrong offset are
+    /// reported as [AppendError::AlreadyExists] and
+    /// [AppendError::OffsetOutOfRange].
     pub async fn send(self) -> AppendResult<AppendResponse> {
-        let (resp_tx, resp_rx) = oneshot::channel();
-        let req = self.req.to_proto().map_err(Error::deser)?;
-        let write = WriteRequest { req, resp_tx };
-        let _ = self.req_tx.send(write);
-        let resp = resp_rx
+        let offset = self.req.offset;
+        send_impl(self)
             .await
-            .map_err(|_| AppendError::UnexpectedEndOfStream)??;
-        let resp = resp.cnv().map_err(Error::ser)?;
-        to_result(resp)
+            .map_err(|e| map_offset_error(e, offset))
     }
 }
 
+async fn send_impl(append: Append) -> AppendResult<AppendResponse> {
+    let (resp_tx, resp_rx) = oneshot::channel();
+    let req = append.req.to_proto().map_err(Error::deser)?;
+    let write = WriteRequest { req, resp_tx };
+    let _ = append.req_tx.send(write);
+    let resp = resp_rx
+        .await
+        .map_err(|_| AppendError::UnexpectedEndOfStream)??;
+    let resp = resp.cnv().map_err(Error::ser)?;
+    to_result(resp)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
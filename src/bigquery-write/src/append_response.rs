@@ -16,6 +16,7 @@ use crate::Error;
 use crate::error::{AppendError, AppendResult};
 use crate::generated::gapic_storage::model::append_rows_response::Response;
 use crate::model::{AppendRowsResponse, TableSchema};
+use google_cloud_gax::error::rpc::Code;
 
 /// The return type of an `append()` operation.
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -54,14 +55,30 @@ pub(crate) fn to_result(resp: AppendRowsResponse) -> AppendResult<AppendResponse
     })
 }
 
+/// Maps the errors returned for appends with an explicit offset.
+///
+/// The service returns `ALREADY_EXISTS` and `OUT_OF_RANGE` to reject appends
+/// at the wrong offset. Applications need to tell these apart from other
+/// errors to implement exactly-once semantics.
+pub(crate) fn map_offset_error(err: AppendError, offset: Option<i64>) -> AppendError {
+    let (Some(offset), AppendError::Rpc { source }) = (offset, &err) else {
+        return err;
+    };
+    match source.status().map(|s| s.code) {
+        Some(Code::AlreadyExists) => AppendError::AlreadyExists { offset },
+        Some(Code::OutOfRange) => AppendError::OffsetOutOfRange { offset },
+        _ => err,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::model::RowError;
     use crate::model::append_rows_response::AppendResult;
     use crate::model::row_error::RowErrorCode;
-    use google_cloud_gax::error::rpc::Code;
     use google_cloud_rpc::model::Status as RpcStatus;
+    use test_case::test_case;
 
     fn schema() -> TableSchema {
         TableSchema::new()
@@ -122,4 +139,36 @@ mod tests {
         assert_eq!(res.updated_schema, Some(schema()));
         Ok(())
     }
+
+    #[test_case(Code::AlreadyExists, Some(42), Some(42), None; "already exists")]
+    #[test_case(Code::OutOfRange, Some(42), None, Some(42); "out of range")]
+    #[test_case(Code::AlreadyExists, None, None, None; "no offset")]
+    #[test_case(Code::InvalidArgument, Some(42), None, None; "other code")]
+    fn offset_error(
+        code: Code,
+        offset: Option<i64>,
+        already_exists: Option<i64>,
+        out_of_range: Option<i64>,
+    ) {
+        let err: AppendError =
+            Error::service(google_cloud_gax::error::rpc::Status::default().set_code(code)).into();
+        let got = map_offset_error(err, offset);
+        match got {
+            AppendError::AlreadyExists { offset } => assert_eq!(Some(offset), already_exists),
+            AppendError::OffsetOutOfRange { offset } => assert_eq!(Some(offset), out_of_range),
+            AppendError::Rpc { .. } => {
+                assert!(
+                    already_exists.is_none() && out_of_range.is_none(),
+                    "{got:?}"
+                )
+            }
+            _ => panic!("unexpected error {got:?}"),
+        }
+    }
+
+    #[test]
+    fn offset_error_row_errors() {
+        let err = map_offset_error(AppendError::RowErrors(vec![row_error(1)]), Some(42));
+        assert!(matches!(err, AppendError::RowErrors(_)), "{err:?}");
+    }
 }
@@ -13,7 +13,10 @@
 // limitations under the License.
 
 mod default;
+mod stream_writer;
 mod writer_builder;
 
 pub use default::DefaultWriter;
+pub use stream_writer::StreamWriter;
 pub use writer_builder::WriterBuilder;
+pub(crate) use writer_builder::validate_table;
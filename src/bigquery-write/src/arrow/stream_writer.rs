@@ -0,0 +1,295 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::builder::write::Append;
+use crate::generated::gapic_storage::stub::BigQueryWrite as _;
+use crate::model::append_rows_request::ArrowData;
+use crate::model::write_stream::Type;
+use crate::model::{
+    AppendRowsRequest, ArrowRecordBatch, ArrowSchema, FinalizeWriteStreamRequest, FlushRowsRequest,
+    WriteStream,
+};
+use crate::runner::Runner;
+use crate::transport::Transport;
+use crate::{RequestOptions, Result};
+use std::sync::Arc;
+
+/// A writer for an application-created [write stream].
+///
+/// Use [WriterBuilder][crate::arrow::WriterBuilder] to create a
+/// [committed], [pending], or [buffered] stream.
+///
+/// Each append has an explicit offset. The service rejects appends at an
+/// offset that was already written with
+/// [AlreadyExists][crate::error::AppendError::AlreadyExists], and appends
+/// beyond the end of the stream with
+/// [OffsetOutOfRange][crate::error::AppendError::OffsetOutOfRange]. If the
+/// connection breaks, the client library re-sends any unacknowledged appends.
+/// Together, these provide exactly-once semantics.
+///
+/// # Example
+/// ```
+/// # use google_cloud_bigquery_write::client::Write;
+/// # use google_cloud_bigquery_write::model::{ArrowRecordBatch, ArrowSchema};
+/// # async fn sample(client: Write, schema: ArrowSchema, batches: Vec<(i64, ArrowRecordBatch)>) -> anyhow::Result<()> {
+/// let table = "projects/my-project/datasets/my-dataset/tables/my-table";
+/// let writer = client.arrow(schema).pending(table).await?;
+/// for (offset, rows) in batches {
+///     writer.append(offset, rows).send().await?;
+/// }
+/// writer.finalize().await?;
+/// let response = client.batch_commit(table, [writer.name()]).await?;
+/// println!("committed at {:?}", response.commit_time);
+/// # Ok(()) }
+/// ```
+///
+/// [write stream]: https://docs.cloud.google.com/bigquery/docs/write-api#application-created_streams
+/// [committed]: https://docs.cloud.google.com/bigquery/docs/write-api#committed_type
+/// [pending]: https://docs.cloud.google.com/bigquery/docs/write-api#pending_type
+/// [buffered]: https://docs.cloud.google.com/bigquery/docs/write-api#buffered_type
+#[derive(Debug)]
+pub struct StreamWriter {
+    inner: Arc<Transport>,
+    runner: Runner,
+    pub(crate) write_stream: WriteStream,
+    pub(crate) schema: ArrowSchema,
+}
+
+impl StreamWriter {
+    pub(crate) fn new(
+        inner: Arc<Transport>,
+        write_stream: WriteStream,
+        schema: ArrowSchema,
+    ) -> Self {
+        let runner = Runner::new(inner.clone());
+        Self {
+            inner,
+            runner,
+            write_stream,
+            schema,
+        }
+    }
+
+    /// The name of the stream.
+    ///
+    /// Use this name to commit [pending] streams with
+    /// [Write::batch_commit][crate::client::Write::batch_commit].
+    ///
+    /// [pending]: https://docs.cloud.google.com/bigquery/docs/write-api#pending_type
+    pub fn name(&self) -> &str {
+        &self.write_stream.name
+    }
+
+    /// The type of the stream.
+    pub fn stream_type(&self) -> &Type {
+        &self.write_stream.r#type
+    }
+
+    /// Append rows to the stream, at the given offset.
+    ///
+    /// The offset is the number of rows in the stream before this append. It
+    /// starts at `0` and must grow by the number of rows in each append.
+    pub fn append(&self, offset: i64, rows: ArrowRecordBatch) -> Append {
+        let req = AppendRowsRequest::new()
+            .set_write_stream(&self.write_stream.name)
+            .set_offset(offset)
+            .set_arrow_rows(
+                ArrowData::new()
+                    .set_writer_schema(self.schema.clone())
+                    .set_rows(rows),
+            );
+        Append::new(self.runner.req_tx.clone(), req)
+    }
+
+    /// Finalize the stream, so it does not accept any more appends.
+    ///
+    /// Returns the number of rows in the stream. Pending streams must be
+    /// finalized before they are committed.
+    pub async fn finalize(&self) -> Result<i64> {
+        let req = FinalizeWriteStreamRequest::new().set_name(&self.write_stream.name);
+        let resp = self
+            .inner
+            .finalize_write_stream(req, RequestOptions::default())
+            .await?;
+        Ok(resp.into_body().row_count)
+    }
+
+    /// Make the rows in a [buffered] stream visible, up to and including
+    /// `offset`.
+    ///
+    /// Returns the offset up to which rows are now visible.
+    ///
+    /// [buffered]: https://docs.cloud.google.com/bigquery/docs/write-api#buffered_type
+    pub async fn flush(&self, offset: i64) -> Result<i64> {
+        let req = FlushRowsRequest::new()
+            .set_write_stream(&self.write_stream.name)
+            .set_offset(offset);
+        let resp = self
+            .inner
+            .flush_rows(req, RequestOptions::default())
+            .await?;
+        Ok(resp.into_body().offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::AppendError;
+    use crate::google::cloud::bigquery::storage::v1::AppendRowsResponse;
+    use crate::google::cloud::bigquery::storage::v1::append_rows_response::Response;
+    use crate::google::rpc::Status as RpcStatus;
+    use crate::runner::tests::*;
+    use crate::transport::tests::*;
+    use bigquery_write_grpc_mock::google::cloud::bigquery::storage::v1;
+    use bigquery_write_grpc_mock::{MockBigQueryWrite, start};
+    use gaxi::grpc::tonic::{Response as TonicResponse, Status as TonicStatus};
+    use google_cloud_gax::error::rpc::Code;
+    use tokio::sync::mpsc;
+
+    const STREAM: &str = "projects/p/datasets/d/tables/t/streams/s";
+
+    #[tokio::test]
+    async fn request_fields() -> anyhow::Result<()> {
+        let transport = Arc::new(test_transport("http://ignored:1".to_string()).await?);
+        let writer = StreamWriter::new(transport, write_stream(), schema());
+        assert_eq!(writer.name(), STREAM);
+        assert_eq!(writer.stream_type(), &Type::Committed);
+
+        let b = writer.append(0, rows(1));
+        assert_eq!(b.req.write_stream, STREAM);
+        assert_eq!(b.req.offset, Some(0));
+        let data = b.req.arrow_rows().expect("arrow rows should be set");
+        let s = data.writer_schema.as_ref().expect("schema should be set");
+        assert_eq!(s.serialized_schema, "test");
+        let r = data.rows.as_ref().expect("rows should be set");
+        assert_eq!(r.serialized_record_batch, "1");
+
+        let b = writer.append(10, rows(2));
+        assert_eq!(b.req.offset, Some(10));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn offset_errors() -> anyhow::Result<()> {
+        let (response_tx, response_rx) = mpsc::channel(10);
+        let mut mock = MockBigQueryWrite::new();
+        mock.expect_append_rows()
+            .return_once(|_| Ok(TonicResponse::from(response_rx)));
+        let (endpoint, _server) = start("0.0.0.0:0", mock).await?;
+        let transport = Arc::new(test_transport(endpoint).await?);
+        let writer = StreamWriter::new(transport, write_stream(), schema());
+
+        response_tx.send(Ok(convert(&test_response(0)))).await?;
+        let resp = writer.append(0, rows(1)).send().await?;
+        assert_eq!(resp.offset, Some(0));
+
+        response_tx
+            .send(Ok(convert(&error_response(Code::AlreadyExists))))
+            .await?;
+        let err = writer.append(0, rows(1)).send().await.expect_err("error");
+        assert!(
+            matches!(err, AppendError::AlreadyExists { offset: 0 }),
+            "{err:?}"
+        );
+
+        response_tx
+            .send(Ok(convert(&error_response(Code::OutOfRange))))
+            .await?;
+        let err = writer.append(5, rows(1)).send().await.expect_err("error");
+        assert!(
+            matches!(err, AppendError::OffsetOutOfRange { offset: 5 }),
+            "{err:?}"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn finalize() -> anyhow::Result<()> {
+        let mut mock = MockBigQueryWrite::new();
+        mock.expect_finalize_write_stream().return_once(|request| {
+            assert_eq!(request.into_inner().name, STREAM);
+            Ok(TonicResponse::new(v1::FinalizeWriteStreamResponse {
+                row_count: 42,
+            }))
+        });
+        let (endpoint, _server) = start("0.0.0.0:0", mock).await?;
+        let transport = Arc::new(test_transport(endpoint).await?);
+        let writer = StreamWriter::new(transport, write_stream(), schema());
+
+        let count = writer.finalize().await?;
+        assert_eq!(count, 42);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn flush() -> anyhow::Result<()> {
+        let mut mock = MockBigQueryWrite::new();
+        mock.expect_flush_rows().return_once(|request| {
+            let request = request.into_inner();
+            assert_eq!(request.write_stream, STREAM);
+            assert_eq!(request.offset, Some(41));
+            Ok(TonicResponse::new(v1::FlushRowsResponse { offset: 41 }))
+        });
+        let (endpoint, _server) = start("0.0.0.0:0", mock).await?;
+        let transport = Arc::new(test_transport(endpoint).await?);
+        let writer = StreamWriter::new(transport, write_stream(), schema());
+
+        let offset = writer.flush(41).await?;
+        assert_eq!(offset, 41);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn flush_error() -> anyhow::Result<()> {
+        let mut mock = MockBigQueryWrite::new();
+        mock.expect_flush_rows()
+            .return_once(|_| Err(TonicStatus::out_of_range("fail")));
+        let (endpoint, _server) = start("0.0.0.0:0", mock).await?;
+        let transport = Arc::new(test_transport(endpoint).await?);
+        let writer = StreamWriter::new(transport, write_stream(), schema());
+
+        let err = writer.flush(41).await.expect_err("flush should fail");
+        assert_eq!(err.status().map(|s| s.code), Some(Code::OutOfRange));
+        Ok(())
+    }
+
+    fn error_response(code: Code) -> AppendRowsResponse {
+        AppendRowsResponse {
+            response: Some(Response::Error(RpcStatus {
+                code: code as i32,
+                message: "fail".to_string(),
+                ..Default::default()
+            })),
+            write_stream: STREAM.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn write_stream() -> WriteStream {
+        WriteStream::new()
+            .set_name(STREAM)
+            .set_type(Type::Committed)
+    }
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::new().set_serialized_schema("test")
+    }
+
+    fn rows(id: i64) -> ArrowRecordBatch {
+        ArrowRecordBatch::new().set_serialized_record_batch(id.to_string())
+    }
+}
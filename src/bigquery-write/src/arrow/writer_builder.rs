@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::arrow::DefaultWriter;
-use crate::model::ArrowSchema;
+use crate::arrow::{DefaultWriter, StreamWriter};
+use crate::generated::gapic_storage::stub::BigQueryWrite as _;
+use crate::model::write_stream::Type;
+use crate::model::{ArrowSchema, CreateWriteStreamRequest, WriteStream};
 use crate::transport::Transport;
-use crate::{Error, Result};
+use crate::{Error, RequestOptions, Result};
 use gaxi::path_parameter::{PathMismatchBuilder, try_match};
 use gaxi::routing_parameter::Segment;
 use google_cloud_gax::error::binding::BindingError;
@@ -43,9 +45,51 @@ impl WriterBuilder {
         write_stream.push_str("/streams/_default");
         Ok(DefaultWriter::new(self.inner, write_stream, self.schema))
     }
+
+    /// Create a writer for a new [committed] stream for the given table.
+    ///
+    /// Rows are visible as soon as the service acknowledges each append.
+    ///
+    /// [committed]: https://docs.cloud.google.com/bigquery/docs/write-api#committed_type
+    pub async fn committed<T: Into<String>>(self, table: T) -> Result<StreamWriter> {
+        self.create(table.into(), Type::Committed).await
+    }
+
+    /// Create a writer for a new [pending] stream for the given table.
+    ///
+    /// Rows are not visible until the stream is finalized and committed with
+    /// [Write::batch_commit][crate::client::Write::batch_commit].
+    ///
+    /// [pending]: https://docs.cloud.google.com/bigquery/docs/write-api#pending_type
+    pub async fn pending<T: Into<String>>(self, table: T) -> Result<StreamWriter> {
+        self.create(table.into(), Type::Pending).await
+    }
+
+    /// Create a writer for a new [buffered] stream for the given table.
+    ///
+    /// Rows are not visible until they are flushed with
+    /// [StreamWriter::flush].
+    ///
+    /// [buffered]: https://docs.cloud.google.com/bigquery/docs/write-api#buffered_type
+    pub async fn buffered<T: Into<String>>(self, table: T) -> Result<StreamWriter> {
+        self.create(table.into(), Type::Buffered).await
+    }
+
+    async fn create(self, table: String, r#type: Type) -> Result<StreamWriter> {
+        validate_table(table.as_str())?;
+        let req = CreateWriteStreamRequest::new()
+            .set_parent(table)
+            .set_write_stream(WriteStream::new().set_type(r#type));
+        let write_stream = self
+            .inner
+            .create_write_stream(req, RequestOptions::default())
+            .await?
+            .into_body();
+        Ok(StreamWriter::new(self.inner, write_stream, self.schema))
+    }
 }
 
-fn validate_table(table: &str) -> Result<()> {
+pub(crate) fn validate_table(table: &str) -> Result<()> {
     let segments = &[
         Segment::Literal("projects/"),
         Segment::SingleWildcard,
@@ -73,6 +117,10 @@ fn validate_table(table: &str) -> Result<()> {
 mod tests {
     use super::*;
     use crate::transport::tests::test_transport;
+    use bigquery_write_grpc_mock::google::cloud::bigquery::storage::v1;
+    use bigquery_write_grpc_mock::{MockBigQueryWrite, start};
+    use gaxi::grpc::tonic::{Response as TonicResponse, Status as TonicStatus};
+    use google_cloud_gax::error::rpc::Code;
     use test_case::test_case;
 
     #[tokio::test]
@@ -106,4 +154,66 @@ mod tests {
         assert!(err.is_binding(), "{err:?}");
         Ok(())
     }
+
+    #[test_case(Type::Committed, v1::write_stream::Type::Committed)]
+    #[test_case(Type::Pending, v1::write_stream::Type::Pending)]
+    #[test_case(Type::Buffered, v1::write_stream::Type::Buffered)]
+    #[tokio::test]
+    async fn create_stream(want: Type, want_proto: v1::write_stream::Type) -> anyhow::Result<()> {
+        let mut mock = MockBigQueryWrite::new();
+        mock.expect_create_write_stream()
+            .return_once(move |request| {
+                let request = request.into_inner();
+                assert_eq!(request.parent, "projects/p/datasets/d/tables/t");
+                let stream = request.write_stream.expect("write stream should be set");
+                assert_eq!(stream.r#type, want_proto as i32);
+                Ok(TonicResponse::new(v1::WriteStream {
+                    name: "projects/p/datasets/d/tables/t/streams/s".to_string(),
+                    r#type: want_proto as i32,
+                    ..Default::default()
+                }))
+            });
+        let (endpoint, _server) = start("0.0.0.0:0", mock).await?;
+        let transport = Arc::new(test_transport(endpoint).await?);
+        let schema = ArrowSchema::new().set_serialized_schema("test");
+        let builder = WriterBuilder::new(transport, schema.clone());
+        let table = "projects/p/datasets/d/tables/t";
+        let writer = match want {
+            Type::Committed => builder.committed(table).await?,
+            Type::Pending => builder.pending(table).await?,
+            _ => builder.buffered(table).await?,
+        };
+        assert_eq!(writer.name(), "projects/p/datasets/d/tables/t/streams/s");
+        assert_eq!(writer.stream_type(), &want);
+        assert_eq!(writer.schema, schema);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_stream_error() -> anyhow::Result<()> {
+        let mut mock = MockBigQueryWrite::new();
+        mock.expect_create_write_stream()
+            .return_once(|_| Err(TonicStatus::not_found("missing table")));
+        let (endpoint, _server) = start("0.0.0.0:0", mock).await?;
+        let transport = Arc::new(test_transport(endpoint).await?);
+        let builder = WriterBuilder::new(transport, ArrowSchema::new());
+        let err = builder
+            .committed("projects/p/datasets/d/tables/t")
+            .await
+            .expect_err("create should fail");
+        assert_eq!(err.status().map(|s| s.code), Some(Code::NotFound));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_stream_bad_table() -> anyhow::Result<()> {
+        let transport = Arc::new(test_transport("http://ignored:1".to_string()).await?);
+        let builder = WriterBuilder::new(transport, ArrowSchema::new());
+        let err = builder
+            .pending("projects/p/tables/t")
+            .await
+            .expect_err("should fail locally on bad format");
+        assert!(err.is_binding(), "{err:?}");
+        Ok(())
+    }
 }
@@ -15,14 +15,16 @@
 use crate::ClientBuilderResult as BuilderResult;
 use crate::arrow::WriterBuilder as ArrowWriterBuilder;
 use crate::client_builder::ClientBuilder;
-use crate::model::ArrowSchema;
+use crate::generated::gapic_storage::stub::BigQueryWrite as _;
+use crate::model::{ArrowSchema, BatchCommitWriteStreamsRequest, BatchCommitWriteStreamsResponse};
+use crate::proto::WriterBuilder as ProtoWriterBuilder;
 use crate::transport::Transport;
+use crate::{RequestOptions, Result};
 use std::sync::Arc;
 
 /// A client for BigQuery Storage Write API.
 #[derive(Debug)]
 pub struct Write {
-    #[allow(unused)]
     inner: Arc<Transport>,
 }
 
@@ -60,6 +62,69 @@ impl Write {
     pub fn arrow(&self, schema: ArrowSchema) -> ArrowWriterBuilder {
         ArrowWriterBuilder::new(self.inner.clone(), schema)
     }
+
+    /// Create a writer using [Protocol Buffers] as the data format.
+    ///
+    /// The writer derives the protobuf descriptor from the table schema, and
+    /// accepts rows as [serde::Serialize] values or [prost::Message]s.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_bigquery_write::client::Write;
+    /// # async fn sample(client: Write) -> anyhow::Result<()> {
+    /// let writer = client
+    ///   .proto()
+    ///   .default("projects/my-project/datasets/my-dataset/tables/my-table")
+    ///   .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [protocol buffers]: https://protobuf.dev/
+    pub fn proto(&self) -> ProtoWriterBuilder {
+        ProtoWriterBuilder::new(self.inner.clone())
+    }
+
+    /// Atomically commit a group of [pending] streams for the given table.
+    ///
+    /// The streams must be finalized before they are committed. If any stream
+    /// cannot be committed, no streams are committed. The service reports the
+    /// problems in `stream_errors`, and does not set `commit_time`.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_bigquery_write::client::Write;
+    /// # async fn sample(client: Write, streams: Vec<String>) -> anyhow::Result<()> {
+    /// let response = client
+    ///     .batch_commit("projects/my-project/datasets/my-dataset/tables/my-table", streams)
+    ///     .await?;
+    /// if response.commit_time.is_none() {
+    ///     println!("commit failed: {:?}", response.stream_errors);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [pending]: https://docs.cloud.google.com/bigquery/docs/write-api#pending_type
+    pub async fn batch_commit<T, I, V>(
+        &self,
+        table: T,
+        write_streams: I,
+    ) -> Result<BatchCommitWriteStreamsResponse>
+    where
+        T: Into<String>,
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        let table = table.into();
+        crate::arrow::validate_table(table.as_str())?;
+        let req = BatchCommitWriteStreamsRequest::new()
+            .set_parent(table)
+            .set_write_streams(write_streams);
+        let resp = self
+            .inner
+            .batch_commit_write_streams(req, RequestOptions::default())
+            .await?;
+        Ok(resp.into_body())
+    }
 }
 
 #[cfg(test)]
@@ -94,4 +159,42 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn batch_commit() -> anyhow::Result<()> {
+        use bigquery_write_grpc_mock::google::cloud::bigquery::storage::v1;
+        let mut mock = MockBigQueryWrite::new();
+        mock.expect_batch_commit_write_streams()
+            .return_once(|request| {
+                let request = request.into_inner();
+                assert_eq!(request.parent, "projects/p/datasets/d/tables/t");
+                assert_eq!(request.write_streams, vec!["s1", "s2"]);
+                Ok(tonic::Response::new(v1::BatchCommitWriteStreamsResponse {
+                    commit_time: Some(prost_types::Timestamp {
+                        seconds: 123,
+                        nanos: 0,
+                    }),
+                    ..Default::default()
+                }))
+            });
+        let (endpoint, _server) = start("0.0.0.0:0", mock).await?;
+        let client = Write::builder()
+            .with_endpoint(endpoint)
+            .with_credentials(Anonymous::new().build())
+            .build()
+            .await?;
+        let response = client
+            .batch_commit("projects/p/datasets/d/tables/t", ["s1", "s2"])
+            .await?;
+        assert_eq!(response.commit_time, Some(wkt::Timestamp::clamp(123, 0)));
+        assert!(response.stream_errors.is_empty(), "{response:?}");
+
+        let err = client
+            .batch_commit("projects/p/tables/t", ["s1"])
+            .await
+            .expect_err("should fail locally on bad format");
+        assert!(err.is_binding(), "{err:?}");
+
+        Ok(())
+    }
 }
@@ -40,6 +40,43 @@ pub enum AppendError {
         "the `AppendRows` stream closed unexpectedly and the client library could not recover."
     )]
     UnexpectedEndOfStream,
+
+    /// The rows at this offset are already in the stream.
+    ///
+    /// The service has already accepted an append at this offset, possibly from
+    /// a previous attempt. Applications using offsets for exactly-once
+    /// ingestion can treat this error as a success.
+    #[non_exhaustive]
+    #[error("the rows at offset {offset} were already appended to the stream")]
+    AlreadyExists {
+        /// The offset of the rejected append.
+        offset: i64,
+    },
+
+    /// The offset is beyond the current end of the stream.
+    ///
+    /// This typically means that a previous append failed. Applications should
+    /// retry from the first offset that was not acknowledged.
+    #[non_exhaustive]
+    #[error("the offset {offset} is beyond the current end of the stream")]
+    OffsetOutOfRange {
+        /// The offset of the rejected append.
+        offset: i64,
+    },
+
+    /// Some rows were appended, but a later request failed.
+    ///
+    /// Writers that split the rows into multiple requests stop at the first
+    /// request that fails. The first `appended` rows are in the stream.
+    #[non_exhaustive]
+    #[error("only the first {appended} rows were appended: {source}")]
+    Incomplete {
+        /// The number of rows appended before the failure.
+        appended: usize,
+        /// The error for the first request that failed.
+        #[source]
+        source: Box<AppendError>,
+    },
 }
 
 pub(crate) type AppendResult<T> = std::result::Result<T, AppendError>;
@@ -62,4 +99,15 @@ mod tests {
         assert!(fmt.contains("operation failed."), "{fmt}");
         assert!(fmt.contains("inner fail"), "{fmt}");
     }
+
+    #[test]
+    fn append_error_offsets_debug() {
+        let e = AppendError::AlreadyExists { offset: 42 };
+        let fmt = format!("{e}");
+        assert!(fmt.contains("offset 42"), "{fmt}");
+
+        let e = AppendError::OffsetOutOfRange { offset: 42 };
+        let fmt = format!("{e}");
+        assert!(fmt.contains("offset 42"), "{fmt}");
+    }
 }
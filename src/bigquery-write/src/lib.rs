@@ -43,6 +43,7 @@ pub mod builder {
     pub mod write {
         pub use crate::append_builder::Append;
         pub use crate::client_builder::ClientBuilder;
+        pub use crate::proto::row_writer::AppendRows;
     }
     // TODO(#6152) - add admin client
 }
@@ -58,6 +59,10 @@ pub mod model {
 pub mod arrow;
 /// Custom errors for the Cloud BigQuery Storage Write client
 pub mod error;
+/// Types to write data in [Protocol Buffers] format
+///
+/// [protocol buffers]: https://protobuf.dev/
+pub mod proto;
 
 mod append_builder;
 mod append_future;
@@ -74,7 +79,15 @@ mod status;
 #[allow(dead_code)]
 pub(crate) mod generated;
 
-#[allow(dead_code)]
+// The BigQuery Storage Read API shares these protos with the Write API. The
+// `google-cloud-bigquery` crate implements its Read client using them.
+#[cfg(feature = "_internal-read-protos")]
+#[doc(hidden)]
+pub mod read_protos {
+    pub use crate::google::cloud::bigquery::storage::v1::{ReadRowsResponse, ReadSession};
+}
+
+#[allow(dead_code, missing_docs)]
 pub(crate) mod google {
     pub mod api {
         include!("generated/protos/storage/google.api.rs");
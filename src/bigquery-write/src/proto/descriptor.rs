@@ -0,0 +1,176 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::TableFieldSchema;
+use crate::model::table_field_schema::{Mode, Type as FieldType};
+use crate::{Error, Result};
+use wkt::field_descriptor_proto::{Label, Type};
+use wkt::{DescriptorProto, FieldDescriptorProto};
+
+const ROOT: &str = "root";
+
+/// Creates a descriptor matching the fields in a table schema.
+///
+/// The field numbers follow the order of the columns, starting at `1`. Nested
+/// messages, used for `STRUCT` columns, are declared inside the root message,
+/// so the descriptor is self-contained.
+pub(crate) fn descriptor(fields: &[TableFieldSchema]) -> Result<DescriptorProto> {
+    let mut nested = Vec::new();
+    let root = message(ROOT, fields, &mut nested)?;
+    Ok(root.set_nested_type(nested))
+}
+
+fn message(
+    name: &str,
+    fields: &[TableFieldSchema],
+    nested: &mut Vec<DescriptorProto>,
+) -> Result<DescriptorProto> {
+    let mut descriptor_fields = Vec::with_capacity(fields.len());
+    for (number, field) in (1..).zip(fields) {
+        let label = match field.mode {
+            Mode::Repeated => Label::Repeated,
+            _ => Label::Optional,
+        };
+        let f = FieldDescriptorProto::new()
+            .set_name(&field.name)
+            .set_number(number)
+            .set_label(label);
+        let f = match &field.r#type {
+            FieldType::Struct => {
+                let type_name = format!("{name}__{}", field.name);
+                let m = message(&type_name, &field.fields, nested)?;
+                nested.push(m);
+                f.set_type(Type::Message).set_type_name(type_name)
+            }
+            t => f.set_type(scalar_type(&field.name, t)?),
+        };
+        descriptor_fields.push(f);
+    }
+    Ok(DescriptorProto::new()
+        .set_name(name)
+        .set_field(descriptor_fields))
+}
+
+/// The protobuf type used to send values for each scalar column type.
+pub(crate) fn scalar_type(name: &str, t: &FieldType) -> Result<Type> {
+    let t = match t {
+        FieldType::Int64 | FieldType::Timestamp => Type::Int64,
+        FieldType::Double => Type::Double,
+        FieldType::Bool => Type::Bool,
+        FieldType::Bytes => Type::Bytes,
+        FieldType::String
+        | FieldType::Date
+        | FieldType::Time
+        | FieldType::Datetime
+        | FieldType::Geography
+        | FieldType::Numeric
+        | FieldType::Bignumeric
+        | FieldType::Interval
+        | FieldType::Json => Type::String,
+        t => {
+            return Err(Error::ser(format!(
+                "unsupported type {t:?} for column `{name}`"
+            )));
+        }
+    };
+    Ok(t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn field(name: &str, t: FieldType) -> TableFieldSchema {
+        TableFieldSchema::new().set_name(name).set_type(t)
+    }
+
+    #[test]
+    fn flat() -> anyhow::Result<()> {
+        let fields = [
+            field("name", FieldType::String),
+            field("age", FieldType::Int64).set_mode(Mode::Required),
+            field("tags", FieldType::String).set_mode(Mode::Repeated),
+        ];
+        let got = descriptor(&fields)?;
+        assert_eq!(got.name, "root");
+        assert!(got.nested_type.is_empty(), "{got:?}");
+        let want = vec![
+            FieldDescriptorProto::new()
+                .set_name("name")
+                .set_number(1)
+                .set_label(Label::Optional)
+                .set_type(Type::String),
+            FieldDescriptorProto::new()
+                .set_name("age")
+                .set_number(2)
+                .set_label(Label::Optional)
+                .set_type(Type::Int64),
+            FieldDescriptorProto::new()
+                .set_name("tags")
+                .set_number(3)
+                .set_label(Label::Repeated)
+                .set_type(Type::String),
+        ];
+        assert_eq!(got.field, want);
+        Ok(())
+    }
+
+    #[test]
+    fn nested() -> anyhow::Result<()> {
+        let inner = field("inner", FieldType::Struct).set_fields([field("x", FieldType::Double)]);
+        let outer = field("outer", FieldType::Struct)
+            .set_mode(Mode::Repeated)
+            .set_fields([field("y", FieldType::Bool), inner]);
+        let got = descriptor(&[outer])?;
+
+        assert_eq!(got.field.len(), 1);
+        assert_eq!(got.field[0].r#type, Type::Message);
+        assert_eq!(got.field[0].label, Label::Repeated);
+        assert_eq!(got.field[0].type_name, "root__outer");
+
+        let names = got
+            .nested_type
+            .iter()
+            .map(|m| m.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["root__outer__inner", "root__outer"]);
+        let outer = &got.nested_type[1];
+        assert_eq!(outer.field[1].type_name, "root__outer__inner");
+        assert_eq!(outer.field[1].number, 2);
+        Ok(())
+    }
+
+    #[test_case(FieldType::Int64, Type::Int64)]
+    #[test_case(FieldType::Timestamp, Type::Int64)]
+    #[test_case(FieldType::Double, Type::Double)]
+    #[test_case(FieldType::Bool, Type::Bool)]
+    #[test_case(FieldType::Bytes, Type::Bytes)]
+    #[test_case(FieldType::String, Type::String)]
+    #[test_case(FieldType::Date, Type::String)]
+    #[test_case(FieldType::Numeric, Type::String)]
+    #[test_case(FieldType::Json, Type::String)]
+    fn scalars(input: FieldType, want: Type) -> anyhow::Result<()> {
+        assert_eq!(scalar_type("col", &input)?, want);
+        Ok(())
+    }
+
+    #[test_case(FieldType::Range)]
+    #[test_case(FieldType::Unspecified)]
+    fn unsupported(input: FieldType) {
+        let err = descriptor(&[field("col", input)]).expect_err("should fail");
+        assert!(err.is_serialization(), "{err:?}");
+        assert!(err.to_string().contains("col"), "{err}");
+    }
+}
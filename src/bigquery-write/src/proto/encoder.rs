@@ -0,0 +1,330 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::TableFieldSchema;
+use crate::model::table_field_schema::{Mode, Type as FieldType};
+use crate::{Error, Result};
+use prost::encoding::{self, WireType, encode_key, encode_varint};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// Encodes a row using the descriptor created by [super::descriptor::descriptor].
+///
+/// The row is first converted to a JSON value, and then each column is
+/// encoded from the object member with the same name. Missing members and
+/// `null` values are not sent.
+pub(crate) fn encode<T: Serialize>(fields: &[TableFieldSchema], row: &T) -> Result<Vec<u8>> {
+    let value = serde_json::to_value(row).map_err(Error::ser)?;
+    let mut buf = Vec::new();
+    encode_message(fields, &value, &mut buf).map_err(Error::ser)?;
+    Ok(buf)
+}
+
+#[derive(thiserror::Error, Debug)]
+#[error("cannot encode column `{column}`: {message}")]
+struct EncodeError {
+    column: String,
+    message: String,
+}
+
+type EncodeResult<T> = std::result::Result<T, EncodeError>;
+
+fn encode_message(
+    fields: &[TableFieldSchema],
+    value: &Value,
+    buf: &mut Vec<u8>,
+) -> EncodeResult<()> {
+    let Value::Object(map) = value else {
+        return Err(EncodeError {
+            column: String::new(),
+            message: format!("rows must serialize to an object, got {value}"),
+        });
+    };
+    for (tag, field) in (1..).zip(fields) {
+        let Some(v) = member(map, &field.name) else {
+            continue;
+        };
+        match (v, &field.mode) {
+            (Value::Null, _) => {}
+            (Value::Array(items), Mode::Repeated) => {
+                for item in items {
+                    encode_field(field, tag, item, buf)?;
+                }
+            }
+            (v, Mode::Repeated) => return Err(mismatch(field, "an array", v)),
+            (v, _) => encode_field(field, tag, v, buf)?,
+        }
+    }
+    Ok(())
+}
+
+// Column names in BigQuery are case-insensitive.
+fn member<'a>(map: &'a Map<String, Value>, name: &str) -> Option<&'a Value> {
+    map.get(name).or_else(|| {
+        map.iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v)
+    })
+}
+
+fn encode_field(
+    field: &TableFieldSchema,
+    tag: u32,
+    value: &Value,
+    buf: &mut Vec<u8>,
+) -> EncodeResult<()> {
+    match &field.r#type {
+        FieldType::Int64 => {
+            let v = as_i64(value).ok_or_else(|| mismatch(field, "an integer", value))?;
+            encoding::int64::encode(tag, &v, buf);
+        }
+        FieldType::Timestamp => {
+            let v = timestamp_micros(value).ok_or_else(|| mismatch(field, "a timestamp", value))?;
+            encoding::int64::encode(tag, &v, buf);
+        }
+        FieldType::Double => {
+            let v = as_f64(value).ok_or_else(|| mismatch(field, "a number", value))?;
+            encoding::double::encode(tag, &v, buf);
+        }
+        FieldType::Bool => {
+            let v = value
+                .as_bool()
+                .ok_or_else(|| mismatch(field, "a boolean", value))?;
+            encoding::bool::encode(tag, &v, buf);
+        }
+        FieldType::Bytes => {
+            let v = as_bytes(value).ok_or_else(|| mismatch(field, "bytes", value))?;
+            encoding::bytes::encode(tag, &v, buf);
+        }
+        FieldType::Json => {
+            let v = match value {
+                Value::String(s) => s.clone(),
+                v => v.to_string(),
+            };
+            encoding::string::encode(tag, &v, buf);
+        }
+        FieldType::Numeric | FieldType::Bignumeric => {
+            let v = match value {
+                Value::String(s) => s.clone(),
+                Value::Number(n) => n.to_string(),
+                v => return Err(mismatch(field, "a number", v)),
+            };
+            encoding::string::encode(tag, &v, buf);
+        }
+        FieldType::Struct => {
+            let mut nested = Vec::new();
+            encode_message(&field.fields, value, &mut nested).map_err(|e| EncodeError {
+                column: format!("{}.{}", field.name, e.column),
+                message: e.message,
+            })?;
+            encode_key(tag, WireType::LengthDelimited, buf);
+            encode_varint(nested.len() as u64, buf);
+            buf.extend_from_slice(&nested);
+        }
+        _ => {
+            let v = value
+                .as_str()
+                .ok_or_else(|| mismatch(field, "a string", value))?;
+            encoding::string::encode(tag, &v.to_string(), buf);
+        }
+    }
+    Ok(())
+}
+
+fn as_i64(value: &Value) -> Option<i64> {
+    match value {
+        Value::Number(n) => n.as_i64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn as_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+// Serde serializes `Vec<u8>` and `[u8; N]` as arrays of numbers. We also
+// accept strings, and send their UTF-8 encoding.
+fn as_bytes(value: &Value) -> Option<Vec<u8>> {
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .map(|i| i.as_u64().and_then(|b| u8::try_from(b).ok()))
+            .collect(),
+        Value::String(s) => Some(s.as_bytes().to_vec()),
+        _ => None,
+    }
+}
+
+// The service expects timestamps as microseconds since the Unix epoch. We also
+// accept RFC 3339 strings, such as the serialization of `wkt::Timestamp`.
+fn timestamp_micros(value: &Value) -> Option<i64> {
+    match value {
+        Value::Number(n) => n.as_i64(),
+        Value::String(_) => {
+            let ts = serde_json::from_value::<wkt::Timestamp>(value.clone()).ok()?;
+            ts.seconds()
+                .checked_mul(1_000_000)?
+                .checked_add(i64::from(ts.nanos() / 1_000))
+        }
+        _ => None,
+    }
+}
+
+fn mismatch(field: &TableFieldSchema, want: &str, got: &Value) -> EncodeError {
+    EncodeError {
+        column: field.name.clone(),
+        message: format!("expected {want}, got {got}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+    use serde_json::json;
+    use test_case::test_case;
+
+    // A message matching the descriptor for `schema()`.
+    #[derive(Clone, PartialEq, Message)]
+    struct Row {
+        #[prost(string, optional, tag = "1")]
+        name: Option<String>,
+        #[prost(int64, optional, tag = "2")]
+        age: Option<i64>,
+        #[prost(double, optional, tag = "3")]
+        score: Option<f64>,
+        #[prost(bool, optional, tag = "4")]
+        active: Option<bool>,
+        #[prost(bytes = "vec", optional, tag = "5")]
+        data: Option<Vec<u8>>,
+        #[prost(int64, optional, tag = "6")]
+        created: Option<i64>,
+        #[prost(string, repeated, tag = "7")]
+        tags: Vec<String>,
+        #[prost(message, optional, tag = "8")]
+        address: Option<Address>,
+        #[prost(string, optional, tag = "9")]
+        price: Option<String>,
+        #[prost(string, optional, tag = "10")]
+        extra: Option<String>,
+    }
+
+    #[derive(Clone, PartialEq, Message)]
+    struct Address {
+        #[prost(string, optional, tag = "1")]
+        city: Option<String>,
+        #[prost(int64, repeated, packed = "false", tag = "2")]
+        zip: Vec<i64>,
+    }
+
+    fn field(name: &str, t: FieldType) -> TableFieldSchema {
+        TableFieldSchema::new().set_name(name).set_type(t)
+    }
+
+    fn schema() -> Vec<TableFieldSchema> {
+        vec![
+            field("name", FieldType::String),
+            field("age", FieldType::Int64),
+            field("score", FieldType::Double),
+            field("active", FieldType::Bool),
+            field("data", FieldType::Bytes),
+            field("created", FieldType::Timestamp),
+            field("tags", FieldType::String).set_mode(Mode::Repeated),
+            field("address", FieldType::Struct).set_fields([
+                field("city", FieldType::String),
+                field("zip", FieldType::Int64).set_mode(Mode::Repeated),
+            ]),
+            field("price", FieldType::Numeric),
+            field("extra", FieldType::Json),
+        ]
+    }
+
+    #[test]
+    fn all_types() -> anyhow::Result<()> {
+        let row = json!({
+            "NAME": "alice",
+            "age": 42,
+            "score": 1.5,
+            "active": true,
+            "data": [1, 2, 3],
+            "created": "1970-01-01T00:00:01.000002Z",
+            "tags": ["a", "b"],
+            "address": {"city": "Paris", "zip": [75001, "75002"]},
+            "price": 12.25,
+            "extra": {"k": "v"},
+            "unknown": "ignored",
+        });
+        let bytes = encode(&schema(), &row)?;
+        let got = Row::decode(bytes.as_slice())?;
+        let want = Row {
+            name: Some("alice".to_string()),
+            age: Some(42),
+            score: Some(1.5),
+            active: Some(true),
+            data: Some(vec![1, 2, 3]),
+            created: Some(1_000_002),
+            tags: vec!["a".to_string(), "b".to_string()],
+            address: Some(Address {
+                city: Some("Paris".to_string()),
+                zip: vec![75001, 75002],
+            }),
+            price: Some("12.25".to_string()),
+            extra: Some(r#"{"k":"v"}"#.to_string()),
+        };
+        assert_eq!(got, want);
+        Ok(())
+    }
+
+    #[test]
+    fn struct_input() -> anyhow::Result<()> {
+        #[derive(Serialize)]
+        struct Input {
+            name: String,
+            age: Option<i64>,
+            tags: Vec<String>,
+        }
+        let row = Input {
+            name: "bob".to_string(),
+            age: None,
+            tags: Vec::new(),
+        };
+        let bytes = encode(&schema(), &row)?;
+        let got = Row::decode(bytes.as_slice())?;
+        let want = Row {
+            name: Some("bob".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(got, want);
+        Ok(())
+    }
+
+    #[test_case(json!({"age": "abc"}), "age"; "bad integer")]
+    #[test_case(json!({"active": 1}), "active"; "bad bool")]
+    #[test_case(json!({"tags": "a"}), "tags"; "not an array")]
+    #[test_case(json!({"data": [256]}), "data"; "bad bytes")]
+    #[test_case(json!({"created": "yesterday"}), "created"; "bad timestamp")]
+    #[test_case(json!({"address": {"zip": [true]}}), "address.zip"; "nested")]
+    #[test_case(json!([1, 2]), ""; "not an object")]
+    fn errors(row: Value, column: &str) {
+        let err = encode(&schema(), &row).expect_err("should fail");
+        assert!(err.is_serialization(), "{err:?}");
+        let msg = err.to_string();
+        assert!(msg.contains(&format!("column `{column}`")), "{msg}");
+    }
+}
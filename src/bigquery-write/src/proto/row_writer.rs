@@ -0,0 +1,428 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::encoder::encode;
+use crate::Result;
+use crate::builder::write::Append;
+use crate::error::{AppendError, AppendResult};
+use crate::model::append_rows_request::ProtoData;
+use crate::model::{AppendResponse, AppendRowsRequest, ProtoRows, ProtoSchema, TableSchema};
+use crate::runner::Runner;
+use crate::transport::Transport;
+use bytes::Bytes;
+use prost::Message;
+use serde::Serialize;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// The maximum size of an `AppendRows` request.
+const MAX_REQUEST_BYTES: usize = 10 * 1000 * 1000;
+
+/// Space reserved for the request fields other than the rows.
+const REQUEST_OVERHEAD_BYTES: usize = 64 * 1024;
+
+/// The space used to frame each row, in addition to its contents.
+const ROW_OVERHEAD_BYTES: usize = 6;
+
+/// A writer that appends rows in protobuf format.
+///
+/// The writer derives the protobuf descriptor from the table schema. Each
+/// column becomes a field, numbered after its position in the schema, starting
+/// at `1`. `STRUCT` columns become nested messages.
+///
+/// Use [append][RowWriter::append] to write any type implementing
+/// [serde::Serialize], or [append_messages][RowWriter::append_messages] to
+/// write [prost::Message]s that match the descriptor.
+///
+/// # Example
+/// ```
+/// # use google_cloud_bigquery_write::client::Write;
+/// # async fn sample(client: Write) -> anyhow::Result<()> {
+/// #[derive(serde::Serialize)]
+/// struct Row {
+///     name: String,
+///     age: i64,
+/// }
+/// let writer = client
+///     .proto()
+///     .default("projects/my-project/datasets/my-dataset/tables/my-table")
+///     .await?;
+/// let rows = vec![
+///     Row { name: "alice".into(), age: 42 },
+///     Row { name: "bob".into(), age: 24 },
+/// ];
+/// writer.append(&rows)?.send().await?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct RowWriter {
+    runner: Runner,
+    // The maximum size of the rows in each request.
+    batch_bytes: usize,
+    pub(crate) write_stream: String,
+    pub(crate) table_schema: TableSchema,
+    pub(crate) schema: ProtoSchema,
+}
+
+impl RowWriter {
+    pub(crate) fn new(
+        inner: Arc<Transport>,
+        write_stream: String,
+        table_schema: TableSchema,
+        schema: ProtoSchema,
+    ) -> Self {
+        let runner = Runner::new(inner);
+        Self {
+            runner,
+            batch_bytes: MAX_REQUEST_BYTES - REQUEST_OVERHEAD_BYTES,
+            write_stream,
+            table_schema,
+            schema,
+        }
+    }
+
+    /// The table schema used to encode the rows.
+    pub fn table_schema(&self) -> &TableSchema {
+        &self.table_schema
+    }
+
+    /// The protobuf descriptor derived from the table schema.
+    pub fn proto_schema(&self) -> &ProtoSchema {
+        &self.schema
+    }
+
+    /// Append rows to the stream.
+    ///
+    /// Each row must serialize to an object. The writer matches the object
+    /// members to the columns by name, ignoring case. Members without a
+    /// matching column are ignored, and missing or `null` members are not
+    /// sent.
+    ///
+    /// Returns an error if a row does not match the table schema.
+    pub fn append<I>(&self, rows: I) -> Result<AppendRows>
+    where
+        I: IntoIterator,
+        I::Item: Serialize,
+    {
+        let fields = &self.table_schema.fields;
+        let rows = rows
+            .into_iter()
+            .map(|r| encode(fields, &r).map(Bytes::from))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self.batches(rows))
+    }
+
+    /// Append protobuf messages to the stream.
+    ///
+    /// The messages must match the descriptor in
+    /// [proto_schema][RowWriter::proto_schema].
+    pub fn append_messages<I>(&self, rows: I) -> AppendRows
+    where
+        I: IntoIterator,
+        I::Item: Message,
+    {
+        let rows = rows
+            .into_iter()
+            .map(|r| Bytes::from(r.encode_to_vec()))
+            .collect();
+        self.batches(rows)
+    }
+
+    fn batches(&self, rows: Vec<Bytes>) -> AppendRows {
+        let limit = self.batch_bytes;
+        let mut batches = Vec::new();
+        let mut start = 0;
+        let mut size = 0;
+        for (i, row) in rows.iter().enumerate() {
+            let row_size = row.len() + ROW_OVERHEAD_BYTES;
+            if i != start && size + row_size > limit {
+                batches.push(self.request(start..i, &rows[start..i]));
+                start = i;
+                size = 0;
+            }
+            size += row_size;
+        }
+        if start != rows.len() {
+            batches.push(self.request(start..rows.len(), &rows[start..]));
+        }
+        AppendRows { batches }
+    }
+
+    fn request(&self, range: Range<usize>, rows: &[Bytes]) -> (Range<usize>, Append) {
+        // Only the first request on a connection needs the schema, but the
+        // runner may open a new connection at any time.
+        let req = AppendRowsRequest::new()
+            .set_write_stream(&self.write_stream)
+            .set_proto_rows(
+                ProtoData::new()
+                    .set_writer_schema(self.schema.clone())
+                    .set_rows(ProtoRows::new().set_serialized_rows(rows.iter().cloned())),
+            );
+        (range, Append::new(self.runner.req_tx.clone(), req))
+    }
+}
+
+/// A request builder to append rows with a [RowWriter][crate::proto::RowWriter].
+///
+/// The service limits the size of each `AppendRows` request. The writer splits
+/// large sets of rows into multiple requests. Each request is atomic: the
+/// service appends all the rows in the request, or none of them.
+#[derive(Clone, Debug)]
+pub struct AppendRows {
+    batches: Vec<(Range<usize>, Append)>,
+}
+
+impl AppendRows {
+    /// The number of `AppendRows` requests needed to send the rows.
+    pub fn request_count(&self) -> usize {
+        self.batches.len()
+    }
+
+    /// Send the rows, one request at a time.
+    ///
+    /// Returns the responses for each request. On failure, the writer stops
+    /// sending requests. If no request succeeded, it returns the error for the
+    /// first request. Otherwise it returns [AppendError::Incomplete], with the
+    /// number of rows appended, and the error for the next request.
+    ///
+    /// In both cases, the index in [AppendError::RowErrors] refers to the
+    /// position in the rows given to [RowWriter::append].
+    pub async fn send(self) -> AppendResult<Vec<AppendResponse>> {
+        let mut responses = Vec::with_capacity(self.batches.len());
+        for (range, append) in self.batches {
+            let error = match append.send().await {
+                Ok(r) => {
+                    responses.push(r);
+                    continue;
+                }
+                Err(e) => with_row_offset(e, range.start),
+            };
+            if range.start == 0 {
+                return Err(error);
+            }
+            return Err(AppendError::Incomplete {
+                appended: range.start,
+                source: Box::new(error),
+            });
+        }
+        Ok(responses)
+    }
+}
+
+fn with_row_offset(err: AppendError, start: usize) -> AppendError {
+    match err {
+        AppendError::RowErrors(errors) => AppendError::RowErrors(
+            errors
+                .into_iter()
+                .map(|e| {
+                    let index = e.index + start as i64;
+                    e.set_index(index)
+                })
+                .collect(),
+        ),
+        e => e,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::google::cloud::bigquery::storage::v1;
+    use crate::runner::tests::*;
+    use crate::transport::tests::*;
+    use bigquery_write_grpc_mock::{MockBigQueryWrite, start};
+    use gaxi::grpc::tonic::Response as TonicResponse;
+    use tokio::sync::mpsc;
+
+    const STREAM: &str = "projects/p/datasets/d/tables/t/streams/_default";
+
+    fn schema() -> TableSchema {
+        use crate::model::TableFieldSchema;
+        use crate::model::table_field_schema::Type;
+        TableSchema::new().set_fields([
+            TableFieldSchema::new()
+                .set_name("name")
+                .set_type(Type::String),
+            TableFieldSchema::new()
+                .set_name("age")
+                .set_type(Type::Int64),
+        ])
+    }
+
+    fn proto_schema() -> ProtoSchema {
+        let descriptor =
+            super::super::descriptor::descriptor(&schema().fields).expect("schema is supported");
+        ProtoSchema::new().set_proto_descriptor(descriptor)
+    }
+
+    async fn writer(endpoint: String) -> anyhow::Result<RowWriter> {
+        let transport = Arc::new(test_transport(endpoint).await?);
+        Ok(RowWriter::new(
+            transport,
+            STREAM.to_string(),
+            schema(),
+            proto_schema(),
+        ))
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct Row {
+        #[prost(string, optional, tag = "1")]
+        name: Option<String>,
+        #[prost(int64, optional, tag = "2")]
+        age: Option<i64>,
+    }
+
+    #[tokio::test]
+    async fn request_fields() -> anyhow::Result<()> {
+        use prost::Message;
+        let writer = writer("http://ignored:1".to_string()).await?;
+        assert_eq!(writer.table_schema(), &schema());
+        assert_eq!(writer.proto_schema(), &proto_schema());
+
+        let rows = vec![
+            serde_json::json!({"name": "alice", "age": 42}),
+            serde_json::json!({"name": "bob"}),
+        ];
+        let append = writer.append(&rows)?;
+        assert_eq!(append.request_count(), 1);
+        let (range, b) = &append.batches[0];
+        assert_eq!(range, &(0..2));
+        assert_eq!(b.req.write_stream, STREAM);
+        assert_eq!(b.req.offset, None);
+        let data = b.req.proto_rows().expect("proto rows should be set");
+        assert_eq!(data.writer_schema.as_ref(), Some(&proto_schema()));
+        let r = data.rows.as_ref().expect("rows should be set");
+        let got = r
+            .serialized_rows
+            .iter()
+            .map(|b| Row::decode(b.clone()))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let want = vec![
+            Row {
+                name: Some("alice".into()),
+                age: Some(42),
+            },
+            Row {
+                name: Some("bob".into()),
+                age: None,
+            },
+        ];
+        assert_eq!(got, want);
+
+        let append = writer.append_messages(want.clone());
+        let (_, b) = &append.batches[0];
+        let data = b.req.proto_rows().expect("proto rows should be set");
+        let r = data.rows.as_ref().expect("rows should be set");
+        assert_eq!(r.serialized_rows[0], Bytes::from(want[0].encode_to_vec()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn encoding_error() -> anyhow::Result<()> {
+        let writer = writer("http://ignored:1".to_string()).await?;
+        let rows = vec![serde_json::json!({"age": "abc"})];
+        let err = writer.append(&rows).expect_err("should fail");
+        assert!(err.is_serialization(), "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn split_large_requests() -> anyhow::Result<()> {
+        let writer = writer("http://ignored:1".to_string()).await?;
+        // Each row is a bit over 1 MB.
+        let name = "x".repeat(1024 * 1024);
+        let rows = (0..25)
+            .map(|_| serde_json::json!({ "name": name }))
+            .collect::<Vec<_>>();
+        let append = writer.append(&rows)?;
+        let ranges = append
+            .batches
+            .iter()
+            .map(|(r, _)| r.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(ranges, vec![0..9, 9..18, 18..25]);
+
+        let append = writer.append(Vec::<serde_json::Value>::new())?;
+        assert_eq!(append.request_count(), 0);
+        assert!(append.send().await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn row_errors_use_input_index() -> anyhow::Result<()> {
+        let (response_tx, response_rx) = mpsc::channel(10);
+        let (forward_tx, mut forward_rx) = mpsc::unbounded_channel();
+        let mut mock = MockBigQueryWrite::new();
+        mock.expect_append_rows().return_once(move |request| {
+            tokio::spawn(async move {
+                let mut request_rx = request.into_inner();
+                while let Some(Ok(request)) = request_rx.recv().await {
+                    let _ = forward_tx.send(request);
+                }
+            });
+            Ok(TonicResponse::from(response_rx))
+        });
+        let (endpoint, _server) = start("0.0.0.0:0", mock).await?;
+        let mut writer = writer(endpoint).await?;
+        // Use small requests, the mock server rejects messages over 4 MiB.
+        writer.batch_bytes = 1000;
+
+        let name = "x".repeat(100);
+        let rows = (0..25)
+            .map(|_| serde_json::json!({ "name": name }))
+            .collect::<Vec<_>>();
+        let append = writer.append(&rows)?;
+        assert_eq!(append.request_count(), 3);
+        let pending = tokio::spawn(append.send());
+
+        // The first request succeeds, the second has a bad row.
+        let _ = forward_rx.recv().await.expect("first request");
+        response_tx.send(Ok(convert(&test_response(0)))).await?;
+        let _ = forward_rx.recv().await.expect("second request");
+        let bad_row = v1::AppendRowsResponse {
+            row_errors: vec![v1::RowError {
+                index: 2,
+                code: v1::row_error::RowErrorCode::FieldsError as i32,
+                message: "fail".to_string(),
+            }],
+            ..Default::default()
+        };
+        response_tx.send(Ok(convert(&bad_row))).await?;
+
+        let err = pending.await?.expect_err("should fail");
+        let AppendError::Incomplete { appended, source } = err else {
+            anyhow::bail!("expected an incomplete error, got {err:?}");
+        };
+        assert_eq!(appended, 9);
+        let AppendError::RowErrors(errors) = *source else {
+            anyhow::bail!("expected row errors, got {source:?}");
+        };
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].index, 11);
+        assert_eq!(errors[0].message, "fail");
+
+        // The writer stops after the first failure.
+        assert!(forward_rx.try_recv().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn row_offset() {
+        let err = with_row_offset(AppendError::UnexpectedEndOfStream, 10);
+        assert!(matches!(err, AppendError::UnexpectedEndOfStream), "{err:?}");
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::RowWriter;
+use super::descriptor::descriptor;
+use crate::arrow::validate_table;
+use crate::generated::gapic_storage::stub::BigQueryWrite as _;
+use crate::model::{GetWriteStreamRequest, ProtoSchema, TableSchema, WriteStreamView};
+use crate::transport::Transport;
+use crate::{Error, RequestOptions, Result};
+use std::sync::Arc;
+
+/// A builder to create a stream writer for rows in protobuf format.
+#[derive(Clone, Debug)]
+pub struct WriterBuilder {
+    inner: Arc<Transport>,
+    table_schema: Option<TableSchema>,
+}
+
+impl WriterBuilder {
+    pub(crate) fn new(inner: Arc<Transport>) -> Self {
+        Self {
+            inner,
+            table_schema: None,
+        }
+    }
+
+    /// Use the given table schema to encode the rows.
+    ///
+    /// By default, the writer fetches the table schema from the service when
+    /// it is created.
+    pub fn with_table_schema(mut self, v: TableSchema) -> Self {
+        self.table_schema = Some(v);
+        self
+    }
+
+    /// Create a writer for the [default stream] for the given table.
+    ///
+    /// [default stream]: https://docs.cloud.google.com/bigquery/docs/write-api#default_stream
+    pub async fn default<T: Into<String>>(self, table: T) -> Result<RowWriter> {
+        let table = table.into();
+        validate_table(table.as_str())?;
+        let mut write_stream = table;
+        write_stream.push_str("/streams/_default");
+        let table_schema = match self.table_schema {
+            Some(s) => s,
+            None => fetch_schema(&self.inner, &write_stream).await?,
+        };
+        let schema = ProtoSchema::new().set_proto_descriptor(descriptor(&table_schema.fields)?);
+        Ok(RowWriter::new(
+            self.inner,
+            write_stream,
+            table_schema,
+            schema,
+        ))
+    }
+}
+
+async fn fetch_schema(inner: &Transport, write_stream: &str) -> Result<TableSchema> {
+    let req = GetWriteStreamRequest::new()
+        .set_name(write_stream)
+        .set_view(WriteStreamView::Full);
+    inner
+        .get_write_stream(req, RequestOptions::default())
+        .await?
+        .into_body()
+        .table_schema
+        .ok_or_else(|| Error::deser("the service did not return the table schema"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::TableFieldSchema;
+    use crate::model::table_field_schema::Type;
+    use crate::transport::tests::test_transport;
+    use bigquery_write_grpc_mock::google::cloud::bigquery::storage::v1;
+    use bigquery_write_grpc_mock::{MockBigQueryWrite, start};
+    use gaxi::grpc::tonic::{Response as TonicResponse, Status as TonicStatus};
+    use google_cloud_gax::error::rpc::Code;
+
+    #[tokio::test]
+    async fn with_table_schema() -> anyhow::Result<()> {
+        let transport = Arc::new(test_transport("http://ignored:1".to_string()).await?);
+        let schema = TableSchema::new().set_fields([TableFieldSchema::new()
+            .set_name("name")
+            .set_type(Type::String)]);
+        let writer = WriterBuilder::new(transport)
+            .with_table_schema(schema.clone())
+            .default("projects/p/datasets/d/tables/t")
+            .await?;
+        assert_eq!(
+            writer.write_stream,
+            "projects/p/datasets/d/tables/t/streams/_default"
+        );
+        assert_eq!(writer.table_schema, schema);
+        let descriptor = writer
+            .schema
+            .proto_descriptor
+            .as_ref()
+            .expect("descriptor should be set");
+        assert_eq!(descriptor.field[0].name, "name");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fetch_table_schema() -> anyhow::Result<()> {
+        let mut mock = MockBigQueryWrite::new();
+        mock.expect_get_write_stream().return_once(|request| {
+            let request = request.into_inner();
+            assert_eq!(
+                request.name,
+                "projects/p/datasets/d/tables/t/streams/_default"
+            );
+            assert_eq!(request.view, v1::WriteStreamView::Full as i32);
+            Ok(TonicResponse::new(v1::WriteStream {
+                name: request.name,
+                table_schema: Some(v1::TableSchema {
+                    fields: vec![v1::TableFieldSchema {
+                        name: "age".to_string(),
+                        r#type: v1::table_field_schema::Type::Int64 as i32,
+                        ..Default::default()
+                    }],
+                }),
+                ..Default::default()
+            }))
+        });
+        let (endpoint, _server) = start("0.0.0.0:0", mock).await?;
+        let transport = Arc::new(test_transport(endpoint).await?);
+        let writer = WriterBuilder::new(transport)
+            .default("projects/p/datasets/d/tables/t")
+            .await?;
+        let fields = &writer.table_schema.fields;
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name, "age");
+        assert_eq!(fields[0].r#type, Type::Int64);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn missing_table_schema() -> anyhow::Result<()> {
+        let mut mock = MockBigQueryWrite::new();
+        mock.expect_get_write_stream()
+            .return_once(|_| Ok(TonicResponse::new(v1::WriteStream::default())));
+        let (endpoint, _server) = start("0.0.0.0:0", mock).await?;
+        let transport = Arc::new(test_transport(endpoint).await?);
+        let err = WriterBuilder::new(transport)
+            .default("projects/p/datasets/d/tables/t")
+            .await
+            .expect_err("should fail without a schema");
+        assert!(err.is_deserialization(), "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fetch_error() -> anyhow::Result<()> {
+        let mut mock = MockBigQueryWrite::new();
+        mock.expect_get_write_stream()
+            .return_once(|_| Err(TonicStatus::not_found("missing table")));
+        let (endpoint, _server) = start("0.0.0.0:0", mock).await?;
+        let transport = Arc::new(test_transport(endpoint).await?);
+        let err = WriterBuilder::new(transport)
+            .default("projects/p/datasets/d/tables/t")
+            .await
+            .expect_err("should fail");
+        assert_eq!(err.status().map(|s| s.code), Some(Code::NotFound));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bad_table_format() -> anyhow::Result<()> {
+        let transport = Arc::new(test_transport("http://ignored:1".to_string()).await?);
+        let err = WriterBuilder::new(transport)
+            .default("projects/p/tables/t")
+            .await
+            .expect_err("should fail locally on bad format");
+        assert!(err.is_binding(), "{err:?}");
+        Ok(())
+    }
+}
@@ -15,18 +15,184 @@
 use crate::google::cloud::bigquery::storage::v1;
 use crate::model::ProtoSchema;
 use gaxi::prost::{ConvertError, FromProto, ToProto};
+use prost_types::{
+    DescriptorProto, EnumDescriptorProto, EnumValueDescriptorProto, FieldDescriptorProto,
+};
+use wkt::field_descriptor_proto::{Label, Type};
+
+// The service only needs the names, numbers, and types in the descriptor. The
+// conversions drop options, extensions, reserved ranges, and oneofs. The
+// `wkt::FieldDescriptorProto` type cannot tell a missing `oneof_index` apart
+// from the first oneof, so we cannot convert oneofs without losing information.
 
 impl ToProto<v1::ProtoSchema> for ProtoSchema {
     type Output = v1::ProtoSchema;
     fn to_proto(self) -> Result<v1::ProtoSchema, ConvertError> {
-        // TODO(#5315) - implement conversions for DescriptorProto
-        Err(ConvertError::Unimplemented)
+        Ok(v1::ProtoSchema {
+            proto_descriptor: self.proto_descriptor.map(descriptor_to_proto),
+        })
     }
 }
 
 impl FromProto<ProtoSchema> for v1::ProtoSchema {
     fn cnv(self) -> Result<ProtoSchema, ConvertError> {
-        // TODO(#5315) - implement conversions for DescriptorProto
-        Err(ConvertError::Unimplemented)
+        Ok(ProtoSchema::new()
+            .set_or_clear_proto_descriptor(self.proto_descriptor.map(descriptor_from_proto)))
+    }
+}
+
+fn descriptor_to_proto(d: wkt::DescriptorProto) -> DescriptorProto {
+    DescriptorProto {
+        name: Some(d.name),
+        field: d.field.into_iter().map(field_to_proto).collect(),
+        nested_type: d.nested_type.into_iter().map(descriptor_to_proto).collect(),
+        enum_type: d.enum_type.into_iter().map(enum_to_proto).collect(),
+        ..Default::default()
+    }
+}
+
+fn field_to_proto(f: wkt::FieldDescriptorProto) -> FieldDescriptorProto {
+    let non_empty = |s: String| (!s.is_empty()).then_some(s);
+    FieldDescriptorProto {
+        name: Some(f.name),
+        number: Some(f.number),
+        label: f.label.value(),
+        r#type: f.r#type.value(),
+        type_name: non_empty(f.type_name),
+        default_value: non_empty(f.default_value),
+        json_name: non_empty(f.json_name),
+        proto3_optional: f.proto3_optional.then_some(true),
+        ..Default::default()
+    }
+}
+
+fn enum_to_proto(e: wkt::EnumDescriptorProto) -> EnumDescriptorProto {
+    EnumDescriptorProto {
+        name: Some(e.name),
+        value: e
+            .value
+            .into_iter()
+            .map(|v| EnumValueDescriptorProto {
+                name: Some(v.name),
+                number: Some(v.number),
+                ..Default::default()
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+fn descriptor_from_proto(d: DescriptorProto) -> wkt::DescriptorProto {
+    wkt::DescriptorProto::new()
+        .set_name(d.name.unwrap_or_default())
+        .set_field(d.field.into_iter().map(field_from_proto))
+        .set_nested_type(d.nested_type.into_iter().map(descriptor_from_proto))
+        .set_enum_type(d.enum_type.into_iter().map(enum_from_proto))
+}
+
+fn field_from_proto(f: FieldDescriptorProto) -> wkt::FieldDescriptorProto {
+    wkt::FieldDescriptorProto::new()
+        .set_name(f.name.unwrap_or_default())
+        .set_number(f.number.unwrap_or_default())
+        .set_label(Label::from(f.label.unwrap_or_default()))
+        .set_type(Type::from(f.r#type.unwrap_or_default()))
+        .set_type_name(f.type_name.unwrap_or_default())
+        .set_default_value(f.default_value.unwrap_or_default())
+        .set_json_name(f.json_name.unwrap_or_default())
+        .set_proto3_optional(f.proto3_optional.unwrap_or_default())
+}
+
+fn enum_from_proto(e: EnumDescriptorProto) -> wkt::EnumDescriptorProto {
+    wkt::EnumDescriptorProto::new()
+        .set_name(e.name.unwrap_or_default())
+        .set_value(e.value.into_iter().map(|v| {
+            wkt::EnumValueDescriptorProto::new()
+                .set_name(v.name.unwrap_or_default())
+                .set_number(v.number.unwrap_or_default())
+        }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model() -> ProtoSchema {
+        let nested = wkt::DescriptorProto::new().set_name("nested").set_field([
+            wkt::FieldDescriptorProto::new()
+                .set_name("value")
+                .set_number(1)
+                .set_label(Label::Optional)
+                .set_type(Type::Int64),
+        ]);
+        let color = wkt::EnumDescriptorProto::new()
+            .set_name("Color")
+            .set_value([
+                wkt::EnumValueDescriptorProto::new()
+                    .set_name("RED")
+                    .set_number(0),
+                wkt::EnumValueDescriptorProto::new()
+                    .set_name("BLUE")
+                    .set_number(1),
+            ]);
+        let root = wkt::DescriptorProto::new()
+            .set_name("root")
+            .set_field([
+                wkt::FieldDescriptorProto::new()
+                    .set_name("name")
+                    .set_number(1)
+                    .set_label(Label::Optional)
+                    .set_type(Type::String),
+                wkt::FieldDescriptorProto::new()
+                    .set_name("items")
+                    .set_number(2)
+                    .set_label(Label::Repeated)
+                    .set_type(Type::Message)
+                    .set_type_name("nested"),
+            ])
+            .set_nested_type([nested])
+            .set_enum_type([color]);
+        ProtoSchema::new().set_proto_descriptor(root)
+    }
+
+    #[test]
+    fn to_proto() -> anyhow::Result<()> {
+        let got = model().to_proto()?;
+        let d = got.proto_descriptor.expect("descriptor should be set");
+        assert_eq!(d.name.as_deref(), Some("root"));
+        assert_eq!(d.field.len(), 2);
+        let f = &d.field[1];
+        assert_eq!(f.name.as_deref(), Some("items"));
+        assert_eq!(f.number, Some(2));
+        assert_eq!(
+            f.label(),
+            prost_types::field_descriptor_proto::Label::Repeated
+        );
+        assert_eq!(
+            f.r#type(),
+            prost_types::field_descriptor_proto::Type::Message
+        );
+        assert_eq!(f.type_name.as_deref(), Some("nested"));
+        assert_eq!(f.default_value, None);
+        assert_eq!(f.proto3_optional, None);
+        assert_eq!(d.nested_type[0].name.as_deref(), Some("nested"));
+        assert_eq!(d.enum_type[0].value[1].name.as_deref(), Some("BLUE"));
+        Ok(())
+    }
+
+    #[test]
+    fn roundtrip() -> anyhow::Result<()> {
+        let proto = model().to_proto()?;
+        let got: ProtoSchema = proto.cnv()?;
+        assert_eq!(got, model());
+        Ok(())
+    }
+
+    #[test]
+    fn empty() -> anyhow::Result<()> {
+        let proto = ProtoSchema::new().to_proto()?;
+        assert_eq!(proto.proto_descriptor, None);
+        let got: ProtoSchema = proto.cnv()?;
+        assert_eq!(got, ProtoSchema::new());
+        Ok(())
     }
 }
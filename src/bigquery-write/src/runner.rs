@@ -19,6 +19,7 @@ use crate::stream::Stream;
 use crate::transport::Transport;
 use gaxi::grpc::from_status::to_gax_error;
 use gaxi::grpc::tonic::{Status as TonicStatus, Streaming};
+use google_cloud_gax::error::rpc::Code;
 use std::collections::VecDeque;
 use std::sync::Arc;
 use tokio::sync::{mpsc, oneshot};
@@ -42,8 +43,13 @@ pub(crate) struct WriteRequest {
 /// they were received, the client can queue multiple requests concurrently
 /// before receiving a response.
 ///
-/// If the stream terminates for any reason, the background task exits. Any
-/// unsatisfied requests are dropped, which surfaces to the client as a
+/// If the stream breaks with a transient error, and all the unacknowledged
+/// requests have an explicit offset, the background task opens a new stream
+/// and re-sends these requests in order. The offsets guarantee the service does
+/// not append the same rows twice.
+///
+/// If the stream terminates for any other reason, the background task exits.
+/// Any unsatisfied requests are dropped, which surfaces to the client as a
 /// `oneshot::error::RecvError` on their response channel.
 #[derive(Debug)]
 pub(crate) struct Runner {
@@ -63,76 +69,154 @@ impl Runner {
     }
 }
 
+/// The maximum number of times we reopen a stream without making progress.
+const MAX_RESUMES: u32 = 3;
+
+/// A request waiting for its response.
+#[derive(Debug)]
+struct Pending {
+    // A copy of the request, only kept if it is safe to re-send it.
+    req: Option<AppendRowsRequest>,
+    resp_tx: oneshot::Sender<AppendResult<AppendRowsResponse>>,
+}
+
+impl Pending {
+    fn new(
+        req: &AppendRowsRequest,
+        resp_tx: oneshot::Sender<AppendResult<AppendRowsResponse>>,
+    ) -> Self {
+        // Without an offset the service cannot detect duplicates.
+        let req = req.offset.is_some().then(|| req.clone());
+        Self { req, resp_tx }
+    }
+}
+
+/// How one attempt to use a stream ended.
+enum Attempt {
+    /// The stream, or the application, is done.
+    Closed,
+    /// The stream broke with an error.
+    Broken { error: crate::Error, progress: bool },
+}
+
 async fn run_stream_task(inner: Arc<Transport>, mut req_rx: mpsc::UnboundedReceiver<WriteRequest>) {
-    // Wait for the first write before opening the stream. Tonic will not yield
-    // us a stream until we have performed the first write.
-    let Some(initial_req) = req_rx.recv().await else {
-        return;
-    };
+    // A queue of requests waiting for a response.
+    let mut pending = VecDeque::new();
+    // The requests to send when opening the stream.
+    let mut requests = Vec::new();
+    let mut resumes = 0;
+    loop {
+        if requests.is_empty() {
+            // Wait for the first write before opening the stream. Tonic will
+            // not yield us a stream until we have performed the first write.
+            let Some(WriteRequest { req, resp_tx }) = req_rx.recv().await else {
+                return;
+            };
+            pending.push_back(Pending::new(&req, resp_tx));
+            requests.push(req);
+        }
 
-    // A queue of responses we need to satisfy
-    let mut resp_txs = VecDeque::new();
-    resp_txs.push_back(initial_req.resp_tx);
+        // Open the stream.
+        let mut requests_iter = std::mem::take(&mut requests).into_iter();
+        let initial_req = requests_iter.next().expect("there is at least one request");
+        let (error, progress) = match Stream::new(inner.clone(), initial_req).await {
+            Err(e) => (e, false),
+            Ok(stream) => {
+                match run_attempt(stream, requests_iter, &mut req_rx, &mut pending).await {
+                    Attempt::Closed => return,
+                    Attempt::Broken { error, progress } => (error, progress),
+                }
+            }
+        };
+
+        if progress {
+            resumes = 0;
+        }
+        if pending.is_empty() {
+            // Nothing to recover, open a new stream on the next write.
+            continue;
+        }
+        if resumes >= MAX_RESUMES || !can_resume(&error, &pending) {
+            process_gax_response(&mut pending, Err(error));
+            return;
+        }
+        resumes += 1;
+        requests = pending.iter().filter_map(|p| p.req.clone()).collect();
+    }
+}
+
+/// Returns true if we can re-send the pending requests on a new stream.
+fn can_resume(error: &crate::Error, pending: &VecDeque<Pending>) -> bool {
+    let transient = error
+        .status()
+        .is_some_and(|s| matches!(s.code, Code::Unavailable | Code::Aborted));
+    transient && pending.iter().all(|p| p.req.is_some())
+}
 
-    // Open the stream.
+async fn run_attempt(
+    stream: Stream,
+    replay: impl Iterator<Item = AppendRowsRequest>,
+    req_rx: &mut mpsc::UnboundedReceiver<WriteRequest>,
+    pending: &mut VecDeque<Pending>,
+) -> Attempt {
     let Stream {
         mut stream,
         request_tx,
-    } = match Stream::new(inner, initial_req.req).await {
-        Ok(s) => s,
-        Err(e) => {
-            process_gax_response(&mut resp_txs, Err(e));
-            return;
-        }
-    };
+    } = stream;
 
+    // Re-send any requests from a previous stream, in order.
+    for req in replay {
+        let _ = request_tx.send(req).await;
+    }
+
+    let mut progress = false;
     loop {
         tokio::select! {
             req = req_rx.recv() => {
                 match req {
                     Some(r) => {
                         // Keep track of the response channel.
-                        resp_txs.push_back(r.resp_tx);
+                        pending.push_back(Pending::new(&r.req, r.resp_tx));
 
                         // Forward the request to the stream.
                         let _ = request_tx.send(r.req).await;
                     }
-                    None => break drain_stream(stream, resp_txs).await,
+                    None => {
+                        drain_stream(stream, pending).await;
+                        return Attempt::Closed;
+                    }
                 }
             }
             resp = stream.message() => {
                 match resp.transpose() {
-                    Some(r) => process_response(&mut resp_txs, r),
+                    Some(Ok(r)) => {
+                        progress = true;
+                        process_gax_response(pending, Ok(r));
+                    }
+                    Some(Err(status)) => {
+                        return Attempt::Broken { error: to_gax_error(status), progress };
+                    }
                     // Note that tonic yields `None` after an `Err(e)`.
-                    None => break,
+                    None => return Attempt::Closed,
                 }
             }
         }
     }
 }
 
-async fn drain_stream(
-    mut stream: Streaming<AppendRowsResponse>,
-    mut resp_txs: VecDeque<oneshot::Sender<AppendResult<AppendRowsResponse>>>,
-) {
+async fn drain_stream(mut stream: Streaming<AppendRowsResponse>, pending: &mut VecDeque<Pending>) {
     while let Some(r) = stream.message().await.transpose() {
-        process_response(&mut resp_txs, r);
+        process_response(pending, r);
     }
 }
 
-fn process_response(
-    resp_txs: &mut VecDeque<oneshot::Sender<AppendResult<AppendRowsResponse>>>,
-    resp: TonicResult<AppendRowsResponse>,
-) {
-    process_gax_response(resp_txs, resp.map_err(to_gax_error))
+fn process_response(pending: &mut VecDeque<Pending>, resp: TonicResult<AppendRowsResponse>) {
+    process_gax_response(pending, resp.map_err(to_gax_error))
 }
 
-fn process_gax_response(
-    resp_txs: &mut VecDeque<oneshot::Sender<AppendResult<AppendRowsResponse>>>,
-    resp: Result<AppendRowsResponse>,
-) {
+fn process_gax_response(pending: &mut VecDeque<Pending>, resp: Result<AppendRowsResponse>) {
     // Pop the response channel associated with this response.
-    let resp_tx = resp_txs
+    let Pending { resp_tx, .. } = pending
         .pop_front()
         .expect("the service sends one response per request");
 
@@ -147,6 +231,7 @@ pub(crate) mod tests {
         AppendResult, Response,
     };
     use crate::transport::tests::*;
+    use bigquery_write_grpc_mock::google::cloud::bigquery::storage::v1;
     use bigquery_write_grpc_mock::{MockBigQueryWrite, start};
     use gaxi::grpc::tonic::Response as TonicResponse;
     use google_cloud_gax::error::rpc::Code;
@@ -437,6 +522,160 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    type ResponseRx = mpsc::Receiver<TonicResult<v1::AppendRowsResponse>>;
+    type RequestTx = mpsc::UnboundedSender<TonicResult<v1::AppendRowsRequest>>;
+
+    // Returns a mock that serves each `AppendRows` call from the next response
+    // channel, and forwards the requests received on that call.
+    fn resumable_mock(responses: Vec<(ResponseRx, RequestTx)>) -> MockBigQueryWrite {
+        let responses = std::sync::Mutex::new(VecDeque::from(responses));
+        let mut mock = MockBigQueryWrite::new();
+        mock.expect_append_rows().returning(move |request| {
+            let Some((response_rx, forward_tx)) = responses.lock().unwrap().pop_front() else {
+                return Err(TonicStatus::internal("too many calls"));
+            };
+            tokio::spawn(async move {
+                let mut request_rx = request.into_inner();
+                while let Some(request) = request_rx.recv().await {
+                    let _ = forward_tx.send(request);
+                }
+            });
+            Ok(TonicResponse::from(response_rx))
+        });
+        mock
+    }
+
+    fn write(
+        req_tx: &mpsc::UnboundedSender<WriteRequest>,
+        req: AppendRowsRequest,
+    ) -> anyhow::Result<oneshot::Receiver<crate::error::AppendResult<AppendRowsResponse>>> {
+        let (resp_tx, resp_rx) = oneshot::channel();
+        req_tx.send(WriteRequest { req, resp_tx })?;
+        Ok(resp_rx)
+    }
+
+    #[tokio::test]
+    async fn resume_after_transient_error() -> anyhow::Result<()> {
+        let (response_tx1, response_rx1) = mpsc::channel(10);
+        let (response_tx2, response_rx2) = mpsc::channel(10);
+        let (forward_tx1, mut forward_rx1) = mpsc::unbounded_channel();
+        let (forward_tx2, mut forward_rx2) = mpsc::unbounded_channel();
+        let mock = resumable_mock(vec![
+            (response_rx1, forward_tx1),
+            (response_rx2, forward_tx2),
+        ]);
+        let (endpoint, _server) = start("0.0.0.0:0", mock).await?;
+        let transport = Arc::new(test_transport(endpoint).await?);
+
+        let Runner { req_tx, handle } = Runner::new(transport);
+
+        let resp_rx1 = write(&req_tx, test_request(1))?;
+        let resp_rx2 = write(&req_tx, test_request(2))?;
+        let resp_rx3 = write(&req_tx, test_request(3))?;
+        for want in 1..=3 {
+            let got = forward_rx1.recv().await.expect("request on first stream")?;
+            assert_eq!(got.offset, Some(want));
+        }
+
+        // Acknowledge the first write, then break the stream.
+        response_tx1.send(Ok(convert(&test_response(1)))).await?;
+        response_tx1
+            .send(Err(TonicStatus::unavailable("try again")))
+            .await?;
+        assert_eq!(resp_rx1.await??, test_response(1));
+
+        // The unacknowledged writes are re-sent, in order, on a new stream.
+        for want in 2..=3 {
+            let got = forward_rx2
+                .recv()
+                .await
+                .expect("request on second stream")?;
+            assert_eq!(got.offset, Some(want));
+        }
+        // New writes go to the new stream.
+        let resp_rx4 = write(&req_tx, test_request(4))?;
+        let got = forward_rx2
+            .recv()
+            .await
+            .expect("request on second stream")?;
+        assert_eq!(got.offset, Some(4));
+
+        for i in 2..=4 {
+            response_tx2.send(Ok(convert(&test_response(i)))).await?;
+        }
+        assert_eq!(resp_rx2.await??, test_response(2));
+        assert_eq!(resp_rx3.await??, test_response(3));
+        assert_eq!(resp_rx4.await??, test_response(4));
+
+        drop(req_tx);
+        drop(response_tx2);
+        handle.await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn no_resume_without_offsets() -> anyhow::Result<()> {
+        let (response_tx, response_rx) = mpsc::channel(10);
+        let (forward_tx, _forward_rx) = mpsc::unbounded_channel();
+        let mock = resumable_mock(vec![(response_rx, forward_tx)]);
+        let (endpoint, _server) = start("0.0.0.0:0", mock).await?;
+        let transport = Arc::new(test_transport(endpoint).await?);
+
+        let Runner { req_tx, handle } = Runner::new(transport);
+
+        // A write without an offset cannot be safely re-sent.
+        let mut req = test_request(1);
+        req.offset = None;
+        let resp_rx = write(&req_tx, req)?;
+
+        response_tx
+            .send(Err(TonicStatus::unavailable("try again")))
+            .await?;
+        let resp = resp_rx.await?;
+        let Err(AppendError::Rpc { source: err }) = resp else {
+            anyhow::bail!("expected an RPC error, got: {resp:?}");
+        };
+        assert_eq!(err.status().map(|s| s.code), Some(Code::Unavailable));
+
+        drop(req_tx);
+        handle.await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resume_too_many_attempts() -> anyhow::Result<()> {
+        let mut streams = Vec::new();
+        let mut response_txs = Vec::new();
+        for _ in 0..=MAX_RESUMES {
+            let (response_tx, response_rx) = mpsc::channel(10);
+            let (forward_tx, _) = mpsc::unbounded_channel();
+            response_tx
+                .send(Err(TonicStatus::unavailable("try again")))
+                .await?;
+            streams.push((response_rx, forward_tx));
+            response_txs.push(response_tx);
+        }
+        let mock = resumable_mock(streams);
+        let (endpoint, _server) = start("0.0.0.0:0", mock).await?;
+        let transport = Arc::new(test_transport(endpoint).await?);
+
+        let Runner { req_tx, handle } = Runner::new(transport);
+
+        let resp_rx = write(&req_tx, test_request(1))?;
+        let resp = resp_rx.await?;
+        let Err(AppendError::Rpc { source: err }) = resp else {
+            anyhow::bail!("expected an RPC error, got: {resp:?}");
+        };
+        assert_eq!(err.status().map(|s| s.code), Some(Code::Unavailable));
+
+        drop(req_tx);
+        handle.await?;
+
+        Ok(())
+    }
+
     pub(crate) fn test_request(index: i64) -> AppendRowsRequest {
         AppendRowsRequest {
             write_stream: "projects/p/datasets/d/tables/t/streams/s".to_string(),
@@ -65,6 +65,8 @@ use std::sync::Arc;
 pub struct BigQuery {
     job_service: Arc<JobService>,
     project_id: Option<String>,
+    #[cfg(feature = "arrow")]
+    read_client: Option<crate::query::ReadClient>,
 }
 
 impl BigQuery {
@@ -85,6 +87,17 @@ impl BigQuery {
     }
 
     pub(crate) async fn new(builder: ClientBuilder) -> BuilderResult<Self> {
+        #[cfg(feature = "arrow")]
+        let read_client = {
+            // The Storage Read API uses a different endpoint, only the
+            // credentials, universe domain, and retry policies apply.
+            let mut config = gaxi::options::ClientConfig::default();
+            config.cred = builder.config.cred.clone();
+            config.universe_domain = builder.config.universe_domain.clone();
+            config.retry_policy = builder.config.retry_policy.clone();
+            config.backoff_policy = builder.config.backoff_policy.clone();
+            Some(crate::query::ReadClient::new(config).await?)
+        };
         let mut job_service_builder = JobService::builder();
         if let Some(creds) = builder.config.cred {
             job_service_builder = job_service_builder.with_credentials(creds);
@@ -111,9 +124,54 @@ impl BigQuery {
         Ok(BigQuery {
             job_service,
             project_id: builder.project_id,
+            #[cfg(feature = "arrow")]
+            read_client,
         })
     }
 
+    #[cfg(feature = "arrow")]
+    pub(crate) fn read_client(&self) -> Option<crate::query::ReadClient> {
+        self.read_client.clone()
+    }
+
+    #[cfg(feature = "arrow")]
+    pub(crate) fn project_id(&self) -> Option<String> {
+        self.project_id.clone()
+    }
+
+    /// Creates a request builder to read a table as [Arrow] record batches.
+    ///
+    /// The table is read in parallel using the [Storage Read API]. The
+    /// session is billed to the client's default project, if set, or to the
+    /// project containing the table.
+    ///
+    /// [Arrow]: https://arrow.apache.org/
+    /// [Storage Read API]: https://cloud.google.com/bigquery/docs/reference/storage
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_bigquery::client::BigQuery;
+    /// # use google_cloud_bigquery::model::TableReference;
+    /// # async fn sample(client: &BigQuery) -> anyhow::Result<()> {
+    /// let table = TableReference::new()
+    ///     .set_project_id("bigquery-public-data")
+    ///     .set_dataset_id("usa_names")
+    ///     .set_table_id("usa_1910_2013");
+    /// let mut batches = client.read_table(table).send().await?;
+    /// while let Some(batch) = batches.next().await.transpose()? {
+    ///     println!("Rows: {}", batch.num_rows());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "arrow")]
+    pub fn read_table(
+        &self,
+        table: google_cloud_bigquery_v2::model::TableReference,
+    ) -> crate::query::ReadArrow {
+        crate::query::ReadArrow::new_table(self.read_client(), self.project_id(), table)
+    }
+
     /// Creates a request builder to configure and execute a SQL query.
     ///
     /// This method returns a [`Query`] builder. You can chain additional configuration methods
@@ -241,6 +299,8 @@ mod tests {
             Self {
                 job_service,
                 project_id,
+                #[cfg(feature = "arrow")]
+                read_client: None,
             }
         }
     }
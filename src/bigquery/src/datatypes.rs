@@ -18,8 +18,10 @@
 //! [`Interval`] and [`Range`].
 
 use crate::error::ConvertError;
-use crate::query::FromSql;
 use crate::query::from_sql::parse_time;
+use crate::query::to_sql::{scalar_type, scalar_value};
+use crate::query::{FromSql, ToSql};
+use google_cloud_bigquery_v2::model::{QueryParameterType, QueryParameterValue, RangeValue};
 
 /// Represents a BigQuery time [INTERVAL] value.
 ///
@@ -146,6 +148,43 @@ impl FromSql for Interval {
     }
 }
 
+impl ToSql for Interval {
+    fn sql_type() -> QueryParameterType {
+        scalar_type("INTERVAL")
+    }
+
+    // Uses the canonical `Y-M D H:M:S.F` format. The year-month and time
+    // parts each have a single sign, and the fields may have different
+    // signs. Normalize each part to a total before splitting it again.
+    // BigQuery supports microsecond precision, any remaining nanoseconds are
+    // truncated.
+    fn to_sql(&self) -> QueryParameterValue {
+        const MICROS_PER_SECOND: u128 = 1_000_000;
+        let months = self.years as i64 * 12 + self.months as i64;
+        let nanos = ((self.hours as i128 * 60 + self.minutes as i128) * 60 + self.seconds as i128)
+            * 1_000_000_000
+            + self.nanos as i128;
+        let micros = nanos.unsigned_abs() / 1_000;
+        let ym_sign = if months < 0 { "-" } else { "" };
+        let time_sign = if nanos < 0 && micros != 0 { "-" } else { "" };
+        let seconds = micros / MICROS_PER_SECOND;
+        let mut value = format!(
+            "{ym_sign}{}-{} {} {time_sign}{}:{}:{}",
+            months.unsigned_abs() / 12,
+            months.unsigned_abs() % 12,
+            self.days,
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60,
+        );
+        let micros = micros % MICROS_PER_SECOND;
+        if micros != 0 {
+            value.push_str(&format!(".{micros:06}"));
+        }
+        scalar_value(value)
+    }
+}
+
 /// Represents a BigQuery [RANGE] value.
 ///
 /// [RANGE]: https://docs.cloud.google.com/bigquery/docs/reference/standard-sql/data-types#range_type
@@ -236,6 +275,23 @@ impl<T: FromSql> FromSql for Range<T> {
     }
 }
 
+impl<T: ToSql> ToSql for Range<T> {
+    fn sql_type() -> QueryParameterType {
+        QueryParameterType::new()
+            .set_type("RANGE")
+            .set_range_element_type(T::sql_type())
+    }
+
+    // Unbounded ends are represented by missing values.
+    fn to_sql(&self) -> QueryParameterValue {
+        QueryParameterValue::new().set_range_value(
+            RangeValue::new()
+                .set_or_clear_start(self.start.as_ref().map(ToSql::to_sql))
+                .set_or_clear_end(self.end.as_ref().map(ToSql::to_sql)),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -295,4 +351,50 @@ mod tests {
     ) -> Result<Range<google_cloud_type::model::Date>, TestConvertError> {
         FromSql::from_sql(value).map_err(TestConvertError::from)
     }
+
+    #[test_case(Interval { years: 1, months: 2, days: 3, hours: 4, minutes: 5, seconds: 6, nanos: 789_123_000 } => Some("1-2 3 4:5:6.789123".to_string()) ; "positive interval")]
+    #[test_case(Interval::default() => Some("0-0 0 0:0:0".to_string()) ; "zero interval")]
+    #[test_case(Interval { years: -1, months: -2, days: 3, hours: -4, minutes: -5, seconds: -6, nanos: -123_000_000 } => Some("-1-2 3 -4:5:6.123000".to_string()) ; "mixed signs interval")]
+    #[test_case(Interval { nanos: 999, ..Default::default() } => Some("0-0 0 0:0:0".to_string()) ; "truncated nanos")]
+    #[test_case(Interval { nanos: -999, ..Default::default() } => Some("0-0 0 0:0:0".to_string()) ; "truncated negative nanos")]
+    #[test_case(Interval { years: 1, months: -1, ..Default::default() } => Some("0-11 0 0:0:0".to_string()) ; "mixed sign year month")]
+    #[test_case(Interval { years: -1, months: 13, ..Default::default() } => Some("0-1 0 0:0:0".to_string()) ; "months overflow")]
+    #[test_case(Interval { years: -2, months: 1, ..Default::default() } => Some("-1-11 0 0:0:0".to_string()) ; "negative year positive month")]
+    #[test_case(Interval { hours: 1, minutes: -30, ..Default::default() } => Some("0-0 0 0:30:0".to_string()) ; "mixed sign hour minute")]
+    #[test_case(Interval { minutes: 1, seconds: -1, nanos: 500_000_000, ..Default::default() } => Some("0-0 0 0:0:59.500000".to_string()) ; "mixed sign seconds nanos")]
+    #[test_case(Interval { hours: -1, minutes: 30, ..Default::default() } => Some("0-0 0 -0:30:0".to_string()) ; "negative hour positive minute")]
+    #[test_case(Interval { seconds: 90, ..Default::default() } => Some("0-0 0 0:1:30".to_string()) ; "seconds overflow")]
+    fn test_to_sql_interval(input: Interval) -> Option<String> {
+        assert_eq!(Interval::sql_type().r#type, "INTERVAL");
+        input.to_sql().value
+    }
+
+    #[test_case(Interval { years: 1, months: 2, days: 3, hours: 4, minutes: 5, seconds: 6, nanos: 789_123_000 } ; "positive")]
+    #[test_case(Interval { years: -1, months: -2, days: -3, hours: -4, minutes: -5, seconds: -6, nanos: -123_000_000 } ; "negative")]
+    fn test_to_sql_interval_roundtrip(input: Interval) {
+        let value = input.to_sql().value.expect("interval value should be set");
+        let got = Interval::from_sql(wkt::Value::String(value)).expect("interval should parse");
+        assert_eq!(got, input);
+    }
+
+    #[test_case(Range { start: Some(1_i64), end: Some(5_i64) } => (Some("1".to_string()), Some("5".to_string())) ; "bounded range")]
+    #[test_case(Range { start: None, end: Some(5_i64) } => (None, Some("5".to_string())) ; "unbounded start")]
+    #[test_case(Range { start: Some(1_i64), end: None } => (Some("1".to_string()), None) ; "unbounded end")]
+    #[test_case(Range { start: None, end: None } => (None, None) ; "unbounded both")]
+    fn test_to_sql_range(input: Range<i64>) -> (Option<String>, Option<String>) {
+        let t = Range::<i64>::sql_type();
+        assert_eq!(t.r#type, "RANGE");
+        assert_eq!(
+            t.range_element_type.map(|t| t.r#type).as_deref(),
+            Some("INT64")
+        );
+        let range = input
+            .to_sql()
+            .range_value
+            .expect("range value should be set");
+        (
+            range.start.and_then(|v| v.value),
+            range.end.and_then(|v| v.value),
+        )
+    }
 }
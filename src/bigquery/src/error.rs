@@ -75,6 +75,11 @@ pub enum RowError {
     #[error("internal service JSON layout invalid: {0}")]
     InvalidRowFormat(String),
 
+    /// The Arrow data could not be decoded or converted.
+    #[cfg(feature = "arrow")]
+    #[error("invalid Arrow data: {0}")]
+    Arrow(#[source] arrow::error::ArrowError),
+
     /// The underlying RPC failed.
     #[non_exhaustive]
     #[error("the operation failed. RPC error: {source}")]
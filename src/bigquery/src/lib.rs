@@ -34,11 +34,16 @@
 //! For streaming and reading results:
 //! * [RowIterator]
 //! * [Row]
+//! * `ArrowReader`, with the `arrow` feature, to read results using the
+//!   [Storage Read API](https://cloud.google.com/bigquery/docs/reference/storage)
 //!
 //! For converting results to Rust types:
 //! * [FromRow]
 //! * [FromSql]
 //!
+//! For binding query parameters:
+//! * [ToSql]
+//!
 //! [bigquery]: https://cloud.google.com/bigquery
 //!
 //! # Example: Executing a Query
@@ -101,8 +106,10 @@ pub use google_cloud_gax::error::Error;
 pub mod datatypes;
 pub mod error;
 pub use crate::error::{ConvertError, QueryError, RowError};
-pub use crate::query::{CompleteQuery, FromSql, Query, Row, RowIterator};
-pub use google_cloud_bigquery_derive::{FromRow, FromSql};
+#[cfg(feature = "arrow")]
+pub use crate::query::ArrowReader;
+pub use crate::query::{CompleteQuery, FromSql, Query, Row, RowIterator, ToSql};
+pub use google_cloud_bigquery_derive::{FromRow, FromSql, ToSql};
 
 pub(crate) mod generated;
 pub(crate) mod query;
@@ -125,6 +132,8 @@ pub mod builder {
         //! Builder for [BigQuery][crate::client::BigQuery].
         pub use crate::client_builder::ClientBuilder;
         pub use crate::generated::QueryRequest;
+        #[cfg(feature = "arrow")]
+        pub use crate::query::ReadArrow;
         pub use crate::query::builder::Query;
     }
 }
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "arrow")]
+mod arrow_reader;
 pub(crate) mod builder;
 pub(crate) mod execution;
 pub(crate) mod from_sql;
@@ -19,13 +21,19 @@ mod iterator;
 mod query_handle;
 mod row;
 mod schema;
+pub(crate) mod to_sql;
 
+#[cfg(feature = "arrow")]
+pub(crate) use arrow_reader::ReadClient;
+#[cfg(feature = "arrow")]
+pub use arrow_reader::{ArrowReader, ReadArrow};
 pub use iterator::RowIterator;
 pub use query_handle::{CompleteQuery, Query};
 pub(crate) use schema::Schema;
 
 pub use from_sql::FromSql;
 pub use row::Row;
+pub use to_sql::ToSql;
 
 /// Result type for query execution.
 pub type Result<T> = std::result::Result<T, crate::error::QueryError>;
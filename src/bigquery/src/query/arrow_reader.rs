@@ -0,0 +1,711 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::error::RowError;
+use crate::query::{CompleteQuery, Result, RowIterator, Schema};
+use arrow::buffer::Buffer;
+use arrow::ipc::reader::StreamDecoder;
+use arrow::record_batch::RecordBatch;
+use google_cloud_bigquery_v2::model::TableReference;
+use google_cloud_bigquery_write::model::read_rows_response::Rows;
+use google_cloud_bigquery_write::model::{
+    CreateReadSessionRequest, DataFormat, ReadRowsRequest, ReadSession,
+};
+use google_cloud_gax::error::Error;
+use google_cloud_gax::retry_result::RetryResult;
+use google_cloud_gax::retry_state::RetryState;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio::task::JoinSet;
+
+mod read_client;
+mod rows;
+
+pub(crate) use read_client::ReadClient;
+
+// The number of rows in each record batch when reading with the REST API.
+const DEFAULT_BATCH_SIZE: usize = 1024;
+
+/// A request builder to read a query result set or a table in [Arrow] format.
+///
+/// [`CompleteQuery::read_arrow()`](crate::CompleteQuery::read_arrow) and
+/// [`BigQuery::read_table()`](crate::client::BigQuery::read_table) return
+/// this builder.
+///
+/// The builder uses the [Storage Read API] to read the destination table of
+/// the query. The data is divided into multiple streams, which are read in
+/// parallel over gRPC. Small result sets, which are already available in
+/// memory, and queries without a destination table are read using the REST
+/// API and converted to Arrow.
+///
+/// Interrupted streams are resumed from the last row received, using the
+/// retry and backoff policies of the client.
+///
+/// [Arrow]: https://arrow.apache.org/
+/// [Storage Read API]: https://cloud.google.com/bigquery/docs/reference/storage
+///
+/// # Example
+///
+/// ```
+/// # use google_cloud_bigquery::client::BigQuery;
+/// # async fn sample(client: BigQuery) -> anyhow::Result<()> {
+/// let mut batches = client
+///     .query("SELECT name, state FROM `bigquery-public-data.usa_names.usa_1910_2013`")
+///     .until_done()
+///     .await?
+///     .read_arrow(&client)
+///     .send()
+///     .await?;
+///
+/// while let Some(batch) = batches.next().await.transpose()? {
+///     println!("received {} rows", batch.num_rows());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReadArrow {
+    read_client: Option<ReadClient>,
+    project_id: Option<String>,
+    source: Source,
+    max_stream_count: i32,
+    batch_size: usize,
+}
+
+#[derive(Clone, Debug)]
+enum Source {
+    Query(Box<CompleteQuery>),
+    Table(TableReference),
+}
+
+impl ReadArrow {
+    pub(crate) fn new_query(
+        read_client: Option<ReadClient>,
+        project_id: Option<String>,
+        query: CompleteQuery,
+    ) -> Self {
+        Self::new(read_client, project_id, Source::Query(Box::new(query)))
+    }
+
+    pub(crate) fn new_table(
+        read_client: Option<ReadClient>,
+        project_id: Option<String>,
+        table: TableReference,
+    ) -> Self {
+        Self::new(read_client, project_id, Source::Table(table))
+    }
+
+    fn new(read_client: Option<ReadClient>, project_id: Option<String>, source: Source) -> Self {
+        Self {
+            read_client,
+            project_id,
+            source,
+            max_stream_count: 0,
+            batch_size: DEFAULT_BATCH_SIZE,
+        }
+    }
+
+    /// Sets the maximum number of streams read in parallel.
+    ///
+    /// The service may return fewer streams. Use `1` to preserve the order of
+    /// the rows, for example, for queries with an `ORDER BY` clause. The
+    /// default (`0`) lets the service choose the number of streams.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use google_cloud_bigquery::client::BigQuery;
+    /// # async fn sample(client: BigQuery) -> anyhow::Result<()> {
+    /// let batches = client
+    ///     .query("SELECT name FROM `my-project.my_dataset.people` ORDER BY name")
+    ///     .until_done()
+    ///     .await?
+    ///     .read_arrow(&client)
+    ///     .with_max_stream_count(1)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_max_stream_count(mut self, v: i32) -> Self {
+        self.max_stream_count = v;
+        self
+    }
+
+    /// Sets the number of rows in each record batch when the result set is
+    /// read using the REST API.
+    ///
+    /// Record batches read using the Storage Read API are sized by the
+    /// service.
+    pub fn with_batch_size(mut self, v: usize) -> Self {
+        self.batch_size = v.max(1);
+        self
+    }
+
+    /// Creates the read session, if needed, and starts reading the data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the service cannot create the read session, for
+    /// example, because the caller does not have the
+    /// `bigquery.readsessions.create` permission.
+    pub async fn send(self) -> Result<ArrowReader> {
+        let Self {
+            read_client,
+            project_id,
+            source,
+            max_stream_count,
+            batch_size,
+        } = self;
+        let (parent, table) = match source {
+            Source::Table(table) => {
+                let parent = project_id.unwrap_or_else(|| table.project_id.clone());
+                (parent, table)
+            }
+            Source::Query(query) => {
+                let table = match &read_client {
+                    Some(_) => destination_table(&query).await?,
+                    None => None,
+                };
+                match table {
+                    Some(table) => (table.project_id.clone(), table),
+                    None => return Ok(ArrowReader::from_rows(*query, batch_size)),
+                }
+            }
+        };
+        let Some(client) = read_client else {
+            return Err(Error::binding("the Storage Read API client is not configured").into());
+        };
+        let session = client
+            .create_read_session(
+                CreateReadSessionRequest::new()
+                    .set_parent(format!("projects/{parent}"))
+                    .set_read_session(
+                        ReadSession::new()
+                            .set_table(format!(
+                                "projects/{}/datasets/{}/tables/{}",
+                                table.project_id, table.dataset_id, table.table_id
+                            ))
+                            .set_data_format(DataFormat::Arrow),
+                    )
+                    .set_max_stream_count(max_stream_count),
+            )
+            .await?;
+        Ok(ArrowReader::from_session(client, session))
+    }
+}
+
+// Returns the table to read with the Storage Read API, if any.
+async fn destination_table(query: &CompleteQuery) -> Result<Option<TableReference>> {
+    // The result set is already in memory, there is no need to read it again.
+    if query.page_token.is_none() {
+        return Ok(None);
+    }
+    // Queries executed without a job do not have a destination table.
+    let Some(get_job) = query.get_job() else {
+        return Ok(None);
+    };
+    let job = get_job.send().await?;
+    Ok(job
+        .configuration
+        .and_then(|c| c.query)
+        .and_then(|q| q.destination_table))
+}
+
+/// An iterator over the [Arrow] record batches in a result set.
+///
+/// [`ReadArrow::send()`](crate::builder::bigquery::ReadArrow::send) returns
+/// an `ArrowReader`.
+///
+/// When reading with the Storage Read API, the streams are read in parallel
+/// and the order of the batches is not guaranteed.
+///
+/// [Arrow]: https://arrow.apache.org/
+///
+/// # Example
+///
+/// ```
+/// # use google_cloud_bigquery::ArrowReader;
+/// # async fn sample(mut batches: ArrowReader) -> anyhow::Result<()> {
+/// let mut total = 0;
+/// while let Some(batch) = batches.next().await.transpose()? {
+///     total += batch.num_rows();
+/// }
+/// println!("Total rows: {total}");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ArrowReader {
+    inner: Inner,
+}
+
+#[derive(Debug)]
+enum Inner {
+    Storage {
+        batches: mpsc::Receiver<std::result::Result<RecordBatch, RowError>>,
+        // Dropping the reader cancels any pending reads.
+        _tasks: JoinSet<()>,
+    },
+    Rows {
+        rows: RowIterator,
+        schema: Arc<Schema>,
+        batch_size: usize,
+    },
+}
+
+impl ArrowReader {
+    fn from_rows(query: CompleteQuery, batch_size: usize) -> Self {
+        let schema = query.schema.clone();
+        Self {
+            inner: Inner::Rows {
+                rows: query.read(),
+                schema,
+                batch_size,
+            },
+        }
+    }
+
+    fn from_session(client: ReadClient, session: ReadSession) -> Self {
+        let schema = session
+            .arrow_schema()
+            .map(|s| s.serialized_schema.clone())
+            .unwrap_or_default();
+        let (tx, rx) = mpsc::channel(2 * session.streams.len().max(1));
+        let mut tasks = JoinSet::new();
+        for stream in session.streams {
+            let client = client.clone();
+            let schema = schema.clone();
+            let tx = tx.clone();
+            tasks.spawn(async move {
+                if let Err(e) = read_stream(&client, stream.name, schema, &tx).await {
+                    let _ = tx.send(Err(e)).await;
+                }
+            });
+        }
+        Self {
+            inner: Inner::Storage {
+                batches: rx,
+                _tasks: tasks,
+            },
+        }
+    }
+
+    /// Fetches the next record batch.
+    ///
+    /// Returns `None` when all the batches have been received.
+    pub async fn next(&mut self) -> Option<std::result::Result<RecordBatch, RowError>> {
+        match &mut self.inner {
+            Inner::Storage { batches, .. } => batches.recv().await,
+            Inner::Rows {
+                rows,
+                schema,
+                batch_size,
+            } => next_rows_batch(rows, schema, *batch_size).await,
+        }
+    }
+}
+
+async fn next_rows_batch(
+    iter: &mut RowIterator,
+    schema: &Schema,
+    batch_size: usize,
+) -> Option<std::result::Result<RecordBatch, RowError>> {
+    // DDL and DML statements have no result set.
+    if schema.fields().is_empty() {
+        return None;
+    }
+    let mut buffer = Vec::new();
+    while buffer.len() < batch_size {
+        match iter.next().await {
+            None => break,
+            Some(Ok(row)) => buffer.push(row),
+            Some(Err(e)) => return Some(Err(e)),
+        }
+    }
+    if buffer.is_empty() {
+        return None;
+    }
+    let batch = rows::arrow_schema(schema)
+        .and_then(|arrow| rows::to_record_batch(&arrow, schema.fields(), &buffer));
+    Some(batch)
+}
+
+async fn read_stream(
+    client: &ReadClient,
+    stream: String,
+    schema: bytes::Bytes,
+    tx: &mpsc::Sender<std::result::Result<RecordBatch, RowError>>,
+) -> std::result::Result<(), RowError> {
+    let mut decoder = StreamDecoder::new();
+    decode(&mut decoder, schema)?;
+    let mut offset = 0_i64;
+    // The retry policy applies to each interruption, the attempts and elapsed
+    // time are reset once the stream makes progress.
+    let mut start = tokio::time::Instant::now();
+    let mut attempt_count = 0_u32;
+    loop {
+        let request = ReadRowsRequest::new()
+            .set_read_stream(stream.clone())
+            .set_offset(offset);
+        let error = match client.read_rows(request).await {
+            Err(e) => e,
+            Ok(mut responses) => loop {
+                let response = match responses.next().await {
+                    None => return Ok(()),
+                    Some(Err(e)) => break e,
+                    Some(Ok(r)) => r,
+                };
+                start = tokio::time::Instant::now();
+                attempt_count = 0;
+                offset += response.row_count;
+                let Some(Rows::ArrowRecordBatch(rows)) = response.rows else {
+                    continue;
+                };
+                for batch in decode(&mut decoder, rows.serialized_record_batch)? {
+                    if tx.send(Ok(batch)).await.is_err() {
+                        // The reader was dropped, stop reading.
+                        return Ok(());
+                    }
+                }
+            },
+        };
+        attempt_count += 1;
+        let state = RetryState::new(true)
+            .set_start(start)
+            .set_attempt_count(attempt_count);
+        match client.retry_policy.on_error(&state, error) {
+            RetryResult::Continue(_) => {
+                tokio::time::sleep(client.backoff_policy.on_failure(&state)).await;
+            }
+            RetryResult::Permanent(e) | RetryResult::Exhausted(e) => return Err(e.into()),
+        }
+    }
+}
+
+// Decodes Arrow IPC messages. The schema message produces no batches.
+fn decode(
+    decoder: &mut StreamDecoder,
+    bytes: bytes::Bytes,
+) -> std::result::Result<Vec<RecordBatch>, RowError> {
+    let mut buffer = Buffer::from(bytes.to_vec());
+    let mut batches = Vec::new();
+    while !buffer.is_empty() {
+        if let Some(batch) = decoder.decode(&mut buffer).map_err(RowError::Arrow)? {
+            batches.push(batch);
+        }
+    }
+    Ok(batches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read_client::tests::start;
+    use super::*;
+    use crate::query::tests::{MockJobService, create_job_service};
+    use arrow::array::{Array, AsArray, Int64Array};
+    use arrow::datatypes::{DataType, Field, Int64Type};
+    use arrow::ipc::writer::StreamWriter;
+    use bigquery_write_grpc_mock::google::cloud::bigquery::storage::v1;
+    use google_cloud_auth::credentials::anonymous::Builder as Anonymous;
+    use google_cloud_bigquery_v2::model::{
+        Job, JobConfiguration, JobConfigurationQuery, QueryResponse, TableFieldSchema, TableSchema,
+    };
+    use google_cloud_gax::exponential_backoff::ExponentialBackoffBuilder;
+    use google_cloud_gax::response::Response;
+    use google_cloud_gax::retry_policy::{AlwaysRetry, RetryPolicyExt};
+    use serde_json::{Map, json};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+    use tokio_stream::wrappers::ReceiverStream;
+    use tonic::{Request, Response as TonicResponse, Status};
+    use v1::big_query_read_server::BigQueryRead;
+
+    fn create_test_schema() -> TableSchema {
+        TableSchema::new().set_fields([TableFieldSchema::new()
+            .set_name("col")
+            .set_type("INTEGER")
+            .set_mode("NULLABLE")])
+    }
+
+    fn create_test_row(val: &str) -> wkt::Struct {
+        Map::from_iter([("f".to_string(), json!([{ "v": val }]))])
+    }
+
+    fn create_test_query(mock: MockJobService, page_token: Option<&str>) -> CompleteQuery {
+        let mut res = QueryResponse::new()
+            .set_schema(create_test_schema())
+            .set_rows(["1", "2", "3"].map(create_test_row))
+            .set_job_reference(
+                google_cloud_bigquery_v2::model::JobReference::new()
+                    .set_project_id("test-project")
+                    .set_job_id("test-job"),
+            );
+        if let Some(token) = page_token {
+            res = res.set_page_token(token);
+        }
+        CompleteQuery::from_query_response(create_job_service(mock), res, None)
+    }
+
+    async fn collect(reader: &mut ArrowReader) -> anyhow::Result<Vec<RecordBatch>> {
+        let mut batches = Vec::new();
+        while let Some(batch) = reader.next().await.transpose()? {
+            batches.push(batch);
+        }
+        Ok(batches)
+    }
+
+    #[tokio::test]
+    async fn small_results_use_rest() -> anyhow::Result<()> {
+        // No RPCs are expected, the rows are already in memory.
+        let query = create_test_query(MockJobService::new(), None);
+        let mut reader = ReadArrow::new_query(None, None, query)
+            .with_batch_size(2)
+            .send()
+            .await?;
+        let batches = collect(&mut reader).await?;
+        let sizes = batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+        assert_eq!(sizes, vec![2, 1]);
+        let values = batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<Int64Type>().values().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![1, 2, 3]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn anonymous_results_use_rest() -> anyhow::Result<()> {
+        let mut mock = MockJobService::new();
+        mock.expect_get_job().times(1).returning(|req, _| {
+            assert_eq!(req.job_id, "test-job");
+            // The job has no destination table.
+            let job = Job::new()
+                .set_configuration(JobConfiguration::new().set_query(JobConfigurationQuery::new()));
+            Ok(Response::from(job))
+        });
+        mock.expect_get_query_results()
+            .times(1)
+            .returning(|req, _| {
+                assert_eq!(req.page_token, "token");
+                let res = google_cloud_bigquery_v2::model::GetQueryResultsResponse::new()
+                    .set_rows([create_test_row("4")]);
+                Ok(Response::from(res))
+            });
+        let query = create_test_query(mock, Some("token"));
+        let mut config = gaxi::options::ClientConfig::default();
+        config.cred = Some(Anonymous::new().build());
+        let client = ReadClient::new(config).await?;
+        let mut reader = ReadArrow::new_query(Some(client), None, query)
+            .send()
+            .await?;
+        let batches = collect(&mut reader).await?;
+        let values = batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<Int64Type>().values().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![1, 2, 3, 4]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn table_without_client() -> anyhow::Result<()> {
+        let table = TableReference::new()
+            .set_project_id("p")
+            .set_dataset_id("d")
+            .set_table_id("t");
+        let err = ReadArrow::new_table(None, None, table)
+            .send()
+            .await
+            .expect_err("reading tables requires the Storage Read API");
+        assert!(matches!(err, crate::QueryError::Rpc { .. }), "{err:?}");
+        Ok(())
+    }
+
+    /// Returns the IPC messages for the schema and each batch of `values`.
+    fn ipc_messages(values: &[Vec<i64>]) -> anyhow::Result<(Vec<u8>, Vec<Vec<u8>>)> {
+        let schema = arrow::datatypes::Schema::new(vec![Field::new("n", DataType::Int64, true)]);
+        let mut writer = StreamWriter::try_new(Vec::new(), &schema)?;
+        let header = writer.get_ref().clone();
+        let mut batches = Vec::new();
+        for v in values {
+            let start = writer.get_ref().len();
+            let batch = RecordBatch::try_new(
+                Arc::new(schema.clone()),
+                vec![Arc::new(Int64Array::from(v.clone()))],
+            )?;
+            writer.write(&batch)?;
+            batches.push(writer.get_ref()[start..].to_vec());
+        }
+        Ok((header, batches))
+    }
+
+    /// A fake `BigQueryRead` service with a single stream of 3 rows.
+    ///
+    /// The first `failures` calls to `ReadRows` fail with `UNAVAILABLE` after
+    /// sending one row.
+    struct FakeRead {
+        calls: Arc<AtomicUsize>,
+        failures: usize,
+    }
+
+    #[async_trait::async_trait]
+    impl BigQueryRead for FakeRead {
+        async fn create_read_session(
+            &self,
+            _request: Request<v1::CreateReadSessionRequest>,
+        ) -> tonic::Result<TonicResponse<v1::ReadSession>> {
+            let (schema, _) = ipc_messages(&[]).expect("valid schema");
+            Ok(TonicResponse::new(v1::ReadSession {
+                name: "projects/p/locations/l/sessions/s".to_string(),
+                streams: vec![v1::ReadStream {
+                    name: "projects/p/locations/l/sessions/s/streams/0".to_string(),
+                }],
+                schema: Some(v1::read_session::Schema::ArrowSchema(v1::ArrowSchema {
+                    serialized_schema: schema,
+                })),
+                ..Default::default()
+            }))
+        }
+
+        type ReadRowsStream = ReceiverStream<tonic::Result<v1::ReadRowsResponse>>;
+
+        async fn read_rows(
+            &self,
+            request: Request<v1::ReadRowsRequest>,
+        ) -> tonic::Result<TonicResponse<Self::ReadRowsStream>> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            let offset = request.into_inner().offset;
+            let values = (offset..3).map(|i| vec![i]).collect::<Vec<_>>();
+            let (_, batches) = ipc_messages(&values).expect("valid batches");
+            let mut responses = batches
+                .into_iter()
+                .map(|b| {
+                    Ok(v1::ReadRowsResponse {
+                        row_count: 1,
+                        rows: Some(v1::read_rows_response::Rows::ArrowRecordBatch(
+                            v1::ArrowRecordBatch {
+                                serialized_record_batch: b,
+                                ..Default::default()
+                            },
+                        )),
+                        ..Default::default()
+                    })
+                })
+                .collect::<Vec<_>>();
+            if call < self.failures {
+                responses.truncate(1);
+                responses.push(Err(Status::unavailable("try-again")));
+            }
+            let (tx, rx) = mpsc::channel(responses.len().max(1));
+            for r in responses {
+                let _ = tx.try_send(r);
+            }
+            Ok(TonicResponse::new(ReceiverStream::new(rx)))
+        }
+
+        async fn split_read_stream(
+            &self,
+            _request: Request<v1::SplitReadStreamRequest>,
+        ) -> tonic::Result<TonicResponse<v1::SplitReadStreamResponse>> {
+            Err(Status::unimplemented("not used in tests"))
+        }
+    }
+
+    async fn read_table(
+        failures: usize,
+        attempt_limit: u32,
+    ) -> (Arc<AtomicUsize>, Result<ArrowReader>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let fake = FakeRead {
+            calls: calls.clone(),
+            failures,
+        };
+        let mut config = gaxi::options::ClientConfig::default();
+        config.endpoint = Some(start(fake).await.expect("server starts"));
+        config.cred = Some(Anonymous::new().build());
+        config.retry_policy = Some(Arc::new(AlwaysRetry.with_attempt_limit(attempt_limit)));
+        config.backoff_policy = Some(Arc::new(
+            ExponentialBackoffBuilder::new()
+                .with_initial_delay(Duration::from_millis(1))
+                .with_maximum_delay(Duration::from_millis(1))
+                .build()
+                .expect("valid backoff"),
+        ));
+        let client = ReadClient::new(config).await.expect("client builds");
+        let table = TableReference::new()
+            .set_project_id("p")
+            .set_dataset_id("d")
+            .set_table_id("t");
+        let reader = ReadArrow::new_table(Some(client), None, table).send().await;
+        (calls, reader)
+    }
+
+    #[tokio::test]
+    async fn resume_with_retry_policy() -> anyhow::Result<()> {
+        // Each interruption follows some progress, so each one starts with a
+        // fresh attempt budget.
+        let (calls, reader) = read_table(2, 2).await;
+        let batches = collect(&mut reader?).await?;
+        let values = batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<Int64Type>().values().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![0, 1, 2]);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resume_exhausted() -> anyhow::Result<()> {
+        let (calls, reader) = read_table(usize::MAX, 2).await;
+        let mut reader = reader?;
+        let mut values = Vec::new();
+        let err = loop {
+            match reader.next().await {
+                Some(Ok(b)) => {
+                    values.extend(b.column(0).as_primitive::<Int64Type>().values().to_vec())
+                }
+                Some(Err(e)) => break e,
+                None => anyhow::bail!("expected an error"),
+            }
+        };
+        assert!(matches!(err, RowError::Rpc { .. }), "{err:?}");
+        assert_eq!(values, vec![0, 1, 2]);
+        // The third call fails after the last row, and the fourth call fails
+        // without progress, which exhausts the policy.
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn decode_ipc_messages() -> anyhow::Result<()> {
+        let schema = arrow::datatypes::Schema::new(vec![Field::new("n", DataType::Int64, true)]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(Int64Array::from(vec![Some(1), None, Some(3)]))],
+        )?;
+
+        let mut writer = StreamWriter::try_new(Vec::new(), &schema)?;
+        writer.write(&batch)?;
+        writer.finish()?;
+        let bytes = writer.into_inner()?;
+
+        let mut decoder = StreamDecoder::new();
+        let got = decode(&mut decoder, bytes.into())?;
+        assert_eq!(got, vec![batch]);
+        assert_eq!(got[0].column(0).null_count(), 1);
+        Ok(())
+    }
+}
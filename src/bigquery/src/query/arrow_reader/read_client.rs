@@ -0,0 +1,357 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A client for the BigQuery Storage Read API.
+
+use crate::ClientBuilderResult as BuilderResult;
+use crate::retry_policy::{default_backoff_policy, default_retry_policy};
+use gaxi::grpc::from_status::to_gax_error;
+use gaxi::grpc::tonic::{Extensions, GrpcMethod, Streaming};
+use gaxi::options::ClientConfig;
+use gaxi::prost::{FromProto, ToProto};
+use google_cloud_bigquery_write::model::{
+    CreateReadSessionRequest, ReadRowsRequest, ReadRowsResponse, ReadSession,
+};
+use google_cloud_bigquery_write::read_protos;
+use google_cloud_gax::backoff_policy::BackoffPolicy;
+use google_cloud_gax::error::Error;
+use google_cloud_gax::options::RequestOptions;
+use google_cloud_gax::options::internal::set_default_idempotency;
+use google_cloud_gax::retry_policy::RetryPolicy;
+use std::sync::Arc;
+
+type Result<T> = std::result::Result<T, Error>;
+
+const DEFAULT_HOST: &str = "https://bigquerystorage.googleapis.com";
+
+mod info {
+    use std::sync::LazyLock;
+
+    const NAME: &str = env!("CARGO_PKG_NAME");
+    const VERSION: &str = env!("CARGO_PKG_VERSION");
+    pub(super) static X_GOOG_API_CLIENT_HEADER: LazyLock<String> = LazyLock::new(|| {
+        let ac = gaxi::api_header::XGoogApiClient {
+            name: NAME,
+            version: VERSION,
+            library_type: gaxi::api_header::GCCL,
+        };
+        ac.grpc_header_value()
+    });
+}
+
+/// Implements the `CreateReadSession` and `ReadRows` RPCs.
+///
+/// The protos are shared with the Write API, and defined in
+/// `google-cloud-bigquery-write`.
+#[derive(Clone, Debug)]
+pub(crate) struct ReadClient {
+    inner: gaxi::grpc::Client,
+    /// Decides if an interrupted `ReadRows` stream is resumed.
+    pub(crate) retry_policy: Arc<dyn RetryPolicy>,
+    /// The delay before resuming an interrupted `ReadRows` stream.
+    pub(crate) backoff_policy: Arc<dyn BackoffPolicy>,
+}
+
+impl ReadClient {
+    pub(crate) async fn new(config: ClientConfig) -> BuilderResult<Self> {
+        let retry_policy = config
+            .retry_policy
+            .clone()
+            .unwrap_or_else(default_retry_policy);
+        let backoff_policy = config
+            .backoff_policy
+            .clone()
+            .unwrap_or_else(default_backoff_policy);
+        let inner = gaxi::grpc::Client::new(config, DEFAULT_HOST).await?;
+        Ok(Self {
+            inner,
+            retry_policy,
+            backoff_policy,
+        })
+    }
+
+    /// Creates a new read session.
+    pub(crate) async fn create_read_session(
+        &self,
+        req: CreateReadSessionRequest,
+    ) -> Result<ReadSession> {
+        // Creating a read session has no side-effects, it is safe to retry.
+        let options = set_default_idempotency(RequestOptions::default(), true);
+        let extensions = {
+            let mut e = Extensions::new();
+            e.insert(GrpcMethod::new(
+                "google.cloud.bigquery.storage.v1.BigQueryRead",
+                "CreateReadSession",
+            ));
+            e
+        };
+        let path = http::uri::PathAndQuery::from_static(
+            "/google.cloud.bigquery.storage.v1.BigQueryRead/CreateReadSession",
+        );
+        let request_params = req
+            .read_session
+            .as_ref()
+            .map(|s| format!("read_session.table={}", s.table))
+            .unwrap_or_default();
+        self.inner
+            .execute(
+                extensions,
+                path,
+                req.to_proto().map_err(Error::deser)?,
+                options,
+                &info::X_GOOG_API_CLIENT_HEADER,
+                &request_params,
+            )
+            .await
+            .and_then(gaxi::grpc::to_gax_response::<read_protos::ReadSession, ReadSession>)
+            .map(|r| r.into_body())
+    }
+
+    /// Reads rows from a stream in a read session.
+    ///
+    /// The stream is not resumed on errors, the caller resumes by calling this
+    /// function again, with the offset set to the number of rows received.
+    pub(crate) async fn read_rows(&self, req: ReadRowsRequest) -> Result<ReadRowsStream> {
+        let extensions = {
+            let mut e = Extensions::new();
+            e.insert(GrpcMethod::new(
+                "google.cloud.bigquery.storage.v1.BigQueryRead",
+                "ReadRows",
+            ));
+            e
+        };
+        let path = http::uri::PathAndQuery::from_static(
+            "/google.cloud.bigquery.storage.v1.BigQueryRead/ReadRows",
+        );
+        let request_params = format!("read_stream={}", req.read_stream);
+        let response = self
+            .inner
+            .server_streaming(
+                extensions,
+                path,
+                req.to_proto().map_err(Error::deser)?,
+                RequestOptions::default(),
+                &info::X_GOOG_API_CLIENT_HEADER,
+                &request_params,
+            )
+            .await?;
+        Ok(ReadRowsStream {
+            inner: response.into_inner(),
+        })
+    }
+}
+
+/// The responses from a `ReadRows` RPC.
+#[derive(Debug)]
+pub(crate) struct ReadRowsStream {
+    inner: Streaming<read_protos::ReadRowsResponse>,
+}
+
+impl ReadRowsStream {
+    /// Returns the next response in the stream.
+    ///
+    /// Returns `None` once the service has sent all the rows in the stream.
+    pub(crate) async fn next(&mut self) -> Option<Result<ReadRowsResponse>> {
+        match self.inner.message().await {
+            Ok(Some(response)) => Some(response.cnv().map_err(Error::deser)),
+            Ok(None) => None,
+            Err(status) => Some(Err(to_gax_error(status))),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use bigquery_write_grpc_mock::google::cloud::bigquery::storage::v1;
+    use bigquery_write_grpc_mock::to_uri;
+    use google_cloud_auth::credentials::anonymous::Builder as Anonymous;
+    use google_cloud_bigquery_write::model::read_rows_response::Rows;
+    use google_cloud_bigquery_write::model::{ArrowRecordBatch, DataFormat};
+    use google_cloud_gax::error::rpc::Code;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+    use tonic::{Request, Response, Status};
+    use v1::big_query_read_server::{BigQueryRead, BigQueryReadServer};
+
+    const TABLE: &str = "projects/p/datasets/d/tables/t";
+    const STREAM: &str = "projects/p/locations/l/sessions/s/streams/0";
+
+    // The generated mocks only cover `BigQueryWrite`, this is a fake
+    // implementation of the `BigQueryRead` service.
+    struct FakeRead;
+
+    #[async_trait::async_trait]
+    impl BigQueryRead for FakeRead {
+        async fn create_read_session(
+            &self,
+            request: Request<v1::CreateReadSessionRequest>,
+        ) -> tonic::Result<Response<v1::ReadSession>> {
+            let params = request
+                .metadata()
+                .get("x-goog-request-params")
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default()
+                .to_string();
+            let request = request.into_inner();
+            let session = request.read_session.unwrap_or_default();
+            if session.table != TABLE {
+                return Err(Status::not_found(session.table));
+            }
+            assert_eq!(params, format!("read_session.table={TABLE}"));
+            assert_eq!(request.parent, "projects/p");
+            Ok(Response::new(v1::ReadSession {
+                name: "projects/p/locations/l/sessions/s".to_string(),
+                table: session.table,
+                streams: vec![v1::ReadStream {
+                    name: STREAM.to_string(),
+                }],
+                ..Default::default()
+            }))
+        }
+
+        type ReadRowsStream = ReceiverStream<tonic::Result<v1::ReadRowsResponse>>;
+
+        async fn read_rows(
+            &self,
+            request: Request<v1::ReadRowsRequest>,
+        ) -> tonic::Result<Response<Self::ReadRowsStream>> {
+            let params = request
+                .metadata()
+                .get("x-goog-request-params")
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default()
+                .to_string();
+            assert_eq!(params, format!("read_stream={STREAM}"));
+            let request = request.into_inner();
+            let (tx, rx) = mpsc::channel(4);
+            let offset = request.offset;
+            tokio::spawn(async move {
+                for i in offset..3 {
+                    let response = v1::ReadRowsResponse {
+                        row_count: 1,
+                        rows: Some(v1::read_rows_response::Rows::ArrowRecordBatch(
+                            v1::ArrowRecordBatch {
+                                serialized_record_batch: vec![i as u8],
+                                ..Default::default()
+                            },
+                        )),
+                        ..Default::default()
+                    };
+                    let _ = tx.send(Ok(response)).await;
+                }
+                let _ = tx.send(Err(Status::unavailable("try-again"))).await;
+            });
+            Ok(Response::new(ReceiverStream::new(rx)))
+        }
+
+        async fn split_read_stream(
+            &self,
+            _request: Request<v1::SplitReadStreamRequest>,
+        ) -> tonic::Result<Response<v1::SplitReadStreamResponse>> {
+            Err(Status::unimplemented("not used in tests"))
+        }
+    }
+
+    /// Starts a server for `service` and returns its endpoint.
+    pub(crate) async fn start<S: BigQueryRead>(service: S) -> anyhow::Result<String> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        tokio::spawn(async move {
+            let stream = tokio_stream::wrappers::TcpListenerStream::new(listener);
+            let _ = tonic::transport::Server::builder()
+                .add_service(BigQueryReadServer::new(service))
+                .serve_with_incoming(stream)
+                .await;
+        });
+        Ok(to_uri(addr))
+    }
+
+    async fn test_client() -> anyhow::Result<ReadClient> {
+        let mut config = ClientConfig::default();
+        config.endpoint = Some(start(FakeRead).await?);
+        config.cred = Some(Anonymous::new().build());
+        Ok(ReadClient::new(config).await?)
+    }
+
+    #[tokio::test]
+    async fn create_read_session() -> anyhow::Result<()> {
+        let client = test_client().await?;
+        let session = client
+            .create_read_session(
+                CreateReadSessionRequest::new()
+                    .set_parent("projects/p")
+                    .set_read_session(
+                        ReadSession::new()
+                            .set_table(TABLE)
+                            .set_data_format(DataFormat::Arrow),
+                    ),
+            )
+            .await?;
+        assert_eq!(session.name, "projects/p/locations/l/sessions/s");
+        let streams = session
+            .streams
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(streams, vec![STREAM]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_read_session_error() -> anyhow::Result<()> {
+        let client = test_client().await?;
+        let err = client
+            .create_read_session(
+                CreateReadSessionRequest::new()
+                    .set_parent("projects/p")
+                    .set_read_session(ReadSession::new().set_table("missing")),
+            )
+            .await
+            .expect_err("missing tables should fail");
+        assert_eq!(
+            err.status().map(|s| s.code),
+            Some(Code::NotFound),
+            "{err:?}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_rows() -> anyhow::Result<()> {
+        let client = test_client().await?;
+        let mut stream = client
+            .read_rows(ReadRowsRequest::new().set_read_stream(STREAM).set_offset(1))
+            .await?;
+        let mut got = Vec::new();
+        while let Some(response) = stream.next().await {
+            let response = match response {
+                Ok(r) => r,
+                Err(e) => {
+                    assert_eq!(e.status().map(|s| s.code), Some(Code::Unavailable), "{e:?}");
+                    break;
+                }
+            };
+            let Some(Rows::ArrowRecordBatch(batch)) = response.rows else {
+                anyhow::bail!("expected arrow rows in {response:?}");
+            };
+            got.push(*batch);
+        }
+        let want = [1_u8, 2]
+            .map(|i| ArrowRecordBatch::new().set_serialized_record_batch(vec![i]))
+            .to_vec();
+        assert_eq!(got, want);
+        Ok(())
+    }
+}
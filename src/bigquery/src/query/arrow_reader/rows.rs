@@ -0,0 +1,426 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Converts rows returned by the REST API into Arrow record batches.
+//!
+//! The Storage Read API is not available for every result set. In those
+//! cases the [ArrowReader][super::ArrowReader] reads the rows using
+//! `jobs.getQueryResults` and converts them. The Arrow types match the types
+//! used by the Storage Read API, so applications see the same schema
+//! regardless of how the data was read.
+
+use crate::error::{ConvertError, RowError};
+use crate::query::from_sql::{
+    BIGQUERY_DATE_FORMAT, BIGQUERY_DATETIME_FORMAT, BIGQUERY_DATETIME_SUBSEC_FORMAT, parse_time,
+};
+use crate::query::{FromSql, Row, Schema};
+use arrow::array::{
+    ArrayRef, BinaryArray, BooleanArray, Date32Array, Decimal128Array, Decimal256Array,
+    Float64Array, Int64Array, ListArray, StringArray, StructArray, Time64MicrosecondArray,
+    TimestampMicrosecondArray,
+};
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::datatypes::{DataType, Field, Fields, SchemaRef, TimeUnit, i256};
+use arrow::record_batch::RecordBatch;
+use google_cloud_bigquery_v2::model::TableFieldSchema;
+use std::sync::Arc;
+use wkt::Value;
+
+type Result<T> = std::result::Result<T, RowError>;
+
+// The Julian day number for 1970-01-01.
+const UNIX_EPOCH_JULIAN_DAY: i32 = 2_440_588;
+
+/// Returns the Arrow schema for a BigQuery table schema.
+pub(crate) fn arrow_schema(schema: &Schema) -> Result<SchemaRef> {
+    let fields = schema
+        .fields()
+        .iter()
+        .map(arrow_field)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Arc::new(arrow::datatypes::Schema::new(fields)))
+}
+
+/// Converts a group of rows into a single record batch.
+pub(crate) fn to_record_batch(
+    schema: &SchemaRef,
+    fields: &[TableFieldSchema],
+    rows: &[Row],
+) -> Result<RecordBatch> {
+    let columns = fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| {
+            let values = rows.iter().map(|r| cell(&r.values, i)).collect::<Vec<_>>();
+            to_array(f, &values)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    RecordBatch::try_new(schema.clone(), columns).map_err(RowError::Arrow)
+}
+
+fn cell(values: &Value, index: usize) -> &Value {
+    values
+        .as_array()
+        .and_then(|v| v.get(index))
+        .unwrap_or(&Value::Null)
+}
+
+fn arrow_field(field: &TableFieldSchema) -> Result<Field> {
+    let data_type = arrow_type(field)?;
+    if field.mode == "REPEATED" {
+        let item = Field::new_list_field(data_type, false);
+        return Ok(Field::new(
+            &field.name,
+            DataType::List(Arc::new(item)),
+            false,
+        ));
+    }
+    Ok(Field::new(&field.name, data_type, field.mode != "REQUIRED"))
+}
+
+fn arrow_type(field: &TableFieldSchema) -> Result<DataType> {
+    let data_type = match field.r#type.as_str() {
+        "STRING" | "GEOGRAPHY" | "JSON" | "INTERVAL" | "RANGE" => DataType::Utf8,
+        "BYTES" => DataType::Binary,
+        "INTEGER" | "INT64" => DataType::Int64,
+        "FLOAT" | "FLOAT64" => DataType::Float64,
+        "BOOLEAN" | "BOOL" => DataType::Boolean,
+        "TIMESTAMP" => DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+        "DATE" => DataType::Date32,
+        "TIME" => DataType::Time64(TimeUnit::Microsecond),
+        "DATETIME" => DataType::Timestamp(TimeUnit::Microsecond, None),
+        "NUMERIC" => DataType::Decimal128(38, 9),
+        "BIGNUMERIC" => DataType::Decimal256(76, 38),
+        "RECORD" | "STRUCT" => {
+            let fields = field
+                .fields
+                .iter()
+                .map(arrow_field)
+                .collect::<Result<Fields>>()?;
+            DataType::Struct(fields)
+        }
+        t => {
+            return Err(RowError::InvalidRowFormat(format!(
+                "unknown field type: {t} at column {}",
+                field.name
+            )));
+        }
+    };
+    Ok(data_type)
+}
+
+fn to_array(field: &TableFieldSchema, values: &[&Value]) -> Result<ArrayRef> {
+    if field.mode == "REPEATED" {
+        return to_list_array(field, values);
+    }
+    to_scalar_array(field, values)
+}
+
+fn to_list_array(field: &TableFieldSchema, values: &[&Value]) -> Result<ArrayRef> {
+    // BigQuery does not distinguish between NULL and empty arrays.
+    let lists = values
+        .iter()
+        .map(|v| v.as_array().map(Vec::as_slice).unwrap_or_default())
+        .collect::<Vec<_>>();
+    let offsets = OffsetBuffer::from_lengths(lists.iter().map(|l| l.len()));
+    let items = lists.iter().flat_map(|l| l.iter()).collect::<Vec<_>>();
+    let items = to_scalar_array(field, &items)?;
+    let item = Field::new_list_field(arrow_type(field)?, false);
+    let array =
+        ListArray::try_new(Arc::new(item), offsets, items, None).map_err(RowError::Arrow)?;
+    Ok(Arc::new(array))
+}
+
+fn to_scalar_array(field: &TableFieldSchema, values: &[&Value]) -> Result<ArrayRef> {
+    let array: ArrayRef = match field.r#type.as_str() {
+        "STRING" | "GEOGRAPHY" | "JSON" | "INTERVAL" | "RANGE" => {
+            Arc::new(StringArray::from(convert::<String>(field, values)?))
+        }
+        "BYTES" => Arc::new(BinaryArray::from_iter(convert::<Vec<u8>>(field, values)?)),
+        "INTEGER" | "INT64" => Arc::new(Int64Array::from(convert::<i64>(field, values)?)),
+        "FLOAT" | "FLOAT64" => Arc::new(Float64Array::from(convert::<f64>(field, values)?)),
+        "BOOLEAN" | "BOOL" => Arc::new(BooleanArray::from(convert::<bool>(field, values)?)),
+        "TIMESTAMP" => {
+            let micros = convert::<wkt::Timestamp>(field, values)?
+                .into_iter()
+                .map(|t| t.map(|t| t.seconds() * 1_000_000 + (t.nanos() / 1_000) as i64))
+                .collect::<Vec<_>>();
+            Arc::new(TimestampMicrosecondArray::from(micros).with_timezone("UTC"))
+        }
+        "DATE" => Arc::new(Date32Array::from(parse(field, values, parse_date)?)),
+        "TIME" => Arc::new(Time64MicrosecondArray::from(parse(
+            field,
+            values,
+            parse_time_micros,
+        )?)),
+        "DATETIME" => Arc::new(TimestampMicrosecondArray::from(parse(
+            field,
+            values,
+            parse_datetime_micros,
+        )?)),
+        "NUMERIC" => {
+            let values = parse(field, values, |s| parse_decimal::<i128>(s, 9))?;
+            let array = Decimal128Array::from(values)
+                .with_precision_and_scale(38, 9)
+                .map_err(RowError::Arrow)?;
+            Arc::new(array)
+        }
+        "BIGNUMERIC" => {
+            let values = parse(field, values, |s| parse_decimal::<i256>(s, 38))?;
+            let array = Decimal256Array::from(values)
+                .with_precision_and_scale(76, 38)
+                .map_err(RowError::Arrow)?;
+            Arc::new(array)
+        }
+        "RECORD" | "STRUCT" => to_struct_array(field, values)?,
+        t => {
+            return Err(RowError::InvalidRowFormat(format!(
+                "unknown field type: {t} at column {}",
+                field.name
+            )));
+        }
+    };
+    Ok(array)
+}
+
+fn to_struct_array(field: &TableFieldSchema, values: &[&Value]) -> Result<ArrayRef> {
+    let fields = field
+        .fields
+        .iter()
+        .map(arrow_field)
+        .collect::<Result<Fields>>()?;
+    let columns = field
+        .fields
+        .iter()
+        .map(|f| {
+            let children = values
+                .iter()
+                .map(|v| {
+                    v.as_object()
+                        .and_then(|o| o.get(&f.name))
+                        .unwrap_or(&Value::Null)
+                })
+                .collect::<Vec<_>>();
+            to_array(f, &children)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let nulls = NullBuffer::from(values.iter().map(|v| !v.is_null()).collect::<Vec<_>>());
+    let array = StructArray::try_new(fields, columns, Some(nulls)).map_err(RowError::Arrow)?;
+    Ok(Arc::new(array))
+}
+
+fn convert<T: FromSql>(field: &TableFieldSchema, values: &[&Value]) -> Result<Vec<Option<T>>> {
+    values
+        .iter()
+        .map(|v| {
+            Option::<T>::from_sql((*v).clone()).map_err(|source| RowError::TypeConversion {
+                column: field.name.clone(),
+                source,
+            })
+        })
+        .collect()
+}
+
+fn parse<T, F>(field: &TableFieldSchema, values: &[&Value], f: F) -> Result<Vec<Option<T>>>
+where
+    F: Fn(&str) -> std::result::Result<T, ConvertError>,
+{
+    convert::<String>(field, values)?
+        .into_iter()
+        .map(|v| {
+            v.as_deref()
+                .map(&f)
+                .transpose()
+                .map_err(|source| RowError::TypeConversion {
+                    column: field.name.clone(),
+                    source,
+                })
+        })
+        .collect()
+}
+
+fn parse_date(s: &str) -> std::result::Result<i32, ConvertError> {
+    let date = time::Date::parse(s, BIGQUERY_DATE_FORMAT)
+        .map_err(|e| ConvertError::Convert(Box::new(e)))?;
+    Ok(date.to_julian_day() - UNIX_EPOCH_JULIAN_DAY)
+}
+
+fn parse_time_micros(s: &str) -> std::result::Result<i64, ConvertError> {
+    let time = parse_time(s)?;
+    let (h, m, s, micros) = time.as_hms_micro();
+    Ok(((h as i64 * 60 + m as i64) * 60 + s as i64) * 1_000_000 + micros as i64)
+}
+
+fn parse_datetime_micros(s: &str) -> std::result::Result<i64, ConvertError> {
+    let format = if s.contains('.') {
+        BIGQUERY_DATETIME_SUBSEC_FORMAT
+    } else {
+        BIGQUERY_DATETIME_FORMAT
+    };
+    let dt = time::PrimitiveDateTime::parse(s, format)
+        .map_err(|e| ConvertError::Convert(Box::new(e)))?;
+    Ok((dt.assume_utc().unix_timestamp_nanos() / 1_000) as i64)
+}
+
+/// Integer types used to represent decimal values in Arrow.
+trait DecimalValue: Sized {
+    fn parse_digits(digits: &str) -> Option<Self>;
+}
+
+impl DecimalValue for i128 {
+    fn parse_digits(digits: &str) -> Option<Self> {
+        digits.parse().ok()
+    }
+}
+
+impl DecimalValue for i256 {
+    fn parse_digits(digits: &str) -> Option<Self> {
+        i256::from_string(digits)
+    }
+}
+
+/// Parses a decimal string, such as `-123.45`, into an integer with `scale`
+/// implied fractional digits.
+fn parse_decimal<T: DecimalValue>(s: &str, scale: usize) -> std::result::Result<T, ConvertError> {
+    let invalid = || ConvertError::Convert(format!("invalid decimal value: {s}").into());
+    let (integer, fraction) = s.split_once('.').unwrap_or((s, ""));
+    if fraction.len() > scale || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let digits = format!("{integer}{fraction:0<scale$}");
+    T::parse_digits(&digits).ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Array, AsArray};
+    use arrow::datatypes::{Date32Type, Decimal128Type, Int64Type};
+    use google_cloud_bigquery_v2::model::TableSchema;
+    use serde_json::json;
+
+    fn field(name: &str, r#type: &str, mode: &str) -> TableFieldSchema {
+        TableFieldSchema::new()
+            .set_name(name)
+            .set_type(r#type)
+            .set_mode(mode)
+    }
+
+    fn to_row(schema: &Arc<Schema>, cells: serde_json::Value) -> anyhow::Result<Row> {
+        let raw = serde_json::from_value::<wkt::Struct>(json!({ "f": cells }))?;
+        Ok(Row::try_new(raw, schema)?)
+    }
+
+    #[test]
+    fn schema_mapping() -> anyhow::Result<()> {
+        let schema = Schema::new(TableSchema::new().set_fields([
+            field("s", "STRING", "REQUIRED"),
+            field("i", "INTEGER", "NULLABLE"),
+            field("d", "DATE", "NULLABLE"),
+            field("n", "NUMERIC", "NULLABLE"),
+            field("r", "INT64", "REPEATED"),
+            field("t", "RECORD", "NULLABLE").set_fields([field("x", "BOOL", "NULLABLE")]),
+        ]));
+        let got = arrow_schema(&schema)?;
+        let types = got
+            .fields()
+            .iter()
+            .map(|f| (f.name().as_str(), f.data_type().clone(), f.is_nullable()))
+            .collect::<Vec<_>>();
+        let item = Arc::new(Field::new_list_field(DataType::Int64, false));
+        let record = Fields::from(vec![Field::new("x", DataType::Boolean, true)]);
+        assert_eq!(
+            types,
+            vec![
+                ("s", DataType::Utf8, false),
+                ("i", DataType::Int64, true),
+                ("d", DataType::Date32, true),
+                ("n", DataType::Decimal128(38, 9), true),
+                ("r", DataType::List(item), false),
+                ("t", DataType::Struct(record), true),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_type() {
+        let schema = Schema::new(TableSchema::new().set_fields([field("u", "UNKNOWN", "")]));
+        let err = arrow_schema(&schema).unwrap_err();
+        assert!(matches!(err, RowError::InvalidRowFormat(_)), "{err:?}");
+    }
+
+    #[test]
+    fn record_batch() -> anyhow::Result<()> {
+        let table = TableSchema::new().set_fields([
+            field("i", "INTEGER", "NULLABLE"),
+            field("d", "DATE", "NULLABLE"),
+            field("n", "NUMERIC", "NULLABLE"),
+            field("r", "INT64", "REPEATED"),
+        ]);
+        let schema = Arc::new(Schema::new(table));
+        let rows = vec![
+            to_row(
+                &schema,
+                json!([{"v": "1"}, {"v": "1970-01-02"}, {"v": "-1.5"}, {"v": [{"v": "1"}, {"v": "2"}]}]),
+            )?,
+            to_row(
+                &schema,
+                json!([{"v": null}, {"v": null}, {"v": null}, {"v": []}]),
+            )?,
+        ];
+        let arrow = arrow_schema(&schema)?;
+        let batch = to_record_batch(&arrow, schema.fields(), &rows)?;
+        assert_eq!(batch.num_rows(), 2);
+
+        let i = batch.column(0).as_primitive::<Int64Type>();
+        assert_eq!(i.value(0), 1);
+        assert!(i.is_null(1));
+
+        let d = batch.column(1).as_primitive::<Date32Type>();
+        assert_eq!(d.value(0), 1);
+        assert!(d.is_null(1));
+
+        let n = batch.column(2).as_primitive::<Decimal128Type>();
+        assert_eq!(n.value(0), -1_500_000_000);
+        assert!(n.is_null(1));
+
+        let r = batch.column(3).as_list::<i32>();
+        assert_eq!(r.value_length(0), 2);
+        assert_eq!(r.value_length(1), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn decimals() -> anyhow::Result<()> {
+        assert_eq!(parse_decimal::<i128>("123.45", 9)?, 123_450_000_000);
+        assert_eq!(parse_decimal::<i128>("-7", 2)?, -700);
+        assert!(parse_decimal::<i128>("1.2345", 2).is_err());
+        assert!(parse_decimal::<i128>("abc", 2).is_err());
+        assert_eq!(
+            parse_decimal::<i256>("1.5", 38)?,
+            i256::from_string("150000000000000000000000000000000000000").expect("valid")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn times() -> anyhow::Result<()> {
+        assert_eq!(parse_date("1970-01-01")?, 0);
+        assert_eq!(parse_date("1969-12-31")?, -1);
+        assert_eq!(parse_time_micros("01:00:00.5")?, 3_600_500_000);
+        assert_eq!(parse_datetime_micros("1970-01-01T00:00:01")?, 1_000_000);
+        Ok(())
+    }
+}
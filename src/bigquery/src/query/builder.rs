@@ -14,18 +14,21 @@
 
 use crate::generated::QueryRequest;
 use crate::query::execution::{InsertJobExecutor, PostQueryExecutor};
-use crate::query::{CompleteQuery, Query as QueryHandle, Result};
+use crate::query::{CompleteQuery, Query as QueryHandle, Result, ToSql};
 use google_cloud_bigquery_v2::client::JobService;
 use google_cloud_bigquery_v2::model::query_request::JobCreationMode;
 use google_cloud_bigquery_v2::model::{
-    InsertJobRequest, Job, JobConfiguration, JobReference, PostQueryRequest,
+    InsertJobRequest, Job, JobConfiguration, JobReference, PostQueryRequest, QueryParameter,
     QueryRequest as JobsQueryRequest,
 };
+use google_cloud_gax::error::Error;
 use std::sync::Arc;
 use uuid::Uuid;
 
 pub(crate) const JOB_ID_PREFIX: &str = "job_";
 pub(crate) const QUERY_REQUEST_ID_PREFIX: &str = "req_";
+const NAMED: &str = "NAMED";
+const POSITIONAL: &str = "POSITIONAL";
 
 /// A builder for executing a SQL query.
 ///
@@ -111,6 +114,69 @@ impl Query {
         self
     }
 
+    /// Binds a value to a named parameter in the query.
+    ///
+    /// Named parameters appear in the SQL text as `@name`. The value is sent
+    /// separately from the SQL text, so it is never interpreted as SQL. Use
+    /// this instead of formatting values into the query.
+    ///
+    /// A query uses either named or positional parameters, calling both
+    /// `bind()` and [`bind_positional()`](Query::bind_positional) on the same
+    /// query fails when the query is sent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use google_cloud_bigquery::client::BigQuery;
+    /// # async fn sample(client: BigQuery) -> anyhow::Result<()> {
+    /// let query_handle = client
+    ///     .query("SELECT name FROM `my-dataset.users` WHERE state = @state AND age >= @age")
+    ///     .bind("state", "WA")
+    ///     .bind("age", 21_i64)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bind<N, T>(mut self, name: N, value: T) -> Self
+    where
+        N: Into<String>,
+        T: ToSql,
+    {
+        self.request.parameter_mode = NAMED.to_string();
+        self.request
+            .query_parameters
+            .push(parameter(name.into(), &value));
+        self
+    }
+
+    /// Binds a value to the next positional parameter in the query.
+    ///
+    /// Positional parameters appear in the SQL text as `?`, and are bound in
+    /// the order of the calls to this method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use google_cloud_bigquery::client::BigQuery;
+    /// # async fn sample(client: BigQuery) -> anyhow::Result<()> {
+    /// let query_handle = client
+    ///     .query("SELECT name FROM `my-dataset.users` WHERE state = ? AND age >= ?")
+    ///     .bind_positional("WA")
+    ///     .bind_positional(21_i64)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bind_positional<T: ToSql>(mut self, value: T) -> Self {
+        self.request.parameter_mode = POSITIONAL.to_string();
+        self.request
+            .query_parameters
+            .push(parameter(String::new(), &value));
+        self
+    }
+
     /// Executes the SQL query.
     ///
     /// This returns a [`Query`](crate::Query) handle representing an
@@ -151,6 +217,7 @@ impl Query {
     /// # }
     /// ```
     pub async fn send(self) -> Result<QueryHandle> {
+        check_parameters(&self.request.query_parameters)?;
         let project_id = self.project_id.unwrap_or_default();
         let max_results = self.request.max_results;
 
@@ -216,6 +283,27 @@ impl Query {
     }
 }
 
+fn parameter<T: ToSql>(name: String, value: &T) -> QueryParameter {
+    QueryParameter::new()
+        .set_name(name)
+        .set_parameter_type(T::sql_type())
+        .set_parameter_value(value.to_sql())
+}
+
+// BigQuery rejects queries that mix named and positional parameters. Detect
+// this locally, before making any requests.
+fn check_parameters(parameters: &[QueryParameter]) -> Result<()> {
+    let named = parameters.iter().filter(|p| !p.name.is_empty()).count();
+    if named == 0 || named == parameters.len() {
+        return Ok(());
+    }
+    Err(Error::binding(format!(
+        "cannot mix named and positional parameters, got {named} named and {} positional parameters",
+        parameters.len() - named
+    ))
+    .into())
+}
+
 // Create a job reference with a generated job ID.
 //
 // BigQuery does not strictly define a format for job IDs, just a limit in size.
@@ -255,7 +343,8 @@ mod tests {
     use google_cloud_auth::credentials::anonymous::Builder as Anonymous;
     use google_cloud_bigquery_v2::model::query_request::JobCreationMode;
     use google_cloud_bigquery_v2::model::{
-        Job, JobConfiguration, JobReference, JobStatus, QueryResponse,
+        Job, JobConfiguration, JobReference, JobStatus, QueryParameterType, QueryParameterValue,
+        QueryResponse,
     };
     use google_cloud_gax::response::Response;
 
@@ -509,4 +598,110 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bind() {
+        let job_service = create_job_service(MockJobService::new());
+        let run_query = Query::new(job_service, "SELECT @name, @ids".to_string())
+            .bind("name", "alice")
+            .bind("ids", vec![1_i64, 2]);
+        let request = run_query.request;
+        assert_eq!(request.parameter_mode, "NAMED");
+        assert_eq!(
+            request.query_parameters,
+            vec![
+                QueryParameter::new()
+                    .set_name("name")
+                    .set_parameter_type(QueryParameterType::new().set_type("STRING"))
+                    .set_parameter_value(QueryParameterValue::new().set_value("alice")),
+                QueryParameter::new()
+                    .set_name("ids")
+                    .set_parameter_type(Vec::<i64>::sql_type())
+                    .set_parameter_value(vec![1_i64, 2].to_sql()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bind_positional() {
+        let job_service = create_job_service(MockJobService::new());
+        let run_query = Query::new(job_service, "SELECT ?, ?".to_string())
+            .bind_positional(42_i64)
+            .bind_positional(None::<String>);
+        let request = run_query.request;
+        assert_eq!(request.parameter_mode, "POSITIONAL");
+        assert_eq!(
+            request.query_parameters,
+            vec![
+                QueryParameter::new()
+                    .set_parameter_type(QueryParameterType::new().set_type("INT64"))
+                    .set_parameter_value(QueryParameterValue::new().set_value("42")),
+                QueryParameter::new()
+                    .set_parameter_type(QueryParameterType::new().set_type("STRING"))
+                    .set_parameter_value(QueryParameterValue::new()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bind_mixed_parameters() -> TestResult {
+        let job_service = create_job_service(MockJobService::new());
+        let err = Query::new(job_service, "SELECT @name, ?".to_string())
+            .with_project_id("my-project")
+            .bind("name", "alice")
+            .bind_positional(42_i64)
+            .send()
+            .await
+            .expect_err("mixed parameters should fail");
+        assert!(
+            matches!(&err, QueryError::Rpc { source } if source.is_binding()),
+            "{err:?}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_run_jobs_query_with_parameters() -> TestResult {
+        let mut mock = MockJobService::new();
+        mock.expect_query().returning(move |req, _| {
+            let query_request = req.query_request.as_ref().unwrap();
+            assert_eq!(query_request.parameter_mode, "NAMED");
+            assert_eq!(query_request.query_parameters.len(), 1);
+            assert_eq!(query_request.query_parameters[0].name, "name");
+            Ok(Response::from(QueryResponse::new()))
+        });
+        let job_service = create_job_service(mock);
+        Query::new(job_service, "SELECT @name".to_string())
+            .with_project_id("my-project")
+            .bind("name", "alice")
+            .send()
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_run_jobs_insert_with_parameters() -> TestResult {
+        let mut mock = MockJobService::new();
+        mock.expect_insert_job().returning(|req, _| {
+            let config = req.job.as_ref().unwrap().configuration.as_ref().unwrap();
+            let query = config.query.as_ref().unwrap();
+            assert_eq!(query.parameter_mode, "POSITIONAL");
+            assert_eq!(query.query_parameters.len(), 1);
+            let job_ref = JobReference::new()
+                .set_job_id("test-job")
+                .set_project_id("my-project");
+            let job = Job::new()
+                .set_job_reference(job_ref)
+                .set_status(JobStatus::new().set_state("DONE"));
+            Ok(google_cloud_gax::response::Response::from(job))
+        });
+        let job_service = create_job_service(mock);
+        Query::new(job_service, "SELECT ?".to_string())
+            .with_project_id("my-project")
+            .set_allow_large_results(true)
+            .bind_positional(42_i64)
+            .send()
+            .await?;
+        Ok(())
+    }
 }
@@ -357,6 +357,40 @@ impl CompleteQuery {
         RowIterator::new(self)
     }
 
+    /// Read the result set of the query as [Arrow] record batches.
+    ///
+    /// Large result sets are read in parallel using the [Storage Read API],
+    /// which is much faster than [`read()`](CompleteQuery::read). Small result
+    /// sets, and queries without a destination table, are read using the REST
+    /// API. See [`ReadArrow`](crate::builder::bigquery::ReadArrow) for details.
+    ///
+    /// [Arrow]: https://arrow.apache.org/
+    /// [Storage Read API]: https://cloud.google.com/bigquery/docs/reference/storage
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use google_cloud_bigquery::client::BigQuery;
+    /// # async fn sample(client: BigQuery) -> anyhow::Result<()> {
+    /// let mut batches = client
+    ///     .query("SELECT 100 AS score")
+    ///     .until_done()
+    ///     .await?
+    ///     .read_arrow(&client)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// while let Some(batch) = batches.next().await.transpose()? {
+    ///     println!("Rows: {}", batch.num_rows());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "arrow")]
+    pub fn read_arrow(self, client: &crate::client::BigQuery) -> crate::query::ReadArrow {
+        crate::query::ReadArrow::new_query(client.read_client(), client.project_id(), self)
+    }
+
     /// Returns a reference to the cached summary metadata for this query.
     ///
     /// The returned [`CompleteQueryMetadata`](crate::model::CompleteQueryMetadata) contains
@@ -35,6 +35,11 @@ impl Schema {
         self.0.fields.get(index)
     }
 
+    #[cfg(feature = "arrow")]
+    pub(crate) fn fields(&self) -> &[TableFieldSchema] {
+        &self.0.fields
+    }
+
     pub(crate) fn len(&self) -> usize {
         self.0.fields.len()
     }
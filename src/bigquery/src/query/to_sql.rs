@@ -0,0 +1,437 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use google_cloud_bigquery_v2::model::{QueryParameterType, QueryParameterValue};
+
+/// A trait for converting Rust types into BigQuery [query parameters].
+///
+/// [`Query::bind()`](crate::builder::bigquery::Query::bind) and
+/// [`Query::bind_positional()`](crate::builder::bigquery::Query::bind_positional)
+/// use this trait to send values separately from the SQL text, and the
+/// [`ToSql`](crate::ToSql) derive macro uses it for `STRUCT` fields.
+///
+/// [query parameters]: https://cloud.google.com/bigquery/docs/parameterized-queries
+///
+/// # Supported Types
+///
+/// Built-in implementations include:
+/// - Numbers: `i32`, `i64`, `f32`, `f64`, [`Decimal`](rust_decimal::Decimal) (as `NUMERIC`), [`Decimal`](google_cloud_type::model::Decimal) (as `BIGNUMERIC`)
+/// - Text & Bytes: `String`, `str`, `Vec<u8>`, [`Bytes`](bytes::Bytes)
+/// - Dates & Times: [`Timestamp`](wkt::Timestamp), [`Date`](google_cloud_type::model::Date), [`TimeOfDay`](google_cloud_type::model::TimeOfDay), [`DateTime`](google_cloud_type::model::DateTime)
+/// - Intervals: [`Interval`](crate::datatypes::Interval)
+/// - Collections: `Option<T>` (for `NULL`), `Vec<T>` (for `ARRAY`), [`Range<T>`](crate::datatypes::Range) (for `RANGE` types)
+/// - Raw JSON: [`Value`](wkt::Value), [`Struct`](wkt::Struct)
+///
+/// # Example
+///
+/// ```
+/// # use google_cloud_bigquery::client::BigQuery;
+/// # async fn sample(client: BigQuery) -> anyhow::Result<()> {
+/// let mut rows = client
+///     .query("SELECT name FROM `bigquery-public-data.usa_names.usa_1910_2013` WHERE state = @state AND number > @min")
+///     .bind("state", "WA")
+///     .bind("min", 1000_i64)
+///     .until_done()
+///     .await?
+///     .read();
+///
+/// while let Some(row) = rows.next().await.transpose()? {
+///     let name: String = row.get("name");
+///     println!("{name}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub trait ToSql {
+    /// The BigQuery type of the parameter.
+    fn sql_type() -> QueryParameterType;
+
+    /// Converts the value into a BigQuery query parameter value.
+    fn to_sql(&self) -> QueryParameterValue;
+}
+
+/// Creates the type for a scalar parameter, such as `INT64` or `STRING`.
+pub(crate) fn scalar_type(name: &str) -> QueryParameterType {
+    QueryParameterType::new().set_type(name)
+}
+
+/// Creates the value for a scalar parameter.
+pub(crate) fn scalar_value<T: Into<String>>(value: T) -> QueryParameterValue {
+    QueryParameterValue::new().set_value(value.into())
+}
+
+impl<T: ToSql + ?Sized> ToSql for &T {
+    fn sql_type() -> QueryParameterType {
+        T::sql_type()
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        (**self).to_sql()
+    }
+}
+
+impl ToSql for str {
+    fn sql_type() -> QueryParameterType {
+        scalar_type("STRING")
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        scalar_value(self)
+    }
+}
+
+impl ToSql for String {
+    fn sql_type() -> QueryParameterType {
+        scalar_type("STRING")
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        scalar_value(self)
+    }
+}
+
+impl ToSql for i32 {
+    fn sql_type() -> QueryParameterType {
+        scalar_type("INT64")
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        scalar_value(self.to_string())
+    }
+}
+
+impl ToSql for i64 {
+    fn sql_type() -> QueryParameterType {
+        scalar_type("INT64")
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        scalar_value(self.to_string())
+    }
+}
+
+impl ToSql for f32 {
+    fn sql_type() -> QueryParameterType {
+        scalar_type("FLOAT64")
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        f64::from(*self).to_sql()
+    }
+}
+
+impl ToSql for f64 {
+    fn sql_type() -> QueryParameterType {
+        scalar_type("FLOAT64")
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        // BigQuery uses these names for the non-finite values.
+        let value = if self.is_nan() {
+            "NaN".to_string()
+        } else if self.is_infinite() && self.is_sign_positive() {
+            "Infinity".to_string()
+        } else if self.is_infinite() {
+            "-Infinity".to_string()
+        } else {
+            self.to_string()
+        };
+        scalar_value(value)
+    }
+}
+
+impl ToSql for bool {
+    fn sql_type() -> QueryParameterType {
+        scalar_type("BOOL")
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        scalar_value(self.to_string())
+    }
+}
+
+impl<T: ToSql> ToSql for Option<T> {
+    fn sql_type() -> QueryParameterType {
+        T::sql_type()
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        match self {
+            Some(v) => v.to_sql(),
+            // A value without any fields is `NULL`.
+            None => QueryParameterValue::new(),
+        }
+    }
+}
+
+impl<T: ToSql> ToSql for Vec<T> {
+    fn sql_type() -> QueryParameterType {
+        QueryParameterType::new()
+            .set_type("ARRAY")
+            .set_array_type(T::sql_type())
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        QueryParameterValue::new().set_array_values(self.iter().map(ToSql::to_sql))
+    }
+}
+
+impl ToSql for wkt::Value {
+    fn sql_type() -> QueryParameterType {
+        scalar_type("JSON")
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        scalar_value(self.to_string())
+    }
+}
+
+impl ToSql for wkt::Struct {
+    fn sql_type() -> QueryParameterType {
+        scalar_type("JSON")
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        // Serializing a map of JSON values cannot fail.
+        scalar_value(serde_json::to_string(self).unwrap_or_default())
+    }
+}
+
+impl ToSql for wkt::Timestamp {
+    fn sql_type() -> QueryParameterType {
+        scalar_type("TIMESTAMP")
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        scalar_value(String::from(*self))
+    }
+}
+
+impl ToSql for google_cloud_type::model::Date {
+    fn sql_type() -> QueryParameterType {
+        scalar_type("DATE")
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        scalar_value(format!(
+            "{:04}-{:02}-{:02}",
+            self.year, self.month, self.day
+        ))
+    }
+}
+
+// BigQuery supports microsecond precision for times, any remaining
+// nanoseconds are truncated.
+fn format_time(hours: i32, minutes: i32, seconds: i32, nanos: i32) -> String {
+    let time = format!("{hours:02}:{minutes:02}:{seconds:02}");
+    match nanos / 1_000 {
+        0 => time,
+        micros => format!("{time}.{micros:06}"),
+    }
+}
+
+impl ToSql for google_cloud_type::model::TimeOfDay {
+    fn sql_type() -> QueryParameterType {
+        scalar_type("TIME")
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        scalar_value(format_time(
+            self.hours,
+            self.minutes,
+            self.seconds,
+            self.nanos,
+        ))
+    }
+}
+
+impl ToSql for google_cloud_type::model::DateTime {
+    fn sql_type() -> QueryParameterType {
+        scalar_type("DATETIME")
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        let time = format_time(self.hours, self.minutes, self.seconds, self.nanos);
+        scalar_value(format!(
+            "{:04}-{:02}-{:02}T{time}",
+            self.year, self.month, self.day
+        ))
+    }
+}
+
+impl ToSql for google_cloud_type::model::Decimal {
+    fn sql_type() -> QueryParameterType {
+        scalar_type("BIGNUMERIC")
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        scalar_value(&self.value)
+    }
+}
+
+impl ToSql for rust_decimal::Decimal {
+    fn sql_type() -> QueryParameterType {
+        scalar_type("NUMERIC")
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        scalar_value(self.to_string())
+    }
+}
+
+impl ToSql for Vec<u8> {
+    fn sql_type() -> QueryParameterType {
+        scalar_type("BYTES")
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        scalar_value(BASE64_STANDARD.encode(self))
+    }
+}
+
+impl ToSql for bytes::Bytes {
+    fn sql_type() -> QueryParameterType {
+        scalar_type("BYTES")
+    }
+
+    fn to_sql(&self) -> QueryParameterValue {
+        scalar_value(BASE64_STANDARD.encode(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as google_cloud_bigquery;
+    use crate::{FromSql, ToSql};
+    use google_cloud_bigquery_v2::model::QueryParameterStructType;
+    use google_cloud_type::model::{Date, DateTime, Decimal, TimeOfDay};
+    use rust_decimal::Decimal as RustDecimal;
+    use test_case::test_case;
+
+    fn value<T: ToSql>(v: T) -> (String, Option<String>) {
+        (T::sql_type().r#type, v.to_sql().value)
+    }
+
+    fn want(t: &str, v: &str) -> (String, Option<String>) {
+        (t.to_string(), Some(v.to_string()))
+    }
+
+    #[test_case(value("hello") => want("STRING", "hello") ; "str")]
+    #[test_case(value("hello".to_string()) => want("STRING", "hello") ; "string")]
+    #[test_case(value(42_i32) => want("INT64", "42") ; "i32")]
+    #[test_case(value(-42_i64) => want("INT64", "-42") ; "i64")]
+    #[test_case(value(1.5_f32) => want("FLOAT64", "1.5") ; "f32")]
+    #[test_case(value(2.25_f64) => want("FLOAT64", "2.25") ; "f64")]
+    #[test_case(value(f64::NAN) => want("FLOAT64", "NaN") ; "nan")]
+    #[test_case(value(f64::INFINITY) => want("FLOAT64", "Infinity") ; "infinity")]
+    #[test_case(value(f64::NEG_INFINITY) => want("FLOAT64", "-Infinity") ; "negative infinity")]
+    #[test_case(value(true) => want("BOOL", "true") ; "bool")]
+    #[test_case(value(Some(42_i64)) => want("INT64", "42") ; "some")]
+    #[test_case(value(None::<i64>) => ("INT64".to_string(), None) ; "none")]
+    #[test_case(value(vec![1_u8, 2, 3, 4]) => want("BYTES", "AQIDBA==") ; "vec u8")]
+    #[test_case(value(bytes::Bytes::from_static(b"\x01\x02\x03\x04")) => want("BYTES", "AQIDBA==") ; "bytes")]
+    #[test_case(value(RustDecimal::new(12345, 2)) => want("NUMERIC", "123.45") ; "rust decimal")]
+    #[test_case(value(Decimal::new().set_value("1.23456789012345678901234567890")) => want("BIGNUMERIC", "1.23456789012345678901234567890") ; "decimal")]
+    #[test_case(value(wkt::Timestamp::new(1_700_000_000, 123_456_000).unwrap()) => want("TIMESTAMP", "2023-11-14T22:13:20.123456Z") ; "timestamp")]
+    #[test_case(value(Date::new().set_year(2026).set_month(5).set_day(8)) => want("DATE", "2026-05-08") ; "date")]
+    #[test_case(value(TimeOfDay::new().set_hours(4).set_minutes(5).set_seconds(6)) => want("TIME", "04:05:06") ; "time")]
+    #[test_case(value(TimeOfDay::new().set_hours(4).set_minutes(5).set_seconds(6).set_nanos(789_123_456)) => want("TIME", "04:05:06.789123") ; "time with nanos")]
+    #[test_case(value(DateTime::new().set_year(2026).set_month(5).set_day(8).set_hours(4).set_minutes(5).set_seconds(6).set_nanos(500_000_000)) => want("DATETIME", "2026-05-08T04:05:06.500000") ; "datetime")]
+    #[test_case(value(wkt::Value::from("k")) => want("JSON", r#""k""#) ; "json value")]
+    #[test_case(value(wkt::Struct::from_iter([("k".to_string(), wkt::Value::from(1))])) => want("JSON", r#"{"k":1}"#) ; "json struct")]
+    fn test_to_sql_scalar(got: (String, Option<String>)) -> (String, Option<String>) {
+        got
+    }
+
+    #[test_case(Date::new().set_year(2026).set_month(5).set_day(8) ; "date")]
+    #[test_case(TimeOfDay::new().set_hours(23).set_minutes(59).set_seconds(1).set_nanos(1_000) ; "time")]
+    #[test_case(DateTime::new().set_year(1).set_month(1).set_day(1).set_hours(1).set_minutes(2).set_seconds(3).set_nanos(123_456_000) ; "datetime")]
+    fn test_to_sql_roundtrip<T>(input: T)
+    where
+        T: ToSql + FromSql + PartialEq + std::fmt::Debug,
+    {
+        let value = input.to_sql().value.expect("scalar value should be set");
+        let got = T::from_sql(wkt::Value::String(value)).expect("value should parse");
+        assert_eq!(got, input);
+    }
+
+    #[test]
+    fn test_to_sql_array() {
+        let t = Vec::<String>::sql_type();
+        assert_eq!(t.r#type, "ARRAY");
+        assert_eq!(t.array_type.map(|t| t.r#type).as_deref(), Some("STRING"));
+
+        let v = vec!["a".to_string(), "b".to_string()].to_sql();
+        let got = v
+            .array_values
+            .into_iter()
+            .map(|v| v.value)
+            .collect::<Vec<_>>();
+        assert_eq!(got, vec![Some("a".to_string()), Some("b".to_string())]);
+
+        let v = Vec::<i64>::new().to_sql();
+        assert!(v.array_values.is_empty(), "{v:?}");
+        assert!(v.value.is_none(), "{v:?}");
+    }
+
+    #[derive(ToSql)]
+    struct TestSqlStruct {
+        name: String,
+        #[bigquery(rename = "custom_int")]
+        some_int: i64,
+        tags: Vec<String>,
+        missing: Option<bool>,
+    }
+
+    #[test]
+    fn test_derive_to_sql() {
+        let want = QueryParameterType::new()
+            .set_type("STRUCT")
+            .set_struct_types([
+                QueryParameterStructType::new()
+                    .set_name("name")
+                    .set_type(scalar_type("STRING")),
+                QueryParameterStructType::new()
+                    .set_name("custom_int")
+                    .set_type(scalar_type("INT64")),
+                QueryParameterStructType::new()
+                    .set_name("tags")
+                    .set_type(Vec::<String>::sql_type()),
+                QueryParameterStructType::new()
+                    .set_name("missing")
+                    .set_type(scalar_type("BOOL")),
+            ]);
+        assert_eq!(TestSqlStruct::sql_type(), want);
+
+        let input = TestSqlStruct {
+            name: "James".to_string(),
+            some_int: 272793,
+            tags: vec!["a".to_string()],
+            missing: None,
+        };
+        let want = QueryParameterValue::new().set_struct_values([
+            ("name", scalar_value("James")),
+            ("custom_int", scalar_value("272793")),
+            (
+                "tags",
+                QueryParameterValue::new().set_array_values([scalar_value("a")]),
+            ),
+            ("missing", QueryParameterValue::new()),
+        ]);
+        assert_eq!(input.to_sql(), want);
+    }
+}
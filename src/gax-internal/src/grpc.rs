@@ -470,6 +470,7 @@ impl Client {
         instrumentation: Option<&'static crate::options::InstrumentationClientInfo>,
     ) -> ClientBuilderResult<(InnerClient, Option<TracingAttributes>)> {
         use ::tonic::transport::{Channel, channel::Change};
+        let client_certificate = crate::mtls::client_certificate()?;
         let endpoint = Self::make_endpoint(
             config.endpoint.clone(),
             default_endpoint,
@@ -479,6 +480,7 @@ impl Client {
                 .extensions
                 .get::<::tonic::transport::ClientTlsConfig>()
                 .cloned(),
+            client_certificate.as_ref(),
         )
         .await?;
         let (channel, tx) = Channel::balance_channel(
@@ -525,9 +527,19 @@ impl Client {
         universe_domain: &str,
         grpc_max_header_list_size: Option<u32>,
         tls_config: Option<::tonic::transport::ClientTlsConfig>,
+        client_certificate: Option<&crate::mtls::ClientCertificate>,
     ) -> ClientBuilderResult<::tonic::transport::Endpoint> {
-        use ::tonic::transport::{ClientTlsConfig, Endpoint};
-
+        use ::tonic::transport::{ClientTlsConfig, Endpoint, Identity};
+
+        // A custom TLS configuration takes precedence over the client
+        // certificate, the endpoint must not require mTLS.
+        let client_certificate = client_certificate.filter(|_| tls_config.is_none());
+        let default_endpoint = match (&client_certificate, &endpoint) {
+            // Only the default endpoint changes, endpoint overrides are used as-is.
+            (Some(_), None) => crate::mtls::default_endpoint(default_endpoint, universe_domain),
+            _ => default_endpoint.to_string(),
+        };
+        let default_endpoint = default_endpoint.as_str();
         let service_endpoint = default_endpoint.replace(DEFAULT_UNIVERSE_DOMAIN, universe_domain);
         let origin = crate::host::origin(endpoint.as_deref(), default_endpoint, universe_domain)
             .map_err(|e| e.client_builder())?;
@@ -538,8 +550,13 @@ impl Client {
             .scheme()
             .is_some_and(|s| s == &http::uri::Scheme::HTTPS)
         {
-            let tls_config =
-                tls_config.unwrap_or_else(|| ClientTlsConfig::new().with_enabled_roots());
+            let tls_config = tls_config.unwrap_or_else(|| {
+                let tls_config = ClientTlsConfig::new().with_enabled_roots();
+                match client_certificate {
+                    Some(c) => tls_config.identity(Identity::from_pem(c.cert_pem(), c.key_pem())),
+                    None => tls_config,
+                }
+            });
             endpoint
                 .tls_config(tls_config)
                 .map_err(BuilderError::transport)?
@@ -622,6 +639,7 @@ mod tests {
             universe_domain,
             None,
             None,
+            None,
         )
         .await?;
 
@@ -670,6 +688,7 @@ mod tests {
             DEFAULT_UNIVERSE_DOMAIN,
             None,
             Some(custom_tls),
+            None,
         )
         .await?;
         assert_eq!(
@@ -679,6 +698,79 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn make_endpoint_with_custom_tls_config_and_client_certificate() -> TestResult {
+        let dir = tempfile::TempDir::new()?;
+        let path = crate::mtls::tests::write_certificate_config(&dir)?;
+        let certificate = google_cloud_auth::mtls::load_client_certificate(&path)?;
+        let custom_tls = ClientTlsConfig::new().domain_name("custom.domain");
+        // The custom configuration has no identity, so the client does not
+        // switch to the mTLS endpoint.
+        let endpoint = Client::make_endpoint(
+            None,
+            "https://test.googleapis.com",
+            DEFAULT_UNIVERSE_DOMAIN,
+            None,
+            Some(custom_tls),
+            Some(&certificate),
+        )
+        .await?;
+        assert_eq!(endpoint.uri().to_string(), "https://test.googleapis.com/");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn make_endpoint_with_client_certificate() -> TestResult {
+        let dir = tempfile::TempDir::new()?;
+        let path = crate::mtls::tests::write_certificate_config(&dir)?;
+        let certificate = google_cloud_auth::mtls::load_client_certificate(&path)?;
+        let endpoint = Client::make_endpoint(
+            None,
+            "https://test.googleapis.com",
+            DEFAULT_UNIVERSE_DOMAIN,
+            None,
+            None,
+            Some(&certificate),
+        )
+        .await?;
+        assert_eq!(
+            endpoint.uri().to_string(),
+            "https://test.mtls.googleapis.com/"
+        );
+
+        // The certificate is ignored for plaintext endpoints, such as emulators.
+        let endpoint = Client::make_endpoint(
+            Some("http://localhost:15000".to_string()),
+            "https://test.mtls.googleapis.com",
+            DEFAULT_UNIVERSE_DOMAIN,
+            None,
+            None,
+            Some(&certificate),
+        )
+        .await?;
+        assert_eq!(endpoint.uri().to_string(), "http://localhost:15000/");
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn new_with_client_certificate() -> TestResult {
+        use google_cloud_auth::mtls::{CERTIFICATE_CONFIG_VAR, USE_CLIENT_CERTIFICATE_VAR};
+        use scoped_env::ScopedEnv;
+        let dir = tempfile::TempDir::new()?;
+        let path = crate::mtls::tests::write_certificate_config(&dir)?;
+        let _e = ScopedEnv::set(USE_CLIENT_CERTIFICATE_VAR, "true");
+        let _c = ScopedEnv::set(CERTIFICATE_CONFIG_VAR, path.to_str().unwrap());
+
+        let mut config = crate::options::ClientConfig::default();
+        config.tracing = true;
+        config.cred = Some(google_cloud_auth::credentials::anonymous::Builder::new().build());
+        let client = Client::new(config, "https://test.googleapis.com").await?;
+        let attributes = client.tracing_attributes.expect("tracing is enabled");
+        assert_eq!(attributes.server_address, "test.mtls.googleapis.com");
+        Ok(())
+    }
+
     #[tokio::test]
     async fn make_endpoint_plaintext_with_tls_config_fails() {
         let custom_tls = ClientTlsConfig::new().domain_name("custom.domain");
@@ -688,6 +780,7 @@ mod tests {
             DEFAULT_UNIVERSE_DOMAIN,
             None,
             Some(custom_tls),
+            None,
         )
         .await;
         assert!(
@@ -704,6 +797,7 @@ mod tests {
             DEFAULT_UNIVERSE_DOMAIN,
             None,
             None,
+            None,
         )
         .await?;
         assert_eq!(endpoint.uri().to_string(), "http://localhost:15000/");
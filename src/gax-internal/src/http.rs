@@ -95,9 +95,21 @@ impl ReqwestClient {
         if config.disable_follow_redirects {
             builder = builder.redirect(::reqwest::redirect::Policy::none());
         }
+        let client_certificate = crate::mtls::client_certificate()?;
+        if let Some(certificate) = &client_certificate {
+            let identity = ::reqwest::Identity::from_pem(&certificate.identity_pem())
+                .map_err(BuilderError::cred)?;
+            builder = builder.identity(identity);
+        }
         let inner = builder.build().map_err(BuilderError::transport)?;
         let universe_domain =
             crate::universe_domain::resolve(config.universe_domain.as_deref(), &cred).await?;
+        let default_endpoint = match (&client_certificate, &config.endpoint) {
+            // Only the default endpoint changes, endpoint overrides are used as-is.
+            (Some(_), None) => crate::mtls::default_endpoint(default_endpoint, &universe_domain),
+            _ => default_endpoint.to_string(),
+        };
+        let default_endpoint = default_endpoint.as_str();
         let host = crate::host::header(
             config.endpoint.as_deref(),
             default_endpoint,
@@ -883,6 +895,31 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    #[test_case(None, "test.mtls.googleapis.com", "https://test.mtls.googleapis.com/"; "default")]
+    #[test_case(Some("https://us-central1-test.googleapis.com"), "us-central1-test.googleapis.com", "https://us-central1-test.googleapis.com/"; "locational endpoint")]
+    #[serial_test::serial]
+    async fn mtls_endpoint(
+        custom_endpoint: Option<&str>,
+        expected_host: &str,
+        expected_url: &str,
+    ) -> anyhow::Result<()> {
+        use google_cloud_auth::mtls::{CERTIFICATE_CONFIG_VAR, USE_CLIENT_CERTIFICATE_VAR};
+        use scoped_env::ScopedEnv;
+        let dir = tempfile::TempDir::new()?;
+        let path = crate::mtls::tests::write_certificate_config(&dir)?;
+        let _e = ScopedEnv::set(USE_CLIENT_CERTIFICATE_VAR, "true");
+        let _c = ScopedEnv::set(CERTIFICATE_CONFIG_VAR, path.to_str().unwrap());
+
+        let mut config = ClientConfig::default();
+        config.endpoint = custom_endpoint.map(String::from);
+        config.cred = Some(Anonymous::new().build());
+        let client = ReqwestClient::new(config, "https://test.googleapis.com").await?;
+        assert_eq!(client.host, expected_host);
+        assert_eq!(client.url.as_str(), expected_url);
+        Ok(())
+    }
+
     // Verify `builder()` appends the path to any path in the endpoint URL.
     //
     // This behavior is not documented, and it may change in the future. Nonethless, we want to
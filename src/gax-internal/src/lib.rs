@@ -69,6 +69,9 @@ pub(crate) mod host;
 #[cfg(feature = "_internal-common")]
 pub(crate) mod universe_domain;
 
+#[cfg(any(feature = "_internal-http-client", feature = "_internal-grpc-client"))]
+pub(crate) mod mtls;
+
 #[cfg(feature = "_internal-grpc-client")]
 pub(crate) mod google {
     pub mod rpc {
@@ -0,0 +1,142 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mutual TLS (mTLS) support for the HTTP and gRPC clients.
+//!
+//! When `GOOGLE_API_USE_CLIENT_CERTIFICATE` is `true`, and a workload
+//! certificate is available, the clients present the certificate in the TLS
+//! handshake and connect to the `*.mtls.googleapis.com` endpoints. gRPC
+//! clients configured with a custom TLS configuration use it as-is, without
+//! the certificate, and keep the default endpoint.
+
+use crate::universe_domain::DEFAULT_UNIVERSE_DOMAIN;
+use google_cloud_auth::mtls::{default_client_certificate, use_client_certificate};
+use google_cloud_gax::client_builder::Error as BuilderError;
+use google_cloud_gax::client_builder::Result as ClientBuilderResult;
+
+pub(crate) use google_cloud_auth::mtls::ClientCertificate;
+
+/// Returns the client certificate, if the environment enables mTLS.
+///
+/// Returns `Ok(None)` if mTLS is not enabled, or if there is no workload
+/// certificate configuration.
+pub(crate) fn client_certificate() -> ClientBuilderResult<Option<ClientCertificate>> {
+    if !use_client_certificate() {
+        return Ok(None);
+    }
+    default_client_certificate().map_err(BuilderError::cred)
+}
+
+/// Returns the default endpoint to use with mTLS.
+///
+/// The mTLS endpoints only exist in the default universe domain. Endpoints
+/// that are not in the `googleapis.com` domain (e.g. emulators and test
+/// servers) are returned unchanged.
+pub(crate) fn default_endpoint(default_endpoint: &str, universe_domain: &str) -> String {
+    if universe_domain != DEFAULT_UNIVERSE_DOMAIN {
+        return default_endpoint.to_string();
+    }
+    let Some((scheme, rest)) = default_endpoint.split_once("://") else {
+        return default_endpoint.to_string();
+    };
+    let (host, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    match host.strip_suffix(".googleapis.com") {
+        Some(service) if !service.ends_with(".mtls") => {
+            format!("{scheme}://{service}.mtls.googleapis.com{path}")
+        }
+        _ => default_endpoint.to_string(),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use google_cloud_auth::mtls::{CERTIFICATE_CONFIG_VAR, USE_CLIENT_CERTIFICATE_VAR};
+    use scoped_env::ScopedEnv;
+    use serde_json::json;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+    use test_case::test_case;
+
+    type TestResult = anyhow::Result<()>;
+
+    const TEST_CERT: &str = include_str!("../../../testdata/mtls/workload_cert.pem");
+    const TEST_KEY: &str = include_str!("../../../testdata/mtls/workload_key.pem");
+
+    /// Creates a certificate configuration, and the files it references.
+    pub(crate) fn write_certificate_config(dir: &TempDir) -> anyhow::Result<PathBuf> {
+        let cert_path = dir.path().join("cert.pem");
+        let key_path = dir.path().join("key.pem");
+        std::fs::write(&cert_path, TEST_CERT)?;
+        std::fs::write(&key_path, TEST_KEY)?;
+        let config = json!({
+            "cert_configs": {
+                "workload": {
+                    "cert_path": cert_path,
+                    "key_path": key_path,
+                }
+            }
+        });
+        let config_path = dir.path().join("certificate_config.json");
+        std::fs::write(&config_path, config.to_string())?;
+        Ok(config_path)
+    }
+
+    #[test_case("https://test.googleapis.com", "googleapis.com", "https://test.mtls.googleapis.com"; "default")]
+    #[test_case("https://test.googleapis.com/", "googleapis.com", "https://test.mtls.googleapis.com/"; "trailing slash")]
+    #[test_case("https://test.googleapis.com/upload/v1", "googleapis.com", "https://test.mtls.googleapis.com/upload/v1"; "with path")]
+    #[test_case("https://test.mtls.googleapis.com", "googleapis.com", "https://test.mtls.googleapis.com"; "already mtls")]
+    #[test_case("https://test.googleapis.com", "my-custom-universe.com", "https://test.googleapis.com"; "custom universe")]
+    #[test_case("http://localhost:8080", "googleapis.com", "http://localhost:8080"; "emulator")]
+    #[test_case("test.googleapis.com", "googleapis.com", "test.googleapis.com"; "no scheme")]
+    fn mtls_default_endpoint(endpoint: &str, universe_domain: &str, want: &str) {
+        assert_eq!(default_endpoint(endpoint, universe_domain), want);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn client_certificate_disabled() -> TestResult {
+        let dir = TempDir::new()?;
+        let path = write_certificate_config(&dir)?;
+        let _e = ScopedEnv::remove(USE_CLIENT_CERTIFICATE_VAR);
+        let _c = ScopedEnv::set(CERTIFICATE_CONFIG_VAR, path.to_str().unwrap());
+        assert!(client_certificate()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn client_certificate_enabled() -> TestResult {
+        let dir = TempDir::new()?;
+        let path = write_certificate_config(&dir)?;
+        let _e = ScopedEnv::set(USE_CLIENT_CERTIFICATE_VAR, "true");
+        let _c = ScopedEnv::set(CERTIFICATE_CONFIG_VAR, path.to_str().unwrap());
+        let got = client_certificate()?.expect("certificate is loaded");
+        assert_eq!(got.cert_pem(), TEST_CERT.as_bytes());
+        Ok(())
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn client_certificate_bad_config() -> TestResult {
+        let dir = TempDir::new()?;
+        let path = dir.path().join("certificate_config.json");
+        std::fs::write(&path, "not json")?;
+        let _e = ScopedEnv::set(USE_CLIENT_CERTIFICATE_VAR, "true");
+        let _c = ScopedEnv::set(CERTIFICATE_CONFIG_VAR, path.to_str().unwrap());
+        let err = client_certificate().unwrap_err();
+        assert!(err.is_default_credentials(), "{err:?}");
+        Ok(())
+    }
+}
@@ -45,6 +45,55 @@ pub enum PublishError {
     /// pending messages.
     #[error("the publisher has shut down")]
     Shutdown,
+
+    /// The message was not published because the publisher has too many
+    /// outstanding messages.
+    ///
+    /// This error is only returned when the publisher is configured with
+    /// [`LimitExceededBehavior::Error`](crate::publisher::LimitExceededBehavior::Error).
+    #[error("the publisher flow control limits were exceeded")]
+    FlowControlLimitExceeded,
+}
+
+/// Represents an error that can occur when encoding or decoding a message
+/// with a topic schema.
+///
+/// See [`schema`](crate::schema) for more information.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum SchemaError {
+    /// A request to fetch the topic or the schema failed.
+    #[error("cannot fetch the schema: {0}")]
+    Rpc(#[source] Error),
+
+    /// The topic is not configured with a schema.
+    #[error("the topic {0} does not have a schema")]
+    NoSchema(String),
+
+    /// The schema type or the message encoding is not supported.
+    #[error("unsupported schema type or encoding: {0}")]
+    Unsupported(String),
+
+    /// The schema definition cannot be parsed.
+    #[error("invalid schema definition: {0}")]
+    InvalidDefinition(String),
+
+    /// The message does not carry the attributes that identify its schema.
+    #[error("the message is missing the {0} attribute")]
+    MissingAttribute(&'static str),
+
+    /// The schema cannot encode the value, or the Protocol Buffer type is not
+    /// the message defined by the schema.
+    #[error("the value does not match the schema: {0}")]
+    Validation(String),
+
+    /// The message data cannot be decoded with the schema.
+    #[error("cannot decode the message data: {0}")]
+    Decode(String),
+
+    /// The value cannot be converted to or from its serde representation.
+    #[error("cannot convert the value: {0}")]
+    Serde(#[source] serde_json::Error),
 }
 
 /// Represents an error that can occur when acking or nacking a message.
@@ -29,6 +29,10 @@
 //! * [SubscriptionAdmin][client::SubscriptionAdmin]
 //! * [SchemaService][client::SchemaService]
 //!
+//! For encoding and decoding messages on topics with a schema:
+//! * [SchemaCodec][schema::SchemaCodec]
+//! * [SchemaCache][schema::SchemaCache]
+//!
 //! **NOTE:** This crate used to contain a different implementation, with a
 //! different surface. [@yoshidan](https://github.com/yoshidan) generously
 //! donated the crate name to Google. Their crate continues to live as
@@ -164,6 +168,7 @@ pub mod client {
 
 pub mod error;
 pub mod retry_policy;
+pub mod schema;
 
 /// Traits to mock the clients in this library.
 pub mod stub {
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub use flow_control::LimitExceededBehavior;
 pub use future::PublishFuture;
 
 pub(super) mod builder;
@@ -23,6 +24,7 @@ mod base_publisher;
 mod batch;
 mod client_builder;
 mod constants;
+mod flow_control;
 mod future;
 mod options;
 mod retry_policy;
@@ -14,9 +14,11 @@
 
 use super::options::BatchingOptions;
 use crate::generated::gapic_dataplane::client::Publisher as GapicPublisher;
-use crate::publisher::batch::Batch;
+use crate::publisher::batch::{Batch, BatchResult};
+use crate::publisher::flow_control::FlowControlPermit;
 use std::collections::{HashMap, VecDeque};
 use std::pin::Pin;
+use std::sync::{Arc, Mutex};
 use tokio::sync::{mpsc, oneshot};
 use tokio::task::JoinSet;
 use tokio::time::Sleep;
@@ -49,8 +51,17 @@ enum ToBatchActor {
 pub(crate) struct BundledMessage {
     pub msg: crate::model::Message,
     pub tx: oneshot::Sender<std::result::Result<String, crate::error::PublishError>>,
+    // Releases the flow control capacity of the message when dropped.
+    pub permit: Option<FlowControlPermit>,
 }
 
+/// The ordering keys that are paused, and the error that paused each one.
+///
+/// The `SequentialBatchActor` for a key inserts the key when it pauses and
+/// removes it when it resumes. The `Publisher` reads it to report the paused
+/// keys to the application.
+pub(crate) type PausedKeys = Arc<Mutex<HashMap<String, Arc<crate::Error>>>>;
+
 /// The Dispatcher runs in a background task and handles all Publisher operations
 /// by dispatching it to BatchActors.
 #[derive(Debug)]
@@ -58,6 +69,7 @@ pub(crate) struct Dispatcher {
     topic_name: String,
     client: GapicPublisher,
     batching_options: BatchingOptions,
+    paused_keys: PausedKeys,
     rx: mpsc::UnboundedReceiver<ToDispatcher>,
 }
 
@@ -66,6 +78,7 @@ impl Dispatcher {
         topic_name: String,
         client: GapicPublisher,
         batching_options: BatchingOptions,
+        paused_keys: PausedKeys,
         rx: mpsc::UnboundedReceiver<ToDispatcher>,
     ) -> Self {
         Self {
@@ -73,6 +86,7 @@ impl Dispatcher {
             client,
             rx,
             batching_options,
+            paused_keys,
         }
     }
 
@@ -91,13 +105,14 @@ impl Dispatcher {
             );
         } else {
             tasks.spawn(
-                key,
+                key.clone(),
                 SequentialBatchActor::new(
                     self.topic_name.clone(),
                     self.client.clone(),
                     self.batching_options.clone(),
                     rx,
                 )
+                .with_ordering_key(key, self.paused_keys.clone())
                 .run(),
             );
         }
@@ -128,31 +143,44 @@ impl Dispatcher {
         let mut actor_tasks: JoinMap<String, ()> = JoinMap::new();
         loop {
             tokio::select! {
-                _ = actor_tasks.join_next(), if !actor_tasks.is_empty() => {
+                joined = actor_tasks.join_next(), if !actor_tasks.is_empty() => {
                     // TODO(#4012): Remove batch actors when there are no outstanding operations
                     // on the ordering key.
-                    continue;
+                    if let Some((ordering_key, Err(e))) = joined {
+                        // A batch actor only stops early if it panics. Isolate the failure to
+                        // its ordering key.
+                        batch_actors.remove(&ordering_key);
+                        self.pause_key(ordering_key, e);
+                    }
                 }
                 // Handle receiving a message from the channel.
                 msg = self.rx.recv() => {
                     match msg {
                         Some(ToDispatcher::Publish(msg)) => {
                             let ordering_key = msg.msg.ordering_key.clone();
+                            if !batch_actors.contains_key(&ordering_key) && self.is_paused(&ordering_key) {
+                                // The batch actor for this key has stopped.
+                                let _ = msg.tx.send(Err(crate::error::PublishError::OrderingKeyPaused));
+                                continue;
+                            }
                             let batch_actor = batch_actors
                                 .entry(ordering_key.clone())
                                 .or_insert_with(|| self.spawn_actor(ordering_key.clone(), &mut actor_tasks));
-                            if batch_actor.sender.send(ToBatchActor::Publish(msg)).is_err() {
-                                return; // Stop the dispatcher if a batch actor is dropped.
+                            if let Err(mpsc::error::SendError(command)) = batch_actor.sender.send(ToBatchActor::Publish(msg)) {
+                                // The batch actor stopped, its task is reaped above.
+                                batch_actors.remove(&ordering_key);
+                                let ToBatchActor::Publish(msg) = command else { unreachable!("sent a Publish command") };
+                                let _ = msg.tx.send(Err(crate::error::PublishError::Shutdown));
                             }
                         },
                         Some(ToDispatcher::Flush(tx)) => {
                             let mut flush_set = JoinSet::new();
                             for batch_actor in batch_actors.values() {
                                 let (tx, rx) = oneshot::channel();
-                                if batch_actor.sender.send(ToBatchActor::Flush(tx)).is_err() {
-                                    return; // Stop the dispatcher if a batch actor is dropped.
+                                // A stopped batch actor has nothing to flush.
+                                if batch_actor.sender.send(ToBatchActor::Flush(tx)).is_ok() {
+                                    flush_set.spawn(rx);
                                 }
-                                flush_set.spawn(rx);
                             }
                             tokio::spawn(async move {
                                 // Wait on all the flush operations.
@@ -161,11 +189,16 @@ impl Dispatcher {
                             });
                         },
                         Some(ToDispatcher::ResumePublish(ordering_key)) => {
-                            if let Some(batch_actor) = batch_actors.get_mut(&ordering_key) {
-                                // Send down the same tx for the BatchActors to directly signal completion
-                                // instead of spawning a new task.
-                                if batch_actor.sender.send(ToBatchActor::ResumePublish()).is_err() {
-                                    return; // Stop the dispatcher if a batch actor is dropped.
+                            match batch_actors.get_mut(&ordering_key) {
+                                // The batch actor removes the key from `paused_keys` when it
+                                // resumes.
+                                Some(batch_actor) => {
+                                    let _ = batch_actor.sender.send(ToBatchActor::ResumePublish());
+                                }
+                                // The batch actor for this key has stopped. The next message
+                                // starts a new one.
+                                None => {
+                                    self.paused_keys.lock().expect("never poisoned").remove(&ordering_key);
                                 }
                             }
                         }
@@ -187,6 +220,25 @@ impl Dispatcher {
             }
         }
     }
+
+    fn is_paused(&self, ordering_key: &str) -> bool {
+        self.paused_keys
+            .lock()
+            .expect("never poisoned")
+            .contains_key(ordering_key)
+    }
+
+    fn pause_key(&self, ordering_key: String, error: tokio::task::JoinError) {
+        tracing::warn!(
+            "the publisher batch actor for ordering key {ordering_key:?} stopped: {error}"
+        );
+        if !ordering_key.is_empty() {
+            self.paused_keys
+                .lock()
+                .expect("never poisoned")
+                .insert(ordering_key, Arc::new(crate::Error::io(error)));
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -317,7 +369,7 @@ impl ConcurrentBatchActor {
     }
 
     // Flush the pending batch if it's not empty.
-    fn flush(&mut self, inflight: &mut JoinSet<crate::Result<()>>, batch: &mut Batch) {
+    fn flush(&mut self, inflight: &mut JoinSet<BatchResult>, batch: &mut Batch) {
         if !batch.is_empty() {
             batch.flush(
                 self.context.client.clone(),
@@ -331,7 +383,7 @@ impl ConcurrentBatchActor {
     // and flush the batch if it is full.
     fn add_msg_and_flush(
         &mut self,
-        inflight: &mut JoinSet<crate::Result<()>>,
+        inflight: &mut JoinSet<BatchResult>,
         batch: &mut Batch,
         msg: BundledMessage,
     ) {
@@ -351,6 +403,8 @@ struct SequentialBatchActor {
     context: BatchActorContext,
     pending_msgs: VecDeque<BundledMessage>,
     paused: bool,
+    ordering_key: String,
+    paused_keys: PausedKeys,
 }
 
 impl SequentialBatchActor {
@@ -364,9 +418,18 @@ impl SequentialBatchActor {
             context: BatchActorContext::new(topic, client, batching_options, rx),
             pending_msgs: VecDeque::new(),
             paused: false,
+            ordering_key: String::new(),
+            paused_keys: PausedKeys::default(),
         }
     }
 
+    /// Sets the ordering key of the actor and where to report it when paused.
+    fn with_ordering_key(mut self, ordering_key: String, paused_keys: PausedKeys) -> Self {
+        self.ordering_key = ordering_key;
+        self.paused_keys = paused_keys;
+        self
+    }
+
     /// The main loop of the sequential batch actor.
     ///
     /// This method continuously handles the following events:
@@ -394,7 +457,7 @@ impl SequentialBatchActor {
         // While it is possible to use Some(JoinHandle) here as there is at max
         // a single inflight task at any given time, the use of JoinSet
         // simplify the managing the inflight JoinHandle.
-        let mut inflight: JoinSet<crate::Result<()>> = JoinSet::new();
+        let mut inflight: JoinSet<BatchResult> = JoinSet::new();
         let mut batch = Batch::new(
             self.context.topic.len() as u32,
             self.context.batching_options.clone(),
@@ -417,6 +480,10 @@ impl SequentialBatchActor {
                     }
                     Some(ToBatchActor::ResumePublish()) => {
                         self.paused = false;
+                        self.paused_keys
+                            .lock()
+                            .expect("never poisoned")
+                            .remove(&self.ordering_key);
                     }
                     None => {
                         // There should be no pending messages and messages in the pending batch as
@@ -479,7 +546,7 @@ impl SequentialBatchActor {
     }
 
     // Flush the pending messages by sending the messages in sequential batches.
-    async fn flush(&mut self, inflight: &mut JoinSet<crate::Result<()>>, batch: &mut Batch) {
+    async fn flush(&mut self, inflight: &mut JoinSet<BatchResult>, batch: &mut Batch) {
         self.handle_inflight_join(inflight.join_next().await);
         while !self.pending_msgs.is_empty() {
             self.move_to_batch_and_flush(inflight, batch);
@@ -498,11 +565,7 @@ impl SequentialBatchActor {
 
     // Move message to the pending batch respecting batch thresholds
     // and flush the batch if it is full.
-    fn move_to_batch_and_flush(
-        &mut self,
-        inflight: &mut JoinSet<crate::Result<()>>,
-        batch: &mut Batch,
-    ) {
+    fn move_to_batch_and_flush(&mut self, inflight: &mut JoinSet<BatchResult>, batch: &mut Batch) {
         let mut should_flush = false;
         while let Some(next) = self.pending_msgs.front() {
             if !batch.can_add(next) && !batch.is_empty() {
@@ -530,8 +593,12 @@ impl SequentialBatchActor {
     }
 
     // Pause publish operations.
-    fn pause(&mut self) {
+    fn pause(&mut self, error: Arc<crate::Error>) {
         self.paused = true;
+        self.paused_keys
+            .lock()
+            .expect("never poisoned")
+            .insert(self.ordering_key.clone(), error);
         while let Some(publish) = self.pending_msgs.pop_front() {
             // The user may have dropped the handle, so it is ok if this fails.
             let _ = publish
@@ -542,14 +609,16 @@ impl SequentialBatchActor {
 
     fn handle_inflight_join(
         &mut self,
-        join_next_option: Option<Result<crate::Result<()>, tokio::task::JoinError>>,
+        join_next_option: Option<Result<BatchResult, tokio::task::JoinError>>,
     ) {
         // If there was a JoinError or non-retryable error:
         // 1. We need to pause publishing and send out errors for pending_msgs.
         // 2. The pending batch should have sent out error for its messages.
         // 3. The messages in rx will be handled when they are received.
-        if let Some(Err(_) | Ok(Err(_))) = join_next_option {
-            self.pause();
+        match join_next_option {
+            Some(Ok(Err(e))) => self.pause(e),
+            Some(Err(e)) => self.pause(Arc::new(crate::Error::io(e))),
+            Some(Ok(Ok(()))) | None => {}
         }
     }
 }
@@ -651,6 +720,7 @@ mod tests {
                 let bundle = BundledMessage {
                     msg: Message::new().set_data(msg.clone()),
                     tx: publish_tx,
+                    permit: None,
                 };
                 $actor_tx.send(ToBatchActor::Publish(bundle))?;
                 $publish_rxs.push_back((msg, publish_rx));
@@ -711,6 +781,7 @@ mod tests {
                 let bundle = BundledMessage {
                     msg: Message::new().set_data(generate_random_data()),
                     tx: publish_tx,
+                    permit: None,
                 };
                 $actor_tx.send(ToBatchActor::Publish(bundle))?;
                 publish_rxs.push(publish_rx);
@@ -989,6 +1060,7 @@ mod tests {
         let bundle = BundledMessage {
             msg: Message::new().set_data(generate_random_data()),
             tx: publish_tx,
+            permit: None,
         };
         actor_tx.send(ToBatchActor::Publish(bundle))?;
         let got_err = publish_rx.await;
@@ -1064,6 +1136,7 @@ mod tests {
                     batch.push(BundledMessage {
                         msg: msg.clone(),
                         tx,
+                        permit: None,
                     });
                 });
                 req.topic == TOPIC && batch.size() <= 25_u32
@@ -1103,6 +1176,7 @@ mod tests {
                     batch.push(BundledMessage {
                         msg: msg.clone(),
                         tx,
+                        permit: None,
                     });
                 });
                 req.topic == TOPIC && batch.size() <= 25_u32
@@ -1142,6 +1216,7 @@ mod tests {
                     batch.push(BundledMessage {
                         msg: msg.clone(),
                         tx,
+                        permit: None,
                     });
                 });
                 req.topic == TOPIC && batch.size() >= 23_u32
@@ -1176,6 +1251,7 @@ mod tests {
                     batch.push(BundledMessage {
                         msg: msg.clone(),
                         tx,
+                        permit: None,
                     });
                 });
                 req.topic == TOPIC && batch.size() >= 23_u32
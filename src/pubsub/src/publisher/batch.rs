@@ -20,6 +20,12 @@ use crate::generated::gapic_dataplane::client::Publisher as GapicPublisher;
 use crate::publisher::actor::BundledMessage;
 use std::sync::Arc;
 
+/// The result of sending a batch.
+///
+/// On failure, the same error is returned to the publisher of each message in
+/// the batch.
+pub(crate) type BatchResult = std::result::Result<(), Arc<crate::Error>>;
+
 #[derive(Debug, Default)]
 pub(crate) struct Batch {
     messages: Vec<BundledMessage>,
@@ -55,7 +61,7 @@ impl Batch {
         self.messages.push(msg);
     }
 
-    fn message_size(msg: &crate::model::Message) -> usize {
+    pub(crate) fn message_size(msg: &crate::model::Message) -> usize {
         // This is only an estimate and not the wire length.
         // TODO(#3963): If we move on to use protobuf crate, then it may be
         // possible to use compute_size to find the wire length.
@@ -86,7 +92,7 @@ impl Batch {
         &mut self,
         client: GapicPublisher,
         topic: String,
-        inflight: &mut JoinSet<crate::Result<()>>,
+        inflight: &mut JoinSet<BatchResult>,
     ) {
         let batch_to_send = Self {
             initial_size: self.initial_size,
@@ -99,30 +105,32 @@ impl Batch {
     }
 
     /// Send the batch to the service and process the results.
-    async fn send(self, client: GapicPublisher, topic: String) -> crate::Result<()> {
+    async fn send(self, client: GapicPublisher, topic: String) -> BatchResult {
         let (msgs, txs): (Vec<_>, Vec<_>) = self
             .messages
             .into_iter()
-            .map(|msg| (msg.msg, msg.tx))
+            .map(|msg| (msg.msg, (msg.tx, msg.permit)))
             .unzip();
         let request = client.publish().set_topic(topic).set_messages(msgs);
 
         // Handle the response by extracting the message ID on success.
         match request.send().await {
             Err(e) => {
-                // TODO(#4013): To support message ordering retry, we need to correctly handle
-                // the send error here with either retry or propagate to the user.
+                // The client has already retried transient errors using the configured
+                // retry policy, so this error is final. For ordered messages the
+                // `SequentialBatchActor` does not send the next batch until this one
+                // completes, so these retries never reorder messages.
                 let e = Arc::new(e);
-                for tx in txs {
+                for (tx, _permit) in txs {
                     // The user may have dropped the handle, so it is ok if this fails.
                     let _ = tx.send(Err(PublishError::Rpc(e.clone())));
                 }
-                Err(crate::Error::io(e))
+                Err(e)
             }
             Ok(result) => {
                 txs.into_iter()
                     .zip(result.message_ids)
-                    .for_each(|(tx, result)| {
+                    .for_each(|((tx, _permit), result)| {
                         // The user may have dropped the handle, so it is ok if this fails.
                         let _ = tx.send(Ok(result));
                     });
@@ -238,6 +246,13 @@ mod tests {
         tokio::sync::oneshot::Receiver<std::result::Result<String, crate::error::PublishError>>,
     ) {
         let (tx, rx) = tokio::sync::oneshot::channel();
-        (BundledMessage { tx, msg }, rx)
+        (
+            BundledMessage {
+                tx,
+                msg,
+                permit: None,
+            },
+            rx,
+        )
     }
 }
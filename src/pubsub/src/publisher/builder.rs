@@ -12,15 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use super::constants::*;
-use super::options::BatchingOptions;
+use super::flow_control::{FlowController, LimitExceededBehavior};
+use super::options::{BatchingOptions, FlowControlOptions};
 use crate::client::Publisher;
 use crate::generated::gapic_dataplane::client::Publisher as GapicPublisher;
-use crate::publisher::actor::Dispatcher;
+use crate::publisher::actor::{Dispatcher, PausedKeys};
 use crate::publisher::base_publisher::BasePublisher;
 use google_cloud_gax::{
     backoff_policy::BackoffPolicyArg, retry_policy::RetryPolicyArg,
     retry_throttler::RetryThrottlerArg,
 };
+use std::sync::Arc;
 use std::time::Duration;
 
 pub use super::base_publisher::BasePublisherBuilder;
@@ -30,6 +32,7 @@ pub use super::base_publisher::BasePublisherBuilder;
 pub struct PublisherBuilder {
     topic: String,
     batching_options: BatchingOptions,
+    flow_control_options: FlowControlOptions,
     base_builder: BasePublisherBuilder,
 }
 
@@ -38,6 +41,7 @@ impl PublisherBuilder {
         Self {
             topic,
             batching_options: BatchingOptions::default(),
+            flow_control_options: FlowControlOptions::default(),
             base_builder: BasePublisher::builder(),
         }
     }
@@ -50,6 +54,9 @@ impl PublisherBuilder {
             .set_message_count_threshold(self.batching_options.message_count_threshold)
             .set_byte_threshold(self.batching_options.byte_threshold)
             .set_delay_threshold(self.batching_options.delay_threshold)
+            .set_max_outstanding_messages(self.flow_control_options.max_outstanding_messages)
+            .set_max_outstanding_bytes(self.flow_control_options.max_outstanding_bytes)
+            .set_limit_exceeded_behavior(self.flow_control_options.limit_exceeded_behavior)
             .build();
         Ok(publisher)
     }
@@ -118,6 +125,80 @@ impl PublisherBuilder {
         self
     }
 
+    /// Sets the maximum number of outstanding messages.
+    ///
+    /// A message is outstanding from the call to [`publish()`][Publisher::publish]
+    /// until the result of the publish operation is known. When this limit is
+    /// reached, new messages are handled according to the
+    /// [limit exceeded behavior][Self::set_limit_exceeded_behavior].
+    ///
+    /// Use a value <= 0 to set no limit on the number of outstanding messages.
+    /// There is no limit by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use google_cloud_pubsub::client::Publisher;
+    /// # async fn sample() -> anyhow::Result<()> {
+    /// let publisher = Publisher::builder("projects/my-project/topics/my-topic")
+    ///     .set_max_outstanding_messages(1000)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn set_max_outstanding_messages<T: Into<i64>>(mut self, v: T) -> PublisherBuilder {
+        self.flow_control_options = self.flow_control_options.set_max_outstanding_messages(v);
+        self
+    }
+
+    /// Sets the maximum size in bytes of the outstanding messages.
+    ///
+    /// When this limit is reached, new messages are handled according to the
+    /// [limit exceeded behavior][Self::set_limit_exceeded_behavior]. A single
+    /// message larger than the limit is accepted when there are no other
+    /// outstanding messages.
+    ///
+    /// Use a value <= 0 to set no limit on the number of outstanding bytes.
+    /// There is no limit by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use google_cloud_pubsub::client::Publisher;
+    /// # async fn sample() -> anyhow::Result<()> {
+    /// let publisher = Publisher::builder("projects/my-project/topics/my-topic")
+    ///     .set_max_outstanding_bytes(100 * 1024 * 1024) // 100 MiB
+    ///     .build()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn set_max_outstanding_bytes<T: Into<i64>>(mut self, v: T) -> PublisherBuilder {
+        self.flow_control_options = self.flow_control_options.set_max_outstanding_bytes(v);
+        self
+    }
+
+    /// Sets the behavior when the flow control limits are exceeded.
+    ///
+    /// The default is [`LimitExceededBehavior::Block`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use google_cloud_pubsub::publisher::LimitExceededBehavior;
+    /// # use google_cloud_pubsub::client::Publisher;
+    /// # async fn sample() -> anyhow::Result<()> {
+    /// let publisher = Publisher::builder("projects/my-project/topics/my-topic")
+    ///     .set_max_outstanding_messages(1000)
+    ///     .set_limit_exceeded_behavior(LimitExceededBehavior::Error)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn set_limit_exceeded_behavior(mut self, v: LimitExceededBehavior) -> PublisherBuilder {
+        self.flow_control_options = self.flow_control_options.set_limit_exceeded_behavior(v);
+        self
+    }
+
     /// Sets the endpoint.
     ///
     /// ```
@@ -308,6 +389,7 @@ pub struct PublisherPartialBuilder {
     pub(crate) inner: GapicPublisher,
     topic: String,
     batching_options: BatchingOptions,
+    flow_control_options: FlowControlOptions,
 }
 
 impl PublisherPartialBuilder {
@@ -317,6 +399,7 @@ impl PublisherPartialBuilder {
             inner: client,
             topic,
             batching_options: BatchingOptions::default(),
+            flow_control_options: FlowControlOptions::default(),
         }
     }
 
@@ -387,6 +470,86 @@ impl PublisherPartialBuilder {
         self
     }
 
+    /// Sets the maximum number of outstanding messages.
+    ///
+    /// A message is outstanding from the call to [`publish()`][Publisher::publish]
+    /// until the result of the publish operation is known. When this limit is
+    /// reached, new messages are handled according to the
+    /// [limit exceeded behavior][Self::set_limit_exceeded_behavior].
+    ///
+    /// Use a value <= 0 to set no limit on the number of outstanding messages.
+    /// There is no limit by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use google_cloud_pubsub::client::BasePublisher;
+    /// # async fn sample() -> anyhow::Result<()> {
+    /// # let client: BasePublisher = BasePublisher::builder().build().await?;
+    /// let publisher = client
+    ///     .publisher("projects/my-project/topics/my-topic")
+    ///     .set_max_outstanding_messages(1000)
+    ///     .build();
+    /// # Ok(()) }
+    /// ```
+    pub fn set_max_outstanding_messages<T: Into<i64>>(mut self, v: T) -> PublisherPartialBuilder {
+        self.flow_control_options = self.flow_control_options.set_max_outstanding_messages(v);
+        self
+    }
+
+    /// Sets the maximum size in bytes of the outstanding messages.
+    ///
+    /// When this limit is reached, new messages are handled according to the
+    /// [limit exceeded behavior][Self::set_limit_exceeded_behavior]. A single
+    /// message larger than the limit is accepted when there are no other
+    /// outstanding messages.
+    ///
+    /// Use a value <= 0 to set no limit on the number of outstanding bytes.
+    /// There is no limit by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use google_cloud_pubsub::client::BasePublisher;
+    /// # async fn sample() -> anyhow::Result<()> {
+    /// # let client: BasePublisher = BasePublisher::builder().build().await?;
+    /// let publisher = client
+    ///     .publisher("projects/my-project/topics/my-topic")
+    ///     .set_max_outstanding_bytes(100 * 1024 * 1024) // 100 MiB
+    ///     .build();
+    /// # Ok(()) }
+    /// ```
+    pub fn set_max_outstanding_bytes<T: Into<i64>>(mut self, v: T) -> PublisherPartialBuilder {
+        self.flow_control_options = self.flow_control_options.set_max_outstanding_bytes(v);
+        self
+    }
+
+    /// Sets the behavior when the flow control limits are exceeded.
+    ///
+    /// The default is [`LimitExceededBehavior::Block`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use google_cloud_pubsub::publisher::LimitExceededBehavior;
+    /// # use google_cloud_pubsub::client::BasePublisher;
+    /// # async fn sample() -> anyhow::Result<()> {
+    /// # let client: BasePublisher = BasePublisher::builder().build().await?;
+    /// let publisher = client
+    ///     .publisher("projects/my-project/topics/my-topic")
+    ///     .set_max_outstanding_messages(1000)
+    ///     .set_limit_exceeded_behavior(LimitExceededBehavior::Error)
+    ///     .build();
+    /// # Ok(()) }
+    /// ```
+    pub fn set_limit_exceeded_behavior(
+        mut self,
+        v: LimitExceededBehavior,
+    ) -> PublisherPartialBuilder {
+        self.flow_control_options = self.flow_control_options.set_limit_exceeded_behavior(v);
+        self
+    }
+
     /// Creates a new [`Publisher`] from the builder's configuration.
     pub fn build(self) -> Publisher {
         self.build_return_handle().0
@@ -419,13 +582,22 @@ impl PublisherPartialBuilder {
         // We don't need to keep track of a handle to the dispatcher.
         // Dropping the Publisher will drop the only sender to the channel.
         // This will cause the dispatcher to gracefully exit.
-        let dispatcher = Dispatcher::new(self.topic, self.inner, batching_options.clone(), rx);
+        let paused_keys = PausedKeys::default();
+        let dispatcher = Dispatcher::new(
+            self.topic,
+            self.inner,
+            batching_options.clone(),
+            paused_keys.clone(),
+            rx,
+        );
         let handle = tokio::spawn(dispatcher.run());
 
         (
             Publisher {
                 batching_options,
                 tx,
+                flow_controller: Arc::new(FlowController::new(&self.flow_control_options)),
+                paused_keys,
             },
             handle,
         )
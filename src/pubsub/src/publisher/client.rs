@@ -14,9 +14,15 @@
 
 use super::options::BatchingOptions;
 use crate::publisher::actor::BundledMessage;
+use crate::publisher::actor::PausedKeys;
 use crate::publisher::actor::ToDispatcher;
+use crate::publisher::batch::Batch;
 use crate::publisher::builder::PublisherBuilder;
+use crate::publisher::flow_control::{FlowControlPermit, FlowController};
+use crate::publisher::{LimitExceededBehavior, PublishFuture};
 
+use std::collections::HashMap;
+use std::sync::Arc;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::oneshot;
 
@@ -70,6 +76,8 @@ pub struct Publisher {
     #[cfg_attr(not(test), expect(dead_code))]
     pub(crate) batching_options: BatchingOptions,
     pub(crate) tx: UnboundedSender<ToDispatcher>,
+    pub(crate) flow_controller: Arc<FlowController>,
+    pub(crate) paused_keys: PausedKeys,
 }
 
 impl Publisher {
@@ -90,9 +98,18 @@ impl Publisher {
 
     /// Publishes a message to the topic.
     ///
+    /// Messages with the same ordering key are sent in the order they are
+    /// published. The publisher retries failed batches for an ordering key
+    /// using the configured retry policy, and does not send later batches for
+    /// the key until the retries complete.
+    ///
     /// When this method encounters a non-recoverable error publishing for an ordering key,
     /// it will pause publishing on all new messages on that ordering key. Any outstanding
-    /// messages that have not yet been published may return an error.
+    /// messages that have not yet been published, and any later messages for the key,
+    /// fail with [`PublishError::OrderingKeyPaused`](crate::error::PublishError::OrderingKeyPaused)
+    /// until [`resume_publish`][Self::resume_publish] is called. Messages for other ordering keys
+    /// are not affected. Use [`paused_ordering_keys`][Self::paused_ordering_keys] to find the
+    /// paused keys.
     ///
     /// ```
     /// # use google_cloud_pubsub::client::Publisher;
@@ -103,19 +120,72 @@ impl Publisher {
     /// ```
     #[must_use = "ignoring the publish result may lead to undetected delivery failures"]
     pub fn publish(&self, msg: crate::model::Message) -> crate::publisher::PublishFuture {
-        let (tx, rx) = tokio::sync::oneshot::channel();
+        let size = Batch::message_size(&msg);
+        if let Some(permit) = self.flow_controller.try_acquire(size) {
+            return PublishFuture::new(Self::dispatch(&self.tx, msg, permit));
+        }
+        match self.flow_controller.behavior() {
+            LimitExceededBehavior::Error => {
+                PublishFuture::failed(crate::error::PublishError::FlowControlLimitExceeded)
+            }
+            _ => {
+                let guard = self.flow_controller.block();
+                let flow_controller = self.flow_controller.clone();
+                let tx = self.tx.clone();
+                PublishFuture::blocked(async move {
+                    let permit = flow_controller.acquire(size, guard).await;
+                    PublishFuture::new(Self::dispatch(&tx, msg, permit)).await
+                })
+            }
+        }
+    }
+
+    fn dispatch(
+        tx: &UnboundedSender<ToDispatcher>,
+        msg: crate::model::Message,
+        permit: FlowControlPermit,
+    ) -> oneshot::Receiver<std::result::Result<String, crate::error::PublishError>> {
+        let (result_tx, result_rx) = oneshot::channel();
 
         // If this fails, the Dispatcher is gone, which indicates it has been dropped,
         // possibly due to the background task being stopped by the runtime.
-        // The PublishFuture will automatically receive an error when `tx` is dropped.
-        if self
-            .tx
-            .send(ToDispatcher::Publish(BundledMessage { msg, tx }))
+        // The PublishFuture will automatically receive an error when `result_tx` is dropped.
+        if tx
+            .send(ToDispatcher::Publish(BundledMessage {
+                msg,
+                tx: result_tx,
+                permit: Some(permit),
+            }))
             .is_err()
         {
-            // `tx` is dropped here if the send errors.
+            // `result_tx` is dropped here if the send errors.
         }
-        crate::publisher::PublishFuture { rx }
+        result_rx
+    }
+
+    /// Returns the number of messages published but not yet resolved.
+    ///
+    /// A message is outstanding from the call to [`publish`][Self::publish]
+    /// until its [`PublishFuture`] is ready to resolve, whether it succeeds
+    /// or fails. Messages waiting for flow control capacity are not included.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_pubsub::client::Publisher;
+    /// # async fn sample(publisher: Publisher) -> anyhow::Result<()> {
+    /// println!("outstanding messages: {}", publisher.outstanding_messages());
+    /// # Ok(()) }
+    /// ```
+    pub fn outstanding_messages(&self) -> usize {
+        self.flow_controller.outstanding_messages()
+    }
+
+    /// Returns the estimated size in bytes of the messages published but not
+    /// yet resolved.
+    ///
+    /// See [`outstanding_messages`][Self::outstanding_messages] for details.
+    pub fn outstanding_bytes(&self) -> usize {
+        self.flow_controller.outstanding_bytes()
     }
 
     /// Flushes all buffered messages across all ordering keys, sending them immediately.
@@ -171,6 +241,27 @@ impl Publisher {
             .tx
             .send(ToDispatcher::ResumePublish(ordering_key.into()));
     }
+
+    /// Returns the paused ordering keys, and the error that paused each one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn sample(publisher: google_cloud_pubsub::client::Publisher) -> anyhow::Result<()> {
+    /// for (ordering_key, error) in publisher.paused_ordering_keys() {
+    ///     eprintln!("publishing is paused for {ordering_key}: {error}");
+    ///     publisher.resume_publish(ordering_key);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Publishing for an ordering key pauses when a batch of messages for the
+    /// key fails with a non-recoverable error. The key remains paused until
+    /// [`resume_publish`][Self::resume_publish] is called.
+    pub fn paused_ordering_keys(&self) -> HashMap<String, Arc<crate::Error>> {
+        self.paused_keys.lock().expect("never poisoned").clone()
+    }
 }
 
 #[cfg(test)]
@@ -1112,10 +1203,19 @@ mod tests {
 
         // Validate that new Publish on the paused ordering key will result in an error.
         assert_publishing_is_paused!(publisher, key);
+        let paused = publisher.paused_ordering_keys();
+        assert_eq!(paused.len(), 1, "{paused:?}");
+        let reason = paused.get(key).expect("the key should be paused");
+        assert_eq!(
+            reason.status().map(|s| s.code),
+            Some(google_cloud_gax::error::rpc::Code::Unknown),
+            "{reason:?}"
+        );
 
         // Resume and validate the key is no longer paused.
         publisher.resume_publish(key);
         assert_publishing_is_ok!(publisher, key);
+        assert!(publisher.paused_ordering_keys().is_empty());
 
         // Verify that the other ordering keys continue to work as expected.
         assert_publishing_is_ok!(publisher, "", "without_error");
@@ -1123,6 +1223,33 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn ordering_key_failure_is_isolated() -> anyhow::Result<()> {
+        let mut mock = MockGapicPublisher::new();
+        mock.expect_publish()
+            .withf(|req, _o| req.messages.iter().all(|m| m.ordering_key == "poison"))
+            .returning(|_, _| panic!("simulated failure while publishing"));
+        mock.expect_publish().returning(publish_ok);
+
+        let client = GapicPublisher::from_stub(mock);
+        let publisher = PublisherPartialBuilder::new(client, TOPIC.to_string())
+            .set_message_count_threshold(1_u32)
+            .build();
+
+        let got = publisher
+            .publish(Message::new().set_ordering_key("poison").set_data("msg 0"))
+            .await;
+        assert!(got.is_err(), "{got:?}");
+        assert_publishing_is_paused!(publisher, "poison");
+        let paused = publisher.paused_ordering_keys();
+        assert!(paused.contains_key("poison"), "{paused:?}");
+
+        // Other ordering keys are not affected.
+        assert_publishing_is_ok!(publisher, "", "healthy");
+        assert_eq!(publisher.paused_ordering_keys().len(), 1);
+        Ok(())
+    }
+
     #[tokio_test_no_panics(start_paused = true)]
     async fn resuming_ordering_key_twice_is_safe() -> anyhow::Result<()> {
         // Validate that resuming twice sequentially does not have bad side effects.
@@ -1274,4 +1401,122 @@ mod tests {
         }
         Ok(())
     }
+
+    #[tokio_test_no_panics(start_paused = true)]
+    async fn flow_control_error() -> anyhow::Result<()> {
+        let mut mock = MockGapicPublisherWithFuture::new();
+        mock.expect_publish().times(1).returning(|r, o| {
+            Box::pin(async move {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                publish_ok(r, o)
+            })
+        });
+
+        let client = GapicPublisher::from_stub(mock);
+        let publisher = PublisherPartialBuilder::new(client, TOPIC.to_string())
+            .set_message_count_threshold(2_u32)
+            .set_max_outstanding_messages(2)
+            .set_limit_exceeded_behavior(LimitExceededBehavior::Error)
+            .build();
+
+        let first = publisher.publish(Message::new().set_data("hello"));
+        let second = publisher.publish(Message::new().set_data("world"));
+        assert_eq!(publisher.outstanding_messages(), 2);
+        assert_eq!(publisher.outstanding_bytes(), 10);
+        let got = publisher.publish(Message::new().set_data("rejected")).await;
+        assert!(
+            matches!(
+                got,
+                Err(crate::error::PublishError::FlowControlLimitExceeded)
+            ),
+            "{got:?}"
+        );
+
+        assert_eq!(first.await?, "hello");
+        assert_eq!(second.await?, "world");
+        assert_eq!(publisher.outstanding_messages(), 0);
+        assert_eq!(publisher.outstanding_bytes(), 0);
+        Ok(())
+    }
+
+    #[tokio_test_no_panics(start_paused = true)]
+    async fn flow_control_block() -> anyhow::Result<()> {
+        let mut seq = Sequence::new();
+        let mut mock = MockGapicPublisherWithFuture::new();
+        mock.expect_publish()
+            .times(1)
+            .in_sequence(&mut seq)
+            .withf(|r, _| r.messages.len() == 1 && r.messages[0].data == "hello")
+            .returning(|r, o| {
+                Box::pin(async move {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    publish_ok(r, o)
+                })
+            });
+        mock.expect_publish()
+            .times(1)
+            .in_sequence(&mut seq)
+            .withf(|r, _| r.messages.len() == 1 && r.messages[0].data == "world")
+            .returning(|r, o| Box::pin(async move { publish_ok(r, o) }));
+
+        let client = GapicPublisher::from_stub(mock);
+        let publisher = PublisherPartialBuilder::new(client, TOPIC.to_string())
+            .set_message_count_threshold(1_u32)
+            .set_max_outstanding_bytes(8)
+            .build();
+
+        let first = publisher.publish(Message::new().set_data("hello"));
+        let second = publisher.publish(Message::new().set_data("world"));
+        // The second message waits until the first one is resolved.
+        assert_eq!(publisher.outstanding_messages(), 1);
+        let start = tokio::time::Instant::now();
+        assert_eq!(second.await?, "world");
+        assert!(start.elapsed() >= Duration::from_millis(10));
+        assert_eq!(first.await?, "hello");
+        assert_eq!(publisher.outstanding_messages(), 0);
+        Ok(())
+    }
+
+    #[tokio_test_no_panics]
+    async fn flow_control_ignore() -> anyhow::Result<()> {
+        let mut mock = MockGapicPublisher::new();
+        mock.expect_publish().returning(publish_ok);
+
+        let client = GapicPublisher::from_stub(mock);
+        let publisher = PublisherPartialBuilder::new(client, TOPIC.to_string())
+            .set_message_count_threshold(1_u32)
+            .set_max_outstanding_messages(1)
+            .set_limit_exceeded_behavior(LimitExceededBehavior::Ignore)
+            .build();
+
+        let handles: Vec<_> = ["a", "b", "c"]
+            .into_iter()
+            .map(|d| publisher.publish(Message::new().set_data(d)))
+            .collect();
+        assert_eq!(publisher.outstanding_messages(), 3);
+        for (handle, want) in handles.into_iter().zip(["a", "b", "c"]) {
+            assert_eq!(handle.await?, want);
+        }
+        assert_eq!(publisher.outstanding_messages(), 0);
+        Ok(())
+    }
+
+    #[tokio_test_no_panics]
+    async fn flow_control_releases_on_error() -> anyhow::Result<()> {
+        let mut mock = MockGapicPublisher::new();
+        mock.expect_publish().returning(publish_err);
+
+        let client = GapicPublisher::from_stub(mock);
+        let publisher = PublisherPartialBuilder::new(client, TOPIC.to_string())
+            .set_message_count_threshold(1_u32)
+            .set_max_outstanding_messages(1)
+            .build();
+
+        for _ in 0..3 {
+            let got = publisher.publish(Message::new().set_data("hello")).await;
+            assert_publish_err(got.unwrap_err());
+        }
+        assert_eq!(publisher.outstanding_messages(), 0);
+        Ok(())
+    }
 }
@@ -0,0 +1,303 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::options::FlowControlOptions;
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+
+/// The behavior of the publisher when the flow control limits are exceeded.
+///
+/// # Example
+/// ```
+/// # use google_cloud_pubsub::client::Publisher;
+/// # use google_cloud_pubsub::publisher::LimitExceededBehavior;
+/// # async fn sample() -> anyhow::Result<()> {
+/// let publisher = Publisher::builder("projects/my-project/topics/my-topic")
+///     .set_max_outstanding_messages(1000)
+///     .set_limit_exceeded_behavior(LimitExceededBehavior::Error)
+///     .build()
+///     .await?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LimitExceededBehavior {
+    /// [`publish()`](crate::client::Publisher::publish) returns a future that
+    /// waits until the outstanding messages drop below the limits.
+    ///
+    /// The message is not sent until the future is polled. When publishing
+    /// with an ordering key, await the futures in the order the messages were
+    /// published.
+    #[default]
+    Block,
+
+    /// [`publish()`](crate::client::Publisher::publish) returns a future that
+    /// fails with [`PublishError::FlowControlLimitExceeded`](crate::error::PublishError::FlowControlLimitExceeded).
+    Error,
+
+    /// The limits are not enforced.
+    Ignore,
+}
+
+/// Tracks the messages that have been published but not yet resolved.
+#[derive(Debug)]
+pub(crate) struct FlowController {
+    max_messages: Option<usize>,
+    max_bytes: Option<usize>,
+    behavior: LimitExceededBehavior,
+    state: Mutex<State>,
+    released: Notify,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    messages: usize,
+    bytes: usize,
+    // The number of publish futures waiting for capacity. New messages cannot
+    // skip ahead of them.
+    blocked: usize,
+}
+
+impl FlowController {
+    pub(crate) fn new(options: &FlowControlOptions) -> Self {
+        let limit = |v: i64| usize::try_from(v).ok().filter(|v| *v > 0);
+        Self {
+            max_messages: limit(options.max_outstanding_messages),
+            max_bytes: limit(options.max_outstanding_bytes),
+            behavior: options.limit_exceeded_behavior,
+            state: Mutex::new(State::default()),
+            released: Notify::new(),
+        }
+    }
+
+    pub(crate) fn behavior(&self) -> LimitExceededBehavior {
+        self.behavior
+    }
+
+    pub(crate) fn outstanding_messages(&self) -> usize {
+        self.state.lock().expect("never poisoned").messages
+    }
+
+    pub(crate) fn outstanding_bytes(&self) -> usize {
+        self.state.lock().expect("never poisoned").bytes
+    }
+
+    /// Reserves capacity for a message of `bytes` bytes, if available.
+    ///
+    /// Fails if the message does not fit in the limits, or if other messages
+    /// are already waiting for capacity.
+    pub(crate) fn try_acquire(self: &Arc<Self>, bytes: usize) -> Option<FlowControlPermit> {
+        let mut state = self.state.lock().expect("never poisoned");
+        if state.blocked > 0 || !self.fits(&state, bytes) {
+            return None;
+        }
+        Some(self.reserve(&mut state, bytes))
+    }
+
+    /// Registers a message waiting for capacity.
+    ///
+    /// The caller must complete the reservation with [Self::acquire].
+    pub(crate) fn block(self: &Arc<Self>) -> BlockedGuard {
+        self.state.lock().expect("never poisoned").blocked += 1;
+        BlockedGuard {
+            controller: self.clone(),
+        }
+    }
+
+    /// Waits until a message of `bytes` bytes fits in the limits.
+    pub(crate) async fn acquire(
+        self: &Arc<Self>,
+        bytes: usize,
+        guard: BlockedGuard,
+    ) -> FlowControlPermit {
+        loop {
+            let released = self.released.notified();
+            let permit = {
+                let mut state = self.state.lock().expect("never poisoned");
+                self.fits(&state, bytes)
+                    .then(|| self.reserve(&mut state, bytes))
+            };
+            if let Some(permit) = permit {
+                drop(guard);
+                return permit;
+            }
+            released.await;
+        }
+    }
+
+    fn fits(&self, state: &State, bytes: usize) -> bool {
+        // A message larger than the limits is accepted when nothing else is
+        // outstanding, otherwise it could never be published.
+        self.behavior == LimitExceededBehavior::Ignore
+            || state.messages == 0
+            || (self.max_messages.is_none_or(|max| state.messages < max)
+                && self
+                    .max_bytes
+                    .is_none_or(|max| state.bytes.saturating_add(bytes) <= max))
+    }
+
+    fn reserve(self: &Arc<Self>, state: &mut State, bytes: usize) -> FlowControlPermit {
+        state.messages += 1;
+        state.bytes += bytes;
+        FlowControlPermit {
+            controller: self.clone(),
+            bytes,
+        }
+    }
+}
+
+/// The capacity reserved for an outstanding message.
+///
+/// The capacity is released when the permit is dropped, which happens once
+/// the result of the publish operation is known.
+#[derive(Debug)]
+pub(crate) struct FlowControlPermit {
+    controller: Arc<FlowController>,
+    bytes: usize,
+}
+
+impl Drop for FlowControlPermit {
+    fn drop(&mut self) {
+        {
+            let mut state = self.controller.state.lock().expect("never poisoned");
+            state.messages -= 1;
+            state.bytes -= self.bytes;
+        }
+        self.controller.released.notify_waiters();
+    }
+}
+
+/// Unregisters a blocked message if its future is dropped before it acquires
+/// capacity.
+#[derive(Debug)]
+pub(crate) struct BlockedGuard {
+    controller: Arc<FlowController>,
+}
+
+impl Drop for BlockedGuard {
+    fn drop(&mut self) {
+        self.controller
+            .state
+            .lock()
+            .expect("never poisoned")
+            .blocked -= 1;
+        // Messages that could not skip ahead of this one may proceed.
+        self.controller.released.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn controller(
+        messages: i64,
+        bytes: i64,
+        behavior: LimitExceededBehavior,
+    ) -> Arc<FlowController> {
+        Arc::new(FlowController::new(
+            &FlowControlOptions::default()
+                .set_max_outstanding_messages(messages)
+                .set_max_outstanding_bytes(bytes)
+                .set_limit_exceeded_behavior(behavior),
+        ))
+    }
+
+    #[test]
+    fn message_limit() {
+        let fc = controller(2, 0, LimitExceededBehavior::Error);
+        let p1 = fc.try_acquire(10).expect("within limits");
+        let p2 = fc.try_acquire(10).expect("within limits");
+        assert!(fc.try_acquire(10).is_none());
+        assert_eq!(fc.outstanding_messages(), 2);
+        assert_eq!(fc.outstanding_bytes(), 20);
+        drop(p1);
+        let _p3 = fc.try_acquire(10).expect("capacity was released");
+        drop(p2);
+        assert_eq!(fc.outstanding_messages(), 1);
+        assert_eq!(fc.outstanding_bytes(), 10);
+    }
+
+    #[test]
+    fn byte_limit() {
+        let fc = controller(0, 100, LimitExceededBehavior::Error);
+        let _p1 = fc.try_acquire(60).expect("within limits");
+        assert!(fc.try_acquire(50).is_none());
+        let _p2 = fc.try_acquire(40).expect("within limits");
+        assert_eq!(fc.outstanding_bytes(), 100);
+    }
+
+    #[test]
+    fn oversized_message() {
+        let fc = controller(10, 100, LimitExceededBehavior::Error);
+        let p1 = fc
+            .try_acquire(1000)
+            .expect("accepted when nothing is outstanding");
+        assert!(fc.try_acquire(1).is_none());
+        drop(p1);
+        assert_eq!(fc.outstanding_messages(), 0);
+        assert_eq!(fc.outstanding_bytes(), 0);
+    }
+
+    #[test]
+    fn ignore() {
+        let fc = controller(1, 1, LimitExceededBehavior::Ignore);
+        let _permits: Vec<_> = (0..10)
+            .map(|_| fc.try_acquire(100).expect("limits are ignored"))
+            .collect();
+        assert_eq!(fc.outstanding_messages(), 10);
+        assert_eq!(fc.outstanding_bytes(), 1000);
+    }
+
+    #[test]
+    fn no_limits() {
+        let fc = controller(0, -1, LimitExceededBehavior::Error);
+        let _permits: Vec<_> = (0..10)
+            .map(|_| fc.try_acquire(100).expect("no limits"))
+            .collect();
+        assert_eq!(fc.outstanding_messages(), 10);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn block() -> anyhow::Result<()> {
+        let fc = controller(1, 0, LimitExceededBehavior::Block);
+        let p1 = fc.try_acquire(10).expect("within limits");
+        assert!(fc.try_acquire(10).is_none());
+
+        let guard = fc.block();
+        let waiter = tokio::spawn({
+            let fc = fc.clone();
+            async move { fc.acquire(10, guard).await }
+        });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(!waiter.is_finished());
+
+        drop(p1);
+        // A new message cannot skip ahead of the blocked one.
+        let _p2 = waiter.await?;
+        assert!(fc.try_acquire(10).is_none());
+        assert_eq!(fc.outstanding_messages(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn dropped_blocked_message() {
+        let fc = controller(1, 0, LimitExceededBehavior::Block);
+        let guard = fc.block();
+        assert!(fc.try_acquire(10).is_none(), "a message is waiting");
+        drop(guard);
+        let _p1 = fc.try_acquire(10).expect("no messages are waiting");
+    }
+}
@@ -45,7 +45,54 @@ use tokio::sync::oneshot;
 /// # }
 /// ```
 pub struct PublishFuture {
-    pub(crate) rx: oneshot::Receiver<std::result::Result<String, crate::error::PublishError>>,
+    pub(crate) inner: Inner,
+}
+
+type PublishResult = std::result::Result<String, crate::error::PublishError>;
+
+pub(crate) enum Inner {
+    /// The message was sent to the background task.
+    Sent(oneshot::Receiver<PublishResult>),
+    /// The message is waiting for flow control capacity.
+    Blocked(Pin<Box<dyn Future<Output = PublishResult> + Send>>),
+    /// The message was not published.
+    Failed(Option<crate::error::PublishError>),
+}
+
+impl PublishFuture {
+    pub(crate) fn new(rx: oneshot::Receiver<PublishResult>) -> Self {
+        Self {
+            inner: Inner::Sent(rx),
+        }
+    }
+
+    pub(crate) fn blocked<F>(future: F) -> Self
+    where
+        F: Future<Output = PublishResult> + Send + 'static,
+    {
+        Self {
+            inner: Inner::Blocked(Box::pin(future)),
+        }
+    }
+
+    pub(crate) fn failed(error: crate::error::PublishError) -> Self {
+        Self {
+            inner: Inner::Failed(Some(error)),
+        }
+    }
+}
+
+impl std::fmt::Debug for PublishFuture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = match &self.inner {
+            Inner::Sent(_) => "Sent",
+            Inner::Blocked(_) => "Blocked",
+            Inner::Failed(_) => "Failed",
+        };
+        f.debug_struct("PublishFuture")
+            .field("state", &state)
+            .finish()
+    }
 }
 
 impl Future for PublishFuture {
@@ -53,10 +100,19 @@ impl Future for PublishFuture {
     ///
     /// - `Ok(String)`: The server-assigned message ID.
     /// - [`Err(PublishError)`](crate::error::PublishError): An error occurred while publishing the message.
-    type Output = std::result::Result<String, crate::error::PublishError>;
+    type Output = PublishResult;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let result = ready!(Pin::new(&mut self.rx).poll(cx));
+        let rx = match &mut self.inner {
+            Inner::Sent(rx) => rx,
+            Inner::Blocked(future) => return future.as_mut().poll(cx),
+            Inner::Failed(error) => {
+                return Poll::Ready(Err(error
+                    .take()
+                    .unwrap_or(crate::error::PublishError::Shutdown)));
+            }
+        };
+        let result = ready!(Pin::new(rx).poll(cx));
         // An error will only occur if the sender of the self.rx was dropped,
         // which can happen when the Dispatcher is dropped.
         match result {
@@ -73,7 +129,7 @@ mod tests {
     #[tokio::test]
     async fn resolve_publish_future_success() -> anyhow::Result<()> {
         let (tx, rx) = oneshot::channel();
-        let handle = PublishFuture { rx };
+        let handle = PublishFuture::new(rx);
         let _ = tx.send(Ok("message_id".to_string()));
         assert_eq!(handle.await?, "message_id");
 
@@ -83,7 +139,7 @@ mod tests {
     #[tokio::test]
     async fn resolve_publish_future_error() -> anyhow::Result<()> {
         let (tx, rx) = oneshot::channel();
-        let fut = PublishFuture { rx };
+        let fut = PublishFuture::new(rx);
         let _ = tx.send(Err(crate::error::PublishError::OrderingKeyPaused));
         let res = fut.await;
         assert!(
@@ -97,7 +153,7 @@ mod tests {
     #[tokio::test]
     async fn resolve_publish_future_error_send_error() -> anyhow::Result<()> {
         let (tx, rx) = oneshot::channel();
-        let fut = PublishFuture { rx };
+        let fut = PublishFuture::new(rx);
         drop(tx);
         let res = fut.await;
         assert!(
@@ -107,4 +163,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn resolve_publish_future_failed() {
+        let fut = PublishFuture::failed(crate::error::PublishError::FlowControlLimitExceeded);
+        let res = fut.await;
+        assert!(
+            matches!(
+                res,
+                Err(crate::error::PublishError::FlowControlLimitExceeded)
+            ),
+            "{res:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn resolve_publish_future_blocked() -> anyhow::Result<()> {
+        let (tx, rx) = oneshot::channel();
+        let fut = PublishFuture::blocked(async move { rx.await.expect("sender is not dropped") });
+        assert!(format!("{fut:?}").contains("Blocked"), "{fut:?}");
+        let _ = tx.send(Ok("message_id".to_string()));
+        assert_eq!(fut.await?, "message_id");
+        Ok(())
+    }
 }
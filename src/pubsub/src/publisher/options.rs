@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::flow_control::LimitExceededBehavior;
+
 /// Configure publisher batching behavior.
 #[derive(Clone, Debug)]
 #[non_exhaustive]
@@ -56,9 +58,37 @@ impl std::default::Default for BatchingOptions {
     }
 }
 
+/// Configure publisher flow control.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct FlowControlOptions {
+    pub max_outstanding_messages: i64,
+    pub max_outstanding_bytes: i64,
+    pub limit_exceeded_behavior: LimitExceededBehavior,
+}
+
+impl FlowControlOptions {
+    /// Set the [FlowControlOptions][Self::max_outstanding_messages] field.
+    pub fn set_max_outstanding_messages<V: Into<i64>>(mut self, v: V) -> Self {
+        self.max_outstanding_messages = v.into();
+        self
+    }
+
+    /// Set the [FlowControlOptions][Self::max_outstanding_bytes] field.
+    pub fn set_max_outstanding_bytes<V: Into<i64>>(mut self, v: V) -> Self {
+        self.max_outstanding_bytes = v.into();
+        self
+    }
+
+    /// Set the [FlowControlOptions][Self::limit_exceeded_behavior] field.
+    pub fn set_limit_exceeded_behavior(mut self, v: LimitExceededBehavior) -> Self {
+        self.limit_exceeded_behavior = v;
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::BatchingOptions;
+    use super::{BatchingOptions, FlowControlOptions, LimitExceededBehavior};
 
     #[tokio::test]
     async fn batching_options() -> anyhow::Result<()> {
@@ -74,4 +104,26 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn flow_control_options() {
+        let options = FlowControlOptions::default();
+        assert_eq!(options.max_outstanding_messages, 0);
+        assert_eq!(options.max_outstanding_bytes, 0);
+        assert_eq!(
+            options.limit_exceeded_behavior,
+            LimitExceededBehavior::Block
+        );
+
+        let options = options
+            .set_max_outstanding_messages(10)
+            .set_max_outstanding_bytes(1_000)
+            .set_limit_exceeded_behavior(LimitExceededBehavior::Error);
+        assert_eq!(options.max_outstanding_messages, 10);
+        assert_eq!(options.max_outstanding_bytes, 1_000);
+        assert_eq!(
+            options.limit_exceeded_behavior,
+            LimitExceededBehavior::Error
+        );
+    }
 }
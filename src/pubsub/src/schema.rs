@@ -0,0 +1,776 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Encode and decode messages with the schema of a topic.
+//!
+//! Topics can be configured with a [schema] and an encoding. Pub/Sub rejects
+//! messages published to such topics unless their data conforms to the
+//! schema.
+//!
+//! A [SchemaCodec] encodes application values as message data in the
+//! encoding of a schema, and decodes the data of received messages. A [SchemaCache] fetches the schema revisions with
+//! the [SchemaService] client and keeps them in memory, so subscribers can
+//! decode messages published with any revision of the schema.
+//!
+//! Avro schemas work with any type that implements [serde::Serialize] or
+//! [serde::de::DeserializeOwned], in either the JSON or the binary encoding.
+//! Protocol Buffer schemas work with [prost] messages in the binary encoding,
+//! and with `serde` types in the JSON encoding.
+//!
+//! The codecs do not fully validate messages, the service remains the
+//! authority on whether a message conforms to the schema:
+//! - Avro values are rejected if the schema cannot encode them, for example a
+//!   string for an `int` field. Logical types are encoded as their underlying
+//!   type without further checks, and aliases are ignored.
+//! - For Protocol Buffer schemas the codec only checks that [prost] types are
+//!   the top-level message of the schema. The message fields are not checked,
+//!   and JSON-encoded messages are passed through `serde` unchanged.
+//!
+//! Decoding limits the nesting and the number of items of Avro values, to
+//! bound the resources used by malformed messages.
+//!
+//! # Example: publishing
+//! ```
+//! # use google_cloud_pubsub::client::{Publisher, SchemaService, TopicAdmin};
+//! # use google_cloud_pubsub::schema::SchemaCache;
+//! #[derive(serde::Serialize)]
+//! struct Order {
+//!     id: String,
+//!     quantity: i32,
+//! }
+//!
+//! # async fn sample() -> anyhow::Result<()> {
+//! let topic = "projects/my-project/topics/my-topic";
+//! let cache = SchemaCache::new(SchemaService::builder().build().await?);
+//! let codec = cache
+//!     .topic_codec(&TopicAdmin::builder().build().await?, topic)
+//!     .await?;
+//!
+//! let publisher = Publisher::builder(topic).build().await?;
+//! let order = Order { id: "order-1".into(), quantity: 2 };
+//! // Fails if the schema cannot encode `order`.
+//! let message = codec.encode(&order)?;
+//! let id = publisher.publish(message).await?;
+//! # Ok(()) }
+//! ```
+//!
+//! # Example: receiving
+//! ```
+//! # use google_cloud_pubsub::client::{SchemaService, Subscriber};
+//! # use google_cloud_pubsub::schema::SchemaCache;
+//! #[derive(serde::Deserialize)]
+//! struct Order {
+//!     id: String,
+//!     quantity: i32,
+//! }
+//!
+//! # async fn sample() -> anyhow::Result<()> {
+//! let cache = SchemaCache::new(SchemaService::builder().build().await?);
+//! let client = Subscriber::builder().build().await?;
+//! let mut stream = client
+//!     .subscribe("projects/my-project/subscriptions/my-subscription")
+//!     .build();
+//! while let Some((m, h)) = stream.next().await.transpose()? {
+//!     // Uses the schema revision that the message was published with.
+//!     let order: Order = cache.decode(&m).await?;
+//!     println!("received order {}", order.id);
+//!     h.ack();
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! [schema]: https://cloud.google.com/pubsub/docs/schemas
+
+use crate::client::{SchemaService, TopicAdmin};
+use crate::error::SchemaError;
+use crate::model::{Encoding, Message, Schema, SchemaView, schema::Type};
+use avro::AvroSchema;
+use protobuf::ProtoSchema;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+mod avro;
+mod protobuf;
+
+/// The attribute with the name of the schema used to validate a message.
+///
+/// Pub/Sub sets this attribute on messages published to topics with a schema.
+pub const SCHEMA_NAME_ATTRIBUTE: &str = "googclient_schemaname";
+
+/// The attribute with the revision of the schema used to validate a message.
+///
+/// Pub/Sub sets this attribute on messages published to topics with a schema.
+pub const SCHEMA_REVISION_ID_ATTRIBUTE: &str = "googclient_schemarevisionid";
+
+/// The attribute with the encoding of a message, `JSON` or `BINARY`.
+///
+/// Pub/Sub sets this attribute on messages published to topics with a schema.
+pub const SCHEMA_ENCODING_ATTRIBUTE: &str = "googclient_schemaencoding";
+
+// The value of `SchemaSettings::schema` once the schema is deleted.
+const DELETED_SCHEMA: &str = "_deleted-schema_";
+
+#[derive(Debug)]
+enum ParsedSchema {
+    Avro(AvroSchema),
+    Protobuf(ProtoSchema),
+}
+
+impl ParsedSchema {
+    fn new(schema: &Schema) -> Result<Self, SchemaError> {
+        match &schema.r#type {
+            Type::Avro => Ok(Self::Avro(AvroSchema::parse(&schema.definition)?)),
+            Type::ProtocolBuffer => Ok(Self::Protobuf(ProtoSchema::parse(&schema.definition)?)),
+            t => Err(SchemaError::Unsupported(format!(
+                "schema type {}",
+                t.name().unwrap_or("unknown")
+            ))),
+        }
+    }
+}
+
+/// Encodes and decodes message data with a schema revision.
+///
+/// Create instances with [SchemaCache::topic_codec] or [SchemaCache::codec],
+/// or with [SchemaCodec::new] if the schema definition is already known.
+///
+/// # Example
+/// ```
+/// # use google_cloud_pubsub::model::{Encoding, Schema, schema::Type};
+/// # use google_cloud_pubsub::schema::SchemaCodec;
+/// # fn sample() -> anyhow::Result<()> {
+/// let schema = Schema::new()
+///     .set_type(Type::Avro)
+///     .set_definition(r#"{"type": "record", "name": "R", "fields": [{"name": "id", "type": "long"}]}"#);
+/// let codec = SchemaCodec::new(schema, Encoding::Binary)?;
+/// let message = codec.encode(&serde_json::json!({"id": 42}))?;
+/// let value: serde_json::Value = codec.decode(&message)?;
+/// assert_eq!(value, serde_json::json!({"id": 42}));
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SchemaCodec {
+    schema: Arc<Schema>,
+    parsed: Arc<ParsedSchema>,
+    encoding: Encoding,
+}
+
+impl SchemaCodec {
+    /// Creates a codec for the given schema and encoding.
+    ///
+    /// The schema must include its definition. [Encoding::Unspecified] is
+    /// treated as [Encoding::Json], as the service does.
+    pub fn new(schema: Schema, encoding: Encoding) -> Result<Self, SchemaError> {
+        let parsed = ParsedSchema::new(&schema)?;
+        Self::from_parts(Arc::new(schema), Arc::new(parsed), encoding)
+    }
+
+    fn from_parts(
+        schema: Arc<Schema>,
+        parsed: Arc<ParsedSchema>,
+        encoding: Encoding,
+    ) -> Result<Self, SchemaError> {
+        let encoding = match encoding {
+            Encoding::Unspecified => Encoding::Json,
+            e @ (Encoding::Json | Encoding::Binary) => e,
+            e => {
+                return Err(SchemaError::Unsupported(format!(
+                    "encoding {}",
+                    e.name().unwrap_or("unknown")
+                )));
+            }
+        };
+        Ok(Self {
+            schema,
+            parsed,
+            encoding,
+        })
+    }
+
+    /// The schema revision used by this codec.
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// The encoding used to publish messages.
+    pub fn encoding(&self) -> &Encoding {
+        &self.encoding
+    }
+
+    /// Encodes `value` as the data of a new message.
+    ///
+    /// For Avro schemas this fails if the schema cannot encode `value`. For
+    /// Protocol Buffer schemas in the JSON encoding, `value` is serialized
+    /// without local checks.
+    ///
+    /// Use [encode_proto][Self::encode_proto] for Protocol Buffer schemas in
+    /// the binary encoding.
+    pub fn encode<T>(&self, value: &T) -> Result<Message, SchemaError>
+    where
+        T: serde::Serialize + ?Sized,
+    {
+        let value = serde_json::to_value(value).map_err(SchemaError::Serde)?;
+        let json = self.encoding == Encoding::Json;
+        let data = match self.parsed.as_ref() {
+            ParsedSchema::Avro(avro) => avro.encode(&value, json)?,
+            ParsedSchema::Protobuf(_) if json => {
+                serde_json::to_vec(&value).map_err(SchemaError::Serde)?
+            }
+            ParsedSchema::Protobuf(_) => {
+                return Err(SchemaError::Unsupported(
+                    "use encode_proto() for binary Protocol Buffer messages".into(),
+                ));
+            }
+        };
+        Ok(Message::new().set_data(data))
+    }
+
+    /// Encodes a Protocol Buffer message as the data of a new message.
+    ///
+    /// The type of `value` must be the top-level message of the schema, and
+    /// the codec must use the binary encoding. Only the name of the type is
+    /// checked, the service validates the fields when the message is
+    /// published.
+    pub fn encode_proto<T>(&self, value: &T) -> Result<Message, SchemaError>
+    where
+        T: prost::Message + prost::Name,
+    {
+        let proto = self.proto(&self.encoding)?;
+        proto.check_type(&T::full_name())?;
+        Ok(Message::new().set_data(value.encode_to_vec()))
+    }
+
+    /// Decodes the data of a message.
+    ///
+    /// The encoding is taken from the [SCHEMA_ENCODING_ATTRIBUTE] attribute,
+    /// if present, and from the codec otherwise.
+    ///
+    /// Use [decode_proto][Self::decode_proto] for Protocol Buffer schemas in
+    /// the binary encoding.
+    pub fn decode<T>(&self, message: &Message) -> Result<T, SchemaError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let encoding = self.message_encoding(message);
+        let value = match self.parsed.as_ref() {
+            ParsedSchema::Avro(avro) => avro.decode(&message.data, encoding == Encoding::Json)?,
+            ParsedSchema::Protobuf(_) if encoding == Encoding::Json => {
+                serde_json::from_slice(&message.data)
+                    .map_err(|e| SchemaError::Decode(e.to_string()))?
+            }
+            ParsedSchema::Protobuf(_) => {
+                return Err(SchemaError::Unsupported(
+                    "use decode_proto() for binary Protocol Buffer messages".into(),
+                ));
+            }
+        };
+        serde_json::from_value(value).map_err(SchemaError::Serde)
+    }
+
+    /// Decodes the data of a message as a Protocol Buffer message.
+    ///
+    /// The type `T` must be the top-level message of the schema, and the
+    /// message must use the binary encoding.
+    pub fn decode_proto<T>(&self, message: &Message) -> Result<T, SchemaError>
+    where
+        T: prost::Message + prost::Name + Default,
+    {
+        let proto = self.proto(&self.message_encoding(message))?;
+        proto.check_type(&T::full_name())?;
+        T::decode(message.data.clone()).map_err(|e| SchemaError::Decode(e.to_string()))
+    }
+
+    fn proto(&self, encoding: &Encoding) -> Result<&ProtoSchema, SchemaError> {
+        match (self.parsed.as_ref(), encoding) {
+            (ParsedSchema::Protobuf(proto), Encoding::Binary) => Ok(proto),
+            (ParsedSchema::Protobuf(_), _) => Err(SchemaError::Unsupported(
+                "Protocol Buffer messages in the JSON encoding require a serde type".into(),
+            )),
+            (ParsedSchema::Avro(_), _) => Err(SchemaError::Unsupported(
+                "Avro schemas require a serde type".into(),
+            )),
+        }
+    }
+
+    fn message_encoding(&self, message: &Message) -> Encoding {
+        message
+            .attributes
+            .get(SCHEMA_ENCODING_ATTRIBUTE)
+            .map(|e| Encoding::from(e.as_str()))
+            .filter(|e| matches!(e, Encoding::Json | Encoding::Binary))
+            .unwrap_or_else(|| self.encoding.clone())
+    }
+}
+
+#[derive(Debug)]
+struct CachedRevision {
+    schema: Arc<Schema>,
+    parsed: Arc<ParsedSchema>,
+}
+
+/// Fetches and caches schema revisions.
+///
+/// Schema revisions are immutable, so they are fetched from the service at
+/// most once (unless concurrent requests race to fetch the same revision)
+/// and kept for the lifetime of the cache. Clones share the same cache.
+///
+/// # Example
+/// ```
+/// # use google_cloud_pubsub::client::SchemaService;
+/// # use google_cloud_pubsub::model::Encoding;
+/// # use google_cloud_pubsub::schema::SchemaCache;
+/// # async fn sample() -> anyhow::Result<()> {
+/// let cache = SchemaCache::new(SchemaService::builder().build().await?);
+/// let codec = cache
+///     .codec("projects/my-project/schemas/my-schema", "a1b2c3d4", Encoding::Binary)
+///     .await?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SchemaCache {
+    client: SchemaService,
+    revisions: Arc<Mutex<HashMap<String, Arc<CachedRevision>>>>,
+}
+
+impl SchemaCache {
+    /// Creates a cache that fetches schemas with `client`.
+    pub fn new(client: SchemaService) -> Self {
+        Self {
+            client,
+            revisions: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Returns a codec for a schema revision.
+    ///
+    /// If `revision_id` is empty, this fetches the latest revision of the
+    /// schema.
+    pub async fn codec(
+        &self,
+        name: &str,
+        revision_id: &str,
+        encoding: Encoding,
+    ) -> Result<SchemaCodec, SchemaError> {
+        let revision = self.revision(name, revision_id).await?;
+        SchemaCodec::from_parts(revision.schema.clone(), revision.parsed.clone(), encoding)
+    }
+
+    /// Returns a codec for publishing messages to `topic`.
+    ///
+    /// This uses the schema and encoding configured in the topic. If the topic
+    /// restricts the schema revisions, the codec uses the last revision
+    /// allowed.
+    pub async fn topic_codec(
+        &self,
+        client: &TopicAdmin,
+        topic: &str,
+    ) -> Result<SchemaCodec, SchemaError> {
+        let topic = client
+            .get_topic()
+            .set_topic(topic)
+            .send()
+            .await
+            .map_err(SchemaError::Rpc)?;
+        let settings = topic
+            .schema_settings
+            .filter(|s| !s.schema.is_empty() && s.schema != DELETED_SCHEMA)
+            .ok_or_else(|| SchemaError::NoSchema(topic.name.clone()))?;
+        self.codec(
+            &settings.schema,
+            &settings.last_revision_id,
+            settings.encoding,
+        )
+        .await
+    }
+
+    /// Decodes a received message with the schema revision it was published
+    /// with.
+    ///
+    /// The revision is identified by the [SCHEMA_NAME_ATTRIBUTE] and
+    /// [SCHEMA_REVISION_ID_ATTRIBUTE] attributes. See [SchemaCodec::decode].
+    pub async fn decode<T>(&self, message: &Message) -> Result<T, SchemaError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.message_codec(message).await?.decode(message)
+    }
+
+    /// Decodes a received message as a Protocol Buffer message, with the
+    /// schema revision it was published with.
+    ///
+    /// See [SchemaCodec::decode_proto].
+    pub async fn decode_proto<T>(&self, message: &Message) -> Result<T, SchemaError>
+    where
+        T: prost::Message + prost::Name + Default,
+    {
+        self.message_codec(message).await?.decode_proto(message)
+    }
+
+    async fn message_codec(&self, message: &Message) -> Result<SchemaCodec, SchemaError> {
+        let attribute = |name: &'static str| {
+            message
+                .attributes
+                .get(name)
+                .map(String::as_str)
+                .ok_or(SchemaError::MissingAttribute(name))
+        };
+        let name = attribute(SCHEMA_NAME_ATTRIBUTE)?;
+        let revision_id = attribute(SCHEMA_REVISION_ID_ATTRIBUTE)?;
+        let encoding = attribute(SCHEMA_ENCODING_ATTRIBUTE)
+            .map(Encoding::from)
+            .unwrap_or(Encoding::Unspecified);
+        self.codec(name, revision_id, encoding).await
+    }
+
+    async fn revision(
+        &self,
+        name: &str,
+        revision_id: &str,
+    ) -> Result<Arc<CachedRevision>, SchemaError> {
+        let key = format!("{name}@{revision_id}");
+        let cached = (!revision_id.is_empty())
+            .then(|| {
+                self.revisions
+                    .lock()
+                    .expect("never poisoned")
+                    .get(&key)
+                    .cloned()
+            })
+            .flatten();
+        if let Some(cached) = cached {
+            return Ok(cached);
+        }
+        let request_name = if revision_id.is_empty() {
+            name
+        } else {
+            key.as_str()
+        };
+        let schema = self
+            .client
+            .get_schema()
+            .set_name(request_name)
+            .set_view(SchemaView::Full)
+            .send()
+            .await
+            .map_err(SchemaError::Rpc)?;
+        let parsed = ParsedSchema::new(&schema)?;
+        let key = format!("{name}@{}", schema.revision_id);
+        let revision = Arc::new(CachedRevision {
+            schema: Arc::new(schema),
+            parsed: Arc::new(parsed),
+        });
+        self.revisions
+            .lock()
+            .expect("never poisoned")
+            .insert(key, revision.clone());
+        Ok(revision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::google::pubsub::v1::Schema as ProtoSchemaMessage;
+    use crate::model::{GetSchemaRequest, GetTopicRequest, SchemaSettings, Topic};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const AVRO: &str = r#"{
+        "type": "record",
+        "name": "Order",
+        "fields": [
+            {"name": "id", "type": "string"},
+            {"name": "quantity", "type": "int"},
+            {"name": "note", "type": ["null", "string"], "default": null}
+        ]
+    }"#;
+
+    // The `google.pubsub.v1.Schema` message, which has a `prost` type.
+    const PROTO: &str = r#"
+        syntax = "proto3";
+        package google.pubsub.v1;
+        message Schema {
+            string name = 1;
+            Type type = 2;
+            string definition = 3;
+            string revision_id = 4;
+            google.protobuf.Timestamp revision_create_time = 6;
+        }
+    "#;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Order {
+        id: String,
+        quantity: i32,
+        note: Option<String>,
+    }
+
+    fn order() -> Order {
+        Order {
+            id: "order-1".into(),
+            quantity: 2,
+            note: None,
+        }
+    }
+
+    fn avro_schema() -> Schema {
+        Schema::new().set_type(Type::Avro).set_definition(AVRO)
+    }
+
+    fn proto_schema() -> Schema {
+        Schema::new()
+            .set_type(Type::ProtocolBuffer)
+            .set_definition(PROTO)
+    }
+
+    #[test]
+    fn avro() -> anyhow::Result<()> {
+        for encoding in [Encoding::Json, Encoding::Binary] {
+            let codec = SchemaCodec::new(avro_schema(), encoding.clone())?;
+            let message = codec.encode(&order())?;
+            let got: Order = codec.decode(&message)?;
+            assert_eq!(got, order(), "{encoding:?}");
+        }
+
+        let codec = SchemaCodec::new(avro_schema(), Encoding::Unspecified)?;
+        assert_eq!(codec.encoding(), &Encoding::Json);
+        let message = codec.encode(&order())?;
+        let json: serde_json::Value = serde_json::from_slice(&message.data)?;
+        assert_eq!(
+            json,
+            serde_json::json!({"id": "order-1", "quantity": 2, "note": null})
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn encoding_attribute() -> anyhow::Result<()> {
+        let binary = SchemaCodec::new(avro_schema(), Encoding::Binary)?;
+        let json = SchemaCodec::new(avro_schema(), Encoding::Json)?;
+        let message = json
+            .encode(&order())?
+            .set_attributes([(SCHEMA_ENCODING_ATTRIBUTE, "JSON")]);
+        let got: Order = binary.decode(&message)?;
+        assert_eq!(got, order());
+        Ok(())
+    }
+
+    #[test]
+    fn avro_validation() -> anyhow::Result<()> {
+        let codec = SchemaCodec::new(avro_schema(), Encoding::Binary)?;
+        let err = codec
+            .encode(&serde_json::json!({"id": "order-1", "quantity": "two"}))
+            .unwrap_err();
+        assert!(matches!(err, SchemaError::Validation(_)), "{err:?}");
+        let err = codec
+            .encode_proto(&ProtoSchemaMessage::default())
+            .unwrap_err();
+        assert!(matches!(err, SchemaError::Unsupported(_)), "{err:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn protobuf_binary() -> anyhow::Result<()> {
+        let codec = SchemaCodec::new(proto_schema(), Encoding::Binary)?;
+        let value = ProtoSchemaMessage {
+            name: "projects/p/schemas/s".into(),
+            definition: "syntax = \"proto3\";".into(),
+            ..Default::default()
+        };
+        let message = codec.encode_proto(&value)?;
+        let got: ProtoSchemaMessage = codec.decode_proto(&message)?;
+        assert_eq!(got, value);
+
+        let err = codec.encode(&order()).unwrap_err();
+        assert!(matches!(err, SchemaError::Unsupported(_)), "{err:?}");
+        let err = codec
+            .encode_proto(&crate::google::pubsub::v1::Topic::default())
+            .unwrap_err();
+        assert!(matches!(err, SchemaError::Validation(_)), "{err:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn protobuf_json() -> anyhow::Result<()> {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct SchemaJson {
+            name: String,
+            revision_id: String,
+        }
+        let codec = SchemaCodec::new(proto_schema(), Encoding::Json)?;
+        let value = SchemaJson {
+            name: "projects/p/schemas/s".into(),
+            revision_id: "abc".into(),
+        };
+        let message = codec.encode(&value)?;
+        let got: SchemaJson = codec.decode(&message)?;
+        assert_eq!(got, value);
+
+        let err = codec
+            .decode_proto::<ProtoSchemaMessage>(&message)
+            .unwrap_err();
+        assert!(matches!(err, SchemaError::Unsupported(_)), "{err:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported() {
+        let err = SchemaCodec::new(Schema::new(), Encoding::Json).unwrap_err();
+        assert!(matches!(err, SchemaError::Unsupported(_)), "{err:?}");
+        let err = SchemaCodec::new(
+            Schema::new().set_type(Type::Avro).set_definition("{"),
+            Encoding::Json,
+        )
+        .unwrap_err();
+        assert!(matches!(err, SchemaError::InvalidDefinition(_)), "{err:?}");
+    }
+
+    #[derive(Debug, Default)]
+    struct FakeSchemaService {
+        calls: AtomicUsize,
+    }
+
+    impl crate::stub::SchemaService for FakeSchemaService {
+        async fn get_schema(
+            &self,
+            req: GetSchemaRequest,
+            _options: crate::RequestOptions,
+        ) -> crate::Result<crate::Response<Schema>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            assert_eq!(req.view, SchemaView::Full);
+            let (name, revision_id) = req.name.split_once('@').unwrap_or((&req.name, "latest"));
+            if name != "projects/p/schemas/s" {
+                return Err(crate::Error::service(
+                    google_cloud_gax::error::rpc::Status::default()
+                        .set_code(google_cloud_gax::error::rpc::Code::NotFound),
+                ));
+            }
+            Ok(crate::Response::from(
+                avro_schema().set_name(name).set_revision_id(revision_id),
+            ))
+        }
+    }
+
+    #[derive(Debug)]
+    struct FakeTopicAdmin(Option<SchemaSettings>);
+
+    impl crate::stub::TopicAdmin for FakeTopicAdmin {
+        async fn get_topic(
+            &self,
+            req: GetTopicRequest,
+            _options: crate::RequestOptions,
+        ) -> crate::Result<crate::Response<Topic>> {
+            Ok(crate::Response::from(
+                Topic::new()
+                    .set_name(req.topic)
+                    .set_or_clear_schema_settings(self.0.clone()),
+            ))
+        }
+    }
+
+    fn cache() -> (SchemaCache, Arc<FakeSchemaService>) {
+        let stub = Arc::new(FakeSchemaService::default());
+        let cache = SchemaCache::new(SchemaService::from_stub::<FakeSchemaService>(stub.clone()));
+        (cache, stub)
+    }
+
+    fn received(revision_id: &str, data: bytes::Bytes) -> Message {
+        Message::new().set_data(data).set_attributes([
+            (SCHEMA_NAME_ATTRIBUTE, "projects/p/schemas/s"),
+            (SCHEMA_REVISION_ID_ATTRIBUTE, revision_id),
+            (SCHEMA_ENCODING_ATTRIBUTE, "BINARY"),
+        ])
+    }
+
+    #[tokio::test]
+    async fn cache_decode() -> anyhow::Result<()> {
+        let (cache, stub) = cache();
+        let data = SchemaCodec::new(avro_schema(), Encoding::Binary)?
+            .encode(&order())?
+            .data;
+
+        for _ in 0..3 {
+            let got: Order = cache.decode(&received("r1", data.clone())).await?;
+            assert_eq!(got, order());
+        }
+        assert_eq!(stub.calls.load(Ordering::SeqCst), 1);
+
+        let got: Order = cache.decode(&received("r2", data.clone())).await?;
+        assert_eq!(got, order());
+        assert_eq!(stub.calls.load(Ordering::SeqCst), 2);
+
+        let err = cache
+            .decode::<Order>(&Message::new().set_data(data))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, SchemaError::MissingAttribute(SCHEMA_NAME_ATTRIBUTE)),
+            "{err:?}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cache_rpc_error() {
+        let (cache, _) = cache();
+        let err = cache
+            .codec("projects/p/schemas/missing", "r1", Encoding::Json)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SchemaError::Rpc(_)), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn topic_codec() -> anyhow::Result<()> {
+        let (cache, stub) = cache();
+        let topics = TopicAdmin::from_stub(FakeTopicAdmin(Some(
+            SchemaSettings::new()
+                .set_schema("projects/p/schemas/s")
+                .set_encoding(Encoding::Binary)
+                .set_last_revision_id("r1"),
+        )));
+        let codec = cache.topic_codec(&topics, "projects/p/topics/t").await?;
+        assert_eq!(codec.schema().revision_id, "r1");
+        assert_eq!(codec.encoding(), &Encoding::Binary);
+
+        // The latest revision is cached by its revision id.
+        let topics = TopicAdmin::from_stub(FakeTopicAdmin(Some(
+            SchemaSettings::new().set_schema("projects/p/schemas/s"),
+        )));
+        let codec = cache.topic_codec(&topics, "projects/p/topics/t").await?;
+        assert_eq!(codec.schema().revision_id, "latest");
+        assert_eq!(codec.encoding(), &Encoding::Json);
+        cache
+            .codec("projects/p/schemas/s", "latest", Encoding::Json)
+            .await?;
+        assert_eq!(stub.calls.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn topic_without_schema() {
+        let (cache, _) = cache();
+        for settings in [None, Some(SchemaSettings::new().set_schema(DELETED_SCHEMA))] {
+            let topics = TopicAdmin::from_stub(FakeTopicAdmin(settings));
+            let err = cache
+                .topic_codec(&topics, "projects/p/topics/t")
+                .await
+                .unwrap_err();
+            assert!(
+                matches!(&err, SchemaError::NoSchema(t) if t == "projects/p/topics/t"),
+                "{err:?}"
+            );
+        }
+    }
+}
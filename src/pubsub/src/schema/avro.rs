@@ -0,0 +1,1070 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A minimal implementation of the [Avro] binary and JSON encodings.
+//!
+//! Values are exchanged with the application as `serde_json::Value`, which is
+//! the data model of `serde`. The schema resolves each value to a [Datum]
+//! before encoding it, and decodes the message data to a [Datum] before
+//! converting it back.
+//!
+//! Resolving a value checks that the schema can encode it, which is not a
+//! complete validation: logical types use their underlying type without
+//! further checks, aliases are ignored, and decoding assumes the data was
+//! written with the same schema.
+//!
+//! [Avro]: https://avro.apache.org/docs/1.11.1/specification/
+
+use crate::error::SchemaError;
+use serde_json::{Map, Number, Value};
+use std::collections::HashMap;
+
+/// The maximum nesting of decoded values.
+const MAX_DEPTH: usize = 64;
+
+/// The maximum number of array items and map entries in a decoded value.
+///
+/// Items of types such as `null` take no space in the encoded data, so their
+/// count is not limited by the size of the message.
+const MAX_ITEMS: usize = 1 << 20;
+
+/// A parsed Avro schema.
+#[derive(Clone, Debug)]
+pub(crate) struct AvroSchema {
+    root: Schema,
+    // Named types by their full name. References to named types are resolved
+    // through this table, which allows recursive types.
+    names: HashMap<String, Schema>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Schema {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Record { name: String, fields: Vec<Field> },
+    Enum { name: String, symbols: Vec<String> },
+    Array(Box<Schema>),
+    Map(Box<Schema>),
+    Union(Vec<Schema>),
+    Fixed { name: String, size: usize },
+    Ref(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Field {
+    name: String,
+    schema: Schema,
+    default: Option<Value>,
+}
+
+/// A value resolved against a schema.
+#[derive(Clone, Debug, PartialEq)]
+enum Datum {
+    Null,
+    Boolean(bool),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    Bytes(Vec<u8>),
+    String(String),
+    Record(Vec<(String, Datum)>),
+    Enum(usize, String),
+    Array(Vec<Datum>),
+    Map(Vec<(String, Datum)>),
+    Union(usize, Box<Datum>),
+    Fixed(Vec<u8>),
+}
+
+/// How JSON values represent Avro values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Mode {
+    /// The `serde` data model: union values are not tagged and bytes are
+    /// arrays of numbers.
+    Serde,
+    /// The Avro JSON encoding: non-null union values are wrapped in an object
+    /// keyed by the branch name, and bytes are strings of code points 0-255.
+    AvroJson,
+}
+
+fn invalid<T: std::fmt::Display>(msg: T) -> SchemaError {
+    SchemaError::Validation(msg.to_string())
+}
+
+fn decode_error<T: std::fmt::Display>(msg: T) -> SchemaError {
+    SchemaError::Decode(msg.to_string())
+}
+
+fn in_field(name: &str, e: SchemaError) -> SchemaError {
+    match e {
+        SchemaError::Validation(msg) => SchemaError::Validation(format!("{name}: {msg}")),
+        e => e,
+    }
+}
+
+impl AvroSchema {
+    /// Parses an Avro schema definition in JSON format.
+    pub(crate) fn parse(definition: &str) -> Result<Self, SchemaError> {
+        let json: Value = serde_json::from_str(definition)
+            .map_err(|e| SchemaError::InvalidDefinition(e.to_string()))?;
+        let mut names = HashMap::new();
+        let root = parse_schema(&json, "", &mut names)?;
+        Ok(Self { root, names })
+    }
+
+    /// Validates a value in the `serde` data model and encodes it.
+    pub(crate) fn encode(&self, value: &Value, json: bool) -> Result<Vec<u8>, SchemaError> {
+        let datum = self.resolve(&self.root, value, Mode::Serde)?;
+        if json {
+            let value = self.to_avro_json(&self.root, &datum)?;
+            return serde_json::to_vec(&value).map_err(SchemaError::Serde);
+        }
+        let mut buf = Vec::new();
+        write_binary(&datum, &mut buf);
+        Ok(buf)
+    }
+
+    /// Decodes the message data into a value in the `serde` data model.
+    pub(crate) fn decode(&self, data: &[u8], json: bool) -> Result<Value, SchemaError> {
+        let datum = if json {
+            let value: Value = serde_json::from_slice(data).map_err(decode_error)?;
+            self.resolve(&self.root, &value, Mode::AvroJson)
+                .map_err(|e| match e {
+                    SchemaError::Validation(msg) => SchemaError::Decode(msg),
+                    e => e,
+                })?
+        } else {
+            let mut reader = Reader {
+                data,
+                pos: 0,
+                items: 0,
+            };
+            let datum = self.read_binary(&self.root, &mut reader, 0)?;
+            if reader.pos != data.len() {
+                return Err(decode_error("unexpected trailing data"));
+            }
+            datum
+        };
+        to_serde(datum)
+    }
+
+    fn lookup<'a>(&'a self, schema: &'a Schema) -> &'a Schema {
+        match schema {
+            Schema::Ref(name) => self.names.get(name).unwrap_or(schema),
+            s => s,
+        }
+    }
+
+    fn resolve(&self, schema: &Schema, value: &Value, mode: Mode) -> Result<Datum, SchemaError> {
+        let mismatch = |expected: &str| invalid(format!("expected {expected}, got {value}"));
+        let datum = match self.lookup(schema) {
+            Schema::Null => value
+                .is_null()
+                .then_some(Datum::Null)
+                .ok_or_else(|| mismatch("null"))?,
+            Schema::Boolean => Datum::Boolean(value.as_bool().ok_or_else(|| mismatch("boolean"))?),
+            Schema::Int => Datum::Int(
+                value
+                    .as_i64()
+                    .and_then(|v| i32::try_from(v).ok())
+                    .ok_or_else(|| mismatch("int"))?,
+            ),
+            Schema::Long => Datum::Long(value.as_i64().ok_or_else(|| mismatch("long"))?),
+            Schema::Float => Datum::Float(value.as_f64().ok_or_else(|| mismatch("float"))? as f32),
+            Schema::Double => Datum::Double(value.as_f64().ok_or_else(|| mismatch("double"))?),
+            Schema::Bytes => Datum::Bytes(to_bytes(value, mode).ok_or_else(|| mismatch("bytes"))?),
+            Schema::String => Datum::String(
+                value
+                    .as_str()
+                    .ok_or_else(|| mismatch("string"))?
+                    .to_string(),
+            ),
+            Schema::Fixed { name, size } => {
+                let bytes = to_bytes(value, mode)
+                    .filter(|b| b.len() == *size)
+                    .ok_or_else(|| mismatch(&format!("{size} bytes for {name}")))?;
+                Datum::Fixed(bytes)
+            }
+            Schema::Enum { name, symbols } => {
+                let symbol = value.as_str().ok_or_else(|| mismatch(name))?;
+                let index = symbols
+                    .iter()
+                    .position(|s| s == symbol)
+                    .ok_or_else(|| invalid(format!("{symbol:?} is not a symbol of {name}")))?;
+                Datum::Enum(index, symbol.to_string())
+            }
+            Schema::Array(items) => {
+                let values = value.as_array().ok_or_else(|| mismatch("array"))?;
+                let items = values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| {
+                        self.resolve(items, v, mode)
+                            .map_err(|e| in_field(&format!("[{i}]"), e))
+                    })
+                    .collect::<Result<_, _>>()?;
+                Datum::Array(items)
+            }
+            Schema::Map(values) => {
+                let object = value.as_object().ok_or_else(|| mismatch("map"))?;
+                let entries = object
+                    .iter()
+                    .map(|(k, v)| {
+                        let datum = self.resolve(values, v, mode).map_err(|e| in_field(k, e))?;
+                        Ok((k.clone(), datum))
+                    })
+                    .collect::<Result<_, SchemaError>>()?;
+                Datum::Map(entries)
+            }
+            Schema::Record { name, fields } => {
+                let object = value.as_object().ok_or_else(|| mismatch(name))?;
+                if let Some(unknown) = object.keys().find(|k| fields.iter().all(|f| &f.name != *k))
+                {
+                    return Err(invalid(format!("{unknown:?} is not a field of {name}")));
+                }
+                let fields = fields
+                    .iter()
+                    .map(|field| {
+                        let datum = match (object.get(&field.name), &field.default) {
+                            (Some(v), _) => self.resolve(&field.schema, v, mode),
+                            // Defaults use the Avro JSON encoding, except that
+                            // union defaults are not tagged.
+                            (None, Some(default)) => {
+                                self.resolve(&field.schema, default, Mode::Serde)
+                            }
+                            (None, None) => self
+                                .resolve(&field.schema, &Value::Null, mode)
+                                .map_err(|_| invalid("missing required field")),
+                        };
+                        Ok((
+                            field.name.clone(),
+                            datum.map_err(|e| in_field(&field.name, e))?,
+                        ))
+                    })
+                    .collect::<Result<_, SchemaError>>()?;
+                Datum::Record(fields)
+            }
+            Schema::Union(branches) => self.resolve_union(branches, value, mode)?,
+            Schema::Ref(name) => return Err(invalid(format!("unknown type {name}"))),
+        };
+        Ok(datum)
+    }
+
+    fn resolve_union(
+        &self,
+        branches: &[Schema],
+        value: &Value,
+        mode: Mode,
+    ) -> Result<Datum, SchemaError> {
+        let tagged = value
+            .as_object()
+            .filter(|o| o.len() == 1)
+            .and_then(|o| o.iter().next())
+            .and_then(|(tag, inner)| {
+                branches
+                    .iter()
+                    .position(|b| self.branch_name(b) == *tag)
+                    .map(|i| (i, inner))
+            });
+        match (mode, value, tagged) {
+            (_, Value::Null, _) => {
+                let index = branches
+                    .iter()
+                    .position(|b| *self.lookup(b) == Schema::Null)
+                    .ok_or_else(|| invalid("null is not allowed"))?;
+                return Ok(Datum::Union(index, Box::new(Datum::Null)));
+            }
+            (_, _, Some((index, inner))) => {
+                if let Ok(datum) = self.resolve(&branches[index], inner, mode) {
+                    return Ok(Datum::Union(index, Box::new(datum)));
+                }
+            }
+            (Mode::AvroJson, _, None) => {
+                return Err(invalid(format!(
+                    "expected a tagged union value, got {value}"
+                )));
+            }
+            (Mode::Serde, _, None) => {}
+        }
+        if mode == Mode::Serde {
+            for (index, branch) in branches.iter().enumerate() {
+                if let Ok(datum) = self.resolve(branch, value, mode) {
+                    return Ok(Datum::Union(index, Box::new(datum)));
+                }
+            }
+        }
+        Err(invalid(format!(
+            "{value} does not match any branch of the union"
+        )))
+    }
+
+    fn branch_name(&self, schema: &Schema) -> String {
+        match schema {
+            Schema::Null => "null".into(),
+            Schema::Boolean => "boolean".into(),
+            Schema::Int => "int".into(),
+            Schema::Long => "long".into(),
+            Schema::Float => "float".into(),
+            Schema::Double => "double".into(),
+            Schema::Bytes => "bytes".into(),
+            Schema::String => "string".into(),
+            Schema::Array(_) => "array".into(),
+            Schema::Map(_) => "map".into(),
+            Schema::Union(_) => "union".into(),
+            Schema::Record { name, .. }
+            | Schema::Enum { name, .. }
+            | Schema::Fixed { name, .. } => name.clone(),
+            Schema::Ref(name) => name.clone(),
+        }
+    }
+
+    fn to_avro_json(&self, schema: &Schema, datum: &Datum) -> Result<Value, SchemaError> {
+        let value = match (self.lookup(schema), datum) {
+            (_, Datum::Null) => Value::Null,
+            (_, Datum::Boolean(b)) => Value::Bool(*b),
+            (_, Datum::Int(v)) => Value::from(*v),
+            (_, Datum::Long(v)) => Value::from(*v),
+            (_, Datum::Float(v)) => float(f64::from(*v))?,
+            (_, Datum::Double(v)) => float(*v)?,
+            (_, Datum::Bytes(b) | Datum::Fixed(b)) => {
+                Value::String(b.iter().map(|b| char::from(*b)).collect())
+            }
+            (_, Datum::String(s)) => Value::String(s.clone()),
+            (_, Datum::Enum(_, symbol)) => Value::String(symbol.clone()),
+            (Schema::Array(items), Datum::Array(values)) => Value::Array(
+                values
+                    .iter()
+                    .map(|d| self.to_avro_json(items, d))
+                    .collect::<Result<_, _>>()?,
+            ),
+            (Schema::Map(values), Datum::Map(entries)) => Value::Object(
+                entries
+                    .iter()
+                    .map(|(k, d)| Ok((k.clone(), self.to_avro_json(values, d)?)))
+                    .collect::<Result<Map<_, _>, SchemaError>>()?,
+            ),
+            (Schema::Record { fields, .. }, Datum::Record(values)) => Value::Object(
+                fields
+                    .iter()
+                    .zip(values)
+                    .map(|(f, (k, d))| Ok((k.clone(), self.to_avro_json(&f.schema, d)?)))
+                    .collect::<Result<Map<_, _>, SchemaError>>()?,
+            ),
+            (Schema::Union(branches), Datum::Union(index, inner)) => {
+                let branch = &branches[*index];
+                match inner.as_ref() {
+                    Datum::Null => Value::Null,
+                    inner => {
+                        let mut object = Map::new();
+                        object.insert(self.branch_name(branch), self.to_avro_json(branch, inner)?);
+                        Value::Object(object)
+                    }
+                }
+            }
+            (schema, datum) => {
+                return Err(invalid(format!("{datum:?} does not match {schema:?}")));
+            }
+        };
+        Ok(value)
+    }
+
+    /// Returns true if every value of `schema` takes at least one byte in the
+    /// binary encoding.
+    fn has_width(&self, schema: &Schema, depth: usize) -> bool {
+        if depth > MAX_DEPTH {
+            return false;
+        }
+        match self.lookup(schema) {
+            Schema::Null | Schema::Ref(_) => false,
+            Schema::Fixed { size, .. } => *size > 0,
+            Schema::Record { fields, .. } => {
+                fields.iter().any(|f| self.has_width(&f.schema, depth + 1))
+            }
+            _ => true,
+        }
+    }
+
+    fn read_binary(
+        &self,
+        schema: &Schema,
+        reader: &mut Reader,
+        depth: usize,
+    ) -> Result<Datum, SchemaError> {
+        if depth > MAX_DEPTH {
+            return Err(decode_error(format!(
+                "values are nested more than {MAX_DEPTH} levels deep"
+            )));
+        }
+        let datum = match self.lookup(schema) {
+            Schema::Null => Datum::Null,
+            Schema::Boolean => match reader.take(1)?[0] {
+                0 => Datum::Boolean(false),
+                1 => Datum::Boolean(true),
+                b => return Err(decode_error(format!("invalid boolean {b}"))),
+            },
+            Schema::Int => Datum::Int(
+                i32::try_from(reader.long()?).map_err(|_| decode_error("int out of range"))?,
+            ),
+            Schema::Long => Datum::Long(reader.long()?),
+            Schema::Float => {
+                let bytes = reader.take(4)?.try_into().expect("4 bytes");
+                Datum::Float(f32::from_le_bytes(bytes))
+            }
+            Schema::Double => {
+                let bytes = reader.take(8)?.try_into().expect("8 bytes");
+                Datum::Double(f64::from_le_bytes(bytes))
+            }
+            Schema::Bytes => Datum::Bytes(reader.bytes()?.to_vec()),
+            Schema::String => {
+                Datum::String(String::from_utf8(reader.bytes()?.to_vec()).map_err(decode_error)?)
+            }
+            Schema::Fixed { size, .. } => Datum::Fixed(reader.take(*size)?.to_vec()),
+            Schema::Enum { name, symbols } => {
+                let index = reader.long()?;
+                let symbol = usize::try_from(index)
+                    .ok()
+                    .and_then(|i| symbols.get(i))
+                    .ok_or_else(|| decode_error(format!("invalid index {index} for {name}")))?;
+                Datum::Enum(index as usize, symbol.clone())
+            }
+            Schema::Array(items) => {
+                let width = self.has_width(items, depth);
+                let mut values = Vec::new();
+                while let Some(count) = reader.block(width)? {
+                    for _ in 0..count {
+                        values.push(self.read_binary(items, reader, depth + 1)?);
+                    }
+                }
+                Datum::Array(values)
+            }
+            Schema::Map(values) => {
+                let mut entries = Vec::new();
+                // Each entry has a key, which takes at least one byte.
+                while let Some(count) = reader.block(true)? {
+                    for _ in 0..count {
+                        let key =
+                            String::from_utf8(reader.bytes()?.to_vec()).map_err(decode_error)?;
+                        entries.push((key, self.read_binary(values, reader, depth + 1)?));
+                    }
+                }
+                Datum::Map(entries)
+            }
+            Schema::Record { fields, .. } => Datum::Record(
+                fields
+                    .iter()
+                    .map(|f| {
+                        let datum = self.read_binary(&f.schema, reader, depth + 1)?;
+                        Ok((f.name.clone(), datum))
+                    })
+                    .collect::<Result<_, SchemaError>>()?,
+            ),
+            Schema::Union(branches) => {
+                let index = reader.long()?;
+                let branch = usize::try_from(index)
+                    .ok()
+                    .and_then(|i| branches.get(i))
+                    .ok_or_else(|| decode_error(format!("invalid union index {index}")))?;
+                Datum::Union(
+                    index as usize,
+                    Box::new(self.read_binary(branch, reader, depth + 1)?),
+                )
+            }
+            Schema::Ref(name) => return Err(decode_error(format!("unknown type {name}"))),
+        };
+        Ok(datum)
+    }
+}
+
+fn float(v: f64) -> Result<Value, SchemaError> {
+    Number::from_f64(v)
+        .map(Value::Number)
+        .ok_or_else(|| invalid(format!("{v} cannot be represented in JSON")))
+}
+
+fn to_bytes(value: &Value, mode: Mode) -> Option<Vec<u8>> {
+    match value {
+        Value::String(s) => s.chars().map(|c| u8::try_from(c).ok()).collect(),
+        Value::Array(a) if mode == Mode::Serde => a
+            .iter()
+            .map(|v| v.as_u64().and_then(|b| u8::try_from(b).ok()))
+            .collect(),
+        _ => None,
+    }
+}
+
+/// Converts a datum to the `serde` data model.
+fn to_serde(datum: Datum) -> Result<Value, SchemaError> {
+    let value = match datum {
+        Datum::Null => Value::Null,
+        Datum::Boolean(b) => Value::Bool(b),
+        Datum::Int(v) => Value::from(v),
+        Datum::Long(v) => Value::from(v),
+        Datum::Float(v) => float(f64::from(v)).map_err(|e| decode_error(e.to_string()))?,
+        Datum::Double(v) => float(v).map_err(|e| decode_error(e.to_string()))?,
+        Datum::Bytes(b) | Datum::Fixed(b) => Value::Array(b.into_iter().map(Value::from).collect()),
+        Datum::String(s) | Datum::Enum(_, s) => Value::String(s),
+        Datum::Array(values) => {
+            Value::Array(values.into_iter().map(to_serde).collect::<Result<_, _>>()?)
+        }
+        Datum::Map(entries) | Datum::Record(entries) => Value::Object(
+            entries
+                .into_iter()
+                .map(|(k, d)| Ok((k, to_serde(d)?)))
+                .collect::<Result<_, SchemaError>>()?,
+        ),
+        Datum::Union(_, inner) => to_serde(*inner)?,
+    };
+    Ok(value)
+}
+
+fn write_long(v: i64, buf: &mut Vec<u8>) {
+    let mut n = ((v << 1) ^ (v >> 63)) as u64;
+    while n >= 0x80 {
+        buf.push((n as u8) | 0x80);
+        n >>= 7;
+    }
+    buf.push(n as u8);
+}
+
+fn write_binary(datum: &Datum, buf: &mut Vec<u8>) {
+    match datum {
+        Datum::Null => {}
+        Datum::Boolean(b) => buf.push(u8::from(*b)),
+        Datum::Int(v) => write_long(i64::from(*v), buf),
+        Datum::Long(v) => write_long(*v, buf),
+        Datum::Float(v) => buf.extend_from_slice(&v.to_le_bytes()),
+        Datum::Double(v) => buf.extend_from_slice(&v.to_le_bytes()),
+        Datum::Bytes(b) => {
+            write_long(b.len() as i64, buf);
+            buf.extend_from_slice(b);
+        }
+        Datum::String(s) => {
+            write_long(s.len() as i64, buf);
+            buf.extend_from_slice(s.as_bytes());
+        }
+        Datum::Fixed(b) => buf.extend_from_slice(b),
+        Datum::Enum(index, _) => write_long(*index as i64, buf),
+        Datum::Array(values) => {
+            if !values.is_empty() {
+                write_long(values.len() as i64, buf);
+                values.iter().for_each(|d| write_binary(d, buf));
+            }
+            write_long(0, buf);
+        }
+        Datum::Map(entries) => {
+            if !entries.is_empty() {
+                write_long(entries.len() as i64, buf);
+                for (k, d) in entries {
+                    write_long(k.len() as i64, buf);
+                    buf.extend_from_slice(k.as_bytes());
+                    write_binary(d, buf);
+                }
+            }
+            write_long(0, buf);
+        }
+        Datum::Record(fields) => fields.iter().for_each(|(_, d)| write_binary(d, buf)),
+        Datum::Union(index, inner) => {
+            write_long(*index as i64, buf);
+            write_binary(inner, buf);
+        }
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    // The number of array items and map entries read so far.
+    items: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], SchemaError> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| decode_error("unexpected end of data"))?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn long(&mut self) -> Result<i64, SchemaError> {
+        let mut n = 0_u64;
+        for shift in (0..64).step_by(7) {
+            let b = self.take(1)?[0];
+            n |= u64::from(b & 0x7f) << shift;
+            if b & 0x80 == 0 {
+                return Ok(((n >> 1) as i64) ^ -((n & 1) as i64));
+            }
+        }
+        Err(decode_error("invalid variable-length integer"))
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8], SchemaError> {
+        let len = self.long()?;
+        let len =
+            usize::try_from(len).map_err(|_| decode_error(format!("invalid length {len}")))?;
+        self.take(len)
+    }
+
+    /// Reads the item count of the next block of an array or map, returning
+    /// `None` at the end.
+    ///
+    /// If each item takes at least one byte (`width` is true), the count
+    /// cannot exceed the remaining data. The total count is limited to
+    /// [MAX_ITEMS] in any case.
+    fn block(&mut self, width: bool) -> Result<Option<usize>, SchemaError> {
+        let count = self.long()?;
+        if count < 0 {
+            // A negative count is followed by the size of the block in bytes.
+            let _ = self.long()?;
+        }
+        let count = usize::try_from(count.unsigned_abs())
+            .map_err(|_| decode_error(format!("invalid item count {count}")))?;
+        if width && count > self.data.len() - self.pos {
+            return Err(decode_error(format!(
+                "{count} items exceed the remaining data"
+            )));
+        }
+        self.items = self
+            .items
+            .checked_add(count)
+            .filter(|n| *n <= MAX_ITEMS)
+            .ok_or_else(|| decode_error(format!("more than {MAX_ITEMS} items")))?;
+        Ok((count != 0).then_some(count))
+    }
+}
+
+fn definition_error<T: std::fmt::Display>(msg: T) -> SchemaError {
+    SchemaError::InvalidDefinition(msg.to_string())
+}
+
+fn full_name(
+    object: &Map<String, Value>,
+    namespace: &str,
+) -> Result<(String, String), SchemaError> {
+    let name = object
+        .get("name")
+        .and_then(Value::as_str)
+        .ok_or_else(|| definition_error("named types require a name"))?;
+    if name.contains('.') {
+        let namespace = name.rsplit_once('.').map(|(ns, _)| ns).unwrap_or_default();
+        return Ok((name.to_string(), namespace.to_string()));
+    }
+    let namespace = object
+        .get("namespace")
+        .and_then(Value::as_str)
+        .unwrap_or(namespace);
+    let full_name = if namespace.is_empty() {
+        name.to_string()
+    } else {
+        format!("{namespace}.{name}")
+    };
+    Ok((full_name, namespace.to_string()))
+}
+
+fn parse_schema(
+    json: &Value,
+    namespace: &str,
+    names: &mut HashMap<String, Schema>,
+) -> Result<Schema, SchemaError> {
+    match json {
+        Value::String(name) => parse_type_name(name, namespace, names),
+        Value::Array(branches) => {
+            let branches = branches
+                .iter()
+                .map(|b| parse_schema(b, namespace, names))
+                .collect::<Result<Vec<_>, _>>()?;
+            if branches.iter().any(|b| matches!(b, Schema::Union(_))) {
+                return Err(definition_error("unions may not contain unions"));
+            }
+            Ok(Schema::Union(branches))
+        }
+        Value::Object(object) => {
+            let type_name = object
+                .get("type")
+                .ok_or_else(|| definition_error("missing type"))?;
+            let type_name = match type_name {
+                Value::String(t) => t.as_str(),
+                // For example `{"type": {"type": "array", "items": "int"}}`.
+                other => return parse_schema(other, namespace, names),
+            };
+            match type_name {
+                "record" | "error" => {
+                    let (name, namespace) = full_name(object, namespace)?;
+                    // Register the name before parsing the fields to support
+                    // recursive types.
+                    names.insert(name.clone(), Schema::Ref(name.clone()));
+                    let fields = object
+                        .get("fields")
+                        .and_then(Value::as_array)
+                        .ok_or_else(|| definition_error(format!("record {name} requires fields")))?
+                        .iter()
+                        .map(|f| {
+                            let field_name = f
+                                .get("name")
+                                .and_then(Value::as_str)
+                                .ok_or_else(|| definition_error("fields require a name"))?;
+                            let schema = parse_schema(
+                                f.get("type").ok_or_else(|| {
+                                    definition_error(format!("field {field_name} requires a type"))
+                                })?,
+                                &namespace,
+                                names,
+                            )?;
+                            Ok(Field {
+                                name: field_name.to_string(),
+                                schema,
+                                default: f.get("default").cloned(),
+                            })
+                        })
+                        .collect::<Result<Vec<_>, SchemaError>>()?;
+                    let schema = Schema::Record {
+                        name: name.clone(),
+                        fields,
+                    };
+                    names.insert(name, schema.clone());
+                    Ok(schema)
+                }
+                "enum" => {
+                    let (name, _) = full_name(object, namespace)?;
+                    let symbols = object
+                        .get("symbols")
+                        .and_then(Value::as_array)
+                        .ok_or_else(|| definition_error(format!("enum {name} requires symbols")))?
+                        .iter()
+                        .map(|s| {
+                            s.as_str()
+                                .map(str::to_string)
+                                .ok_or_else(|| definition_error("enum symbols must be strings"))
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let schema = Schema::Enum {
+                        name: name.clone(),
+                        symbols,
+                    };
+                    names.insert(name, schema.clone());
+                    Ok(schema)
+                }
+                "fixed" => {
+                    let (name, _) = full_name(object, namespace)?;
+                    let size = object
+                        .get("size")
+                        .and_then(Value::as_u64)
+                        .and_then(|s| usize::try_from(s).ok())
+                        .ok_or_else(|| definition_error(format!("fixed {name} requires a size")))?;
+                    let schema = Schema::Fixed {
+                        name: name.clone(),
+                        size,
+                    };
+                    names.insert(name, schema.clone());
+                    Ok(schema)
+                }
+                "array" => {
+                    let items = object
+                        .get("items")
+                        .ok_or_else(|| definition_error("arrays require items"))?;
+                    Ok(Schema::Array(Box::new(parse_schema(
+                        items, namespace, names,
+                    )?)))
+                }
+                "map" => {
+                    let values = object
+                        .get("values")
+                        .ok_or_else(|| definition_error("maps require values"))?;
+                    Ok(Schema::Map(Box::new(parse_schema(
+                        values, namespace, names,
+                    )?)))
+                }
+                // Primitive types, possibly annotated with a logical type. The
+                // logical types use the encoding of the underlying type.
+                other => parse_type_name(other, namespace, names),
+            }
+        }
+        other => Err(definition_error(format!("unexpected schema {other}"))),
+    }
+}
+
+fn parse_type_name(
+    name: &str,
+    namespace: &str,
+    names: &HashMap<String, Schema>,
+) -> Result<Schema, SchemaError> {
+    let schema = match name {
+        "null" => Schema::Null,
+        "boolean" => Schema::Boolean,
+        "int" => Schema::Int,
+        "long" => Schema::Long,
+        "float" => Schema::Float,
+        "double" => Schema::Double,
+        "bytes" => Schema::Bytes,
+        "string" => Schema::String,
+        name => {
+            let qualified = format!("{namespace}.{name}");
+            let full_name = [qualified.as_str(), name]
+                .into_iter()
+                .find(|n| names.contains_key(*n))
+                .ok_or_else(|| definition_error(format!("unknown type {name}")))?;
+            Schema::Ref(full_name.to_string())
+        }
+    };
+    Ok(schema)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const USER: &str = r#"{
+        "type": "record",
+        "name": "User",
+        "namespace": "com.example",
+        "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "name", "type": "string"},
+            {"name": "email", "type": ["null", "string"], "default": null},
+            {"name": "age", "type": "int", "default": 0},
+            {"name": "score", "type": "double"},
+            {"name": "active", "type": "boolean"},
+            {"name": "role", "type": {"type": "enum", "name": "Role", "symbols": ["ADMIN", "USER"]}},
+            {"name": "tags", "type": {"type": "array", "items": "string"}},
+            {"name": "labels", "type": {"type": "map", "values": "long"}},
+            {"name": "avatar", "type": "bytes"},
+            {"name": "checksum", "type": {"type": "fixed", "name": "MD5", "size": 2}},
+            {"name": "created", "type": {"type": "long", "logicalType": "timestamp-millis"}}
+        ]
+    }"#;
+
+    fn user() -> Value {
+        json!({
+            "id": 42,
+            "name": "alice",
+            "email": "alice@example.com",
+            "age": 30,
+            "score": 1.5,
+            "active": true,
+            "role": "ADMIN",
+            "tags": ["a", "b"],
+            "labels": {"x": 1},
+            "avatar": [0, 255],
+            "checksum": [1, 2],
+            "created": 1_700_000_000_000_i64,
+        })
+    }
+
+    #[test]
+    fn binary_roundtrip() -> anyhow::Result<()> {
+        let schema = AvroSchema::parse(USER)?;
+        let encoded = schema.encode(&user(), false)?;
+        let decoded = schema.decode(&encoded, false)?;
+        assert_eq!(decoded, user());
+        Ok(())
+    }
+
+    #[test]
+    fn json_roundtrip() -> anyhow::Result<()> {
+        let schema = AvroSchema::parse(USER)?;
+        let encoded = schema.encode(&user(), true)?;
+        let json: Value = serde_json::from_slice(&encoded)?;
+        // The Avro JSON encoding tags union values and uses strings for bytes.
+        assert_eq!(json["email"], json!({"string": "alice@example.com"}));
+        assert_eq!(json["avatar"], json!("\u{0}\u{ff}"));
+        let decoded = schema.decode(&encoded, true)?;
+        assert_eq!(decoded, user());
+        Ok(())
+    }
+
+    #[test]
+    fn binary_encoding() -> anyhow::Result<()> {
+        let schema = AvroSchema::parse(
+            r#"{"type": "record", "name": "R", "fields": [
+                {"name": "a", "type": "long"},
+                {"name": "b", "type": "string"},
+                {"name": "c", "type": ["null", "int"]}
+            ]}"#,
+        )?;
+        let encoded = schema.encode(&json!({"a": -64, "b": "hi", "c": 1}), false)?;
+        // From the examples in the Avro specification.
+        assert_eq!(encoded, vec![0x7f, 0x04, b'h', b'i', 0x02, 0x02]);
+        let encoded = schema.encode(&json!({"a": 64, "b": "", "c": null}), false)?;
+        assert_eq!(encoded, vec![0x80, 0x01, 0x00, 0x00]);
+        Ok(())
+    }
+
+    #[test]
+    fn defaults() -> anyhow::Result<()> {
+        let schema = AvroSchema::parse(USER)?;
+        let mut value = user();
+        let object = value.as_object_mut().unwrap();
+        object.remove("email");
+        object.remove("age");
+        let decoded = schema.decode(&schema.encode(&value, false)?, false)?;
+        assert_eq!(decoded["email"], Value::Null);
+        assert_eq!(decoded["age"], json!(0));
+        Ok(())
+    }
+
+    #[test]
+    fn validation_errors() -> anyhow::Result<()> {
+        let schema = AvroSchema::parse(USER)?;
+        let cases = [
+            ("name", json!(1), "name"),
+            ("age", json!(1_i64 << 40), "age"),
+            ("role", json!("GUEST"), "GUEST"),
+            ("tags", json!(["a", 1]), "tags: [1]"),
+            ("checksum", json!([1, 2, 3]), "checksum"),
+            ("unknown", json!(1), "unknown"),
+        ];
+        for (field, v, want) in cases {
+            let mut value = user();
+            value[field] = v;
+            let err = schema.encode(&value, false).unwrap_err();
+            assert!(matches!(err, SchemaError::Validation(_)), "{err:?}");
+            assert!(err.to_string().contains(want), "{field}: {err}");
+        }
+
+        let mut value = user();
+        value.as_object_mut().unwrap().remove("id");
+        let err = schema.encode(&value, false).unwrap_err();
+        assert!(err.to_string().contains("id: missing"), "{err}");
+        Ok(())
+    }
+
+    #[test]
+    fn recursive_types() -> anyhow::Result<()> {
+        let schema = AvroSchema::parse(
+            r#"{"type": "record", "name": "Node", "fields": [
+                {"name": "value", "type": "int"},
+                {"name": "next", "type": ["null", "Node"]}
+            ]}"#,
+        )?;
+        let value = json!({"value": 1, "next": {"value": 2, "next": null}});
+        for json in [false, true] {
+            let decoded = schema.decode(&schema.encode(&value, json)?, json)?;
+            assert_eq!(decoded, value);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn decode_errors() -> anyhow::Result<()> {
+        let schema = AvroSchema::parse(r#"{"type": "enum", "name": "E", "symbols": ["A"]}"#)?;
+        let cases: [&[u8]; 3] = [&[], &[0x02], &[0x00, 0x00]];
+        for data in cases {
+            let err = schema.decode(data, false).unwrap_err();
+            assert!(matches!(err, SchemaError::Decode(_)), "{data:?}: {err:?}");
+        }
+        let err = schema.decode(b"\"B\"", true).unwrap_err();
+        assert!(matches!(err, SchemaError::Decode(_)), "{err:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn decode_limits() -> anyhow::Result<()> {
+        let mut huge_count = Vec::new();
+        write_long(i64::MAX, &mut huge_count);
+        let mut items = Vec::new();
+        write_long(MAX_ITEMS as i64 + 1, &mut items);
+        let cases = [
+            // The items take one byte each, the count exceeds the data.
+            (r#"{"type": "array", "items": "int"}"#, huge_count.clone()),
+            (r#"{"type": "map", "values": "null"}"#, huge_count),
+            // The items take no space, the count exceeds the limit.
+            (r#"{"type": "array", "items": "null"}"#, items),
+        ];
+        for (definition, data) in cases {
+            let schema = AvroSchema::parse(definition)?;
+            let err = schema.decode(&data, false).unwrap_err();
+            assert!(
+                matches!(err, SchemaError::Decode(_)),
+                "{definition}: {err:?}"
+            );
+        }
+
+        let schema = AvroSchema::parse(r#"{"type": "array", "items": "null"}"#)?;
+        let mut data = Vec::new();
+        write_long(MAX_ITEMS as i64, &mut data);
+        write_long(0, &mut data);
+        let decoded = schema.decode(&data, false)?;
+        assert_eq!(decoded.as_array().map(Vec::len), Some(MAX_ITEMS));
+
+        // Each level of the list takes two bytes.
+        let schema = AvroSchema::parse(
+            r#"{"type": "record", "name": "Node", "fields": [
+                {"name": "next", "type": ["null", "Node"]}
+            ]}"#,
+        )?;
+        let data = [vec![0x02; MAX_DEPTH], vec![0x00]].concat();
+        let err = schema.decode(&data, false).unwrap_err();
+        assert!(err.to_string().contains("nested"), "{err:?}");
+        let data = [vec![0x02; MAX_DEPTH / 2 - 1], vec![0x00]].concat();
+        schema.decode(&data, false)?;
+        Ok(())
+    }
+
+    #[test]
+    fn decode_arbitrary_data() -> anyhow::Result<()> {
+        let schema = AvroSchema::parse(USER)?;
+        let valid = schema.encode(&user(), false)?;
+        // A simple linear congruential generator, to corrupt the data in a
+        // reproducible way.
+        let mut state = 42_u64;
+        let mut next = move || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) as usize
+        };
+        for _ in 0..1_000 {
+            let mut data = valid.clone();
+            for _ in 0..4 {
+                let i = next() % data.len();
+                data[i] = next() as u8;
+            }
+            data.truncate(next() % (valid.len() + 1));
+            // Any result is fine, as long as decoding terminates without a
+            // panic.
+            let _ = schema.decode(&data, false);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_definitions() {
+        let cases = [
+            "not json",
+            r#"{"type": "record", "name": "R"}"#,
+            r#"{"type": "record", "name": "R", "fields": [{"name": "a", "type": "Unknown"}]}"#,
+            r#"{"type": "enum", "name": "E"}"#,
+            r#"{"type": "fixed", "name": "F"}"#,
+            r#"["null", ["int"]]"#,
+            "42",
+        ];
+        for definition in cases {
+            let err = AvroSchema::parse(definition).unwrap_err();
+            assert!(
+                matches!(err, SchemaError::InvalidDefinition(_)),
+                "{definition}: {err:?}"
+            );
+        }
+    }
+}
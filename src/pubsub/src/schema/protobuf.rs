@@ -0,0 +1,220 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Extracts the message name from Protocol Buffer schemas.
+//!
+//! Pub/Sub schemas of type `PROTOCOL_BUFFER` contain a single top-level
+//! message definition, possibly with nested types. The service validates
+//! messages against that top-level message. This module only extracts its
+//! fully qualified name, which is enough to check that a `prost` type is the
+//! message the schema describes. The fields are not parsed, the service
+//! validates the message contents when it is published.
+
+use crate::error::SchemaError;
+
+/// The top-level message of a Protocol Buffer schema.
+#[derive(Clone, Debug)]
+pub(crate) struct ProtoSchema {
+    full_name: String,
+}
+
+fn definition_error<T: std::fmt::Display>(msg: T) -> SchemaError {
+    SchemaError::InvalidDefinition(msg.to_string())
+}
+
+impl ProtoSchema {
+    /// Parses a Protocol Buffer schema definition.
+    pub(crate) fn parse(definition: &str) -> Result<Self, SchemaError> {
+        let tokens = tokenize(definition)?;
+        let mut package = String::new();
+        let mut i = 0;
+        while i < tokens.len() {
+            match tokens[i].as_str() {
+                "package" => {
+                    package = tokens
+                        .get(i + 1)
+                        .cloned()
+                        .ok_or_else(|| definition_error("missing package name"))?;
+                    i += 2;
+                }
+                "message" => {
+                    let name = tokens
+                        .get(i + 1)
+                        .ok_or_else(|| definition_error("missing message name"))?;
+                    if tokens.get(i + 2).map(String::as_str) != Some("{") {
+                        return Err(definition_error(format!("expected {{ after {name}")));
+                    }
+                    skip_block(&tokens, i + 2)?;
+                    let full_name = if package.is_empty() {
+                        name.clone()
+                    } else {
+                        format!("{package}.{name}")
+                    };
+                    return Ok(Self { full_name });
+                }
+                "{" => i = skip_block(&tokens, i)?,
+                _ => i += 1,
+            }
+        }
+        Err(definition_error("the schema does not define a message"))
+    }
+
+    /// Verifies that the `prost` type named `full_name` is the schema's
+    /// top-level message.
+    pub(crate) fn check_type(&self, full_name: &str) -> Result<(), SchemaError> {
+        if full_name != self.full_name {
+            return Err(SchemaError::Validation(format!(
+                "expected a {} message, got {full_name}",
+                self.full_name
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Returns the position after the block that opens at `start`.
+fn skip_block(tokens: &[String], start: usize) -> Result<usize, SchemaError> {
+    let mut depth = 0_usize;
+    for (i, token) in tokens.iter().enumerate().skip(start) {
+        match token.as_str() {
+            "{" => depth += 1,
+            "}" => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    Err(definition_error("unbalanced braces"))
+}
+
+fn tokenize(definition: &str) -> Result<Vec<String>, SchemaError> {
+    let mut tokens = Vec::new();
+    let mut chars = definition.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '/' if chars.peek() == Some(&'/') => {
+                chars.by_ref().take_while(|c| *c != '\n').for_each(drop);
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = ' ';
+                loop {
+                    let c = chars
+                        .next()
+                        .ok_or_else(|| definition_error("unterminated comment"))?;
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            '"' | '\'' => {
+                let mut literal = String::from(c);
+                loop {
+                    let next = chars
+                        .next()
+                        .ok_or_else(|| definition_error("unterminated string"))?;
+                    literal.push(next);
+                    if next == '\\' {
+                        literal.extend(chars.next());
+                    } else if next == c {
+                        break;
+                    }
+                }
+                tokens.push(literal);
+            }
+            c if c.is_alphanumeric() || matches!(c, '_' | '.' | '-' | '+') => {
+                let mut word = String::from(c);
+                while let Some(&next) = chars.peek() {
+                    if !(next.is_alphanumeric() || matches!(next, '_' | '.' | '-' | '+')) {
+                        break;
+                    }
+                    word.push(next);
+                    chars.next();
+                }
+                tokens.push(word);
+            }
+            c => tokens.push(c.to_string()),
+        }
+    }
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORDER: &str = r#"
+        syntax = "proto3";
+        package example.v1;
+
+        import "google/protobuf/timestamp.proto";
+
+        /* An order
+         * placed by a customer. */
+        message Order {
+            option deprecated = false;
+            // The order id, with a } in the comment.
+            string order_id = 1 [json_name = "}"];
+            message Note { string text = 1; }
+            repeated Note notes = 2;
+            google.protobuf.Timestamp create_time = 3;
+        }
+
+        message Ignored { string field = 1; }
+    "#;
+
+    #[test]
+    fn parse() -> anyhow::Result<()> {
+        let schema = ProtoSchema::parse(ORDER)?;
+        assert_eq!(schema.full_name, "example.v1.Order");
+
+        let schema = ProtoSchema::parse("message Order { string id = 1; }")?;
+        assert_eq!(schema.full_name, "Order");
+        Ok(())
+    }
+
+    #[test]
+    fn check_type() -> anyhow::Result<()> {
+        let schema = ProtoSchema::parse(ORDER)?;
+        schema.check_type("example.v1.Order")?;
+        let err = schema.check_type("example.v1.Other").unwrap_err();
+        assert!(matches!(err, SchemaError::Validation(_)), "{err:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_definitions() {
+        let cases = [
+            "syntax = \"proto3\";",
+            "package",
+            "message {",
+            "message M string a = 1; }",
+            "message M { string a = 1; ",
+            "message M { /* unterminated }",
+            "message M { string a = 1 [json_name = \"a]; }",
+        ];
+        for definition in cases {
+            let err = ProtoSchema::parse(definition).unwrap_err();
+            assert!(
+                matches!(err, SchemaError::InvalidDefinition(_)),
+                "{definition}: {err:?}"
+            );
+        }
+    }
+}
@@ -14,7 +14,9 @@
 
 pub mod handler;
 
+pub use executor::Run;
 pub use message_stream::MessageStream;
+pub use pull::{AckBatch, PullHandler};
 pub use shutdown_behavior::ShutdownBehavior;
 pub use shutdown_token::ShutdownToken;
 
@@ -22,11 +24,13 @@ pub(super) mod builder;
 pub(super) mod client;
 mod client_builder;
 mod exactly_once_retry;
+mod executor;
 mod keepalive;
 mod lease_loop;
 mod lease_state;
 mod leaser;
 mod message_stream;
+mod pull;
 mod retry_policy;
 mod shutdown_behavior;
 mod shutdown_token;
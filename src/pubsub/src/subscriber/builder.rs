@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use super::MessageStream;
+use super::Run;
 use super::ShutdownBehavior;
 use super::transport::Transport;
+use crate::model::Message;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -77,6 +79,54 @@ impl Subscribe {
         MessageStream::new(self)
     }
 
+    /// Receives messages from the subscription and processes them with `f`.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_pubsub::client::Subscriber;
+    /// # async fn sample(client: Subscriber) -> anyhow::Result<()> {
+    /// client
+    ///     .subscribe("projects/my-project/subscriptions/my-subscription")
+    ///     .run(async |m| {
+    ///         println!("Received message m={m:?}");
+    ///         anyhow::Ok(())
+    ///     }, 16)
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// At most `concurrency` messages are processed at the same time. Messages
+    /// with the same (non-empty) ordering key are processed one at a time, in
+    /// the order they are received. Messages waiting for their ordering key do
+    /// not count towards `concurrency`, their number is only bounded by the
+    /// flow control settings, such as
+    /// [set_max_outstanding_messages][Subscribe::set_max_outstanding_messages].
+    ///
+    /// The message is acknowledged if `f` returns `Ok`, and negatively
+    /// acknowledged if `f` returns `Err` or panics. If `f` fails for a message
+    /// with an ordering key, the messages with the same ordering key waiting to
+    /// be processed are also negatively acknowledged, so the service can
+    /// redeliver them in order. Until the redelivery starts, new messages with
+    /// that ordering key are negatively acknowledged without calling `f`.
+    ///
+    /// The returned future completes when the stream is shut down, via
+    /// [`Run::shutdown_token()`], or fails with a permanent error. In either
+    /// case, the messages already given to `f` are processed before the future
+    /// completes. If the [shutdown behavior][Subscribe::set_shutdown_behavior]
+    /// is [`NackImmediately`][nack], messages waiting for their ordering key
+    /// are negatively acknowledged instead of processed.
+    ///
+    /// [nack]: crate::subscriber::ShutdownBehavior::NackImmediately
+    pub fn run<F, Fut, E>(self, f: F, concurrency: usize) -> Run
+    where
+        F: Fn(Message) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+        E: Send + 'static,
+    {
+        let shutdown_behavior = self.shutdown_behavior;
+        Run::new(self.build(), f, concurrency, shutdown_behavior)
+    }
+
     /// Sets the maximum lease deadline for a message.
     ///
     /// # Example
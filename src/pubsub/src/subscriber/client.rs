@@ -14,8 +14,10 @@
 
 use super::builder::Subscribe;
 use super::client_builder::ClientBuilder;
+use super::pull::{PullHandler, pull};
 use super::transport::Transport;
 use crate::ClientBuilderResult as BuilderResult;
+use crate::model::Message;
 use std::sync::Arc;
 
 /// A Subscriber client for the [Cloud Pub/Sub] API.
@@ -162,6 +164,55 @@ impl Subscriber {
         )
     }
 
+    /// Pull up to `max_messages` messages from a [subscription].
+    ///
+    /// This makes a single `Pull` RPC, which may return fewer messages than
+    /// requested, or none at all. Unlike [subscribe()][Subscriber::subscribe],
+    /// it does not start any background tasks, and the client does not extend
+    /// the leases of the messages. It is intended for short-lived workers that
+    /// process a bounded number of messages and exit.
+    ///
+    /// The `subscription` is the full name, in the format of
+    /// `projects/*/subscriptions/*`.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_pubsub::client::Subscriber;
+    /// # use google_cloud_pubsub::subscriber::AckBatch;
+    /// # async fn sample(client: Subscriber) -> anyhow::Result<()> {
+    /// let messages = client
+    ///     .pull("projects/my-project/subscriptions/my-subscription", 100)
+    ///     .await?;
+    /// let mut batch = AckBatch::new();
+    /// for (m, h) in messages {
+    ///     println!("Received message m={m:?}");
+    ///     batch.ack(h);
+    /// }
+    /// let _ = batch.send().await;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// See [PullHandler] for the operations on pulled messages.
+    ///
+    /// [PullHandler]: crate::subscriber::PullHandler
+    /// [subscription]: https://docs.cloud.google.com/pubsub/docs/subscription-overview
+    pub async fn pull<T>(
+        &self,
+        subscription: T,
+        max_messages: i32,
+    ) -> crate::Result<Vec<(Message, PullHandler)>>
+    where
+        T: Into<String>,
+    {
+        pull(
+            self.inner.clone(),
+            subscription.into(),
+            max_messages,
+            self.grpc_subchannel_count,
+        )
+        .await
+    }
+
     pub(super) async fn new(builder: ClientBuilder) -> BuilderResult<Self> {
         let grpc_subchannel_count =
             std::cmp::max(1, builder.config.grpc_subchannel_count.unwrap_or(1));
@@ -216,6 +267,46 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn pull() -> anyhow::Result<()> {
+        use pubsub_grpc_mock::google::pubsub::v1;
+        let mut mock = MockSubscriber::new();
+        mock.expect_pull().return_once(|request| {
+            let request = request.into_inner();
+            assert_eq!(request.subscription, "projects/p/subscriptions/s");
+            assert_eq!(request.max_messages, 5);
+            Ok(gaxi::grpc::tonic::Response::new(v1::PullResponse {
+                received_messages: vec![v1::ReceivedMessage {
+                    ack_id: "ack-0".to_string(),
+                    message: Some(v1::PubsubMessage {
+                        data: "hello".into(),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }],
+            }))
+        });
+        mock.expect_acknowledge().return_once(|request| {
+            let request = request.into_inner();
+            assert_eq!(request.subscription, "projects/p/subscriptions/s");
+            assert_eq!(request.ack_ids, vec!["ack-0"]);
+            Ok(gaxi::grpc::tonic::Response::new(()))
+        });
+        let (endpoint, _server) = start("0.0.0.0:0", mock).await?;
+        let client = Subscriber::builder()
+            .with_endpoint(endpoint)
+            .with_credentials(Anonymous::new().build())
+            .build()
+            .await?;
+        let mut messages = client.pull("projects/p/subscriptions/s", 5).await?;
+        assert_eq!(messages.len(), 1, "{messages:?}");
+        let (m, h) = messages.pop().unwrap();
+        assert_eq!(m.data, "hello");
+        assert_eq!(h.delivery_attempt(), None);
+        h.ack().await?;
+        Ok(())
+    }
+
     #[tokio::test]
     async fn grpc_subchannel_count() -> anyhow::Result<()> {
         let client = Subscriber::builder()
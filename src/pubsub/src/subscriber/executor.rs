@@ -0,0 +1,621 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::MessageStream;
+use super::ShutdownBehavior;
+use super::ShutdownToken;
+use super::handler::Handler;
+use crate::Result;
+use crate::model::Message;
+use futures::FutureExt;
+use futures::future::BoxFuture;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::task::JoinSet;
+
+/// The future returned by [`Subscribe::run()`][run].
+///
+/// The future completes once the stream is shut down, or fails with a
+/// permanent error, and all the messages delivered to the handler function
+/// have been processed.
+///
+/// Use the [shutdown token][Run::shutdown_token] to stop receiving messages.
+///
+/// # Example
+/// ```
+/// # use google_cloud_pubsub::client::Subscriber;
+/// # async fn sample(client: Subscriber) -> anyhow::Result<()> {
+/// let run = client
+///     .subscribe("projects/my-project/subscriptions/my-subscription")
+///     .run(async |m| {
+///         println!("Received message m={m:?}");
+///         anyhow::Ok(())
+///     }, 16);
+/// let token = run.shutdown_token();
+/// tokio::spawn(async move {
+///     tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+///     token.shutdown().await;
+/// });
+/// run.await?;
+/// # Ok(()) }
+/// ```
+///
+/// [run]: crate::builder::subscriber::Subscribe::run
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Run {
+    shutdown: ShutdownToken,
+    fut: BoxFuture<'static, Result<()>>,
+}
+
+impl Run {
+    pub(super) fn new<F, Fut, E>(
+        stream: MessageStream,
+        f: F,
+        concurrency: usize,
+        shutdown_behavior: ShutdownBehavior,
+    ) -> Self
+    where
+        F: Fn(Message) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = std::result::Result<(), E>> + Send + 'static,
+        E: Send + 'static,
+    {
+        let shutdown = stream.shutdown_token();
+        let executor = Executor {
+            f: Arc::new(f),
+            concurrency: concurrency.max(1),
+            shutdown_behavior,
+            shutdown: shutdown.clone(),
+            tasks: JoinSet::new(),
+            keys: HashMap::new(),
+        };
+        Self {
+            shutdown,
+            fut: executor.run(stream).boxed(),
+        }
+    }
+
+    /// Returns a shutdown token for the underlying stream.
+    ///
+    /// Shutting down the stream stops the delivery of new messages. The
+    /// [Run] future completes once the messages already delivered to the
+    /// handler function have been processed.
+    pub fn shutdown_token(&self) -> ShutdownToken {
+        self.shutdown.clone()
+    }
+}
+
+impl std::fmt::Debug for Run {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Run")
+            .field("shutdown", &self.shutdown)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Future for Run {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.fut.as_mut().poll(cx)
+    }
+}
+
+/// The result of processing a message: its ordering key, its id, and whether
+/// the handler function succeeded.
+type Outcome = (String, String, bool);
+
+/// The state of an ordering key with pending work.
+enum KeyState {
+    /// A message is in flight, the queued messages wait for it to complete.
+    Busy(VecDeque<(Message, Handler)>),
+    /// A message failed, or was nacked at shutdown. The service redelivers the
+    /// messages for the key starting with the first nacked message, so new
+    /// arrivals are nacked too, until one of the `nacked` messages returns.
+    Failed(HashSet<String>),
+}
+
+struct Executor<F> {
+    f: Arc<F>,
+    concurrency: usize,
+    shutdown_behavior: ShutdownBehavior,
+    shutdown: ShutdownToken,
+    tasks: JoinSet<Outcome>,
+    // The ordering keys with a message in flight or waiting for redelivery.
+    //
+    // The queued messages do not count towards `concurrency`, as they hold no
+    // task. They are only bounded by the flow control settings of the stream,
+    // e.g. `set_max_outstanding_messages()`.
+    keys: HashMap<String, KeyState>,
+}
+
+impl<F, Fut, E> Executor<F>
+where
+    F: Fn(Message) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = std::result::Result<(), E>> + Send + 'static,
+    E: Send + 'static,
+{
+    async fn run(mut self, mut stream: MessageStream) -> Result<()> {
+        let mut result = Ok(());
+        let mut receiving = true;
+        loop {
+            tokio::select! {
+                biased;
+                Some(joined) = self.tasks.join_next(), if !self.tasks.is_empty() => {
+                    // Tasks catch panics, and are never aborted.
+                    if let Ok((key, id, ok)) = joined {
+                        self.on_done(key, id, ok);
+                    }
+                },
+                next = stream.next(), if receiving && self.tasks.len() < self.concurrency => {
+                    match next {
+                        Some(Ok((m, h))) => self.dispatch(m, h),
+                        Some(Err(e)) => {
+                            result = Err(e);
+                            receiving = false;
+                        }
+                        None => receiving = false,
+                    }
+                },
+                else => break,
+            }
+        }
+        // Flush the pending acks and nacks before returning.
+        drop(stream);
+        self.shutdown.shutdown().await;
+        result
+    }
+
+    fn dispatch(&mut self, message: Message, handler: Handler) {
+        if message.ordering_key.is_empty() {
+            return self.spawn(message, handler);
+        }
+        match self.keys.get_mut(&message.ordering_key) {
+            Some(KeyState::Busy(queue)) => queue.push_back((message, handler)),
+            Some(KeyState::Failed(nacked)) if !nacked.contains(&message.message_id) => {
+                // Processing this message now would skip the messages that
+                // the service is about to redeliver.
+                nacked.insert(message.message_id.clone());
+                handler.nack();
+            }
+            // Either a new key, or the redelivery of a failed key started.
+            _ => {
+                self.keys.insert(
+                    message.ordering_key.clone(),
+                    KeyState::Busy(VecDeque::new()),
+                );
+                self.spawn(message, handler);
+            }
+        }
+    }
+
+    fn spawn(&mut self, message: Message, handler: Handler) {
+        let f = self.f.clone();
+        let key = message.ordering_key.clone();
+        let id = message.message_id.clone();
+        self.tasks.spawn(async move {
+            // A panic in the handler function is treated as a failure.
+            let ok = matches!(
+                AssertUnwindSafe(f(message)).catch_unwind().await,
+                Ok(Ok(()))
+            );
+            if ok {
+                handler.ack();
+            } else {
+                handler.nack();
+            }
+            (key, id, ok)
+        });
+    }
+
+    fn on_done(&mut self, key: String, id: String, ok: bool) {
+        if key.is_empty() {
+            return;
+        }
+        let Some(KeyState::Busy(queue)) = self.keys.get_mut(&key) else {
+            return;
+        };
+        let nack_immediately = self.shutdown_behavior == ShutdownBehavior::NackImmediately
+            && self.shutdown.inner.is_cancelled();
+        if !ok || nack_immediately {
+            // The service redelivers the messages after a nacked message with
+            // the same ordering key, so processing them now would break the
+            // order.
+            let mut nacked = queue
+                .drain(..)
+                .map(|(m, h)| {
+                    h.nack();
+                    m.message_id
+                })
+                .collect::<HashSet<_>>();
+            if !ok {
+                nacked.insert(id);
+            }
+            if nacked.is_empty() {
+                self.keys.remove(&key);
+            } else {
+                self.keys.insert(key, KeyState::Failed(nacked));
+            }
+            return;
+        }
+        match queue.pop_front() {
+            Some((m, h)) => self.spawn(m, h),
+            None => {
+                self.keys.remove(&key);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::client::Subscriber;
+    use super::super::lease_state::tests::{test_id, test_ids};
+    use super::*;
+    use gaxi::grpc::tonic::{Response as TonicResponse, Status as TonicStatus};
+    use google_cloud_auth::credentials::anonymous::Builder as Anonymous;
+    use google_cloud_test_macros::tokio_test_no_panics;
+    use pubsub_grpc_mock::google::pubsub::v1;
+    use pubsub_grpc_mock::{MockSubscriber, start};
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::sync::mpsc::{UnboundedReceiver, channel, unbounded_channel};
+    use tokio::time::Duration;
+
+    fn sorted<T: Ord>(mut v: Vec<T>) -> Vec<T> {
+        v.sort();
+        v
+    }
+
+    fn test_response(messages: &[(i32, &str)]) -> v1::StreamingPullResponse {
+        v1::StreamingPullResponse {
+            received_messages: messages
+                .iter()
+                .map(|(i, key)| v1::ReceivedMessage {
+                    ack_id: test_id(*i),
+                    message: Some(v1::PubsubMessage {
+                        data: test_id(*i).into_bytes(),
+                        message_id: test_id(*i),
+                        ordering_key: key.to_string(),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn unordered(range: std::ops::Range<i32>) -> Vec<(i32, &'static str)> {
+        range.map(|i| (i, "")).collect()
+    }
+
+    struct Server {
+        response_tx:
+            tokio::sync::mpsc::Sender<std::result::Result<v1::StreamingPullResponse, TonicStatus>>,
+        acks: UnboundedReceiver<String>,
+        nacks: UnboundedReceiver<String>,
+        client: Subscriber,
+        _server: tokio::task::JoinHandle<()>,
+    }
+
+    impl Server {
+        async fn new() -> anyhow::Result<Self> {
+            let (response_tx, response_rx) = channel(10);
+            let (ack_tx, acks) = unbounded_channel();
+            let (nack_tx, nacks) = unbounded_channel();
+            let mut mock = MockSubscriber::new();
+            mock.expect_streaming_pull()
+                .return_once(|_| Ok(TonicResponse::from(response_rx)));
+            mock.expect_acknowledge().returning(move |r| {
+                r.into_inner()
+                    .ack_ids
+                    .into_iter()
+                    .for_each(|id| ack_tx.send(id).unwrap());
+                Ok(TonicResponse::from(()))
+            });
+            mock.expect_modify_ack_deadline().returning(move |r| {
+                let r = r.into_inner();
+                if r.ack_deadline_seconds == 0 {
+                    r.ack_ids
+                        .into_iter()
+                        .for_each(|id| nack_tx.send(id).unwrap());
+                }
+                Ok(TonicResponse::from(()))
+            });
+            let (endpoint, _server) = start("0.0.0.0:0", mock).await?;
+            let client = Subscriber::builder()
+                .with_endpoint(endpoint)
+                .with_credentials(Anonymous::new().build())
+                .build()
+                .await?;
+            Ok(Self {
+                response_tx,
+                acks,
+                nacks,
+                client,
+                _server,
+            })
+        }
+
+        async fn send(&self, messages: &[(i32, &str)]) -> anyhow::Result<()> {
+            self.response_tx.send(Ok(test_response(messages))).await?;
+            Ok(())
+        }
+    }
+
+    fn drain(rx: &mut UnboundedReceiver<String>) -> Vec<String> {
+        let mut ids = Vec::new();
+        while let Ok(id) = rx.try_recv() {
+            ids.push(id);
+        }
+        sorted(ids)
+    }
+
+    fn index(m: &Message) -> i32 {
+        let id = String::from_utf8(m.data.to_vec()).unwrap();
+        (0..100).find(|i| test_id(*i) == id).unwrap()
+    }
+
+    #[tokio_test_no_panics(start_paused = true)]
+    async fn ack_on_ok_nack_on_err() -> anyhow::Result<()> {
+        let mut server = Server::new().await?;
+        server.send(&unordered(0..6)).await?;
+        drop(server.response_tx);
+
+        server
+            .client
+            .subscribe("projects/p/subscriptions/s")
+            .run(
+                async |m| match index(&m) {
+                    4 => panic!("handler panics"),
+                    i if i % 2 == 0 => Ok(()),
+                    _ => Err("odd"),
+                },
+                4,
+            )
+            .await?;
+
+        assert_eq!(drain(&mut server.acks), vec![test_id(0), test_id(2)]);
+        assert_eq!(
+            drain(&mut server.nacks),
+            vec![test_id(1), test_id(3), test_id(4), test_id(5)]
+        );
+        Ok(())
+    }
+
+    #[tokio_test_no_panics(start_paused = true)]
+    async fn bounded_concurrency() -> anyhow::Result<()> {
+        let mut server = Server::new().await?;
+        server.send(&unordered(0..10)).await?;
+        drop(server.response_tx);
+
+        let active = Arc::new(AtomicUsize::new(0));
+        let max_active = Arc::new(AtomicUsize::new(0));
+        let (a, m) = (active.clone(), max_active.clone());
+        server
+            .client
+            .subscribe("projects/p/subscriptions/s")
+            .run(
+                move |_| {
+                    let (active, max_active) = (a.clone(), m.clone());
+                    async move {
+                        let n = active.fetch_add(1, Ordering::SeqCst) + 1;
+                        max_active.fetch_max(n, Ordering::SeqCst);
+                        tokio::time::sleep(Duration::from_secs(1)).await;
+                        active.fetch_sub(1, Ordering::SeqCst);
+                        Ok::<(), ()>(())
+                    }
+                },
+                3,
+            )
+            .await?;
+
+        assert_eq!(max_active.load(Ordering::SeqCst), 3);
+        assert_eq!(drain(&mut server.acks), test_ids(0..10));
+        Ok(())
+    }
+
+    #[tokio_test_no_panics(start_paused = true)]
+    async fn serialized_per_ordering_key() -> anyhow::Result<()> {
+        let mut server = Server::new().await?;
+        server
+            .send(&[(0, "a"), (1, "b"), (2, "a"), (3, ""), (4, "a"), (5, "b")])
+            .await?;
+        drop(server.response_tx);
+
+        let active = Arc::new(Mutex::new(Vec::<String>::new()));
+        let processed = Arc::new(Mutex::new(Vec::new()));
+        let (a, p) = (active.clone(), processed.clone());
+        server
+            .client
+            .subscribe("projects/p/subscriptions/s")
+            .run(
+                move |m| {
+                    let (active, processed) = (a.clone(), p.clone());
+                    async move {
+                        let key = m.ordering_key.clone();
+                        if !key.is_empty() {
+                            let mut active = active.lock().unwrap();
+                            assert!(!active.contains(&key), "{key} is already active");
+                            active.push(key.clone());
+                        }
+                        tokio::time::sleep(Duration::from_secs(1)).await;
+                        active.lock().unwrap().retain(|k| *k != key);
+                        processed.lock().unwrap().push(index(&m));
+                        Ok::<(), ()>(())
+                    }
+                },
+                10,
+            )
+            .await?;
+
+        let processed = processed.lock().unwrap().clone();
+        let position = |i| processed.iter().position(|p| *p == i).unwrap();
+        assert!(
+            position(0) < position(2) && position(2) < position(4),
+            "{processed:?}"
+        );
+        assert!(position(1) < position(5), "{processed:?}");
+        assert_eq!(drain(&mut server.acks), test_ids(0..6));
+        Ok(())
+    }
+
+    #[tokio_test_no_panics(start_paused = true)]
+    async fn failure_nacks_queued_messages_for_key() -> anyhow::Result<()> {
+        let mut server = Server::new().await?;
+        server
+            .send(&[(0, "a"), (1, "a"), (2, "b"), (3, "a")])
+            .await?;
+        drop(server.response_tx);
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let c = calls.clone();
+        server
+            .client
+            .subscribe("projects/p/subscriptions/s")
+            .run(
+                move |m| {
+                    let calls = c.clone();
+                    async move {
+                        let i = index(&m);
+                        calls.lock().unwrap().push(i);
+                        tokio::time::sleep(Duration::from_secs(1)).await;
+                        if i == 0 { Err(()) } else { Ok(()) }
+                    }
+                },
+                10,
+            )
+            .await?;
+
+        // The messages after the failed one are not processed.
+        assert_eq!(sorted(calls.lock().unwrap().clone()), vec![0, 2]);
+        assert_eq!(drain(&mut server.acks), vec![test_id(2)]);
+        assert_eq!(
+            drain(&mut server.nacks),
+            vec![test_id(0), test_id(1), test_id(3)]
+        );
+        Ok(())
+    }
+
+    #[tokio_test_no_panics(start_paused = true)]
+    async fn failed_key_nacks_arrivals_until_redelivery() -> anyhow::Result<()> {
+        let mut server = Server::new().await?;
+        server.send(&[(0, "a"), (1, "a"), (2, "b")]).await?;
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let c = calls.clone();
+        let run = server.client.subscribe("projects/p/subscriptions/s").run(
+            move |m| {
+                let calls = c.clone();
+                async move {
+                    let i = index(&m);
+                    let first = {
+                        let mut calls = calls.lock().unwrap();
+                        calls.push(i);
+                        calls.iter().filter(|c| **c == i).count() == 1
+                    };
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                    // Only the first attempt for message 0 fails.
+                    if i == 0 && first { Err(()) } else { Ok(()) }
+                }
+            },
+            10,
+        );
+        let handle = tokio::spawn(run);
+        let mut nacks = Vec::new();
+        while nacks.len() < 2 {
+            nacks.extend(server.nacks.recv().await);
+        }
+        assert_eq!(sorted(nacks), test_ids(0..2));
+
+        // A new message for the failed key arrives before the redelivery.
+        server.send(&[(3, "a"), (4, "b")]).await?;
+        assert_eq!(server.nacks.recv().await, Some(test_id(3)));
+
+        // The service redelivers the messages, starting with the failed one.
+        server.send(&[(0, "a"), (1, "a"), (3, "a")]).await?;
+        drop(server.response_tx);
+        handle.await??;
+
+        let calls = calls.lock().unwrap().clone();
+        let for_a = calls
+            .iter()
+            .filter(|i| ![2, 4].contains(*i))
+            .copied()
+            .collect::<Vec<_>>();
+        assert_eq!(for_a, vec![0, 0, 1, 3], "{calls:?}");
+        assert_eq!(
+            drain(&mut server.acks),
+            vec![test_id(0), test_id(1), test_id(2), test_id(3), test_id(4)]
+        );
+        assert!(drain(&mut server.nacks).is_empty());
+        Ok(())
+    }
+
+    #[tokio_test_no_panics(start_paused = true)]
+    async fn shutdown_drains_in_flight_work() -> anyhow::Result<()> {
+        let mut server = Server::new().await?;
+        server.send(&unordered(0..2)).await?;
+
+        let (started_tx, mut started_rx) = unbounded_channel();
+        let run = server.client.subscribe("projects/p/subscriptions/s").run(
+            move |_| {
+                let started_tx = started_tx.clone();
+                async move {
+                    let _ = started_tx.send(());
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    Ok::<(), ()>(())
+                }
+            },
+            2,
+        );
+        let token = run.shutdown_token();
+        let handle = tokio::spawn(run);
+        started_rx.recv().await;
+        started_rx.recv().await;
+
+        token.shutdown().await;
+        handle.await??;
+
+        // The messages delivered before the shutdown are processed and acked.
+        assert_eq!(drain(&mut server.acks), test_ids(0..2));
+        // The stream stops receiving messages.
+        server.send(&unordered(2..4)).await.ok();
+        assert!(started_rx.try_recv().is_err());
+        Ok(())
+    }
+
+    #[tokio_test_no_panics(start_paused = true)]
+    async fn stream_error() -> anyhow::Result<()> {
+        let mut mock = MockSubscriber::new();
+        mock.expect_streaming_pull()
+            .return_once(|_| Err(TonicStatus::failed_precondition("fail")));
+        let (endpoint, _server) = start("0.0.0.0:0", mock).await?;
+        let client = Subscriber::builder()
+            .with_endpoint(endpoint)
+            .with_credentials(Anonymous::new().build())
+            .build()
+            .await?;
+        let err = client
+            .subscribe("projects/p/subscriptions/s")
+            .run(async |_| Ok::<(), ()>(()), 1)
+            .await
+            .unwrap_err();
+        assert!(err.status().is_some(), "{err:?}");
+        Ok(())
+    }
+}
@@ -44,7 +44,7 @@ const EXTEND_PERIOD: Duration = Duration::from_secs(3);
 const EXTEND_BUFFER: Duration = Duration::from_secs(2);
 
 // Helper function to chunk ack ids into chunks of MAX_IDS_PER_RPC.
-fn batch(ack_ids: Vec<String>) -> Vec<Vec<String>> {
+pub(super) fn batch(ack_ids: Vec<String>) -> Vec<Vec<String>> {
     ack_ids
         .chunks(MAX_IDS_PER_RPC)
         .map(|c| c.to_vec())
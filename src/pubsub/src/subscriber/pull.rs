@@ -0,0 +1,576 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::handler::AckResult;
+use super::lease_state::batch;
+use super::leaser::{ConfirmedAcks, DefaultLeaser, Leaser};
+use super::retry_policy::rpc_options;
+use super::stub::Stub;
+use crate::Result;
+use crate::error::AckError;
+use crate::google::pubsub::v1::PullRequest;
+use crate::model::Message;
+use gaxi::prost::FromProto as _;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+
+// The time limit for retrying nacks, which matches the default ack deadline of
+// a `Subscriber` stream.
+const NACK_DEADLINE_SECONDS: i32 = 60;
+
+// The service rejects ack deadlines longer than 10 minutes.
+const MAX_ACK_DEADLINE_SECONDS: u64 = 600;
+
+/// Pulls up to `max_messages` messages with a single `Pull` RPC.
+pub(super) async fn pull<T>(
+    inner: Arc<T>,
+    subscription: String,
+    max_messages: i32,
+    grpc_subchannel_count: usize,
+) -> Result<Vec<(Message, PullHandler)>>
+where
+    T: Stub + 'static,
+{
+    let req = PullRequest {
+        subscription: subscription.clone(),
+        max_messages,
+        ..Default::default()
+    };
+    let resp = inner.pull(req, rpc_options(grpc_subchannel_count)).await?;
+    let acknowledger: Arc<dyn Acknowledger> = Arc::new(DefaultAcknowledger {
+        inner,
+        subscription,
+        grpc_subchannel_count,
+    });
+    resp.received_messages
+        .into_iter()
+        .filter_map(|rm| {
+            // As with streams, ignore ack IDs without an associated message.
+            let message = rm.message?;
+            let handler = PullHandler {
+                ack_id: rm.ack_id,
+                delivery_attempt: (rm.delivery_attempt > 0).then_some(rm.delivery_attempt),
+                acknowledger: acknowledger.clone(),
+            };
+            Some(
+                message
+                    .cnv()
+                    .map_err(crate::Error::deser)
+                    .map(|m| (m, handler)),
+            )
+        })
+        .collect()
+}
+
+/// Sends acks and modacks for pulled messages.
+///
+/// The public handler types hold this trait object, so they do not depend on
+/// the type of the transport stub.
+#[async_trait::async_trait]
+trait Acknowledger: std::fmt::Debug + Send + Sync {
+    fn subscription(&self) -> &str;
+    async fn ack(&self, ack_ids: Vec<String>) -> ConfirmedAcks;
+    async fn modify_ack_deadline(&self, ack_ids: Vec<String>, seconds: i32) -> ConfirmedAcks;
+}
+
+#[derive(Debug)]
+struct DefaultAcknowledger<T> {
+    inner: Arc<T>,
+    subscription: String,
+    grpc_subchannel_count: usize,
+}
+
+impl<T> DefaultAcknowledger<T>
+where
+    T: Stub + 'static,
+{
+    /// Returns a leaser that reports results on the returned channel.
+    ///
+    /// The leaser retries the RPCs with the exactly-once retry loop, which
+    /// reports a result for each ack ID. `ack_deadline_seconds` is both the
+    /// deadline sent in modacks and the time limit for retrying them.
+    fn leaser(
+        &self,
+        ack_deadline_seconds: i32,
+    ) -> (DefaultLeaser<T>, UnboundedReceiver<ConfirmedAcks>) {
+        let (confirmed_tx, confirmed_rx) = unbounded_channel();
+        let leaser = DefaultLeaser::new(
+            self.inner.clone(),
+            confirmed_tx.clone(),
+            confirmed_tx,
+            self.subscription.clone(),
+            ack_deadline_seconds,
+            self.grpc_subchannel_count,
+        );
+        (leaser, confirmed_rx)
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> Acknowledger for DefaultAcknowledger<T>
+where
+    T: Stub + 'static,
+{
+    fn subscription(&self) -> &str {
+        &self.subscription
+    }
+
+    async fn ack(&self, ack_ids: Vec<String>) -> ConfirmedAcks {
+        let (leaser, rx) = self.leaser(NACK_DEADLINE_SECONDS);
+        futures::future::join_all(batch(ack_ids).into_iter().map(|b| leaser.confirmed_ack(b)))
+            .await;
+        drop(leaser);
+        collect(rx).await
+    }
+
+    async fn modify_ack_deadline(&self, ack_ids: Vec<String>, seconds: i32) -> ConfirmedAcks {
+        if seconds == 0 {
+            let (leaser, rx) = self.leaser(NACK_DEADLINE_SECONDS);
+            futures::future::join_all(batch(ack_ids).into_iter().map(|b| leaser.confirmed_nack(b)))
+                .await;
+            drop(leaser);
+            return collect(rx).await;
+        }
+        let (leaser, rx) = self.leaser(seconds);
+        futures::future::join_all(batch(ack_ids).into_iter().map(|b| leaser.eo_extend(b))).await;
+        drop(leaser);
+        collect(rx).await
+    }
+}
+
+async fn collect(mut rx: UnboundedReceiver<ConfirmedAcks>) -> ConfirmedAcks {
+    let mut results = ConfirmedAcks::new();
+    while let Some(r) = rx.recv().await {
+        results.extend(r);
+    }
+    results
+}
+
+fn result_for(results: &mut ConfirmedAcks, ack_id: &str) -> AckResult {
+    results.remove(ack_id).unwrap_or_else(|| {
+        Err(AckError::Shutdown(
+            "the operation completed without a result for the message".into(),
+        ))
+    })
+}
+
+/// A handler for messages received with [Subscriber::pull].
+///
+/// Each operation is a unary RPC, retried until the service confirms the
+/// result, as with [exactly-once] delivery. Use an [AckBatch] to acknowledge
+/// many messages with a few RPCs.
+///
+/// There is no lease management for pulled messages. The service redelivers
+/// a message if it is not acknowledged before its ack deadline expires. Use
+/// [modify_ack_deadline()][PullHandler::modify_ack_deadline] to extend the
+/// deadline of messages that take longer to process. Dropping the handler
+/// does not nack the message.
+///
+/// # Example
+/// ```
+/// # use google_cloud_pubsub::model::Message;
+/// # use google_cloud_pubsub::subscriber::PullHandler;
+/// async fn on_message(m: Message, h: PullHandler) -> anyhow::Result<()> {
+///     println!("Received message m={m:?}");
+///     h.ack().await?;
+///     Ok(())
+/// }
+/// ```
+///
+/// [exactly-once]: https://docs.cloud.google.com/pubsub/docs/exactly-once-delivery
+/// [Subscriber::pull]: crate::client::Subscriber::pull
+#[derive(Debug)]
+pub struct PullHandler {
+    ack_id: String,
+    delivery_attempt: Option<i32>,
+    acknowledger: Arc<dyn Acknowledger>,
+}
+
+impl PullHandler {
+    /// Returns the ack ID of the message.
+    pub fn ack_id(&self) -> &str {
+        &self.ack_id
+    }
+
+    /// Returns the delivery attempt count for this message, if available.
+    ///
+    /// This returns `None` if dead-letter topics are not configured on the
+    /// subscription.
+    pub fn delivery_attempt(&self) -> Option<i32> {
+        self.delivery_attempt
+    }
+
+    /// Acknowledges the message and waits for confirmation.
+    ///
+    /// If the subscription has exactly-once delivery enabled, the message is
+    /// guaranteed not to be delivered again when the result is `Ok`.
+    pub async fn ack(self) -> std::result::Result<(), AckError> {
+        let mut results = self.acknowledger.ack(vec![self.ack_id.clone()]).await;
+        result_for(&mut results, &self.ack_id)
+    }
+
+    /// Rejects the message and waits for confirmation.
+    ///
+    /// The service will redeliver the message, possibly to another client.
+    pub async fn nack(self) -> std::result::Result<(), AckError> {
+        let mut results = self
+            .acknowledger
+            .modify_ack_deadline(vec![self.ack_id.clone()], 0)
+            .await;
+        result_for(&mut results, &self.ack_id)
+    }
+
+    /// Sets the ack deadline of the message to `deadline` from now.
+    ///
+    /// The deadline is rounded down to seconds, and limited to 10 minutes. A
+    /// zero deadline is equivalent to a [nack()][PullHandler::nack].
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_pubsub::subscriber::PullHandler;
+    /// # use std::time::Duration;
+    /// async fn extend(h: &PullHandler) -> anyhow::Result<()> {
+    ///     h.modify_ack_deadline(Duration::from_secs(120)).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn modify_ack_deadline(
+        &self,
+        deadline: Duration,
+    ) -> std::result::Result<(), AckError> {
+        let seconds = deadline.as_secs().min(MAX_ACK_DEADLINE_SECONDS) as i32;
+        let mut results = self
+            .acknowledger
+            .modify_ack_deadline(vec![self.ack_id.clone()], seconds)
+            .await;
+        result_for(&mut results, &self.ack_id)
+    }
+}
+
+/// Acknowledges or rejects a batch of pulled messages.
+///
+/// The batch sends one `Acknowledge` and one `ModifyAckDeadline` RPC per
+/// subscription, split as needed to satisfy the request size limits. The RPCs
+/// are retried until the service confirms the result of each message.
+///
+/// # Example
+/// ```
+/// # use google_cloud_pubsub::client::Subscriber;
+/// # use google_cloud_pubsub::subscriber::AckBatch;
+/// # async fn sample(client: Subscriber) -> anyhow::Result<()> {
+/// let messages = client
+///     .pull("projects/my-project/subscriptions/my-subscription", 100)
+///     .await?;
+/// let mut batch = AckBatch::new();
+/// for (m, h) in messages {
+///     println!("Received message m={m:?}");
+///     batch.ack(h);
+/// }
+/// for (ack_id, result) in batch.send().await {
+///     if let Err(e) = result {
+///         println!("failed to ack {ack_id}: {e}");
+///     }
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Default)]
+pub struct AckBatch {
+    acks: Vec<PullHandler>,
+    nacks: Vec<PullHandler>,
+}
+
+impl AckBatch {
+    /// Creates an empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a message to acknowledge.
+    pub fn ack(&mut self, handler: PullHandler) {
+        self.acks.push(handler);
+    }
+
+    /// Adds a message to reject.
+    pub fn nack(&mut self, handler: PullHandler) {
+        self.nacks.push(handler);
+    }
+
+    /// The number of messages in the batch.
+    pub fn len(&self) -> usize {
+        self.acks.len() + self.nacks.len()
+    }
+
+    /// Returns true if the batch has no messages.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Sends the batch and waits for the result of each message.
+    ///
+    /// The results are keyed by [ack ID][PullHandler::ack_id].
+    pub async fn send(self) -> HashMap<String, std::result::Result<(), AckError>> {
+        let acks = group(self.acks).into_values().map(|(a, ids)| async move {
+            let mut results = a.ack(ids.clone()).await;
+            ids.into_iter()
+                .map(|id| {
+                    let r = result_for(&mut results, &id);
+                    (id, r)
+                })
+                .collect::<Vec<_>>()
+        });
+        let nacks = group(self.nacks).into_values().map(|(a, ids)| async move {
+            let mut results = a.modify_ack_deadline(ids.clone(), 0).await;
+            ids.into_iter()
+                .map(|id| {
+                    let r = result_for(&mut results, &id);
+                    (id, r)
+                })
+                .collect::<Vec<_>>()
+        });
+        let (acks, nacks) = futures::future::join(
+            futures::future::join_all(acks),
+            futures::future::join_all(nacks),
+        )
+        .await;
+        acks.into_iter().chain(nacks).flatten().collect()
+    }
+}
+
+type Group = (Arc<dyn Acknowledger>, Vec<String>);
+
+fn group(handlers: Vec<PullHandler>) -> HashMap<String, Group> {
+    let mut groups = HashMap::<String, Group>::new();
+    for h in handlers {
+        groups
+            .entry(h.acknowledger.subscription().to_string())
+            .or_insert_with(|| (h.acknowledger.clone(), Vec::new()))
+            .1
+            .push(h.ack_id);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::lease_state::tests::{sorted, test_id, test_ids};
+    use super::super::stub::tests::MockStub;
+    use super::*;
+    use crate::google::pubsub::v1::{PubsubMessage, PullResponse, ReceivedMessage};
+    use crate::{Error, Response};
+    use google_cloud_gax::error::rpc::{Code, Status, StatusDetails};
+    use google_cloud_rpc::model::ErrorInfo;
+
+    const SUBSCRIPTION: &str = "projects/p/subscriptions/s";
+
+    fn received(i: i32) -> ReceivedMessage {
+        ReceivedMessage {
+            ack_id: test_id(i),
+            message: Some(PubsubMessage {
+                data: format!("message-{i}").into(),
+                ..Default::default()
+            }),
+            delivery_attempt: i,
+        }
+    }
+
+    fn mock_pull(mock: &mut MockStub, messages: Vec<ReceivedMessage>) {
+        mock.expect_pull().return_once(move |r, _| {
+            assert_eq!(r.subscription, SUBSCRIPTION);
+            assert_eq!(r.max_messages, 10);
+            Ok(PullResponse {
+                received_messages: messages,
+            })
+        });
+    }
+
+    async fn pull_from(mock: MockStub) -> Result<Vec<(Message, PullHandler)>> {
+        pull(Arc::new(mock), SUBSCRIPTION.to_string(), 10, 1).await
+    }
+
+    #[tokio::test]
+    async fn pull_messages() -> anyhow::Result<()> {
+        let mut mock = MockStub::new();
+        let mut messages: Vec<_> = (0..3).map(received).collect();
+        // Ack IDs without a message are ignored.
+        messages.push(ReceivedMessage {
+            ack_id: test_id(3),
+            ..Default::default()
+        });
+        mock_pull(&mut mock, messages);
+        mock.expect_acknowledge().never();
+        mock.expect_modify_ack_deadline().never();
+
+        let got = pull_from(mock).await?;
+        assert_eq!(got.len(), 3, "{got:?}");
+        for (i, (m, h)) in got.iter().enumerate() {
+            assert_eq!(m.data, format!("message-{i}"));
+            assert_eq!(h.ack_id(), test_id(i as i32));
+            assert_eq!(h.delivery_attempt(), (i > 0).then_some(i as i32));
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn pull_error() {
+        let mut mock = MockStub::new();
+        mock.expect_pull()
+            .return_once(|_, _| Err(Error::service(Status::default().set_code(Code::NotFound))));
+        let err = pull_from(mock).await.unwrap_err();
+        assert_eq!(
+            err.status().map(|s| s.code),
+            Some(Code::NotFound),
+            "{err:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn handler_operations() -> anyhow::Result<()> {
+        let mut mock = MockStub::new();
+        mock_pull(&mut mock, (0..3).map(received).collect());
+        mock.expect_acknowledge().times(1).return_once(|r, _| {
+            assert_eq!(r.subscription, SUBSCRIPTION);
+            assert_eq!(r.ack_ids, vec![test_id(0)]);
+            Ok(Response::from(()))
+        });
+        let mut seq = mockall::Sequence::new();
+        mock.expect_modify_ack_deadline()
+            .times(1)
+            .in_sequence(&mut seq)
+            .return_once(|r, _| {
+                assert_eq!(r.ack_ids, vec![test_id(1)]);
+                assert_eq!(r.ack_deadline_seconds, 0);
+                Ok(Response::from(()))
+            });
+        mock.expect_modify_ack_deadline()
+            .times(1)
+            .in_sequence(&mut seq)
+            .return_once(|r, _| {
+                assert_eq!(r.ack_ids, vec![test_id(2)]);
+                assert_eq!(r.ack_deadline_seconds, 600);
+                Ok(Response::from(()))
+            });
+
+        let mut got = pull_from(mock).await?.into_iter();
+        let (_, h0) = got.next().unwrap();
+        let (_, h1) = got.next().unwrap();
+        let (_, h2) = got.next().unwrap();
+        h0.ack().await?;
+        h1.nack().await?;
+        h2.modify_ack_deadline(Duration::from_secs(3600)).await?;
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn handler_retries_transient_failures() -> anyhow::Result<()> {
+        let mut mock = MockStub::new();
+        mock_pull(&mut mock, vec![received(0)]);
+        let mut seq = mockall::Sequence::new();
+        mock.expect_acknowledge()
+            .times(1)
+            .in_sequence(&mut seq)
+            .return_once(|_, _| {
+                Err(Error::service(
+                    Status::default().set_code(Code::Unavailable),
+                ))
+            });
+        mock.expect_acknowledge()
+            .times(1)
+            .in_sequence(&mut seq)
+            .return_once(|_, _| Ok(Response::from(())));
+
+        let (_, h) = pull_from(mock).await?.pop().unwrap();
+        h.ack().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn handler_permanent_failure() -> anyhow::Result<()> {
+        let mut mock = MockStub::new();
+        mock_pull(&mut mock, vec![received(0)]);
+        mock.expect_acknowledge().times(1).return_once(|_, _| {
+            Err(Error::service(
+                Status::default().set_code(Code::InvalidArgument),
+            ))
+        });
+
+        let (_, h) = pull_from(mock).await?.pop().unwrap();
+        let err = h.ack().await.unwrap_err();
+        assert!(matches!(err, AckError::Rpc { .. }), "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn ack_batch() -> anyhow::Result<()> {
+        let mut mock = MockStub::new();
+        mock_pull(&mut mock, (0..10).map(received).collect());
+        let mut seq = mockall::Sequence::new();
+        // The first attempt reports a transient failure for one ack ID and a
+        // permanent failure for another.
+        mock.expect_acknowledge()
+            .times(1)
+            .in_sequence(&mut seq)
+            .return_once(|r, _| {
+                assert_eq!(sorted(&r.ack_ids), test_ids(0..6));
+                let info = ErrorInfo::new().set_metadata([
+                    (test_id(1), "TRANSIENT_FAILURE_OTHER"),
+                    (test_id(2), "PERMANENT_FAILURE_INVALID_ACK_ID"),
+                ]);
+                Err(Error::service(
+                    Status::default()
+                        .set_code(Code::InvalidArgument)
+                        .set_details([StatusDetails::ErrorInfo(info)]),
+                ))
+            });
+        mock.expect_acknowledge()
+            .times(1)
+            .in_sequence(&mut seq)
+            .return_once(|r, _| {
+                assert_eq!(r.ack_ids, vec![test_id(1)]);
+                Ok(Response::from(()))
+            });
+        mock.expect_modify_ack_deadline()
+            .times(1)
+            .return_once(|r, _| {
+                assert_eq!(sorted(&r.ack_ids), test_ids(6..10));
+                assert_eq!(r.ack_deadline_seconds, 0);
+                Ok(Response::from(()))
+            });
+
+        let mut batch = AckBatch::new();
+        assert!(batch.is_empty());
+        for (i, (_, h)) in pull_from(mock).await?.into_iter().enumerate() {
+            if i < 6 { batch.ack(h) } else { batch.nack(h) }
+        }
+        assert_eq!(batch.len(), 10);
+
+        let results = batch.send().await;
+        assert_eq!(results.len(), 10, "{results:?}");
+        for (id, result) in results {
+            if id == test_id(2) {
+                assert!(matches!(result, Err(AckError::Rpc { .. })), "{result:?}");
+            } else {
+                assert!(result.is_ok(), "{id}: {result:?}");
+            }
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn empty_batch() {
+        let results = AckBatch::new().send().await;
+        assert!(results.is_empty(), "{results:?}");
+    }
+}
@@ -209,6 +209,14 @@ mod tests {
         ) -> Result<crate::Response<()>> {
             unreachable!()
         }
+
+        async fn pull(
+            &self,
+            _req: crate::google::pubsub::v1::PullRequest,
+            _options: RequestOptions,
+        ) -> Result<crate::google::pubsub::v1::PullResponse> {
+            unreachable!()
+        }
     }
 
     fn transient_error() -> Error {
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use crate::Result;
-use crate::google::pubsub::v1::{StreamingPullRequest, StreamingPullResponse};
+use crate::google::pubsub::v1::{
+    PullRequest, PullResponse, StreamingPullRequest, StreamingPullResponse,
+};
 use gaxi::grpc::tonic::{Response as TonicResponse, Result as TonicResult};
 use tokio::sync::mpsc::Receiver;
 
@@ -45,6 +47,12 @@ pub(super) trait Stub: std::fmt::Debug + Send + Sync {
         _req: crate::model::AcknowledgeRequest,
         _options: crate::RequestOptions,
     ) -> Result<crate::Response<()>>;
+
+    async fn pull(
+        &self,
+        _req: PullRequest,
+        _options: crate::RequestOptions,
+    ) -> Result<PullResponse>;
 }
 
 #[cfg(test)]
@@ -82,6 +90,11 @@ pub(super) mod tests {
                 _req: crate::model::AcknowledgeRequest,
                 _options: crate::RequestOptions,
             ) -> Result<crate::Response<()>>;
+            async fn pull(
+                &self,
+                _req: PullRequest,
+                _options: crate::RequestOptions,
+            ) -> Result<PullResponse>;
         }
     }
 }
@@ -16,7 +16,9 @@ use super::stub::{Stub, TonicStreaming};
 use crate::Result;
 use crate::generated::gapic_dataplane::stub::dynamic::Subscriber as GapicStub;
 pub(super) use crate::generated::gapic_dataplane::transport::Subscriber as Transport;
-use crate::google::pubsub::v1::{StreamingPullRequest, StreamingPullResponse};
+use crate::google::pubsub::v1::{
+    PullRequest, PullResponse, StreamingPullRequest, StreamingPullResponse,
+};
 use gaxi::grpc::tonic::{Response as TonicResponse, Result as TonicResult, Streaming};
 use tokio::sync::mpsc::Receiver;
 use tokio_stream::wrappers::ReceiverStream;
@@ -90,6 +92,28 @@ impl Stub for Transport {
     ) -> Result<crate::Response<()>> {
         GapicStub::acknowledge(self, req, options).await
     }
+
+    async fn pull(&self, req: PullRequest, options: crate::RequestOptions) -> Result<PullResponse> {
+        use gaxi::grpc::tonic::{Extensions, GrpcMethod};
+        let extensions = {
+            let mut e = Extensions::new();
+            e.insert(GrpcMethod::new("google.pubsub.v1.Subscriber", "Pull"));
+            e
+        };
+        let path = http::uri::PathAndQuery::from_static("/google.pubsub.v1.Subscriber/Pull");
+        let request_params = format!("subscription={}", req.subscription);
+        self.inner
+            .execute(
+                extensions,
+                path,
+                req,
+                options,
+                &info::X_GOOG_API_CLIENT_HEADER,
+                &request_params,
+            )
+            .await
+            .map(TonicResponse::into_inner)
+    }
 }
 
 #[cfg(test)]
@@ -177,6 +201,48 @@ pub(super) mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn pull() -> anyhow::Result<()> {
+        let expected = PullResponse {
+            received_messages: vec![ReceivedMessage {
+                ack_id: "test-ack-id".to_string(),
+                ..Default::default()
+            }],
+        };
+        let response = {
+            use prost::Message;
+            v1::PullResponse::decode(expected.encode_to_vec().as_slice())?
+        };
+        let mut mock = MockSubscriber::new();
+        mock.expect_pull().return_once(|request| {
+            assert_eq!(
+                request
+                    .metadata()
+                    .get("x-goog-request-params")
+                    .expect("routing header missing"),
+                "subscription=projects/p/subscriptions/s"
+            );
+            let request = request.into_inner();
+            assert_eq!(request.subscription, "projects/p/subscriptions/s");
+            assert_eq!(request.max_messages, 10);
+            Ok(TonicResponse::from(response))
+        });
+        let (endpoint, _server) = start("0.0.0.0:0", mock).await?;
+        let transport = test_transport(endpoint).await?;
+        let got = Stub::pull(
+            &transport,
+            PullRequest {
+                subscription: "projects/p/subscriptions/s".to_string(),
+                max_messages: 10,
+                ..Default::default()
+            },
+            crate::RequestOptions::default(),
+        )
+        .await?;
+        assert_eq!(got, expected);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn acknowledge() -> anyhow::Result<()> {
         let mut mock = MockSubscriber::new();
@@ -0,0 +1,248 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Derive macros for the Google Cloud Spanner client.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{Data, DeriveInput, Field, Fields, parse_macro_input};
+
+/// Derives standard library [TryFrom] for converting a Spanner `Row` into a struct.
+///
+/// Each field is read from the column with the same name. Use `Option<T>`
+/// fields for nullable columns.
+///
+/// The macro also implements `FromValue`, so the struct can be used as the
+/// type of a `STRUCT` column in another struct, or as the element type of an
+/// `ARRAY<STRUCT<...>>` column.
+///
+/// Supports renaming attributes via `#[spanner(rename = "new_name")]`.
+#[proc_macro_derive(FromRow, attributes(spanner))]
+pub fn derive_from_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident;
+
+    let fields = match named_fields(&name, input.data, "FromRow") {
+        Ok(f) => f,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let attrs = match fields
+        .iter()
+        .map(FieldAttrs::parse)
+        .collect::<syn::Result<Vec<_>>>()
+    {
+        Ok(a) => a,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let row_extractions = fields.iter().zip(&attrs).map(|(f, a)| {
+        let field_name = f.ident.as_ref().expect("named field must have identifier");
+        let column_name = &a.column;
+        quote! {
+            #field_name: row.try_get(#column_name)?,
+        }
+    });
+
+    let struct_extractions = fields.iter().zip(&attrs).map(|(f, a)| {
+        let field_name = f.ident.as_ref().expect("named field must have identifier");
+        let column_name = &a.column;
+        quote! {
+            #field_name: google_cloud_spanner::value::struct_field(value, type_, #column_name)?,
+        }
+    });
+
+    let expanded = quote! {
+        impl std::convert::TryFrom<&google_cloud_spanner::result::Row> for #name {
+            type Error = google_cloud_spanner::Error;
+
+            fn try_from(row: &google_cloud_spanner::result::Row) -> std::result::Result<Self, Self::Error> {
+                std::result::Result::Ok(Self {
+                    #( #row_extractions )*
+                })
+            }
+        }
+
+        impl std::convert::TryFrom<google_cloud_spanner::result::Row> for #name {
+            type Error = google_cloud_spanner::Error;
+
+            fn try_from(row: google_cloud_spanner::result::Row) -> std::result::Result<Self, Self::Error> {
+                Self::try_from(&row)
+            }
+        }
+
+        impl google_cloud_spanner::value::FromValue for #name {
+            fn from_value(
+                value: &google_cloud_spanner::value::Value,
+                type_: &google_cloud_spanner::value::Type,
+            ) -> std::result::Result<Self, google_cloud_spanner::error::ConvertError> {
+                std::result::Result::Ok(Self {
+                    #( #struct_extractions )*
+                })
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+/// Derives `ToMutation` for converting a struct into mutations for a table.
+///
+/// Each field is written to the column with the same name. The fields marked
+/// with `#[spanner(key)]` form the primary key, in declaration order. The
+/// table name defaults to the name of the struct.
+///
+/// Supports the following attributes:
+/// - `#[spanner(table = "name")]` on the struct, to set the table name.
+/// - `#[spanner(rename = "new_name")]` on a field, to set the column name.
+/// - `#[spanner(key)]` on a field, to include it in the primary key.
+#[proc_macro_derive(ToMutation, attributes(spanner))]
+pub fn derive_to_mutation(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident;
+
+    let table_name = match table_name(&input.attrs) {
+        Ok(t) => t.unwrap_or_else(|| name.to_string()),
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let fields = match named_fields(&name, input.data, "ToMutation") {
+        Ok(f) => f,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let attrs = match fields
+        .iter()
+        .map(FieldAttrs::parse)
+        .collect::<syn::Result<Vec<_>>>()
+    {
+        Ok(a) => a,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let column_names = attrs.iter().map(|a| &a.column);
+    let values = fields.iter().map(|f| {
+        let field_name = f.ident.as_ref().expect("named field must have identifier");
+        quote! {
+            google_cloud_spanner::value::ToValue::to_value(&self.#field_name)
+        }
+    });
+    let key_values = fields
+        .iter()
+        .zip(&attrs)
+        .filter(|(_, a)| a.key)
+        .map(|(f, _)| {
+            let field_name = f.ident.as_ref().expect("named field must have identifier");
+            quote! {
+                google_cloud_spanner::value::ToValue::to_value(&self.#field_name)
+            }
+        });
+
+    let expanded = quote! {
+        impl google_cloud_spanner::mutation::ToMutation for #name {
+            fn table_name() -> &'static str {
+                #table_name
+            }
+
+            fn column_names() -> &'static [&'static str] {
+                &[ #( #column_names, )* ]
+            }
+
+            fn values(&self) -> std::vec::Vec<google_cloud_spanner::value::Value> {
+                std::vec![ #( #values, )* ]
+            }
+
+            fn key(&self) -> google_cloud_spanner::key::Key {
+                google_cloud_spanner::key::Key::new(std::vec![ #( #key_values, )* ])
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+fn named_fields(
+    name: &syn::Ident,
+    data: Data,
+    derive: &str,
+) -> syn::Result<Punctuated<Field, Comma>> {
+    match data {
+        Data::Struct(data) => match data.fields {
+            Fields::Named(fields) => Ok(fields.named),
+            _ => Err(syn::Error::new_spanned(
+                name,
+                format!("{derive} can only be derived for structs with named fields"),
+            )),
+        },
+        _ => Err(syn::Error::new_spanned(
+            name,
+            format!("{derive} can only be derived for structs"),
+        )),
+    }
+}
+
+fn table_name(attrs: &[syn::Attribute]) -> syn::Result<Option<String>> {
+    let mut table = None;
+    for attr in attrs {
+        if attr.path().is_ident("spanner") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("table") {
+                    let lit: syn::LitStr = meta.value()?.parse()?;
+                    table = Some(lit.value());
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported spanner attribute"))
+                }
+            })?;
+        }
+    }
+    Ok(table)
+}
+
+struct FieldAttrs {
+    column: String,
+    key: bool,
+}
+
+impl FieldAttrs {
+    fn parse(field: &Field) -> syn::Result<Self> {
+        let mut column = None;
+        let mut key = false;
+        for attr in &field.attrs {
+            if attr.path().is_ident("spanner") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename") {
+                        let lit: syn::LitStr = meta.value()?.parse()?;
+                        column = Some(lit.value());
+                        Ok(())
+                    } else if meta.path.is_ident("key") {
+                        key = true;
+                        Ok(())
+                    } else {
+                        Err(meta.error("unsupported spanner attribute"))
+                    }
+                })?;
+            }
+        }
+        let column = column.unwrap_or_else(|| {
+            field
+                .ident
+                .as_ref()
+                .expect("named field must have identifier")
+                .to_string()
+        });
+        Ok(Self { column, key })
+    }
+}
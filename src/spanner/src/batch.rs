@@ -17,3 +17,7 @@
 pub use crate::batch_dml::BatchDml;
 pub use crate::batch_read_only_transaction::{BatchReadOnlyTransaction, Partition};
 pub use crate::batch_write_transaction::{BatchWriteResponseStream, BatchWriteTransaction};
+pub use crate::bulk_writer::{BulkWriteFailure, BulkWriteSummary, BulkWriter, BulkWriterBuilder};
+pub use crate::partition_executor::{
+    ExecuteAllBuilder, PartitionEvent, PartitionedResults, PartitionedRows,
+};
@@ -14,6 +14,7 @@
 
 use crate::database_client::DatabaseClient;
 use crate::model::{ExecuteSqlRequest, PartitionOptions, ReadRequest};
+use crate::partition_executor::ExecuteAllBuilder;
 use crate::precommit::PrecommitTokenTracker;
 use crate::read_only_transaction::{
     BeginTransactionOption, MultiUseReadOnlyTransaction, MultiUseReadOnlyTransactionBuilder,
@@ -255,6 +256,72 @@ impl BatchReadOnlyTransaction {
             })
             .collect())
     }
+
+    /// Partitions a query and executes all the partitions in parallel.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_spanner::client::Spanner;
+    /// # use google_cloud_spanner::statement::Statement;
+    /// # async fn run(spanner: Spanner) -> Result<(), google_cloud_spanner::Error> {
+    /// let db = spanner.database_client("projects/p/instances/i/databases/d").build().await?;
+    /// let transaction = db.batch_read_only_transaction().build().await?;
+    ///
+    /// let stmt = Statement::builder("SELECT * FROM Users").build();
+    /// let mut rows = transaction.execute_all(stmt, 8).rows().await?;
+    /// while let Some(row) = rows.next().await.transpose()? {
+    ///     // process row
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// At most `concurrency` partitions are executed at the same time. Use
+    /// this method when a single process consumes all the rows; to spread
+    /// the work across processes, send the results of
+    /// [partition_query](Self::partition_query) to each worker instead.
+    pub fn execute_all<T: Into<Statement>>(
+        &self,
+        statement: T,
+        concurrency: usize,
+    ) -> ExecuteAllBuilder<'_> {
+        ExecuteAllBuilder::query(self, statement.into(), concurrency)
+    }
+
+    /// Partitions a read and executes all the partitions in parallel.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_spanner::client::Spanner;
+    /// # use google_cloud_spanner::key::KeySet;
+    /// # use google_cloud_spanner::read::ReadRequest;
+    /// # async fn run(spanner: Spanner) -> Result<(), google_cloud_spanner::Error> {
+    /// let db = spanner.database_client("projects/p/instances/i/databases/d").build().await?;
+    /// let transaction = db.batch_read_only_transaction().build().await?;
+    ///
+    /// let read = ReadRequest::builder("Users", vec!["Id", "Name"])
+    ///     .with_keys(KeySet::all())
+    ///     .build();
+    /// let mut rows = transaction.execute_all_read(read, 8).rows().await?;
+    /// while let Some(row) = rows.next().await.transpose()? {
+    ///     // process row
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// At most `concurrency` partitions are executed at the same time.
+    pub fn execute_all_read<T: Into<crate::read::ReadRequest>>(
+        &self,
+        read: T,
+        concurrency: usize,
+    ) -> ExecuteAllBuilder<'_> {
+        ExecuteAllBuilder::read(self, read.into(), concurrency)
+    }
+
+    pub(crate) fn client(&self) -> &DatabaseClient {
+        &self.inner.context.client
+    }
 }
 
 /// Defines the segments of data to be read in a partitioned read or query.
@@ -0,0 +1,904 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::database_client::DatabaseClient;
+use crate::error::internal_error;
+use crate::mutation::{Mutation, MutationGroup};
+use crate::retry_policy::SpannerRetryPolicy;
+use crate::transaction_retry_policy::{default_retry_backoff, is_aborted};
+use google_cloud_gax::backoff_policy::{BackoffPolicy, BackoffPolicyArg};
+use google_cloud_gax::error::rpc::{Code, Status};
+use google_cloud_gax::retry_policy::{RetryPolicy, RetryPolicyArg, RetryPolicyExt};
+use google_cloud_gax::retry_result::RetryResult;
+use google_cloud_gax::retry_state::RetryState;
+use google_cloud_gax::throttle_result::ThrottleResult;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::{JoinHandle, JoinSet};
+
+const DEFAULT_MAX_MUTATIONS_PER_GROUP: usize = 5_000;
+const DEFAULT_MAX_BYTES_PER_GROUP: usize = 4 * 1024 * 1024;
+const DEFAULT_MAX_GROUPS_PER_REQUEST: usize = 20;
+const DEFAULT_MAX_IN_FLIGHT_REQUESTS: usize = 4;
+const DEFAULT_ATTEMPT_LIMIT: u32 = 10;
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// A builder for [BulkWriter].
+///
+/// # Example
+/// ```
+/// # use google_cloud_spanner::client::DatabaseClient;
+/// # fn sample(db: &DatabaseClient) {
+/// let writer = db
+///     .bulk_writer()
+///     .with_max_mutations_per_group(1_000)
+///     .with_max_in_flight_requests(8)
+///     .build();
+/// # }
+/// ```
+pub struct BulkWriterBuilder {
+    client: DatabaseClient,
+    max_mutations_per_group: usize,
+    max_bytes_per_group: usize,
+    max_groups_per_request: usize,
+    max_in_flight_requests: usize,
+    retry_policy: Arc<dyn RetryPolicy>,
+    backoff_policy: Arc<dyn BackoffPolicy>,
+}
+
+impl BulkWriterBuilder {
+    pub(crate) fn new(client: DatabaseClient) -> Self {
+        Self {
+            client,
+            max_mutations_per_group: DEFAULT_MAX_MUTATIONS_PER_GROUP,
+            max_bytes_per_group: DEFAULT_MAX_BYTES_PER_GROUP,
+            max_groups_per_request: DEFAULT_MAX_GROUPS_PER_REQUEST,
+            max_in_flight_requests: DEFAULT_MAX_IN_FLIGHT_REQUESTS,
+            retry_policy: Arc::new(
+                BulkWriteRetryPolicy::default().with_attempt_limit(DEFAULT_ATTEMPT_LIMIT),
+            ),
+            backoff_policy: Arc::new(default_retry_backoff()),
+        }
+    }
+
+    /// Sets the maximum number of mutations in each mutation group.
+    ///
+    /// Spanner counts one mutation for each column written, and limits each
+    /// commit to 80,000 mutations including the secondary indexes of the
+    /// modified tables. The writer cannot see the indexes, so the default of
+    /// 5,000 leaves room for them.
+    pub fn with_max_mutations_per_group(mut self, v: usize) -> Self {
+        self.max_mutations_per_group = v.max(1);
+        self
+    }
+
+    /// Sets the maximum estimated size in bytes of each mutation group.
+    ///
+    /// Defaults to 4 MiB.
+    pub fn with_max_bytes_per_group(mut self, v: usize) -> Self {
+        self.max_bytes_per_group = v.max(1);
+        self
+    }
+
+    /// Sets the maximum number of mutation groups sent in a single
+    /// `BatchWrite` request.
+    ///
+    /// Defaults to 20.
+    pub fn with_max_groups_per_request(mut self, v: usize) -> Self {
+        self.max_groups_per_request = v.max(1);
+        self
+    }
+
+    /// Sets the maximum number of `BatchWrite` requests in flight.
+    ///
+    /// Once this limit is reached, [BulkWriter::write] waits until a request
+    /// completes. Defaults to 4.
+    pub fn with_max_in_flight_requests(mut self, v: usize) -> Self {
+        self.max_in_flight_requests = v.max(1);
+        self
+    }
+
+    /// Sets the policy to retry mutation groups that fail.
+    ///
+    /// By default, groups that fail with `ABORTED` or `UNAVAILABLE` are
+    /// retried up to 10 times.
+    pub fn with_retry_policy(mut self, policy: impl Into<RetryPolicyArg>) -> Self {
+        self.retry_policy = policy.into().into();
+        self
+    }
+
+    /// Sets the backoff policy used between retries of failed mutation groups.
+    pub fn with_backoff_policy(mut self, policy: impl Into<BackoffPolicyArg>) -> Self {
+        self.backoff_policy = policy.into().into();
+        self
+    }
+
+    /// Builds the [BulkWriter] and starts its background task.
+    pub fn build(self) -> BulkWriter {
+        let (commands, rx) = mpsc::channel(CHANNEL_CAPACITY);
+        let worker = Worker {
+            client: self.client,
+            max_mutations_per_group: self.max_mutations_per_group,
+            max_bytes_per_group: self.max_bytes_per_group,
+            max_groups_per_request: self.max_groups_per_request,
+            max_in_flight_requests: self.max_in_flight_requests,
+            retry_policy: self.retry_policy,
+            backoff_policy: self.backoff_policy,
+        };
+        let task = tokio::spawn(worker.run(rx));
+        BulkWriter { commands, task }
+    }
+}
+
+/// Writes an unbounded number of mutations to Spanner using `BatchWrite`.
+///
+/// # Example
+/// ```
+/// # use google_cloud_spanner::client::DatabaseClient;
+/// # use google_cloud_spanner::mutation::Mutation;
+/// # async fn sample(db: &DatabaseClient) -> anyhow::Result<()> {
+/// let writer = db.bulk_writer().build();
+/// for id in 0..1_000_000 {
+///     let mutation = Mutation::new_insert_or_update_builder("Users")
+///         .set("UserId").to(&id)
+///         .build();
+///     writer.write(mutation).await?;
+/// }
+/// let summary = writer.close().await?;
+/// for failure in &summary.failures {
+///     eprintln!("{} mutation groups failed: {}", failure.groups.len(), failure.error);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The writer packs the mutations into mutation groups, limited by the number
+/// of mutations and the estimated size of each group, and applies the groups
+/// with concurrent `BatchWrite` requests. Each group is applied atomically, but
+/// there is no atomicity across groups, and the groups may be applied in any
+/// order. Groups that fail with a retryable error are retried. If a request
+/// fails before all its groups are acknowledged, for example because the
+/// connection is reset, the groups that were not acknowledged are retried
+/// using the same policy. Groups that fail permanently are reported in the
+/// [BulkWriteSummary].
+///
+/// `BatchWrite` applies mutations at least once. Prefer `InsertOrUpdate` and
+/// `Replace` mutations over `Insert` mutations, which fail if they are
+/// applied twice.
+#[derive(Debug)]
+pub struct BulkWriter {
+    commands: mpsc::Sender<Command>,
+    task: JoinHandle<BulkWriteSummary>,
+}
+
+impl BulkWriter {
+    /// Adds a mutation to the writer.
+    ///
+    /// Waits if the writer has too many pending requests.
+    pub async fn write(&self, mutation: Mutation) -> crate::Result<()> {
+        self.send(Command::Mutation(mutation)).await
+    }
+
+    /// Adds a mutation group to the writer.
+    ///
+    /// The mutations in the group are applied atomically. The group is
+    /// not split, even if it exceeds the configured limits.
+    pub async fn write_group(&self, group: MutationGroup) -> crate::Result<()> {
+        self.send(Command::Group(group)).await
+    }
+
+    /// Sends all pending mutations, and waits until they are applied or fail.
+    pub async fn flush(&self) -> crate::Result<()> {
+        let (tx, rx) = oneshot::channel();
+        self.send(Command::Flush(tx)).await?;
+        rx.await
+            .map_err(|_| internal_error("the bulk writer task stopped unexpectedly"))
+    }
+
+    /// Sends all pending mutations, waits until they are applied or fail,
+    /// and returns a summary of the results.
+    pub async fn close(self) -> crate::Result<BulkWriteSummary> {
+        let Self { commands, task } = self;
+        drop(commands);
+        task.await
+            .map_err(|e| internal_error(format!("the bulk writer task failed: {e}")))
+    }
+
+    async fn send(&self, command: Command) -> crate::Result<()> {
+        self.commands
+            .send(command)
+            .await
+            .map_err(|_| internal_error("the bulk writer task stopped unexpectedly"))
+    }
+}
+
+/// The results of a [BulkWriter].
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct BulkWriteSummary {
+    /// The number of mutation groups that were applied.
+    pub applied_groups: usize,
+    /// The number of [Mutation] values in the applied groups.
+    ///
+    /// This is not the mutation count that Spanner enforces for each commit,
+    /// which counts each column written, including the secondary indexes of
+    /// the modified tables.
+    pub applied_mutations: usize,
+    /// The mutation groups that failed permanently.
+    pub failures: Vec<BulkWriteFailure>,
+}
+
+/// One or more mutation groups that failed permanently with the same error.
+///
+/// A group rejected by Spanner is reported on its own, with the status
+/// returned for the group. If the request fails permanently with an error that
+/// is not a service error, for example an authentication error, all the groups
+/// that were not acknowledged share the error.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct BulkWriteFailure {
+    /// The mutation groups that were not applied.
+    pub groups: Vec<MutationGroup>,
+    /// The error of the last attempt to apply the groups.
+    pub error: crate::Error,
+}
+
+#[derive(Debug)]
+enum Command {
+    Mutation(Mutation),
+    Group(MutationGroup),
+    Flush(oneshot::Sender<()>),
+}
+
+/// The default retry policy of [BulkWriter].
+///
+/// Retries `ABORTED` errors in addition to the errors retried by
+/// [SpannerRetryPolicy].
+#[derive(Clone, Debug, Default)]
+struct BulkWriteRetryPolicy {
+    inner: SpannerRetryPolicy,
+}
+
+impl RetryPolicy for BulkWriteRetryPolicy {
+    fn on_error(&self, state: &RetryState, error: crate::Error) -> RetryResult {
+        if is_aborted(&error) {
+            return RetryResult::Continue(error);
+        }
+        self.inner.on_error(state, error)
+    }
+
+    fn on_throttle(&self, state: &RetryState, error: crate::Error) -> ThrottleResult {
+        self.inner.on_throttle(state, error)
+    }
+
+    fn remaining_time(&self, state: &RetryState) -> Option<Duration> {
+        self.inner.remaining_time(state)
+    }
+}
+
+/// The state of the background task.
+struct Worker {
+    client: DatabaseClient,
+    max_mutations_per_group: usize,
+    max_bytes_per_group: usize,
+    max_groups_per_request: usize,
+    max_in_flight_requests: usize,
+    retry_policy: Arc<dyn RetryPolicy>,
+    backoff_policy: Arc<dyn BackoffPolicy>,
+}
+
+/// The mutations of the group that is being filled.
+#[derive(Default)]
+struct OpenGroup {
+    mutations: Vec<Mutation>,
+    count: usize,
+    bytes: usize,
+}
+
+impl Worker {
+    async fn run(self, mut commands: mpsc::Receiver<Command>) -> BulkWriteSummary {
+        let mut summary = BulkWriteSummary::default();
+        let mut open = OpenGroup::default();
+        let mut ready = Vec::new();
+        let mut in_flight = JoinSet::new();
+
+        while let Some(command) = commands.recv().await {
+            match command {
+                Command::Mutation(mutation) => {
+                    let (count, bytes) = (mutation.mutation_count(), mutation.estimated_size());
+                    if !open.mutations.is_empty()
+                        && (open.count + count > self.max_mutations_per_group
+                            || open.bytes + bytes > self.max_bytes_per_group)
+                    {
+                        ready.push(MutationGroup::new(std::mem::take(&mut open).mutations));
+                    }
+                    open.mutations.push(mutation);
+                    open.count += count;
+                    open.bytes += bytes;
+                }
+                Command::Group(group) => ready.push(group),
+                Command::Flush(done) => {
+                    self.seal(&mut open, &mut ready);
+                    self.dispatch_all(&mut ready, &mut in_flight, &mut summary)
+                        .await;
+                    while let Some(result) = in_flight.join_next().await {
+                        Self::collect(result, &mut summary);
+                    }
+                    let _ = done.send(());
+                    continue;
+                }
+            }
+            while ready.len() >= self.max_groups_per_request {
+                let batch = ready.drain(..self.max_groups_per_request).collect();
+                self.dispatch(batch, &mut in_flight, &mut summary).await;
+            }
+        }
+
+        self.seal(&mut open, &mut ready);
+        self.dispatch_all(&mut ready, &mut in_flight, &mut summary)
+            .await;
+        while let Some(result) = in_flight.join_next().await {
+            Self::collect(result, &mut summary);
+        }
+        summary
+    }
+
+    fn seal(&self, open: &mut OpenGroup, ready: &mut Vec<MutationGroup>) {
+        if !open.mutations.is_empty() {
+            ready.push(MutationGroup::new(std::mem::take(open).mutations));
+        }
+    }
+
+    async fn dispatch_all(
+        &self,
+        ready: &mut Vec<MutationGroup>,
+        in_flight: &mut JoinSet<BulkWriteSummary>,
+        summary: &mut BulkWriteSummary,
+    ) {
+        while !ready.is_empty() {
+            let end = ready.len().min(self.max_groups_per_request);
+            let batch = ready.drain(..end).collect();
+            self.dispatch(batch, in_flight, summary).await;
+        }
+    }
+
+    /// Starts a request, after waiting for a slot if too many requests are in flight.
+    async fn dispatch(
+        &self,
+        batch: Vec<MutationGroup>,
+        in_flight: &mut JoinSet<BulkWriteSummary>,
+        summary: &mut BulkWriteSummary,
+    ) {
+        while in_flight.len() >= self.max_in_flight_requests {
+            match in_flight.join_next().await {
+                Some(result) => Self::collect(result, summary),
+                None => break,
+            }
+        }
+        in_flight.spawn(apply_groups(
+            self.client.clone(),
+            batch,
+            self.retry_policy.clone(),
+            self.backoff_policy.clone(),
+        ));
+    }
+
+    fn collect(
+        result: Result<BulkWriteSummary, tokio::task::JoinError>,
+        summary: &mut BulkWriteSummary,
+    ) {
+        match result {
+            Ok(s) => {
+                summary.applied_groups += s.applied_groups;
+                summary.applied_mutations += s.applied_mutations;
+                summary.failures.extend(s.failures);
+            }
+            Err(e) => tracing::warn!("bulk writer request task failed: {e}"),
+        }
+    }
+}
+
+/// Applies `groups` with `BatchWrite`, retrying the groups that fail.
+async fn apply_groups(
+    client: DatabaseClient,
+    groups: Vec<MutationGroup>,
+    retry_policy: Arc<dyn RetryPolicy>,
+    backoff_policy: Arc<dyn BackoffPolicy>,
+) -> BulkWriteSummary {
+    let mut summary = BulkWriteSummary::default();
+    let mut pending = groups;
+    let mut attempt_count = 0_u32;
+    let start = tokio::time::Instant::now();
+    while !pending.is_empty() {
+        attempt_count += 1;
+        let state = RetryState::new(true)
+            .set_start(start)
+            .set_attempt_count(attempt_count);
+        let outcome = batch_write(&client, &pending).await;
+        let retry = outcome.classify(&state, retry_policy.as_ref(), &mut summary);
+        if !retry.is_empty() {
+            tokio::time::sleep(backoff_policy.on_failure(&state)).await;
+        }
+        pending = retry;
+    }
+    summary
+}
+
+/// The results of a single `BatchWrite` request.
+struct BatchWriteOutcome {
+    /// The result of each group with a status.
+    results: Vec<(MutationGroup, crate::Result<()>)>,
+    /// The groups without a status when the request failed with `error`.
+    unacknowledged: Vec<MutationGroup>,
+    /// The request error, if it is not a service error.
+    error: Option<crate::Error>,
+}
+
+/// Sends a single `BatchWrite` request and returns the result of each group.
+///
+/// Groups that are not acknowledged before the stream fails with a service
+/// error are assigned a copy of its status. Other errors are returned once,
+/// with the groups that were not acknowledged.
+async fn batch_write(client: &DatabaseClient, groups: &[MutationGroup]) -> BatchWriteOutcome {
+    let mut results: Vec<Option<crate::Result<()>>> = groups.iter().map(|_| None).collect();
+    let stream_error = match client
+        .batch_write_transaction()
+        .build()
+        .execute_streaming(groups.iter().cloned())
+        .await
+    {
+        Err(e) => Some(e),
+        Ok(mut stream) => loop {
+            match stream.next().await {
+                None => break None,
+                Some(Err(e)) => break Some(e),
+                Some(Ok(response)) => {
+                    let status = response.status.filter(|s| s.code != Code::Ok as i32);
+                    for index in response.indexes {
+                        let Some(slot) =
+                            usize::try_from(index).ok().and_then(|i| results.get_mut(i))
+                        else {
+                            continue;
+                        };
+                        *slot = Some(match &status {
+                            None => Ok(()),
+                            Some(s) => Err(crate::Error::service(
+                                Status::default()
+                                    .set_code(s.code)
+                                    .set_message(s.message.clone()),
+                            )),
+                        });
+                    }
+                }
+            }
+        },
+    };
+    BatchWriteOutcome::new(groups, results, stream_error)
+}
+
+impl BatchWriteOutcome {
+    fn new(
+        groups: &[MutationGroup],
+        results: Vec<Option<crate::Result<()>>>,
+        stream_error: Option<crate::Error>,
+    ) -> Self {
+        // `crate::Error` is not `Clone`. Service errors keep their status,
+        // which is all the retry policy needs.
+        let (status, error) = match stream_error {
+            None => (
+                Some(
+                    Status::default()
+                        .set_code(Code::Unavailable)
+                        .set_message("BatchWrite did not return a status for the mutation group"),
+                ),
+                None,
+            ),
+            Some(e) => match e.status() {
+                Some(status) => (Some(status.clone()), None),
+                None => (None, Some(e)),
+            },
+        };
+        let mut outcome = BatchWriteOutcome {
+            results: Vec::new(),
+            unacknowledged: Vec::new(),
+            error,
+        };
+        for (group, result) in groups.iter().cloned().zip(results) {
+            match (result, &status) {
+                (Some(result), _) => outcome.results.push((group, result)),
+                (None, Some(status)) => outcome
+                    .results
+                    .push((group, Err(crate::Error::service(status.clone())))),
+                (None, None) => outcome.unacknowledged.push(group),
+            }
+        }
+        outcome
+    }
+
+    /// Records the applied groups and the permanent failures in `summary`,
+    /// and returns the groups to retry.
+    ///
+    /// The groups that were not acknowledged share a single request error, so
+    /// the retry policy decides for all of them at once.
+    fn classify(
+        self,
+        state: &RetryState,
+        retry_policy: &dyn RetryPolicy,
+        summary: &mut BulkWriteSummary,
+    ) -> Vec<MutationGroup> {
+        let mut retry = Vec::new();
+        for (group, result) in self.results {
+            match result {
+                Ok(()) => {
+                    summary.applied_groups += 1;
+                    summary.applied_mutations += group.mutations().len();
+                }
+                Err(error) => match retry_policy.on_error(state, error) {
+                    RetryResult::Continue(_) => retry.push(group),
+                    RetryResult::Permanent(error) | RetryResult::Exhausted(error) => {
+                        summary.failures.push(BulkWriteFailure {
+                            groups: vec![group],
+                            error,
+                        });
+                    }
+                },
+            }
+        }
+        let Some(error) = self.error.filter(|_| !self.unacknowledged.is_empty()) else {
+            return retry;
+        };
+        match retry_policy.on_error(state, error) {
+            RetryResult::Continue(_) => retry.extend(self.unacknowledged),
+            RetryResult::Permanent(error) | RetryResult::Exhausted(error) => {
+                summary.failures.push(BulkWriteFailure {
+                    groups: self.unacknowledged,
+                    error,
+                });
+            }
+        }
+        retry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_only_transaction::tests::{create_session_mock, setup_db_client};
+    use crate::result_set::tests::adapt;
+    use gaxi::grpc::tonic::Response;
+    use google_cloud_test_macros::tokio_test_no_panics;
+    use spanner_grpc_mock::google::rpc as mock_rpc;
+    use spanner_grpc_mock::google::spanner::v1 as mock_v1;
+    use std::sync::Mutex;
+
+    fn mutation(id: i64) -> Mutation {
+        Mutation::new_insert_or_update_builder("Users")
+            .set("UserId")
+            .to(id)
+            .set("Name")
+            .to(format!("user-{id}"))
+            .build()
+    }
+
+    fn applied(indexes: Vec<i32>) -> mock_v1::BatchWriteResponse {
+        mock_v1::BatchWriteResponse {
+            indexes,
+            status: None,
+            commit_timestamp: None,
+        }
+    }
+
+    fn failed(indexes: Vec<i32>, code: Code) -> mock_v1::BatchWriteResponse {
+        mock_v1::BatchWriteResponse {
+            indexes,
+            status: Some(mock_rpc::Status {
+                code: code as i32,
+                message: "failed".to_string(),
+                details: vec![],
+            }),
+            commit_timestamp: None,
+        }
+    }
+
+    #[tokio_test_no_panics]
+    async fn splits_groups_and_requests() -> anyhow::Result<()> {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let mut mock = create_session_mock();
+        let captured = requests.clone();
+        mock.expect_batch_write().returning(move |req| {
+            let req = req.into_inner();
+            let sizes: Vec<usize> = req
+                .mutation_groups
+                .iter()
+                .map(|g| g.mutations.len())
+                .collect();
+            let indexes = (0..sizes.len() as i32).collect();
+            captured.lock().unwrap().push(sizes);
+            Ok(Response::from(adapt([Ok(applied(indexes))])))
+        });
+        let (db_client, _server) = setup_db_client(mock).await;
+
+        // Each mutation writes two columns, so each group holds two mutations.
+        let writer = db_client
+            .bulk_writer()
+            .with_max_mutations_per_group(4)
+            .with_max_groups_per_request(2)
+            .with_max_in_flight_requests(1)
+            .build();
+        for id in 0..9 {
+            writer.write(mutation(id)).await?;
+        }
+        writer.flush().await?;
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![vec![2, 2], vec![2, 2], vec![1]]
+        );
+
+        writer
+            .write_group(MutationGroup::new((10..15).map(mutation).collect()))
+            .await?;
+        let summary = writer.close().await?;
+        assert_eq!(summary.applied_groups, 6, "{summary:?}");
+        assert_eq!(summary.applied_mutations, 14, "{summary:?}");
+        assert!(summary.failures.is_empty(), "{summary:?}");
+        assert_eq!(requests.lock().unwrap().last(), Some(&vec![5]));
+        Ok(())
+    }
+
+    #[tokio_test_no_panics]
+    async fn splits_groups_by_size() -> anyhow::Result<()> {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let mut mock = create_session_mock();
+        let captured = requests.clone();
+        mock.expect_batch_write().returning(move |req| {
+            let req = req.into_inner();
+            let count = req.mutation_groups.len();
+            captured.lock().unwrap().push(count);
+            let indexes = (0..count as i32).collect();
+            Ok(Response::from(adapt([Ok(applied(indexes))])))
+        });
+        let (db_client, _server) = setup_db_client(mock).await;
+
+        let size = mutation(0).estimated_size();
+        let writer = db_client
+            .bulk_writer()
+            .with_max_bytes_per_group(size * 3 / 2)
+            .build();
+        for id in 0..3 {
+            writer.write(mutation(id)).await?;
+        }
+        let summary = writer.close().await?;
+        assert_eq!(*requests.lock().unwrap(), vec![3]);
+        assert_eq!(summary.applied_groups, 3, "{summary:?}");
+        Ok(())
+    }
+
+    #[tokio_test_no_panics]
+    async fn retries_aborted_groups() -> anyhow::Result<()> {
+        let mut mock = create_session_mock();
+        let mut seq = mockall::Sequence::new();
+        mock.expect_batch_write()
+            .once()
+            .in_sequence(&mut seq)
+            .returning(|req| {
+                assert_eq!(req.into_inner().mutation_groups.len(), 3);
+                Ok(Response::from(adapt([
+                    Ok(applied(vec![0])),
+                    Ok(failed(vec![1, 2], Code::Aborted)),
+                ])))
+            });
+        mock.expect_batch_write()
+            .once()
+            .in_sequence(&mut seq)
+            .returning(|req| {
+                assert_eq!(req.into_inner().mutation_groups.len(), 2);
+                Ok(Response::from(adapt([Ok(applied(vec![1, 0]))])))
+            });
+        let (db_client, _server) = setup_db_client(mock).await;
+
+        let writer = db_client
+            .bulk_writer()
+            .with_max_mutations_per_group(2)
+            .with_backoff_policy(test_backoff())
+            .build();
+        for id in 0..3 {
+            writer.write(mutation(id)).await?;
+        }
+        let summary = writer.close().await?;
+        assert_eq!(summary.applied_groups, 3, "{summary:?}");
+        assert_eq!(summary.applied_mutations, 3, "{summary:?}");
+        assert!(summary.failures.is_empty(), "{summary:?}");
+        Ok(())
+    }
+
+    #[tokio_test_no_panics]
+    async fn reports_permanent_failures() -> anyhow::Result<()> {
+        let mut mock = create_session_mock();
+        mock.expect_batch_write().once().returning(|_| {
+            Ok(Response::from(adapt([
+                Ok(failed(vec![0], Code::AlreadyExists)),
+                Ok(applied(vec![1])),
+            ])))
+        });
+        let (db_client, _server) = setup_db_client(mock).await;
+
+        let writer = db_client
+            .bulk_writer()
+            .with_max_mutations_per_group(2)
+            .build();
+        writer.write(mutation(1)).await?;
+        writer.write(mutation(2)).await?;
+        let summary = writer.close().await?;
+        assert_eq!(summary.applied_groups, 1, "{summary:?}");
+        assert_eq!(summary.failures.len(), 1, "{summary:?}");
+        let failure = &summary.failures[0];
+        assert_eq!(failure.groups.len(), 1, "{failure:?}");
+        assert_eq!(failure.groups[0].mutations(), &[mutation(1)]);
+        assert_eq!(
+            failure.error.status().map(|s| s.code),
+            Some(Code::AlreadyExists),
+            "{failure:?}"
+        );
+        Ok(())
+    }
+
+    #[tokio_test_no_panics]
+    async fn stream_error_fails_unacknowledged_groups() -> anyhow::Result<()> {
+        let mut mock = create_session_mock();
+        mock.expect_batch_write().once().returning(|_| {
+            Ok(Response::from(adapt([
+                Ok(applied(vec![0])),
+                Err(gaxi::grpc::tonic::Status::permission_denied("denied")),
+            ])))
+        });
+        let (db_client, _server) = setup_db_client(mock).await;
+
+        let writer = db_client
+            .bulk_writer()
+            .with_max_mutations_per_group(2)
+            .build();
+        for id in 0..3 {
+            writer.write(mutation(id)).await?;
+        }
+        let summary = writer.close().await?;
+        assert_eq!(summary.applied_groups, 1, "{summary:?}");
+        assert_eq!(summary.failures.len(), 2, "{summary:?}");
+        for failure in &summary.failures {
+            assert_eq!(
+                failure.error.status().map(|s| s.code),
+                Some(Code::PermissionDenied),
+                "{failure:?}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn outcome_keeps_other_errors_once() {
+        let groups: Vec<_> = (0..3)
+            .map(|id| MutationGroup::new(vec![mutation(id)]))
+            .collect();
+        let results = vec![Some(Ok(())), None, None];
+        let error = crate::Error::io("connection reset");
+        let outcome = BatchWriteOutcome::new(&groups, results, Some(error));
+        assert_eq!(outcome.results.len(), 1);
+        assert!(outcome.results[0].1.is_ok());
+        assert_eq!(outcome.unacknowledged.len(), 2);
+        assert!(outcome.error.is_some_and(|e| e.is_io()));
+    }
+
+    #[test]
+    fn outcome_copies_service_errors() {
+        let groups: Vec<_> = (0..3)
+            .map(|id| MutationGroup::new(vec![mutation(id)]))
+            .collect();
+        let results = vec![Some(Ok(())), None, None];
+        let error = crate::Error::service(Status::default().set_code(Code::Unavailable));
+        let outcome = BatchWriteOutcome::new(&groups, results, Some(error));
+        assert!(outcome.unacknowledged.is_empty());
+        assert!(outcome.error.is_none());
+        let codes: Vec<_> = outcome
+            .results
+            .iter()
+            .map(|(_, r)| r.as_ref().err().and_then(|e| e.status()).map(|s| s.code))
+            .collect();
+        assert_eq!(
+            codes,
+            vec![None, Some(Code::Unavailable), Some(Code::Unavailable)]
+        );
+    }
+
+    #[test]
+    fn classify_retries_unacknowledged_groups() {
+        let groups: Vec<_> = (0..3)
+            .map(|id| MutationGroup::new(vec![mutation(id)]))
+            .collect();
+        let results = vec![Some(Ok(())), None, None];
+        let error = crate::Error::io("connection reset");
+        let outcome = BatchWriteOutcome::new(&groups, results, Some(error));
+        let mut summary = BulkWriteSummary::default();
+        let policy = BulkWriteRetryPolicy::default();
+        let retry = outcome.classify(&RetryState::new(true), &policy, &mut summary);
+        assert_eq!(retry, groups[1..], "{summary:?}");
+        assert_eq!(summary.applied_groups, 1, "{summary:?}");
+        assert!(summary.failures.is_empty(), "{summary:?}");
+    }
+
+    #[test]
+    fn classify_reports_exhausted_unacknowledged_groups() {
+        let groups: Vec<_> = (0..3)
+            .map(|id| MutationGroup::new(vec![mutation(id)]))
+            .collect();
+        let results = vec![Some(Ok(())), None, None];
+        let error = crate::Error::io("connection reset");
+        let outcome = BatchWriteOutcome::new(&groups, results, Some(error));
+        let mut summary = BulkWriteSummary::default();
+        let policy = BulkWriteRetryPolicy::default().with_attempt_limit(1);
+        let state = RetryState::new(true).set_attempt_count(1_u32);
+        let retry = outcome.classify(&state, &policy, &mut summary);
+        assert!(retry.is_empty(), "{retry:?}");
+        assert_eq!(summary.failures.len(), 1, "{summary:?}");
+        let failure = &summary.failures[0];
+        assert_eq!(failure.groups, groups[1..], "{failure:?}");
+        assert!(failure.error.is_io(), "{failure:?}");
+    }
+
+    #[tokio_test_no_panics]
+    async fn retry_limit() -> anyhow::Result<()> {
+        let mut mock = create_session_mock();
+        mock.expect_batch_write()
+            .times(2)
+            .returning(|_| Ok(Response::from(adapt([Ok(failed(vec![0], Code::Aborted))]))));
+        let (db_client, _server) = setup_db_client(mock).await;
+
+        let writer = db_client
+            .bulk_writer()
+            .with_retry_policy(BulkWriteRetryPolicy::default().with_attempt_limit(2))
+            .with_backoff_policy(test_backoff())
+            .build();
+        writer.write(mutation(1)).await?;
+        let summary = writer.close().await?;
+        assert_eq!(summary.applied_groups, 0, "{summary:?}");
+        assert_eq!(summary.failures.len(), 1, "{summary:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn retry_policy() {
+        let policy = BulkWriteRetryPolicy::default();
+        let state = RetryState::new(true);
+        let error = |code| crate::Error::service(Status::default().set_code(code));
+        assert!(matches!(
+            policy.on_error(&state, error(Code::Aborted)),
+            RetryResult::Continue(_)
+        ));
+        assert!(matches!(
+            policy.on_error(&state, error(Code::Unavailable)),
+            RetryResult::Continue(_)
+        ));
+        assert!(matches!(
+            policy.on_error(&state, error(Code::InvalidArgument)),
+            RetryResult::Permanent(_)
+        ));
+    }
+
+    fn test_backoff() -> google_cloud_gax::exponential_backoff::ExponentialBackoff {
+        google_cloud_gax::exponential_backoff::ExponentialBackoffBuilder::new()
+            .with_initial_delay(Duration::from_millis(1))
+            .with_maximum_delay(Duration::from_millis(1))
+            .build()
+            .expect("valid backoff")
+    }
+}
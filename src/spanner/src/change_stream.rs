@@ -0,0 +1,770 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Read the changes recorded by a Spanner change stream.
+//!
+//! A change stream is read by querying its partitions. Each partition returns
+//! the changes for a range of keys, and is eventually replaced by one or more
+//! child partitions. [ChangeStreamReader] runs these queries, starts the child
+//! partitions once all their parents have finished, and returns the decoded
+//! records of all partitions.
+//!
+//! See [Change streams overview](https://cloud.google.com/spanner/docs/change-streams)
+//! for more information.
+
+mod partition_store;
+mod record;
+
+pub use partition_store::{
+    InMemoryPartitionStore, PartitionMetadata, PartitionState, PartitionStore,
+    SpannerPartitionStore,
+};
+pub use record::{
+    ChangeRecord, ChildPartition, ChildPartitionsRecord, ColumnType, DataChangeRecord,
+    HeartbeatRecord, Mod, ModType,
+};
+
+use crate::database_client::DatabaseClient;
+use crate::statement::Statement;
+use crate::types;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::{JoinHandle, JoinSet};
+
+#[cfg(feature = "unstable-stream")]
+use futures::Stream;
+
+const DEFAULT_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+const CHANNEL_CAPACITY: usize = 128;
+
+/// The SQL dialect of the database that contains the change stream.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Dialect {
+    /// A GoogleSQL database, the change stream is read with `READ_<name>`.
+    #[default]
+    GoogleSql,
+    /// A PostgreSQL database, the change stream is read with `spanner.read_json_<name>`.
+    PostgreSql,
+}
+
+/// A builder for [ChangeStreamReader].
+///
+/// # Example
+/// ```
+/// # use google_cloud_spanner::client::Spanner;
+/// # use google_cloud_spanner::change_stream::ChangeRecord;
+/// # async fn sample(spanner: Spanner) -> Result<(), google_cloud_spanner::Error> {
+/// let db = spanner.database_client("projects/p/instances/i/databases/d").build().await?;
+/// let mut reader = db
+///     .change_stream("SingersStream")
+///     .with_start_timestamp(wkt::Timestamp::new(1_700_000_000, 0).expect("valid timestamp"))
+///     .build()
+///     .await?;
+/// while let Some(record) = reader.next().await {
+///     if let ChangeRecord::DataChange(change) = record? {
+///         println!("{} rows modified in {}", change.mods.len(), change.table_name);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ChangeStreamBuilder {
+    client: DatabaseClient,
+    name: String,
+    start_timestamp: Option<wkt::Timestamp>,
+    end_timestamp: Option<wkt::Timestamp>,
+    heartbeat_interval: Duration,
+    dialect: Dialect,
+    store: Arc<dyn PartitionStore>,
+}
+
+impl ChangeStreamBuilder {
+    pub(crate) fn new(client: DatabaseClient, name: impl Into<String>) -> Self {
+        Self {
+            client,
+            name: name.into(),
+            start_timestamp: None,
+            end_timestamp: None,
+            heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL,
+            dialect: Dialect::default(),
+            store: Arc::new(InMemoryPartitionStore::new()),
+        }
+    }
+
+    /// Sets the timestamp of the first changes to read.
+    ///
+    /// Defaults to the time at which the reader is built. This is ignored
+    /// when the partition store already contains partitions, the reader
+    /// resumes from their watermarks instead.
+    pub fn with_start_timestamp(mut self, timestamp: wkt::Timestamp) -> Self {
+        self.start_timestamp = Some(timestamp);
+        self
+    }
+
+    /// Sets the timestamp at which to stop reading changes.
+    ///
+    /// By default, the reader returns changes until it is dropped.
+    pub fn with_end_timestamp(mut self, timestamp: wkt::Timestamp) -> Self {
+        self.end_timestamp = Some(timestamp);
+        self
+    }
+
+    /// Sets the interval at which Spanner returns heartbeat records for
+    /// partitions without changes.
+    ///
+    /// Defaults to 10 seconds.
+    pub fn with_heartbeat_interval(mut self, interval: Duration) -> Self {
+        self.heartbeat_interval = interval;
+        self
+    }
+
+    /// Sets the SQL dialect of the database.
+    ///
+    /// Defaults to [Dialect::GoogleSql].
+    pub fn with_dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Sets the store used to checkpoint the partitions.
+    ///
+    /// Defaults to an [InMemoryPartitionStore].
+    pub fn with_partition_store<S: PartitionStore + 'static>(mut self, store: S) -> Self {
+        self.store = Arc::new(store);
+        self
+    }
+
+    /// Loads the partitions from the store and starts reading the change stream.
+    ///
+    /// If the store is empty, the reader starts with the initial query of
+    /// the change stream, which returns the first set of partitions.
+    ///
+    /// Returns an error if the change stream name is not a valid identifier,
+    /// that is, a letter or underscore followed by letters, digits, or
+    /// underscores.
+    pub async fn build(self) -> crate::Result<ChangeStreamReader> {
+        validate_name(&self.name)?;
+        let mut partitions = self.store.list().await?;
+        if partitions.is_empty() {
+            let start = match self.start_timestamp {
+                Some(t) => t,
+                None => wkt::Timestamp::try_from(std::time::SystemTime::now())
+                    .map_err(crate::Error::deser)?,
+            };
+            let initial = PartitionMetadata {
+                token: String::new(),
+                parent_tokens: Vec::new(),
+                start_timestamp: start,
+                end_timestamp: self.end_timestamp,
+                watermark: start,
+                state: PartitionState::Created,
+            };
+            self.store.add(vec![initial.clone()]).await?;
+            partitions.push(initial);
+        }
+
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+        let (events_tx, events_rx) = mpsc::channel(CHANNEL_CAPACITY);
+        let coordinator = Coordinator {
+            query: PartitionQuery {
+                client: self.client,
+                name: self.name,
+                dialect: self.dialect,
+                heartbeat_interval: self.heartbeat_interval,
+            },
+            store: self.store,
+            partitions: partitions
+                .into_iter()
+                .map(|p| (p.token.clone(), p))
+                .collect(),
+            started: HashSet::new(),
+            tasks: JoinSet::new(),
+            events_tx,
+            records: tx,
+        };
+        let handle = tokio::spawn(coordinator.run(events_rx));
+        Ok(ChangeStreamReader {
+            records: rx,
+            coordinator: handle,
+        })
+    }
+}
+
+/// Change stream names are interpolated in the query text, they must be valid
+/// identifiers.
+fn validate_name(name: &str) -> crate::Result<()> {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if valid {
+        return Ok(());
+    }
+    Err(crate::Error::ser(format!(
+        "invalid change stream name `{name}`: names must start with a letter or underscore, \
+         followed by letters, digits, or underscores"
+    )))
+}
+
+/// Returns the records of a change stream.
+///
+/// The records of each partition are returned in order, records from
+/// different partitions are interleaved. The watermark of a partition is
+/// checkpointed once its records are handed to the caller. A reader resumed
+/// from a persistent [PartitionStore] may return the records at the watermark
+/// again.
+///
+/// Dropping the reader stops all the partition queries.
+#[derive(Debug)]
+pub struct ChangeStreamReader {
+    records: mpsc::Receiver<crate::Result<ChangeRecord>>,
+    coordinator: JoinHandle<()>,
+}
+
+impl ChangeStreamReader {
+    /// Returns the next record, or `None` when all partitions have finished.
+    ///
+    /// Partitions only finish when the reader has an end timestamp. After an
+    /// error, no more records are returned.
+    pub async fn next(&mut self) -> Option<crate::Result<ChangeRecord>> {
+        self.records.recv().await
+    }
+
+    /// Converts the reader into a [Stream].
+    #[cfg(feature = "unstable-stream")]
+    pub fn into_stream(self) -> impl Stream<Item = crate::Result<ChangeRecord>> + Unpin {
+        use futures::stream::unfold;
+        Box::pin(unfold(self, |mut reader| async move {
+            reader.next().await.map(|record| (record, reader))
+        }))
+    }
+}
+
+impl Drop for ChangeStreamReader {
+    fn drop(&mut self) {
+        self.coordinator.abort();
+    }
+}
+
+#[derive(Debug)]
+enum Event {
+    Record(String, ChangeRecord),
+    Finished(String),
+    Failed(crate::Error),
+}
+
+/// Runs the query of a single partition.
+#[derive(Clone, Debug)]
+struct PartitionQuery {
+    client: DatabaseClient,
+    name: String,
+    dialect: Dialect,
+    heartbeat_interval: Duration,
+}
+
+impl PartitionQuery {
+    fn statement(&self, partition: &PartitionMetadata) -> Statement {
+        let token = (!partition.token.is_empty()).then(|| partition.token.clone());
+        let heartbeat = self.heartbeat_interval.as_millis() as i64;
+        match self.dialect {
+            Dialect::GoogleSql => Statement::builder(format!(
+                "SELECT ChangeRecord FROM READ_{}(\
+                 start_timestamp => @start_timestamp, \
+                 end_timestamp => @end_timestamp, \
+                 partition_token => @partition_token, \
+                 heartbeat_milliseconds => @heartbeat_milliseconds)",
+                self.name
+            ))
+            .add_typed_param("start_timestamp", partition.watermark, types::timestamp())
+            .add_typed_param("end_timestamp", partition.end_timestamp, types::timestamp())
+            .add_typed_param("partition_token", token, types::string())
+            .add_typed_param("heartbeat_milliseconds", heartbeat, types::int64())
+            .build(),
+            Dialect::PostgreSql => Statement::builder(format!(
+                "SELECT * FROM \"spanner\".\"read_json_{}\"($1, $2, $3, $4, null)",
+                self.name
+            ))
+            .add_typed_param("p1", partition.watermark, types::timestamp())
+            .add_typed_param("p2", partition.end_timestamp, types::timestamp())
+            .add_typed_param("p3", token, types::string())
+            .add_typed_param("p4", heartbeat, types::int64())
+            .build(),
+        }
+    }
+
+    async fn run(self, partition: PartitionMetadata, events: mpsc::Sender<Event>) {
+        let token = partition.token.clone();
+        let event = match self.read(partition, &events).await {
+            Ok(()) => Event::Finished(token),
+            Err(e) => Event::Failed(e),
+        };
+        let _ = events.send(event).await;
+    }
+
+    async fn read(
+        &self,
+        partition: PartitionMetadata,
+        events: &mpsc::Sender<Event>,
+    ) -> crate::Result<()> {
+        let statement = self.statement(&partition);
+        let mut rs = self
+            .client
+            .single_use()
+            .build()
+            .execute_query(statement)
+            .await?;
+        while let Some(row) = rs.next().await {
+            for record in record::decode(&row?)? {
+                let event = Event::Record(partition.token.clone(), record);
+                if events.send(event).await.is_err() {
+                    return Ok(());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Tracks the partitions and forwards their records to the reader.
+struct Coordinator {
+    query: PartitionQuery,
+    store: Arc<dyn PartitionStore>,
+    partitions: HashMap<String, PartitionMetadata>,
+    started: HashSet<String>,
+    tasks: JoinSet<()>,
+    events_tx: mpsc::Sender<Event>,
+    records: mpsc::Sender<crate::Result<ChangeRecord>>,
+}
+
+impl Coordinator {
+    async fn run(mut self, mut events: mpsc::Receiver<Event>) {
+        if let Err(e) = self.process(&mut events).await {
+            let _ = self.records.send(Err(e)).await;
+        }
+    }
+
+    async fn process(&mut self, events: &mut mpsc::Receiver<Event>) -> crate::Result<()> {
+        loop {
+            self.schedule().await?;
+            if self.started.is_empty() {
+                return Ok(());
+            }
+            let Some(event) = events.recv().await else {
+                return Ok(());
+            };
+            match event {
+                Event::Record(token, record) => {
+                    if !self.handle_record(token, record).await? {
+                        return Ok(());
+                    }
+                }
+                Event::Finished(token) => self.finish(token).await?,
+                Event::Failed(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Starts the partitions whose parents have all finished.
+    ///
+    /// Parents that are not in the store are considered finished, this is
+    /// the case for the partitions returned by the initial query.
+    async fn schedule(&mut self) -> crate::Result<()> {
+        let ready = self
+            .partitions
+            .values()
+            .filter(|p| !self.started.contains(&p.token))
+            .filter(|p| match p.state {
+                PartitionState::Running => true,
+                PartitionState::Created => p.parent_tokens.iter().all(|t| {
+                    self.partitions
+                        .get(t)
+                        .is_none_or(|parent| parent.state == PartitionState::Finished)
+                }),
+                _ => false,
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        for mut partition in ready {
+            if partition.state != PartitionState::Running {
+                partition.state = PartitionState::Running;
+                self.store.update(partition.clone()).await?;
+                self.partitions
+                    .insert(partition.token.clone(), partition.clone());
+            }
+            self.started.insert(partition.token.clone());
+            self.tasks
+                .spawn(self.query.clone().run(partition, self.events_tx.clone()));
+        }
+        Ok(())
+    }
+
+    /// Tracks a record and forwards it to the reader.
+    ///
+    /// Returns `false` if the reader was dropped.
+    async fn handle_record(&mut self, token: String, record: ChangeRecord) -> crate::Result<bool> {
+        if let ChangeRecord::ChildPartitions(r) = &record {
+            let end_timestamp = self.partitions.get(&token).and_then(|p| p.end_timestamp);
+            let children = r
+                .child_partitions
+                .iter()
+                .filter(|c| !self.partitions.contains_key(&c.token))
+                .map(|c| PartitionMetadata {
+                    token: c.token.clone(),
+                    parent_tokens: c.parent_partition_tokens.clone(),
+                    start_timestamp: r.start_timestamp,
+                    end_timestamp,
+                    watermark: r.start_timestamp,
+                    state: PartitionState::Created,
+                })
+                .collect::<Vec<_>>();
+            if !children.is_empty() {
+                self.store.add(children.clone()).await?;
+                self.partitions
+                    .extend(children.into_iter().map(|c| (c.token.clone(), c)));
+            }
+        }
+
+        let timestamp = record.timestamp();
+        if self.records.send(Ok(record)).await.is_err() {
+            return Ok(false);
+        }
+        if let Some(partition) = self.partitions.get_mut(&token)
+            && timestamp > partition.watermark
+        {
+            partition.watermark = timestamp;
+            self.store.update(partition.clone()).await?;
+        }
+        Ok(true)
+    }
+
+    async fn finish(&mut self, token: String) -> crate::Result<()> {
+        self.started.remove(&token);
+        if let Some(partition) = self.partitions.get_mut(&token) {
+            partition.state = PartitionState::Finished;
+            self.store.update(partition.clone()).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::record::tests::{
+        google_sql_child_partitions, google_sql_data_change, google_sql_heartbeat, google_sql_type,
+    };
+    use super::*;
+    use crate::read_only_transaction::tests::{create_session_mock, setup_db_client};
+    use crate::result_set::tests::adapt;
+    use gaxi::grpc::tonic::Response;
+    use google_cloud_test_macros::tokio_test_no_panics;
+    use serde_json::json;
+    use spanner_grpc_mock::google::spanner::v1 as mock_v1;
+    use std::sync::Mutex;
+
+    fn timestamp(s: &str) -> wkt::Timestamp {
+        wkt::Timestamp::try_from(s).expect("valid timestamp")
+    }
+
+    fn response(values: Vec<crate::value::Value>) -> mock_v1::PartialResultSet {
+        use gaxi::prost::ToProto;
+        use prost::Message;
+        let type_: crate::google::spanner::v1::Type = crate::model::Type::from(google_sql_type())
+            .to_proto()
+            .expect("type converts to proto");
+        let type_ =
+            mock_v1::Type::decode(type_.encode_to_vec().as_slice()).expect("mock type decodes");
+        mock_v1::PartialResultSet {
+            metadata: Some(mock_v1::ResultSetMetadata {
+                row_type: Some(mock_v1::StructType {
+                    fields: vec![mock_v1::struct_type::Field {
+                        name: "ChangeRecord".to_string(),
+                        r#type: Some(type_),
+                    }],
+                }),
+                ..Default::default()
+            }),
+            values: values.into_iter().map(|v| v.0).collect(),
+            last: true,
+            ..Default::default()
+        }
+    }
+
+    fn param<'a>(req: &'a mock_v1::ExecuteSqlRequest, name: &str) -> &'a prost_types::Value {
+        req.params
+            .as_ref()
+            .and_then(|p| p.fields.get(name))
+            .unwrap_or_else(|| panic!("missing parameter {name} in {req:?}"))
+    }
+
+    fn token(req: &mock_v1::ExecuteSqlRequest) -> Option<String> {
+        match &param(req, "partition_token").kind {
+            Some(prost_types::value::Kind::StringValue(s)) => Some(s.clone()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn auto_traits() {
+        static_assertions::assert_impl_all!(ChangeStreamBuilder: Send, Sync, std::fmt::Debug);
+        static_assertions::assert_impl_all!(ChangeStreamReader: Send, Sync, std::fmt::Debug);
+    }
+
+    #[tokio_test_no_panics]
+    async fn statement() {
+        let (db_client, _server) = setup_db_client(create_session_mock()).await;
+        let query = PartitionQuery {
+            client: db_client,
+            name: "Singers".to_string(),
+            dialect: Dialect::GoogleSql,
+            heartbeat_interval: Duration::from_secs(5),
+        };
+        let partition = PartitionMetadata {
+            token: "token".to_string(),
+            parent_tokens: Vec::new(),
+            start_timestamp: timestamp("2026-01-02T03:04:05Z"),
+            end_timestamp: None,
+            watermark: timestamp("2026-01-02T03:04:06Z"),
+            state: PartitionState::Running,
+        };
+
+        let stmt = query.statement(&partition).into_request();
+        assert!(
+            stmt.sql
+                .starts_with("SELECT ChangeRecord FROM READ_Singers("),
+            "{}",
+            stmt.sql
+        );
+        let params = stmt.params.expect("has params");
+        assert_eq!(
+            params["start_timestamp"],
+            json!("2026-01-02T03:04:06.000000000Z")
+        );
+        assert_eq!(params["end_timestamp"], json!(null));
+        assert_eq!(params["partition_token"], json!("token"));
+        assert_eq!(params["heartbeat_milliseconds"], json!("5000"));
+        assert_eq!(stmt.param_types.len(), 4);
+
+        let query = PartitionQuery {
+            dialect: Dialect::PostgreSql,
+            ..query
+        };
+        let initial = PartitionMetadata {
+            token: String::new(),
+            ..partition
+        };
+        let stmt = query.statement(&initial).into_request();
+        assert_eq!(
+            stmt.sql,
+            r#"SELECT * FROM "spanner"."read_json_Singers"($1, $2, $3, $4, null)"#
+        );
+        let params = stmt.params.expect("has params");
+        assert_eq!(params["p3"], json!(null));
+        assert_eq!(stmt.param_types.len(), 4);
+    }
+
+    #[test]
+    fn names() {
+        for name in ["Singers", "_singers_2"] {
+            assert!(validate_name(name).is_ok(), "{name}");
+        }
+        for name in [
+            "",
+            "2Singers",
+            "Singers(start_timestamp => NULL)--",
+            "Sing ers",
+            "Singérs",
+        ] {
+            let err = validate_name(name).unwrap_err();
+            assert!(err.is_serialization(), "{name}: {err:?}");
+        }
+    }
+
+    #[tokio_test_no_panics]
+    async fn build_rejects_invalid_names() {
+        let (db_client, _server) = setup_db_client(create_session_mock()).await;
+        let store = InMemoryPartitionStore::new();
+        let err = ChangeStreamBuilder::new(db_client, "Singers; DROP TABLE Singers")
+            .with_partition_store(store.clone())
+            .build()
+            .await
+            .unwrap_err();
+        assert!(err.is_serialization(), "{err:?}");
+        // Nothing is written to the store.
+        assert!(store.list().await.expect("list succeeds").is_empty());
+    }
+
+    #[tokio_test_no_panics]
+    async fn read_partitions() -> anyhow::Result<()> {
+        let queried = Arc::new(Mutex::new(Vec::new()));
+        let mut mock = create_session_mock();
+        let captured = queried.clone();
+        mock.expect_execute_streaming_sql()
+            .times(4)
+            .returning(move |req| {
+                let req = req.into_inner();
+                let token = token(&req);
+                captured.lock().unwrap().push(token.clone());
+                let values = match token.as_deref() {
+                    // The initial query splits into two partitions.
+                    None => vec![google_sql_child_partitions(
+                        "2026-01-01T00:00:01Z",
+                        &[("a", &[]), ("b", &[])],
+                    )],
+                    // Both partitions merge into a single child.
+                    Some("a") => vec![
+                        google_sql_data_change("2026-01-01T00:00:02Z", "Singers"),
+                        google_sql_child_partitions("2026-01-01T00:00:03Z", &[("c", &["a", "b"])]),
+                    ],
+                    Some("b") => vec![
+                        google_sql_heartbeat("2026-01-01T00:00:02Z"),
+                        google_sql_child_partitions("2026-01-01T00:00:03Z", &[("c", &["a", "b"])]),
+                    ],
+                    Some("c") => vec![google_sql_data_change("2026-01-01T00:00:04Z", "Albums")],
+                    Some(t) => panic!("unexpected partition token {t}"),
+                };
+                Ok(Response::from(adapt([Ok(response(values))])))
+            });
+        let (db_client, _server) = setup_db_client(mock).await;
+
+        let store = InMemoryPartitionStore::new();
+        let mut reader = db_client
+            .change_stream("Singers")
+            .with_start_timestamp(timestamp("2026-01-01T00:00:00Z"))
+            .with_end_timestamp(timestamp("2026-01-02T00:00:00Z"))
+            .with_partition_store(store.clone())
+            .build()
+            .await?;
+
+        let mut records = Vec::new();
+        while let Some(record) = reader.next().await {
+            records.push(record?);
+        }
+        let tables = records
+            .iter()
+            .filter_map(|r| match r {
+                ChangeRecord::DataChange(d) => Some(d.table_name.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(tables, ["Singers", "Albums"]);
+        // The merged partition is the last record, it only starts once both parents finished.
+        assert!(
+            matches!(records.last(), Some(ChangeRecord::DataChange(d)) if d.table_name == "Albums"),
+            "{records:?}"
+        );
+
+        let queried = queried.lock().unwrap().clone();
+        assert_eq!(queried.first(), Some(&None));
+        assert_eq!(queried.last(), Some(&Some("c".to_string())));
+        assert_eq!(queried.len(), 4, "{queried:?}");
+
+        let partitions = store.list().await?;
+        assert_eq!(partitions.len(), 4, "{partitions:?}");
+        assert!(
+            partitions
+                .iter()
+                .all(|p| p.state == PartitionState::Finished),
+            "{partitions:?}"
+        );
+        let c = partitions.iter().find(|p| p.token == "c").expect("has c");
+        assert_eq!(c.parent_tokens, ["a", "b"]);
+        assert_eq!(c.start_timestamp, timestamp("2026-01-01T00:00:03Z"));
+        assert_eq!(c.watermark, timestamp("2026-01-01T00:00:04Z"));
+        assert_eq!(c.end_timestamp, Some(timestamp("2026-01-02T00:00:00Z")));
+        Ok(())
+    }
+
+    #[tokio_test_no_panics]
+    async fn resume_from_store() -> anyhow::Result<()> {
+        let mut mock = create_session_mock();
+        mock.expect_execute_streaming_sql()
+            .once()
+            .returning(move |req| {
+                let req = req.into_inner();
+                assert_eq!(token(&req).as_deref(), Some("b"));
+                assert_eq!(
+                    param(&req, "start_timestamp").kind,
+                    Some(prost_types::value::Kind::StringValue(
+                        "2026-01-01T00:00:05.000000000Z".to_string()
+                    ))
+                );
+                Ok(Response::from(adapt([Ok(response(vec![
+                    google_sql_heartbeat("2026-01-01T00:00:06Z"),
+                ]))])))
+            });
+        let (db_client, _server) = setup_db_client(mock).await;
+
+        let partition = |token: &str, state: PartitionState, watermark: &str| PartitionMetadata {
+            token: token.to_string(),
+            parent_tokens: Vec::new(),
+            start_timestamp: timestamp("2026-01-01T00:00:00Z"),
+            end_timestamp: Some(timestamp("2026-01-02T00:00:00Z")),
+            watermark: timestamp(watermark),
+            state,
+        };
+        let store = InMemoryPartitionStore::new();
+        store
+            .add(vec![
+                partition("a", PartitionState::Finished, "2026-01-01T00:00:09Z"),
+                partition("b", PartitionState::Running, "2026-01-01T00:00:05Z"),
+            ])
+            .await?;
+
+        let mut reader = db_client
+            .change_stream("Singers")
+            .with_partition_store(store.clone())
+            .build()
+            .await?;
+        let record = reader.next().await.expect("has record")?;
+        assert_eq!(record.timestamp(), timestamp("2026-01-01T00:00:06Z"));
+        assert!(reader.next().await.is_none());
+
+        let partitions = store.list().await?;
+        assert_eq!(
+            partitions[1],
+            partition("b", PartitionState::Finished, "2026-01-01T00:00:06Z")
+        );
+        Ok(())
+    }
+
+    #[tokio_test_no_panics]
+    async fn partition_error() -> anyhow::Result<()> {
+        let mut mock = create_session_mock();
+        mock.expect_execute_streaming_sql().once().returning(|_| {
+            Err(gaxi::grpc::tonic::Status::not_found(
+                "change stream not found",
+            ))
+        });
+        let (db_client, _server) = setup_db_client(mock).await;
+
+        let mut reader = db_client
+            .change_stream("Missing")
+            .with_end_timestamp(timestamp("2026-01-02T00:00:00Z"))
+            .build()
+            .await?;
+        let err = reader.next().await.expect("has result").unwrap_err();
+        assert_eq!(
+            err.status().map(|s| s.code),
+            Some(google_cloud_gax::error::rpc::Code::NotFound),
+            "{err:?}"
+        );
+        assert!(reader.next().await.is_none());
+        Ok(())
+    }
+}
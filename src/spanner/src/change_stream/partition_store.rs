@@ -0,0 +1,542 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::database_client::DatabaseClient;
+use crate::key::{Key, KeySet};
+use crate::mutation::Mutation;
+use crate::read::ReadRequest;
+use crate::value::ToValue;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+/// The state of a change stream partition.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PartitionState {
+    /// The partition was announced by its parents, but is not read yet.
+    Created,
+    /// The partition is being read.
+    Running,
+    /// All the records of the partition were returned.
+    Finished,
+}
+
+impl PartitionState {
+    /// Returns the name of the state, as stored by [SpannerPartitionStore].
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Created => "CREATED",
+            Self::Running => "RUNNING",
+            Self::Finished => "FINISHED",
+        }
+    }
+
+    /// Returns the state with the given name, if any.
+    pub fn from_str_name(name: &str) -> Option<Self> {
+        match name {
+            "CREATED" => Some(Self::Created),
+            "RUNNING" => Some(Self::Running),
+            "FINISHED" => Some(Self::Finished),
+            _ => None,
+        }
+    }
+}
+
+/// The checkpoint of a change stream partition.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartitionMetadata {
+    /// The token of the partition.
+    ///
+    /// The initial query of a change stream has no partition token, it is
+    /// represented by an empty string.
+    pub token: String,
+    /// The tokens of the partitions that this partition continues.
+    pub parent_tokens: Vec<String>,
+    /// The timestamp from which the partition returns changes.
+    pub start_timestamp: wkt::Timestamp,
+    /// The timestamp at which the partition stops returning changes, if any.
+    pub end_timestamp: Option<wkt::Timestamp>,
+    /// The timestamp of the last record returned from the partition.
+    ///
+    /// A partition resumes from its watermark when the reader is restarted.
+    pub watermark: wkt::Timestamp,
+    /// The state of the partition.
+    pub state: PartitionState,
+}
+
+/// Stores the partitions of a change stream, so a reader can resume after a restart.
+///
+/// The reader adds the child partitions as they are announced, and updates
+/// the watermark and state of each partition as its records are returned.
+/// Implementations must keep finished partitions, the reader uses them to
+/// decide when a child partition is ready to start.
+///
+/// Use [InMemoryPartitionStore] to track the partitions within a single
+/// process, or [SpannerPartitionStore] to persist them in a Spanner table.
+#[async_trait::async_trait]
+pub trait PartitionStore: std::fmt::Debug + Send + Sync {
+    /// Returns all the partitions in the store.
+    async fn list(&self) -> crate::Result<Vec<PartitionMetadata>>;
+
+    /// Adds the partitions that are not in the store yet.
+    ///
+    /// A partition created by merging several parents is announced by each
+    /// parent. Partitions with a token that is already in the store must be
+    /// ignored.
+    async fn add(&self, partitions: Vec<PartitionMetadata>) -> crate::Result<()>;
+
+    /// Replaces the partition with the same token.
+    async fn update(&self, partition: PartitionMetadata) -> crate::Result<()>;
+}
+
+/// A [PartitionStore] that keeps the partitions in memory.
+///
+/// # Example
+/// ```
+/// # use google_cloud_spanner::change_stream::{InMemoryPartitionStore, PartitionStore};
+/// # async fn sample() -> Result<(), google_cloud_spanner::Error> {
+/// let store = InMemoryPartitionStore::new();
+/// // Clones share the same partitions.
+/// let partitions = store.clone().list().await?;
+/// assert!(partitions.is_empty());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// This is the default store. The partitions are lost when the process
+/// exits, so a new reader starts from the beginning of the change stream.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryPartitionStore {
+    partitions: Arc<Mutex<BTreeMap<String, PartitionMetadata>>>,
+}
+
+impl InMemoryPartitionStore {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl PartitionStore for InMemoryPartitionStore {
+    async fn list(&self) -> crate::Result<Vec<PartitionMetadata>> {
+        let guard = self
+            .partitions
+            .lock()
+            .expect("partition store mutex poisoned");
+        Ok(guard.values().cloned().collect())
+    }
+
+    async fn add(&self, partitions: Vec<PartitionMetadata>) -> crate::Result<()> {
+        let mut guard = self
+            .partitions
+            .lock()
+            .expect("partition store mutex poisoned");
+        for p in partitions {
+            guard.entry(p.token.clone()).or_insert(p);
+        }
+        Ok(())
+    }
+
+    async fn update(&self, partition: PartitionMetadata) -> crate::Result<()> {
+        let mut guard = self
+            .partitions
+            .lock()
+            .expect("partition store mutex poisoned");
+        guard.insert(partition.token.clone(), partition);
+        Ok(())
+    }
+}
+
+const COLUMNS: [&str; 6] = [
+    "PartitionToken",
+    "ParentTokens",
+    "StartTimestamp",
+    "EndTimestamp",
+    "Watermark",
+    "State",
+];
+
+/// A [PartitionStore] that keeps the partitions in a Spanner table.
+///
+/// # Example
+/// ```
+/// # use google_cloud_spanner::client::Spanner;
+/// # use google_cloud_spanner::change_stream::SpannerPartitionStore;
+/// # async fn sample(spanner: Spanner) -> Result<(), google_cloud_spanner::Error> {
+/// let db = spanner.database_client("projects/p/instances/i/databases/d").build().await?;
+/// let store = SpannerPartitionStore::new(db.clone(), "SingersStreamPartitions");
+/// let mut reader = db
+///     .change_stream("SingersStream")
+///     .with_partition_store(store)
+///     .build()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The table must exist before the reader starts, and must only be used by
+/// one change stream reader at a time. In a GoogleSQL database, create it
+/// with:
+///
+/// ```sql
+/// CREATE TABLE SingersStreamPartitions (
+///   PartitionToken STRING(MAX) NOT NULL,
+///   ParentTokens ARRAY<STRING(MAX)> NOT NULL,
+///   StartTimestamp TIMESTAMP NOT NULL,
+///   EndTimestamp TIMESTAMP,
+///   Watermark TIMESTAMP NOT NULL,
+///   State STRING(MAX) NOT NULL,
+/// ) PRIMARY KEY (PartitionToken)
+/// ```
+///
+/// Partitions are read and written with the read and mutation APIs, so the
+/// store also works in PostgreSQL databases with an equivalent table.
+#[derive(Clone, Debug)]
+pub struct SpannerPartitionStore {
+    client: DatabaseClient,
+    table: String,
+}
+
+impl SpannerPartitionStore {
+    /// Creates a store that keeps the partitions in `table`.
+    pub fn new(client: DatabaseClient, table: impl Into<String>) -> Self {
+        Self {
+            client,
+            table: table.into(),
+        }
+    }
+
+    async fn read(&self, keys: KeySet) -> crate::Result<Vec<PartitionMetadata>> {
+        let request = ReadRequest::builder(self.table.clone(), COLUMNS.to_vec())
+            .with_keys(keys)
+            .build();
+        let mut rs = self
+            .client
+            .single_use()
+            .build()
+            .execute_read(request)
+            .await?;
+        let mut partitions = Vec::new();
+        while let Some(row) = rs.next().await {
+            let row = row?;
+            let state: String = row.try_get("State")?;
+            let state = PartitionState::from_str_name(&state)
+                .ok_or_else(|| crate::Error::deser(format!("unknown partition state {state:?}")))?;
+            partitions.push(PartitionMetadata {
+                token: row.try_get("PartitionToken")?,
+                parent_tokens: row.try_get("ParentTokens")?,
+                start_timestamp: row.try_get("StartTimestamp")?,
+                end_timestamp: row.try_get("EndTimestamp")?,
+                watermark: row.try_get("Watermark")?,
+                state,
+            });
+        }
+        Ok(partitions)
+    }
+
+    fn write(&self, builder: crate::mutation::WriteBuilder, p: &PartitionMetadata) -> Mutation {
+        builder
+            .set("PartitionToken")
+            .to(&p.token)
+            .set("ParentTokens")
+            .to(&p.parent_tokens)
+            .set("StartTimestamp")
+            .to(p.start_timestamp)
+            .set("EndTimestamp")
+            .to(p.end_timestamp)
+            .set("Watermark")
+            .to(p.watermark)
+            .set("State")
+            .to(p.state.as_str_name())
+            .build()
+    }
+}
+
+#[async_trait::async_trait]
+impl PartitionStore for SpannerPartitionStore {
+    async fn list(&self) -> crate::Result<Vec<PartitionMetadata>> {
+        self.read(KeySet::all()).await
+    }
+
+    async fn add(&self, partitions: Vec<PartitionMetadata>) -> crate::Result<()> {
+        if partitions.is_empty() {
+            return Ok(());
+        }
+        let keys = partitions
+            .iter()
+            .fold(KeySet::builder(), |b, p| {
+                b.add_key(Key::new(vec![p.token.to_value()]))
+            })
+            .build();
+        let existing = self.read(keys).await?;
+        let mutations = partitions
+            .iter()
+            .filter(|p| existing.iter().all(|e| e.token != p.token))
+            .map(|p| self.write(Mutation::new_insert_builder(&self.table), p))
+            .collect::<Vec<_>>();
+        if mutations.is_empty() {
+            return Ok(());
+        }
+        self.client
+            .write_only_transaction()
+            .build()
+            .write(mutations)
+            .await?;
+        Ok(())
+    }
+
+    async fn update(&self, partition: PartitionMetadata) -> crate::Result<()> {
+        let mutation = self.write(
+            Mutation::new_insert_or_update_builder(&self.table),
+            &partition,
+        );
+        // Replaying the mutation is harmless, the row has the same values.
+        self.client
+            .write_only_transaction()
+            .build()
+            .write_at_least_once(vec![mutation])
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_only_transaction::tests::{create_session_mock, setup_db_client};
+    use crate::result_set::tests::adapt;
+    use gaxi::grpc::tonic::Response;
+    use google_cloud_test_macros::tokio_test_no_panics;
+    use spanner_grpc_mock::google::spanner::v1 as mock_v1;
+
+    fn partition(token: &str, state: PartitionState) -> PartitionMetadata {
+        let ts = wkt::Timestamp::new(1_700_000_000, 0).expect("valid timestamp");
+        PartitionMetadata {
+            token: token.to_string(),
+            parent_tokens: vec!["parent".to_string()],
+            start_timestamp: ts,
+            end_timestamp: None,
+            watermark: ts,
+            state,
+        }
+    }
+
+    #[test]
+    fn auto_traits() {
+        static_assertions::assert_impl_all!(InMemoryPartitionStore: Send, Sync, Clone, std::fmt::Debug);
+        static_assertions::assert_impl_all!(SpannerPartitionStore: Send, Sync, Clone, std::fmt::Debug);
+    }
+
+    #[test]
+    fn state_names() {
+        for state in [
+            PartitionState::Created,
+            PartitionState::Running,
+            PartitionState::Finished,
+        ] {
+            assert_eq!(
+                PartitionState::from_str_name(state.as_str_name()),
+                Some(state)
+            );
+        }
+        assert_eq!(PartitionState::from_str_name("UNKNOWN"), None);
+    }
+
+    #[tokio::test]
+    async fn in_memory() -> anyhow::Result<()> {
+        let store = InMemoryPartitionStore::new();
+        store
+            .add(vec![
+                partition("a", PartitionState::Created),
+                partition("b", PartitionState::Created),
+            ])
+            .await?;
+
+        let mut running = partition("a", PartitionState::Running);
+        running.watermark = wkt::Timestamp::new(1_700_000_010, 0)?;
+        store.update(running.clone()).await?;
+        // Adding a known partition does not reset it.
+        store
+            .add(vec![partition("a", PartitionState::Created)])
+            .await?;
+
+        let got = store.clone().list().await?;
+        assert_eq!(got, vec![running, partition("b", PartitionState::Created)]);
+        Ok(())
+    }
+
+    fn partition_row(token: &str, state: &str) -> Vec<prost_types::Value> {
+        let string = |s: &str| prost_types::Value {
+            kind: Some(prost_types::value::Kind::StringValue(s.to_string())),
+        };
+        vec![
+            string(token),
+            prost_types::Value {
+                kind: Some(prost_types::value::Kind::ListValue(
+                    prost_types::ListValue {
+                        values: vec![string("parent")],
+                    },
+                )),
+            },
+            string("2023-11-14T22:13:20Z"),
+            prost_types::Value {
+                kind: Some(prost_types::value::Kind::NullValue(0)),
+            },
+            string("2023-11-14T22:13:20Z"),
+            string(state),
+        ]
+    }
+
+    fn read_response(rows: Vec<Vec<prost_types::Value>>) -> mock_v1::PartialResultSet {
+        let field = |name: &str, code: mock_v1::TypeCode| mock_v1::struct_type::Field {
+            name: name.to_string(),
+            r#type: Some(mock_v1::Type {
+                code: code as i32,
+                array_element_type: (code == mock_v1::TypeCode::Array).then(|| {
+                    Box::new(mock_v1::Type {
+                        code: mock_v1::TypeCode::String as i32,
+                        ..Default::default()
+                    })
+                }),
+                ..Default::default()
+            }),
+        };
+        mock_v1::PartialResultSet {
+            metadata: Some(mock_v1::ResultSetMetadata {
+                row_type: Some(mock_v1::StructType {
+                    fields: vec![
+                        field("PartitionToken", mock_v1::TypeCode::String),
+                        field("ParentTokens", mock_v1::TypeCode::Array),
+                        field("StartTimestamp", mock_v1::TypeCode::Timestamp),
+                        field("EndTimestamp", mock_v1::TypeCode::Timestamp),
+                        field("Watermark", mock_v1::TypeCode::Timestamp),
+                        field("State", mock_v1::TypeCode::String),
+                    ],
+                }),
+                ..Default::default()
+            }),
+            values: rows.into_iter().flatten().collect(),
+            last: true,
+            ..Default::default()
+        }
+    }
+
+    #[tokio_test_no_panics]
+    async fn spanner_list() -> anyhow::Result<()> {
+        let mut mock = create_session_mock();
+        mock.expect_streaming_read().once().returning(|req| {
+            let req = req.into_inner();
+            assert_eq!(req.table, "Partitions");
+            assert_eq!(req.columns, COLUMNS);
+            assert!(req.key_set.expect("has key set").all);
+            Ok(Response::from(adapt([Ok(read_response(vec![
+                partition_row("a", "RUNNING"),
+            ]))])))
+        });
+        let (db_client, _server) = setup_db_client(mock).await;
+
+        let store = SpannerPartitionStore::new(db_client, "Partitions");
+        let got = store.list().await?;
+        assert_eq!(got, vec![partition("a", PartitionState::Running)]);
+        Ok(())
+    }
+
+    #[tokio_test_no_panics]
+    async fn spanner_list_bad_state() -> anyhow::Result<()> {
+        let mut mock = create_session_mock();
+        mock.expect_streaming_read().once().returning(|_| {
+            Ok(Response::from(adapt([Ok(read_response(vec![
+                partition_row("a", "UNKNOWN"),
+            ]))])))
+        });
+        let (db_client, _server) = setup_db_client(mock).await;
+
+        let store = SpannerPartitionStore::new(db_client, "Partitions");
+        let err = store.list().await.unwrap_err();
+        assert!(err.is_deserialization(), "{err:?}");
+        Ok(())
+    }
+
+    #[tokio_test_no_panics]
+    async fn spanner_add() -> anyhow::Result<()> {
+        let mut mock = create_session_mock();
+        mock.expect_streaming_read().once().returning(|req| {
+            let req = req.into_inner();
+            let keys = req.key_set.expect("has key set").keys;
+            assert_eq!(keys.len(), 2, "{keys:?}");
+            Ok(Response::from(adapt([Ok(read_response(vec![
+                partition_row("a", "RUNNING"),
+            ]))])))
+        });
+        mock.expect_begin_transaction().once().returning(|_| {
+            Ok(Response::new(mock_v1::Transaction {
+                id: vec![42],
+                ..Default::default()
+            }))
+        });
+        mock.expect_commit().once().returning(|req| {
+            let req = req.into_inner();
+            assert_eq!(req.mutations.len(), 1, "{:?}", req.mutations);
+            let Some(mock_v1::mutation::Operation::Insert(write)) = &req.mutations[0].operation
+            else {
+                panic!("expected an insert, got {:?}", req.mutations[0]);
+            };
+            assert_eq!(write.table, "Partitions");
+            assert_eq!(write.columns, COLUMNS);
+            assert_eq!(
+                write.values[0].values[0].kind,
+                Some(prost_types::value::Kind::StringValue("b".to_string()))
+            );
+            Ok(Response::new(mock_v1::CommitResponse::default()))
+        });
+        let (db_client, _server) = setup_db_client(mock).await;
+
+        let store = SpannerPartitionStore::new(db_client, "Partitions");
+        store
+            .add(vec![
+                partition("a", PartitionState::Created),
+                partition("b", PartitionState::Created),
+            ])
+            .await?;
+        Ok(())
+    }
+
+    #[tokio_test_no_panics]
+    async fn spanner_update() -> anyhow::Result<()> {
+        let mut mock = create_session_mock();
+        mock.expect_commit().once().returning(|req| {
+            let req = req.into_inner();
+            let Some(mock_v1::mutation::Operation::InsertOrUpdate(write)) =
+                &req.mutations[0].operation
+            else {
+                panic!("expected an insert or update, got {:?}", req.mutations[0]);
+            };
+            assert_eq!(
+                write.values[0].values[5].kind,
+                Some(prost_types::value::Kind::StringValue(
+                    "FINISHED".to_string()
+                ))
+            );
+            Ok(Response::new(mock_v1::CommitResponse::default()))
+        });
+        let (db_client, _server) = setup_db_client(mock).await;
+
+        let store = SpannerPartitionStore::new(db_client, "Partitions");
+        store
+            .update(partition("a", PartitionState::Finished))
+            .await?;
+        Ok(())
+    }
+}
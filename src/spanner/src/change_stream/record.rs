@@ -0,0 +1,564 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::result::Row;
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+use serde_with::{DisplayFromStr, PickFirst, serde_as};
+
+/// A record returned by a change stream query.
+///
+/// See [Change stream records](https://cloud.google.com/spanner/docs/change-streams/details#change_streams_record_format)
+/// for details on each record type.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ChangeRecord {
+    /// A set of modifications made to a table in a single transaction.
+    DataChange(DataChangeRecord),
+    /// Indicates that all changes up to the timestamp have been returned.
+    Heartbeat(HeartbeatRecord),
+    /// Announces the partitions that replace the partition being read.
+    ChildPartitions(ChildPartitionsRecord),
+}
+
+impl ChangeRecord {
+    /// Returns the timestamp of the record.
+    ///
+    /// This is the commit timestamp of a data change record, the timestamp of
+    /// a heartbeat record, or the start timestamp of a child partitions record.
+    pub fn timestamp(&self) -> wkt::Timestamp {
+        match self {
+            Self::DataChange(r) => r.commit_timestamp,
+            Self::Heartbeat(r) => r.timestamp,
+            Self::ChildPartitions(r) => r.start_timestamp,
+        }
+    }
+}
+
+/// The modifications made to a table by a transaction.
+#[serde_as]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct DataChangeRecord {
+    /// The time at which the change was committed.
+    pub commit_timestamp: wkt::Timestamp,
+    /// The sequence number of the record within the transaction.
+    pub record_sequence: String,
+    /// A globally unique identifier of the transaction.
+    pub server_transaction_id: String,
+    /// Whether this is the last record for the transaction in the partition.
+    pub is_last_record_in_transaction_in_partition: bool,
+    /// The name of the modified table.
+    pub table_name: String,
+    /// The columns that appear in the modifications.
+    pub column_types: Vec<ColumnType>,
+    /// The modified rows.
+    pub mods: Vec<Mod>,
+    /// The type of the modifications.
+    pub mod_type: ModType,
+    /// The value capture type of the change stream, for example `OLD_AND_NEW_VALUES`.
+    pub value_capture_type: String,
+    /// The number of data change records in the transaction across all partitions.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub number_of_records_in_transaction: i64,
+    /// The number of partitions that returned records for the transaction.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub number_of_partitions_in_transaction: i64,
+    /// The tag of the transaction, if any.
+    pub transaction_tag: String,
+    /// Whether the transaction was executed by Spanner itself, for example a TTL deletion.
+    pub is_system_transaction: bool,
+}
+
+/// The name and type of a column in a [DataChangeRecord].
+#[serde_as]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ColumnType {
+    /// The name of the column.
+    pub name: String,
+    /// The type of the column, in the JSON representation of a Spanner `Type`.
+    #[serde(rename = "type")]
+    pub type_: JsonValue,
+    /// Whether the column is part of the primary key.
+    pub is_primary_key: bool,
+    /// The position of the column in the table.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub ordinal_position: i64,
+}
+
+/// A modified row in a [DataChangeRecord].
+///
+/// Each field is a JSON object mapping column names to values.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct Mod {
+    /// The primary key of the row.
+    pub keys: JsonValue,
+    /// The new values of the modified columns.
+    pub new_values: JsonValue,
+    /// The old values of the modified columns.
+    pub old_values: JsonValue,
+}
+
+/// The type of the modifications in a [DataChangeRecord].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum ModType {
+    /// The rows were inserted.
+    #[default]
+    Insert,
+    /// The rows were updated.
+    Update,
+    /// The rows were deleted.
+    Delete,
+}
+
+/// Indicates that all changes committed before the timestamp have been returned.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct HeartbeatRecord {
+    /// The timestamp of the heartbeat.
+    pub timestamp: wkt::Timestamp,
+}
+
+/// Announces the partitions that continue the changes of the current partition.
+///
+/// A partition is split into several children, or merged with other
+/// partitions into a single child. The reader tracks this lineage and starts
+/// a child partition once all its parents have finished.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ChildPartitionsRecord {
+    /// The timestamp from which the child partitions return changes.
+    pub start_timestamp: wkt::Timestamp,
+    /// The sequence number of the record within the partition.
+    pub record_sequence: String,
+    /// The child partitions.
+    pub child_partitions: Vec<ChildPartition>,
+}
+
+/// A child partition in a [ChildPartitionsRecord].
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ChildPartition {
+    /// The token used to query the child partition.
+    pub token: String,
+    /// The tokens of the partitions that this partition continues.
+    pub parent_partition_tokens: Vec<String>,
+}
+
+/// The wrapper around the records in a change stream row.
+///
+/// GoogleSQL databases return an `ARRAY<STRUCT<...>>` column where each
+/// element holds arrays of records. PostgreSQL databases return a `JSONB`
+/// column with a single record. Once converted to JSON, both shapes only
+/// differ in whether the records are wrapped in arrays.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct RecordWrapper {
+    data_change_record: Vec<DataChangeRecord>,
+    heartbeat_record: Vec<HeartbeatRecord>,
+    child_partitions_record: Vec<ChildPartitionsRecord>,
+}
+
+/// Decodes the change records in a row returned by a change stream query.
+pub(crate) fn decode(row: &Row) -> crate::Result<Vec<ChangeRecord>> {
+    let value: JsonValue = row.try_get(0)?;
+    let wrappers: Vec<RecordWrapper> = match value {
+        JsonValue::Null => Vec::new(),
+        JsonValue::Array(_) => serde_json::from_value(value).map_err(crate::Error::deser)?,
+        JsonValue::Object(record) => {
+            // Wrap the single record in an array to match the GoogleSQL shape.
+            let wrapper = record
+                .into_iter()
+                .map(|(k, v)| (k, JsonValue::Array(vec![v])))
+                .collect::<serde_json::Map<_, _>>();
+            vec![serde_json::from_value(wrapper.into()).map_err(crate::Error::deser)?]
+        }
+        _ => {
+            return Err(crate::Error::deser(format!(
+                "unexpected change stream record {value}"
+            )));
+        }
+    };
+    let records = wrappers
+        .into_iter()
+        .flat_map(|w| {
+            w.data_change_record
+                .into_iter()
+                .map(ChangeRecord::DataChange)
+                .chain(w.heartbeat_record.into_iter().map(ChangeRecord::Heartbeat))
+                .chain(
+                    w.child_partitions_record
+                        .into_iter()
+                        .map(ChangeRecord::ChildPartitions),
+                )
+        })
+        .collect();
+    Ok(records)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::result_set_metadata::ResultSetMetadata;
+    use crate::types;
+    use crate::value::{ToValue, Type, Value};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn timestamp(s: &str) -> wkt::Timestamp {
+        wkt::Timestamp::try_from(s).expect("valid timestamp")
+    }
+
+    fn field(name: &str, type_: Type) -> crate::model::struct_type::Field {
+        crate::model::struct_type::Field::new()
+            .set_name(name)
+            .set_type(crate::model::Type::from(type_))
+    }
+
+    fn struct_of(fields: Vec<crate::model::struct_type::Field>) -> Type {
+        Type::from(
+            crate::model::Type::new()
+                .set_code(crate::model::TypeCode::Struct)
+                .set_struct_type(crate::model::StructType::new().set_fields(fields)),
+        )
+    }
+
+    fn list(values: Vec<Value>) -> Value {
+        values.to_value()
+    }
+
+    /// Returns the type of the `ChangeRecord` column in GoogleSQL databases.
+    pub(crate) fn google_sql_type() -> Type {
+        let column_type = struct_of(vec![
+            field("name", types::string()),
+            field("type", types::json()),
+            field("is_primary_key", types::bool()),
+            field("ordinal_position", types::int64()),
+        ]);
+        let modification = struct_of(vec![
+            field("keys", types::json()),
+            field("new_values", types::json()),
+            field("old_values", types::json()),
+        ]);
+        let data_change = struct_of(vec![
+            field("commit_timestamp", types::timestamp()),
+            field("record_sequence", types::string()),
+            field("server_transaction_id", types::string()),
+            field("is_last_record_in_transaction_in_partition", types::bool()),
+            field("table_name", types::string()),
+            field("column_types", types::array(column_type)),
+            field("mods", types::array(modification)),
+            field("mod_type", types::string()),
+            field("value_capture_type", types::string()),
+            field("number_of_records_in_transaction", types::int64()),
+            field("number_of_partitions_in_transaction", types::int64()),
+            field("transaction_tag", types::string()),
+            field("is_system_transaction", types::bool()),
+        ]);
+        let heartbeat = struct_of(vec![field("timestamp", types::timestamp())]);
+        let child_partition = struct_of(vec![
+            field("token", types::string()),
+            field("parent_partition_tokens", types::array(types::string())),
+        ]);
+        let child_partitions = struct_of(vec![
+            field("start_timestamp", types::timestamp()),
+            field("record_sequence", types::string()),
+            field("child_partitions", types::array(child_partition)),
+        ]);
+        types::array(struct_of(vec![
+            field("data_change_record", types::array(data_change)),
+            field("heartbeat_record", types::array(heartbeat)),
+            field("child_partitions_record", types::array(child_partitions)),
+        ]))
+    }
+
+    /// Returns a `ChangeRecord` value in GoogleSQL databases with a single heartbeat.
+    pub(crate) fn google_sql_heartbeat(ts: &str) -> Value {
+        list(vec![list(vec![
+            list(vec![]),
+            list(vec![list(vec![ts.to_value()])]),
+            list(vec![]),
+        ])])
+    }
+
+    /// Returns a `ChangeRecord` value in GoogleSQL databases with a single child partitions record.
+    pub(crate) fn google_sql_child_partitions(ts: &str, children: &[(&str, &[&str])]) -> Value {
+        let children = children
+            .iter()
+            .map(|(token, parents)| list(vec![token.to_value(), parents.to_vec().to_value()]))
+            .collect();
+        list(vec![list(vec![
+            list(vec![]),
+            list(vec![]),
+            list(vec![list(vec![
+                ts.to_value(),
+                "00000001".to_value(),
+                list(children),
+            ])]),
+        ])])
+    }
+
+    /// Returns a `ChangeRecord` value in GoogleSQL databases with a single data change record.
+    pub(crate) fn google_sql_data_change(ts: &str, table: &str) -> Value {
+        let column_types = list(vec![
+            list(vec![
+                "SingerId".to_value(),
+                r#"{"code":"INT64"}"#.to_value(),
+                true.to_value(),
+                1_i64.to_value(),
+            ]),
+            list(vec![
+                "Name".to_value(),
+                r#"{"code":"STRING"}"#.to_value(),
+                false.to_value(),
+                2_i64.to_value(),
+            ]),
+        ]);
+        let mods = list(vec![list(vec![
+            r#"{"SingerId":"1"}"#.to_value(),
+            r#"{"Name":"Alice"}"#.to_value(),
+            "{}".to_value(),
+        ])]);
+        let data_change = list(vec![
+            ts.to_value(),
+            "00000000".to_value(),
+            "txn-1".to_value(),
+            true.to_value(),
+            table.to_value(),
+            column_types,
+            mods,
+            "INSERT".to_value(),
+            "NEW_VALUES".to_value(),
+            1_i64.to_value(),
+            1_i64.to_value(),
+            "".to_value(),
+            false.to_value(),
+        ]);
+        list(vec![list(vec![
+            list(vec![data_change]),
+            list(vec![]),
+            list(vec![]),
+        ])])
+    }
+
+    pub(crate) fn record_row(name: &str, type_: Type, value: Value) -> Row {
+        Row {
+            values: vec![value],
+            metadata: ResultSetMetadata {
+                column_names: Arc::new(vec![name.to_string()]),
+                column_types: Arc::new(vec![type_]),
+                undeclared_parameters: Arc::new(std::collections::BTreeMap::new()),
+            },
+        }
+    }
+
+    #[test]
+    fn decode_google_sql_data_change() -> anyhow::Result<()> {
+        let row = record_row(
+            "ChangeRecord",
+            google_sql_type(),
+            google_sql_data_change("2026-01-02T03:04:05.123456Z", "Singers"),
+        );
+        let records = decode(&row)?;
+        let want = DataChangeRecord {
+            commit_timestamp: timestamp("2026-01-02T03:04:05.123456Z"),
+            record_sequence: "00000000".to_string(),
+            server_transaction_id: "txn-1".to_string(),
+            is_last_record_in_transaction_in_partition: true,
+            table_name: "Singers".to_string(),
+            column_types: vec![
+                ColumnType {
+                    name: "SingerId".to_string(),
+                    type_: json!({"code": "INT64"}),
+                    is_primary_key: true,
+                    ordinal_position: 1,
+                },
+                ColumnType {
+                    name: "Name".to_string(),
+                    type_: json!({"code": "STRING"}),
+                    is_primary_key: false,
+                    ordinal_position: 2,
+                },
+            ],
+            mods: vec![Mod {
+                keys: json!({"SingerId": "1"}),
+                new_values: json!({"Name": "Alice"}),
+                old_values: json!({}),
+            }],
+            mod_type: ModType::Insert,
+            value_capture_type: "NEW_VALUES".to_string(),
+            number_of_records_in_transaction: 1,
+            number_of_partitions_in_transaction: 1,
+            transaction_tag: String::new(),
+            is_system_transaction: false,
+        };
+        assert_eq!(records, vec![ChangeRecord::DataChange(want)]);
+        Ok(())
+    }
+
+    #[test]
+    fn decode_google_sql_heartbeat() -> anyhow::Result<()> {
+        let row = record_row(
+            "ChangeRecord",
+            google_sql_type(),
+            google_sql_heartbeat("2026-01-02T03:04:05Z"),
+        );
+        let records = decode(&row)?;
+        let want = HeartbeatRecord {
+            timestamp: timestamp("2026-01-02T03:04:05Z"),
+        };
+        assert_eq!(records, vec![ChangeRecord::Heartbeat(want)]);
+        assert_eq!(records[0].timestamp(), timestamp("2026-01-02T03:04:05Z"));
+        Ok(())
+    }
+
+    #[test]
+    fn decode_google_sql_child_partitions() -> anyhow::Result<()> {
+        let row = record_row(
+            "ChangeRecord",
+            google_sql_type(),
+            google_sql_child_partitions(
+                "2026-01-02T03:04:05Z",
+                &[("child-1", &["parent"]), ("child-2", &["parent"])],
+            ),
+        );
+        let records = decode(&row)?;
+        let want = ChildPartitionsRecord {
+            start_timestamp: timestamp("2026-01-02T03:04:05Z"),
+            record_sequence: "00000001".to_string(),
+            child_partitions: vec![
+                ChildPartition {
+                    token: "child-1".to_string(),
+                    parent_partition_tokens: vec!["parent".to_string()],
+                },
+                ChildPartition {
+                    token: "child-2".to_string(),
+                    parent_partition_tokens: vec!["parent".to_string()],
+                },
+            ],
+        };
+        assert_eq!(records, vec![ChangeRecord::ChildPartitions(want)]);
+        Ok(())
+    }
+
+    #[test]
+    fn decode_postgresql() -> anyhow::Result<()> {
+        let data_change = json!({
+            "data_change_record": {
+                "commit_timestamp": "2026-01-02T03:04:05Z",
+                "record_sequence": "00000000",
+                "server_transaction_id": "txn-1",
+                "is_last_record_in_transaction_in_partition": true,
+                "table_name": "singers",
+                "column_types": [
+                    {"name": "singer_id", "type": {"code": "INT64"}, "is_primary_key": true, "ordinal_position": 1},
+                ],
+                "mods": [
+                    {"keys": {"singer_id": "1"}, "new_values": {}, "old_values": {}},
+                ],
+                "mod_type": "DELETE",
+                "value_capture_type": "OLD_AND_NEW_VALUES",
+                "number_of_records_in_transaction": 2,
+                "number_of_partitions_in_transaction": 1,
+                "transaction_tag": "tag",
+                "is_system_transaction": false,
+            }
+        });
+        let row = record_row(
+            "read_json_singers",
+            types::pg_jsonb(),
+            data_change.to_string().to_value(),
+        );
+        let records = decode(&row)?;
+        let [ChangeRecord::DataChange(got)] = &records[..] else {
+            panic!("expected a single data change record, got {records:?}");
+        };
+        assert_eq!(got.table_name, "singers");
+        assert_eq!(got.mod_type, ModType::Delete);
+        assert_eq!(got.number_of_records_in_transaction, 2);
+        assert_eq!(got.column_types[0].ordinal_position, 1);
+        assert_eq!(got.mods[0].keys, json!({"singer_id": "1"}));
+        assert_eq!(got.transaction_tag, "tag");
+
+        let heartbeat = json!({"heartbeat_record": {"timestamp": "2026-01-02T03:04:05Z"}});
+        let row = record_row(
+            "read_json_singers",
+            types::pg_jsonb(),
+            heartbeat.to_string().to_value(),
+        );
+        let records = decode(&row)?;
+        let want = HeartbeatRecord {
+            timestamp: timestamp("2026-01-02T03:04:05Z"),
+        };
+        assert_eq!(records, vec![ChangeRecord::Heartbeat(want)]);
+
+        let child_partitions = json!({
+            "child_partitions_record": {
+                "start_timestamp": "2026-01-02T03:04:05Z",
+                "record_sequence": "00000001",
+                "child_partitions": [
+                    {"token": "child", "parent_partition_tokens": ["p1", "p2"]},
+                ],
+            }
+        });
+        let row = record_row(
+            "read_json_singers",
+            types::pg_jsonb(),
+            child_partitions.to_string().to_value(),
+        );
+        let records = decode(&row)?;
+        let want = ChildPartitionsRecord {
+            start_timestamp: timestamp("2026-01-02T03:04:05Z"),
+            record_sequence: "00000001".to_string(),
+            child_partitions: vec![ChildPartition {
+                token: "child".to_string(),
+                parent_partition_tokens: vec!["p1".to_string(), "p2".to_string()],
+            }],
+        };
+        assert_eq!(records, vec![ChangeRecord::ChildPartitions(want)]);
+        Ok(())
+    }
+
+    #[test]
+    fn decode_null() -> anyhow::Result<()> {
+        let row = record_row(
+            "ChangeRecord",
+            google_sql_type(),
+            Option::<String>::None.to_value(),
+        );
+        assert_eq!(decode(&row)?, Vec::new());
+        Ok(())
+    }
+
+    #[test]
+    fn decode_error() {
+        let row = record_row(
+            "read_json_singers",
+            types::pg_jsonb(),
+            r#"{"data_change_record": {"mod_type": "UNKNOWN"}}"#.to_value(),
+        );
+        let err = decode(&row).unwrap_err();
+        assert!(err.is_deserialization(), "{err:?}");
+    }
+}
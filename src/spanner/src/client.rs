@@ -45,6 +45,7 @@ use std::sync::{
 };
 
 pub use crate::database_client::DatabaseClient;
+pub use crate::session_maintainer::{SessionEvent, SessionHealth};
 pub use google_cloud_spanner_admin_database_v1::client::DatabaseAdmin;
 pub use google_cloud_spanner_admin_instance_v1::client::InstanceAdmin;
 
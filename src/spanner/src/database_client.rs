@@ -14,6 +14,8 @@
 
 use crate::batch_read_only_transaction::BatchReadOnlyTransactionBuilder;
 use crate::batch_write_transaction::BatchWriteTransactionBuilder;
+use crate::bulk_writer::BulkWriterBuilder;
+use crate::change_stream::ChangeStreamBuilder;
 use crate::client::Spanner;
 use crate::observability::Observability;
 use crate::omni::{InstanceType, format_database_name};
@@ -21,7 +23,9 @@ use crate::partitioned_dml_transaction::PartitionedDmlTransactionBuilder;
 use crate::read_only_transaction::{
     MultiUseReadOnlyTransactionBuilder, SingleUseReadOnlyTransactionBuilder,
 };
-use crate::session_maintainer::ManagedSessionMaintainer;
+use crate::session_maintainer::{
+    ManagedSessionMaintainer, SessionEvent, SessionEventHandler, SessionHealth,
+};
 use std::sync::Arc;
 
 /// A client for interacting with a specific Spanner database.
@@ -258,6 +262,78 @@ impl DatabaseClient {
         BatchWriteTransactionBuilder::new(self.clone())
     }
 
+    /// Returns a builder for a bulk writer.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_spanner::client::Spanner;
+    /// # use google_cloud_spanner::mutation::Mutation;
+    /// # async fn sample(spanner: Spanner) -> Result<(), google_cloud_spanner::Error> {
+    /// let db = spanner.database_client("projects/p/instances/i/databases/d").build().await?;
+    /// let writer = db.bulk_writer().build();
+    /// for id in 0..10_000 {
+    ///     writer.write(Mutation::new_insert_or_update_builder("Users").set("UserId").to(&id).build()).await?;
+    /// }
+    /// let summary = writer.close().await?;
+    /// println!("applied {} mutations", summary.applied_mutations);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// A bulk writer packs an unbounded number of mutations into mutation
+    /// groups and applies them with concurrent batch writes. See
+    /// [BulkWriter][crate::batch::BulkWriter] for details.
+    pub fn bulk_writer(&self) -> BulkWriterBuilder {
+        BulkWriterBuilder::new(self.clone())
+    }
+
+    /// Returns a builder for a change stream reader.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_spanner::client::Spanner;
+    /// # use google_cloud_spanner::change_stream::ChangeRecord;
+    /// # async fn sample(spanner: Spanner) -> Result<(), google_cloud_spanner::Error> {
+    /// let db = spanner.database_client("projects/p/instances/i/databases/d").build().await?;
+    /// let mut reader = db.change_stream("SingersStream").build().await?;
+    /// while let Some(record) = reader.next().await {
+    ///     if let ChangeRecord::DataChange(change) = record? {
+    ///         println!("{} rows modified in {}", change.mods.len(), change.table_name);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// The reader queries all the partitions of the change stream, follows
+    /// partition splits and merges, and checkpoints the progress of each
+    /// partition in a [PartitionStore](crate::change_stream::PartitionStore).
+    pub fn change_stream(&self, name: impl Into<String>) -> ChangeStreamBuilder {
+        ChangeStreamBuilder::new(self.clone(), name)
+    }
+
+    /// Returns a snapshot of the state of the multiplexed session used by this client.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_spanner::client::DatabaseClient;
+    /// # fn sample(db: &DatabaseClient) {
+    /// let health = db.health();
+    /// println!(
+    ///     "session {} is {:?} old, replaced {} times",
+    ///     health.session_name(),
+    ///     health.age(),
+    ///     health.recreation_count()
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// Readiness probes can use [SessionHealth::is_healthy] to detect clients
+    /// that can no longer obtain a session.
+    pub fn health(&self) -> SessionHealth {
+        self.session_maintainer.health()
+    }
+
     pub(crate) fn session_name(&self) -> String {
         self.session_maintainer.session_name()
     }
@@ -270,6 +346,7 @@ pub struct DatabaseClientBuilder {
     database_role: Option<String>,
     options: Option<crate::RequestOptions>,
     leader_aware_routing_enabled: bool,
+    on_session_event: Option<SessionEventHandler>,
 }
 
 impl DatabaseClientBuilder {
@@ -280,6 +357,7 @@ impl DatabaseClientBuilder {
             database_role: None,
             options: None,
             leader_aware_routing_enabled: true,
+            on_session_event: None,
         }
     }
 
@@ -360,6 +438,37 @@ impl DatabaseClientBuilder {
         self
     }
 
+    /// Sets a callback that is invoked when the multiplexed session is created,
+    /// replaced, or fails to be replaced.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_spanner::client::{SessionEvent, Spanner};
+    /// # async fn sample() -> anyhow::Result<()> {
+    ///     let spanner = Spanner::builder().build().await?;
+    ///     let database_client = spanner
+    ///         .database_client("projects/my-project/instances/my-instance/databases/my-db")
+    ///         .on_session_event(|event| {
+    ///             if let SessionEvent::MaintenanceFailed { error, .. } = event {
+    ///                 eprintln!("cannot replace Spanner session: {error}");
+    ///             }
+    ///         })
+    ///         .build()
+    ///         .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// The callback runs on the task that maintains the session, and should
+    /// return quickly.
+    pub fn on_session_event<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&SessionEvent) + Send + Sync + 'static,
+    {
+        self.on_session_event = Some(SessionEventHandler::new(handler));
+        self
+    }
+
     /// Builds the [DatabaseClient] and creates a single multiplexed session that
     /// will be used for all operations on the database.
     pub async fn build(self) -> crate::Result<DatabaseClient> {
@@ -386,6 +495,7 @@ impl DatabaseClientBuilder {
             self.database_role.unwrap_or_default(),
             self.options.unwrap_or_default(),
             o11y.clone(),
+            self.on_session_event,
         )
         .await?;
 
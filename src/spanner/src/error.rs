@@ -16,6 +16,7 @@ use google_cloud_gax::error::rpc::Status;
 use std::error::Error;
 
 pub use crate::from_value::ConvertError;
+pub use crate::interval::ParseIntervalError;
 pub use wkt::{DurationError, TimestampError};
 
 /// An unexpected error that occurs when the client receives data from Spanner
@@ -393,6 +393,50 @@ impl FromValue for Date {
     }
 }
 
+impl FromValue for crate::Interval {
+    fn from_value(value: &Value, type_: &Type) -> Result<Self, ConvertError> {
+        if type_.code() != TypeCode::Interval {
+            return Err(ConvertError::KindMismatch {
+                want: crate::value::Kind::String,
+                got: value.kind(),
+            });
+        }
+        match &value.0.kind {
+            Some(prost_types::value::Kind::StringValue(s)) => {
+                s.parse().map_err(|e| ConvertError::Convert(Box::new(e)))
+            }
+            Some(prost_types::value::Kind::NullValue(_)) => Err(ConvertError::NotNull),
+            _ => Err(ConvertError::KindMismatch {
+                want: crate::value::Kind::String,
+                got: value.kind(),
+            }),
+        }
+    }
+}
+
+/// Reads `UUID` columns, and `STRING` columns that contain UUIDs.
+#[cfg(feature = "uuid")]
+impl FromValue for uuid::Uuid {
+    fn from_value(value: &Value, type_: &Type) -> Result<Self, ConvertError> {
+        if type_.code() != TypeCode::Uuid && type_.code() != TypeCode::String {
+            return Err(ConvertError::KindMismatch {
+                want: crate::value::Kind::String,
+                got: value.kind(),
+            });
+        }
+        match &value.0.kind {
+            Some(prost_types::value::Kind::StringValue(s)) => {
+                uuid::Uuid::parse_str(s).map_err(|e| ConvertError::Convert(Box::new(e)))
+            }
+            Some(prost_types::value::Kind::NullValue(_)) => Err(ConvertError::NotNull),
+            _ => Err(ConvertError::KindMismatch {
+                want: crate::value::Kind::String,
+                got: value.kind(),
+            }),
+        }
+    }
+}
+
 impl FromValue for bool {
     fn from_value(value: &Value, _type: &Type) -> Result<Self, ConvertError> {
         match &value.0.kind {
@@ -494,6 +538,57 @@ where
     }
 }
 
+/// Converts the field named `name` in a `STRUCT` value into a Rust type.
+///
+/// Spanner sends `STRUCT` values as positional lists, the field names are only
+/// available in the type metadata. This function finds the position of the
+/// field in `type_`, and converts the corresponding value. It also accepts
+/// values in the named (object) format.
+///
+/// The code generated by `#[derive(FromRow)]` uses this function to convert
+/// nested `STRUCT` columns. It can also be used in manual [FromValue]
+/// implementations.
+///
+/// # Errors
+///
+/// Returns a [`ConvertError`] if the value is not a `STRUCT`, if the struct
+/// does not contain a field named `name`, or if the field value cannot be
+/// converted to `T`.
+pub fn struct_field<T: FromValue>(
+    value: &Value,
+    type_: &Type,
+    name: &str,
+) -> Result<T, ConvertError> {
+    let missing = || ConvertError::Convert(format!("missing struct field `{name}`").into());
+    let field = type_
+        .struct_type()
+        .and_then(|s| s.fields.iter().enumerate().find(|(_, f)| f.name == name));
+    let field_type = field
+        .and_then(|(_, f)| f.r#type.as_deref())
+        .map(Type::from_ref);
+    let field_value = match &value.0.kind {
+        Some(prost_types::value::Kind::ListValue(list)) => {
+            let (index, _) = field.ok_or_else(missing)?;
+            list.values.get(index).map(Value::from_ref)
+        }
+        Some(prost_types::value::Kind::StructValue(s)) => {
+            crate::value::Struct::from_ref(s).get(name)
+        }
+        Some(prost_types::value::Kind::NullValue(_)) | None => {
+            return Err(ConvertError::NotNull);
+        }
+        _ => {
+            return Err(ConvertError::KindMismatch {
+                want: crate::value::Kind::List,
+                got: value.kind(),
+            });
+        }
+    };
+    let field_value = field_value.ok_or_else(missing)?;
+    let unspecified = Type::default();
+    T::from_value(field_value, field_type.unwrap_or(&unspecified))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1627,4 +1722,103 @@ mod tests {
         let j = JsonValue::from_value(&v, &spanner_type).unwrap();
         assert_eq!(j, serde_json::json!({"a": "hello"}));
     }
+
+    fn name_age_type() -> Type {
+        let mut inner: mdl::Type = types::create_type(TypeCode::Struct).0;
+        inner.struct_type = Some(Box::new(
+            mdl::StructType::new().set_fields([
+                mdl::struct_type::Field::new()
+                    .set_name("name")
+                    .set_type(mdl::Type::from(types::string())),
+                mdl::struct_type::Field::new()
+                    .set_name("age")
+                    .set_type(mdl::Type::from(types::int64())),
+            ]),
+        ));
+        Type(inner)
+    }
+
+    #[test]
+    fn test_struct_field_positional() {
+        let v = Value(prost_types::Value {
+            kind: Some(prost_types::value::Kind::ListValue(
+                prost_types::ListValue {
+                    values: vec!["Alice".to_value().0, "30".to_value().0],
+                },
+            )),
+        });
+        let t = name_age_type();
+        assert_eq!(struct_field::<String>(&v, &t, "name").unwrap(), "Alice");
+        assert_eq!(struct_field::<i64>(&v, &t, "age").unwrap(), 30);
+
+        let err = struct_field::<i64>(&v, &t, "missing").unwrap_err();
+        assert!(matches!(err, ConvertError::Convert(_)), "{err:?}");
+        assert!(err.to_string().contains("missing"), "{err}");
+    }
+
+    #[test]
+    fn test_struct_field_named() {
+        let v = Value(prost_types::Value {
+            kind: Some(prost_types::value::Kind::StructValue(prost_types::Struct {
+                fields: [("age".to_string(), "30".to_value().0)].into(),
+            })),
+        });
+        let t = name_age_type();
+        assert_eq!(struct_field::<i64>(&v, &t, "age").unwrap(), 30);
+        let err = struct_field::<Option<String>>(&v, &t, "name").unwrap_err();
+        assert!(matches!(err, ConvertError::Convert(_)), "{err:?}");
+    }
+
+    #[test]
+    fn test_struct_field_errors() {
+        let t = name_age_type();
+        let err = struct_field::<i64>(&Value::null(), &t, "age").unwrap_err();
+        assert!(matches!(err, ConvertError::NotNull), "{err:?}");
+
+        let err = struct_field::<i64>(&"30".to_value(), &t, "age").unwrap_err();
+        assert!(
+            matches!(
+                err,
+                ConvertError::KindMismatch {
+                    want: crate::value::Kind::List,
+                    got: crate::value::Kind::String
+                }
+            ),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    fn test_from_value_interval() -> anyhow::Result<()> {
+        let want = crate::Interval::new(14, 3, 1_500_000_000);
+        let v = want.to_value();
+        assert_eq!(v.as_string(), "P1Y2M3DT1.5S");
+        assert_eq!(crate::Interval::from_value(&v, &types::interval())?, want);
+
+        let err = crate::Interval::from_value(&v, &types::string()).unwrap_err();
+        assert!(matches!(err, ConvertError::KindMismatch { .. }), "{err:?}");
+        let err = crate::Interval::from_value(&"1 day".to_value(), &types::interval()).unwrap_err();
+        assert!(matches!(err, ConvertError::Convert(_)), "{err:?}");
+        let err = crate::Interval::from_value(&Value::null(), &types::interval()).unwrap_err();
+        assert!(matches!(err, ConvertError::NotNull), "{err:?}");
+        Ok(())
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn test_from_value_uuid() -> anyhow::Result<()> {
+        let want = uuid::Uuid::parse_str("123e4567-e89b-12d3-a456-426614174000")?;
+        let v = want.to_value();
+        assert_eq!(v.as_string(), "123e4567-e89b-12d3-a456-426614174000");
+        assert_eq!(uuid::Uuid::from_value(&v, &types::uuid())?, want);
+        assert_eq!(uuid::Uuid::from_value(&v, &types::string())?, want);
+
+        let err = uuid::Uuid::from_value(&v, &types::bytes()).unwrap_err();
+        assert!(matches!(err, ConvertError::KindMismatch { .. }), "{err:?}");
+        let err = uuid::Uuid::from_value(&"not-a-uuid".to_value(), &types::uuid()).unwrap_err();
+        assert!(matches!(err, ConvertError::Convert(_)), "{err:?}");
+        let err = uuid::Uuid::from_value(&Value::null(), &types::uuid()).unwrap_err();
+        assert!(matches!(err, ConvertError::NotNull), "{err:?}");
+        Ok(())
+    }
 }
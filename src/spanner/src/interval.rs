@@ -0,0 +1,306 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Display, Formatter, Write};
+use std::str::FromStr;
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const NANOS_PER_MINUTE: i128 = 60 * NANOS_PER_SECOND;
+const NANOS_PER_HOUR: i128 = 60 * NANOS_PER_MINUTE;
+
+/// A Spanner `INTERVAL` value.
+///
+/// # Example
+/// ```
+/// # use google_cloud_spanner::Interval;
+/// let interval: Interval = "P1Y2M3DT4H5M6.5S".parse()?;
+/// assert_eq!(interval.months(), 14);
+/// assert_eq!(interval.days(), 3);
+/// assert_eq!(interval.nanoseconds(), 14_706_500_000_000);
+/// assert_eq!(interval.to_string(), "P1Y2M3DT4H5M6.5S");
+/// # Ok::<(), google_cloud_spanner::error::ParseIntervalError>(())
+/// ```
+///
+/// An interval consists of independent months, days and nanoseconds
+/// components, each of which may be negative. Spanner encodes intervals as
+/// ISO-8601 durations, for example `P1Y2M3DT4H5M6.5S`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Interval {
+    months: i32,
+    days: i32,
+    nanoseconds: i128,
+}
+
+impl Interval {
+    /// Creates an interval from its components.
+    pub fn new(months: i32, days: i32, nanoseconds: i128) -> Self {
+        Self {
+            months,
+            days,
+            nanoseconds,
+        }
+    }
+
+    /// Returns the months component, including the years.
+    pub fn months(&self) -> i32 {
+        self.months
+    }
+
+    /// Returns the days component.
+    pub fn days(&self) -> i32 {
+        self.days
+    }
+
+    /// Returns the nanoseconds component, including the hours, minutes and seconds.
+    pub fn nanoseconds(&self) -> i128 {
+        self.nanoseconds
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if *self == Self::default() {
+            return f.write_str("P0Y");
+        }
+        let mut s = String::from("P");
+        let component = |s: &mut String, value: i128, designator: char| {
+            if value != 0 {
+                let _ = write!(s, "{value}{designator}");
+            }
+        };
+        component(&mut s, (self.months / 12).into(), 'Y');
+        component(&mut s, (self.months % 12).into(), 'M');
+        component(&mut s, self.days.into(), 'D');
+        if self.nanoseconds != 0 {
+            s.push('T');
+            let nanos = self.nanoseconds;
+            component(&mut s, nanos / NANOS_PER_HOUR, 'H');
+            component(&mut s, nanos % NANOS_PER_HOUR / NANOS_PER_MINUTE, 'M');
+            let seconds = nanos % NANOS_PER_MINUTE;
+            if seconds != 0 {
+                let sign = if seconds < 0 { "-" } else { "" };
+                let whole = seconds.abs() / NANOS_PER_SECOND;
+                let fraction = seconds.abs() % NANOS_PER_SECOND;
+                let _ = write!(s, "{sign}{whole}");
+                if fraction != 0 {
+                    let digits = format!("{fraction:09}");
+                    let _ = write!(s, ".{}", digits.trim_end_matches('0'));
+                }
+                s.push('S');
+            }
+        }
+        f.write_str(&s)
+    }
+}
+
+/// The error returned when parsing an invalid [Interval].
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("invalid interval {input:?}: {reason}")]
+pub struct ParseIntervalError {
+    input: String,
+    reason: &'static str,
+}
+
+impl FromStr for Interval {
+    type Err = ParseIntervalError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = |reason| ParseIntervalError {
+            input: input.to_string(),
+            reason,
+        };
+        let mut rest = input
+            .strip_prefix('P')
+            .ok_or_else(|| error("must start with `P`"))?;
+        if rest.is_empty() {
+            return Err(error("must contain at least one component"));
+        }
+
+        let (mut months, mut days, mut nanos) = (0_i128, 0_i128, 0_i128);
+        let mut time = false;
+        // The designators must appear in this order, each one at most once.
+        let mut order = 0;
+        while !rest.is_empty() {
+            if let Some(r) = rest.strip_prefix('T') {
+                if time || r.is_empty() {
+                    return Err(error("misplaced `T`"));
+                }
+                time = true;
+                rest = r;
+                continue;
+            }
+            let end = rest
+                .find(|c: char| !(c.is_ascii_digit() || c == '-' || c == '.' || c == ','))
+                .ok_or_else(|| error("missing designator"))?;
+            let (number, designator) = (&rest[..end], rest[end..].chars().next());
+            rest = &rest[end + designator.map_or(0, char::len_utf8)..];
+            let position = match (time, designator) {
+                (false, Some('Y')) => 1,
+                (false, Some('M')) => 2,
+                (false, Some('D')) => 3,
+                (true, Some('H')) => 4,
+                (true, Some('M')) => 5,
+                (true, Some('S')) => 6,
+                _ => return Err(error("unexpected designator")),
+            };
+            if position <= order {
+                return Err(error("designators out of order"));
+            }
+            order = position;
+            let (total, value) = match position {
+                1 => (
+                    &mut months,
+                    number.parse::<i128>().ok().and_then(|v| v.checked_mul(12)),
+                ),
+                2 => (&mut months, number.parse().ok()),
+                3 => (&mut days, number.parse().ok()),
+                4 => (
+                    &mut nanos,
+                    number
+                        .parse::<i128>()
+                        .ok()
+                        .and_then(|v| v.checked_mul(NANOS_PER_HOUR)),
+                ),
+                5 => (
+                    &mut nanos,
+                    number
+                        .parse::<i128>()
+                        .ok()
+                        .and_then(|v| v.checked_mul(NANOS_PER_MINUTE)),
+                ),
+                _ => (&mut nanos, parse_seconds(number)),
+            };
+            *total = value
+                .and_then(|v| total.checked_add(v))
+                .ok_or_else(|| error("invalid number"))?;
+        }
+
+        Ok(Self {
+            months: months
+                .try_into()
+                .map_err(|_| error("months out of range"))?,
+            days: days.try_into().map_err(|_| error("days out of range"))?,
+            nanoseconds: nanos,
+        })
+    }
+}
+
+/// Parses a number of seconds with up to 9 fractional digits into nanoseconds.
+fn parse_seconds(number: &str) -> Option<i128> {
+    let (negative, number) = match number.strip_prefix('-') {
+        Some(n) => (true, n),
+        None => (false, number),
+    };
+    let (whole, fraction) = match number.split_once(['.', ',']) {
+        Some((w, f)) => (w, f),
+        None => (number, ""),
+    };
+    let digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if whole.is_empty() || !digits(whole) || !digits(fraction) || fraction.len() > 9 {
+        return None;
+    }
+    let whole: i128 = whole.parse().ok()?;
+    let fraction: i128 = format!("{fraction:0<9}").parse().ok()?;
+    let nanos = whole.checked_mul(NANOS_PER_SECOND)?.checked_add(fraction)?;
+    Some(if negative { -nanos } else { nanos })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() -> anyhow::Result<()> {
+        let cases = [
+            ("P0Y", Interval::new(0, 0, 0)),
+            ("P1Y2M3D", Interval::new(14, 3, 0)),
+            ("PT4H5M6S", Interval::new(0, 0, 14_706_000_000_000)),
+            ("P1Y2M3DT4H5M6.5S", Interval::new(14, 3, 14_706_500_000_000)),
+            (
+                "P-1Y-2M-3DT-4H-5M-6.5S",
+                Interval::new(-14, -3, -14_706_500_000_000),
+            ),
+            ("PT0.000000001S", Interval::new(0, 0, 1)),
+            ("PT-0.5S", Interval::new(0, 0, -500_000_000)),
+            ("P10000Y", Interval::new(120_000, 0, 0)),
+            (
+                "PT87840000H",
+                Interval::new(0, 0, 87_840_000 * NANOS_PER_HOUR),
+            ),
+        ];
+        for (input, want) in cases {
+            let got: Interval = input.parse()?;
+            assert_eq!(got, want, "{input}");
+            assert_eq!(got.to_string(), input);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_normalizes() -> anyhow::Result<()> {
+        let cases = [
+            ("P1Y-1M", Interval::new(11, 0, 0), "P11M"),
+            ("P13M", Interval::new(13, 0, 0), "P1Y1M"),
+            (
+                "PT90M",
+                Interval::new(0, 0, 90 * NANOS_PER_MINUTE),
+                "PT1H30M",
+            ),
+            ("PT1,25S", Interval::new(0, 0, 1_250_000_000), "PT1.25S"),
+            ("PT1.100S", Interval::new(0, 0, 1_100_000_000), "PT1.1S"),
+        ];
+        for (input, want, formatted) in cases {
+            let got: Interval = input.parse()?;
+            assert_eq!(got, want, "{input}");
+            assert_eq!(got.to_string(), formatted);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_error() {
+        let cases = [
+            "",
+            "P",
+            "1Y",
+            "P1",
+            "PT",
+            "P1H",
+            "PT1D",
+            "P1M1Y",
+            "P1Y1Y",
+            "P1.5Y",
+            "PT1.0000000001S",
+            "PT.5S",
+            "P300000000Y",
+            "PT99999999999999999999999999999999999999H",
+            "P1é",
+            "PT1€",
+            "P1Y€",
+            "Pé",
+        ];
+        for input in cases {
+            let got = input.parse::<Interval>();
+            assert!(got.is_err(), "{input}: {got:?}");
+        }
+    }
+
+    #[test]
+    fn accessors() {
+        let interval = Interval::new(1, 2, 3);
+        assert_eq!(interval.months(), 1);
+        assert_eq!(interval.days(), 2);
+        assert_eq!(interval.nanoseconds(), 3);
+    }
+}
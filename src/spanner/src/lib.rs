@@ -43,7 +43,13 @@ pub mod value;
 
 pub use google_cloud_gax::Result;
 pub use google_cloud_gax::error::Error;
+pub use google_cloud_spanner_derive::{FromRow, ToMutation};
+pub use interval::Interval;
 pub use rust_decimal::Decimal;
+// Used by the `proto_enum_name!` macro, so callers do not need `prost` as a
+// direct dependency.
+#[doc(hidden)]
+pub use prost;
 
 pub(crate) use google_cloud_gax::client_builder::Result as ClientBuilderResult;
 pub(crate) use google_cloud_gax::options::RequestOptions;
@@ -65,6 +71,8 @@ pub mod transaction;
 /// Batch execution and query partitioning support.
 pub mod batch;
 
+pub mod change_stream;
+
 /// The messages and enums that are part of this client library.
 pub mod model {
     pub use crate::generated::gapic_dataplane::model::*;
@@ -79,11 +87,15 @@ pub mod stub {
 pub(crate) mod batch_dml;
 pub(crate) mod batch_read_only_transaction;
 pub(crate) mod batch_write_transaction;
+pub(crate) mod bulk_writer;
 pub(crate) mod database_client;
 pub(crate) mod from_value;
+pub(crate) mod interval;
 pub(crate) mod observability;
+pub(crate) mod partition_executor;
 pub(crate) mod partitioned_dml_transaction;
 pub(crate) mod precommit;
+pub(crate) mod proto;
 pub(crate) mod read_only_transaction;
 pub(crate) mod read_write_transaction;
 pub(crate) mod request_id;
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::key::KeySet;
+use crate::key::{Key, KeySet};
 use crate::model::batch_write_request::MutationGroup as ProtoMutationGroup;
 use crate::model::mutation::Operation;
 use crate::value::Value;
@@ -164,6 +164,54 @@ impl Mutation {
         }
     }
 
+    /// Returns the number of mutations that this mutation counts for in the
+    /// commit limit.
+    ///
+    /// Spanner counts one mutation for each column that is written and one for
+    /// each deleted key or range. Secondary indexes also count towards the
+    /// limit, but they are not known to the client.
+    pub(crate) fn mutation_count(&self) -> usize {
+        match &self.inner {
+            InternalMutation::Insert(w)
+            | InternalMutation::Update(w)
+            | InternalMutation::InsertOrUpdate(w)
+            | InternalMutation::Replace(w) => w.columns.len().max(1),
+            InternalMutation::Delete(d) => (d.key_set.keys.len() + d.key_set.ranges.len()).max(1),
+        }
+    }
+
+    /// Returns an estimate of the encoded size of this mutation in bytes.
+    pub(crate) fn estimated_size(&self) -> usize {
+        use prost::Message;
+        let key_size =
+            |key: &crate::key::Key| -> usize { key.values.iter().map(|v| v.0.encoded_len()).sum() };
+        match &self.inner {
+            InternalMutation::Insert(w)
+            | InternalMutation::Update(w)
+            | InternalMutation::InsertOrUpdate(w)
+            | InternalMutation::Replace(w) => {
+                w.table.len()
+                    + w.columns.iter().map(String::len).sum::<usize>()
+                    + w.values.iter().map(|v| v.0.encoded_len()).sum::<usize>()
+            }
+            InternalMutation::Delete(d) => {
+                let ranges = d.key_set.ranges.iter().map(|r| {
+                    [&r.start, &r.end]
+                        .into_iter()
+                        .map(|e| match e {
+                            crate::key::Endpoint::Closed(k) | crate::key::Endpoint::Open(k) => {
+                                key_size(k)
+                            }
+                        })
+                        .sum::<usize>()
+                });
+                d.table.len()
+                    + d.key_set.keys.iter().map(key_size).sum::<usize>()
+                    + ranges.sum::<usize>()
+            }
+        }
+    }
+
     /// Selects the best mutation to act as a routing `mutation_key`.
     /// Prefers any non-`Insert` variation (like `Update`, `InsertOrUpdate`, `Replace`, `Delete`)
     /// since inserts more often use auto-generated columns (e.g. for primary key generation).
@@ -304,6 +352,97 @@ impl ValueBinder {
     }
 }
 
+/// Converts a Rust struct into mutations for a table.
+///
+/// Use `#[derive(ToMutation)]` to implement this trait. The derive macro maps
+/// each field to a column with the same name, and uses the fields marked with
+/// `#[spanner(key)]`, in declaration order, as the primary key.
+///
+/// # Example
+/// ```
+/// use google_cloud_spanner::ToMutation;
+/// use google_cloud_spanner::mutation::ToMutation as _;
+///
+/// #[derive(ToMutation)]
+/// #[spanner(table = "Singers")]
+/// struct Singer {
+///     #[spanner(key)]
+///     singer_id: i64,
+///     #[spanner(rename = "FirstName")]
+///     first_name: String,
+///     last_name: Option<String>,
+/// }
+///
+/// let singer = Singer {
+///     singer_id: 1,
+///     first_name: "Marc".to_string(),
+///     last_name: None,
+/// };
+/// let insert = singer.insert();
+/// let delete = singer.delete();
+/// ```
+pub trait ToMutation {
+    /// The name of the table.
+    fn table_name() -> &'static str;
+
+    /// The names of the columns, in the same order as [values][ToMutation::values].
+    ///
+    /// Use these names to read rows with [ReadRequest][crate::read::ReadRequest].
+    fn column_names() -> &'static [&'static str];
+
+    /// The values of the columns.
+    fn values(&self) -> Vec<Value>;
+
+    /// The primary key of the row.
+    fn key(&self) -> Key;
+
+    /// The set of primary keys for the given rows.
+    fn key_set<'a, I>(rows: I) -> KeySet
+    where
+        Self: 'a,
+        I: IntoIterator<Item = &'a Self>,
+    {
+        rows.into_iter()
+            .fold(KeySet::builder(), |b, r| b.add_key(r.key()))
+            .build()
+    }
+
+    /// Returns a mutation to insert the row.
+    fn insert(&self) -> Mutation {
+        write(self, MutationType::Insert)
+    }
+
+    /// Returns a mutation to update the row.
+    fn update(&self) -> Mutation {
+        write(self, MutationType::Update)
+    }
+
+    /// Returns a mutation to insert the row, or update it if it already exists.
+    fn insert_or_update(&self) -> Mutation {
+        write(self, MutationType::InsertOrUpdate)
+    }
+
+    /// Returns a mutation to replace the row.
+    fn replace(&self) -> Mutation {
+        write(self, MutationType::Replace)
+    }
+
+    /// Returns a mutation to delete the row, using its primary key.
+    fn delete(&self) -> Mutation {
+        Mutation::delete(Self::table_name(), self.key().into())
+    }
+}
+
+fn write<T: ToMutation + ?Sized>(row: &T, mutation_type: MutationType) -> Mutation {
+    WriteBuilder {
+        table: T::table_name().to_string(),
+        mutation_type,
+        columns: T::column_names().iter().map(|c| c.to_string()).collect(),
+        values: row.values(),
+    }
+    .build()
+}
+
 /// A group of mutations that are applied atomically in a [crate::batch::BatchWriteTransaction].
 #[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
@@ -431,6 +570,7 @@ macro_rules! mutation {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate as google_cloud_spanner;
     use crate::to_value::ToValue;
 
     #[test]
@@ -715,6 +855,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn mutation_count_and_size() {
+        use crate::key::{Key, KeyRange};
+        let mutation = Mutation::new_insert_builder("Users")
+            .set("UserId")
+            .to(1)
+            .set("UserName")
+            .to("Alice")
+            .build();
+        assert_eq!(mutation.mutation_count(), 2);
+        // The table, the column names, and the encoded values.
+        assert_eq!(mutation.estimated_size(), 5 + 6 + 8 + 3 + 7);
+
+        let key = |id: i64| Key::new(vec![id.into()]);
+        let key_set = KeySet::builder()
+            .add_key(key(1))
+            .add_key(key(2))
+            .add_range(KeyRange::closed_open(key(10), key(20)))
+            .build();
+        let mutation = Mutation::delete("Users", key_set);
+        assert_eq!(mutation.mutation_count(), 3);
+        assert_eq!(mutation.estimated_size(), 5 + 3 + 3 + 4 + 4);
+
+        let mutation = Mutation::delete("Users", KeySet::all());
+        assert_eq!(mutation.mutation_count(), 1);
+        assert_eq!(mutation.estimated_size(), 5);
+    }
+
     #[test]
     fn build_proto_delete() {
         let key_set = crate::key::KeySet::builder().build();
@@ -945,4 +1113,100 @@ mod tests {
         assert_eq!(macro_mutation2, expected_insert);
         assert_eq!(delete_mutation, expected_delete);
     }
+
+    #[derive(crate::ToMutation)]
+    #[spanner(table = "Singers")]
+    struct Singer {
+        #[spanner(key)]
+        singer_id: i64,
+        #[spanner(rename = "FirstName")]
+        first_name: String,
+        last_name: Option<String>,
+    }
+
+    #[derive(crate::ToMutation)]
+    struct Albums {
+        #[spanner(key)]
+        singer_id: i64,
+        #[spanner(key)]
+        album_id: i64,
+        title: String,
+    }
+
+    fn singer() -> Singer {
+        Singer {
+            singer_id: 1,
+            first_name: "Marc".to_string(),
+            last_name: None,
+        }
+    }
+
+    #[test]
+    fn derive_to_mutation_columns() {
+        assert_eq!(Singer::table_name(), "Singers");
+        assert_eq!(
+            Singer::column_names(),
+            &["singer_id", "FirstName", "last_name"]
+        );
+        assert_eq!(
+            singer().values(),
+            vec![1_i64.to_value(), "Marc".to_value(), Value::null()]
+        );
+        assert_eq!(singer().key(), Key::new(vec![1_i64.to_value()]));
+    }
+
+    #[test]
+    fn derive_to_mutation_writes() {
+        let builder = |b: WriteBuilder| {
+            b.set("singer_id")
+                .to(1_i64)
+                .set("FirstName")
+                .to("Marc")
+                .set("last_name")
+                .to(None::<String>)
+                .build()
+        };
+        assert_eq!(
+            singer().insert(),
+            builder(Mutation::new_insert_builder("Singers"))
+        );
+        assert_eq!(
+            singer().update(),
+            builder(Mutation::new_update_builder("Singers"))
+        );
+        assert_eq!(
+            singer().insert_or_update(),
+            builder(Mutation::new_insert_or_update_builder("Singers"))
+        );
+        assert_eq!(
+            singer().replace(),
+            builder(Mutation::new_replace_builder("Singers"))
+        );
+    }
+
+    #[test]
+    fn derive_to_mutation_keys() {
+        let album = Albums {
+            singer_id: 1,
+            album_id: 2,
+            title: "Total Junk".to_string(),
+        };
+        assert_eq!(Albums::table_name(), "Albums");
+        assert_eq!(album.key(), crate::key![1_i64, 2_i64]);
+        assert_eq!(
+            album.delete(),
+            Mutation::delete("Albums", crate::key![1_i64, 2_i64].into())
+        );
+
+        let other = Albums {
+            singer_id: 1,
+            album_id: 3,
+            title: "Go, Go, Go".to_string(),
+        };
+        let want = KeySet::builder()
+            .add_key(crate::key![1_i64, 2_i64])
+            .add_key(crate::key![1_i64, 3_i64])
+            .build();
+        assert_eq!(Albums::key_set([&album, &other]), want);
+    }
 }
@@ -23,8 +23,8 @@ use opentelemetry_sdk::Resource;
 use opentelemetry_sdk::error::{OTelSdkError, OTelSdkResult};
 use opentelemetry_sdk::metrics::Temporality;
 use opentelemetry_sdk::metrics::data::{
-    AggregatedMetrics, HistogramDataPoint, Metric as OTelMetric, MetricData, ResourceMetrics,
-    SumDataPoint,
+    AggregatedMetrics, GaugeDataPoint, HistogramDataPoint, Metric as OTelMetric, MetricData,
+    ResourceMetrics, SumDataPoint,
 };
 use opentelemetry_sdk::metrics::exporter::PushMetricExporter;
 use std::collections::HashMap;
@@ -226,6 +226,17 @@ fn convert_metric_to_time_series(
                 ));
             }
         }
+        AggregatedMetrics::F64(MetricData::Gauge(gauge)) => {
+            let end = gauge.time();
+            for dp in gauge.data_points() {
+                out.push(convert_f64_gauge_point(
+                    &metric_type,
+                    monitored_resource,
+                    dp,
+                    end,
+                ));
+            }
+        }
         _ => {}
     }
 }
@@ -291,6 +302,7 @@ fn resource_to_monitored_resource(resource: &Resource) -> MonitoredResource {
         .set_labels(labels)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn create_time_series<'a>(
     metric_type: &str,
     monitored_resource: &MonitoredResource,
@@ -298,6 +310,7 @@ fn create_time_series<'a>(
     start_time: SystemTime,
     end_time: SystemTime,
     typed_value: TypedValue,
+    metric_kind: metric_descriptor::MetricKind,
     value_type: metric_descriptor::ValueType,
 ) -> TimeSeries {
     let point = Point::new()
@@ -311,7 +324,7 @@ fn create_time_series<'a>(
     TimeSeries::new()
         .set_metric(metric)
         .set_resource(monitored_resource.clone())
-        .set_metric_kind(metric_descriptor::MetricKind::Cumulative)
+        .set_metric_kind(metric_kind)
         .set_value_type(value_type)
         .set_points(vec![point])
 }
@@ -348,6 +361,7 @@ fn convert_histogram_point(
         start_time,
         end_time,
         typed_value,
+        metric_descriptor::MetricKind::Cumulative,
         metric_descriptor::ValueType::Distribution,
     )
 }
@@ -368,6 +382,7 @@ fn convert_u64_point(
         start_time,
         end_time,
         typed_value,
+        metric_descriptor::MetricKind::Cumulative,
         metric_descriptor::ValueType::Int64,
     )
 }
@@ -388,6 +403,29 @@ fn convert_f64_point(
         start_time,
         end_time,
         typed_value,
+        metric_descriptor::MetricKind::Cumulative,
+        metric_descriptor::ValueType::Double,
+    )
+}
+
+/// Converts a gauge data point. Gauges describe a single instant, so the
+/// interval starts and ends at the collection time.
+fn convert_f64_gauge_point(
+    metric_type: &str,
+    monitored_resource: &MonitoredResource,
+    dp: &GaugeDataPoint<f64>,
+    time: SystemTime,
+) -> TimeSeries {
+    let typed_value = TypedValue::new().set_value(Value::DoubleValue(dp.value()));
+
+    create_time_series(
+        metric_type,
+        monitored_resource,
+        dp.attributes(),
+        time,
+        time,
+        typed_value,
+        metric_descriptor::MetricKind::Gauge,
         metric_descriptor::ValueType::Double,
     )
 }
@@ -493,6 +531,7 @@ mod tests {
             now,
             now,
             typed_val,
+            metric_descriptor::MetricKind::Cumulative,
             metric_descriptor::ValueType::Int64,
         );
 
@@ -596,6 +635,51 @@ mod tests {
         assert_eq!(ts_f64.value_type, metric_descriptor::ValueType::Double);
     }
 
+    #[test]
+    fn convert_metric_to_time_series_gauge() {
+        let exporter = InMemoryMetricExporter::default();
+        let reader = opentelemetry_sdk::metrics::PeriodicReader::builder(exporter.clone()).build();
+        let provider = opentelemetry_sdk::metrics::SdkMeterProvider::builder()
+            .with_reader(reader)
+            .build();
+
+        let meter = provider.meter("cloud.google.com/rust");
+        let gauge = meter.f64_gauge("multiplexed_session_age").build();
+        gauge.record(42.5, &[KeyValue::new("database", "test-db")]);
+
+        provider.force_flush().expect("force_flush failed");
+
+        let resource_metrics_list = exporter
+            .get_finished_metrics()
+            .expect("get_finished_metrics failed");
+
+        let mut time_series_list = Vec::new();
+        for resource_metrics in &resource_metrics_list {
+            let monitored_res = super::resource_to_monitored_resource(resource_metrics.resource());
+            for scope_metrics in resource_metrics.scope_metrics() {
+                for m in scope_metrics.metrics() {
+                    convert_metric_to_time_series(m, &monitored_res, &mut time_series_list);
+                }
+            }
+        }
+
+        assert_eq!(time_series_list.len(), 1);
+        let ts = &time_series_list[0];
+        assert_eq!(
+            ts.metric.as_ref().expect("metric").r#type,
+            "spanner.googleapis.com/internal/client/multiplexed_session_age"
+        );
+        assert_eq!(ts.metric_kind, metric_descriptor::MetricKind::Gauge);
+        assert_eq!(ts.value_type, metric_descriptor::ValueType::Double);
+        let point = &ts.points[0];
+        let interval = point.interval.as_ref().expect("interval");
+        assert_eq!(interval.start_time, interval.end_time);
+        assert_eq!(
+            point.value.as_ref().and_then(|v| v.double_value()),
+            Some(&42.5)
+        );
+    }
+
     #[test]
     fn resource_metrics_scope_filtering() {
         let exporter = InMemoryMetricExporter::default();
@@ -29,7 +29,7 @@ use {
     google_cloud_monitoring_v3::client::MetricService,
     http::header::{HeaderName, HeaderValue},
     opentelemetry::KeyValue,
-    opentelemetry::metrics::{Counter, Histogram, Meter, MeterProvider},
+    opentelemetry::metrics::{Counter, Gauge, Histogram, Meter, MeterProvider},
     opentelemetry_sdk::{
         Resource,
         error::OTelSdkError,
@@ -63,6 +63,9 @@ pub(crate) struct SpannerMetrics {
     pub(crate) gfe_connectivity_error_count: Counter<u64>,
     #[allow(dead_code)]
     pub(crate) afe_connectivity_error_count: Counter<u64>,
+    pub(crate) session_age: Gauge<f64>,
+    pub(crate) session_recreation_count: Counter<u64>,
+    pub(crate) session_maintenance_failure_count: Counter<u64>,
 }
 
 #[cfg(feature = "_experimental-builtin-metrics")]
@@ -101,6 +104,20 @@ impl SpannerMetrics {
             afe_connectivity_error_count: meter
                 .u64_counter("spanner.googleapis.com/internal/client/afe_connectivity_error_count")
                 .build(),
+            session_age: meter
+                .f64_gauge("spanner.googleapis.com/internal/client/multiplexed_session_age")
+                .with_unit("s")
+                .build(),
+            session_recreation_count: meter
+                .u64_counter(
+                    "spanner.googleapis.com/internal/client/multiplexed_session_recreation_count",
+                )
+                .build(),
+            session_maintenance_failure_count: meter
+                .u64_counter(
+                    "spanner.googleapis.com/internal/client/multiplexed_session_maintenance_failure_count",
+                )
+                .build(),
         }
     }
 }
@@ -379,6 +396,36 @@ impl Observability {
         }
     }
 
+    /// Records the age of the multiplexed session.
+    pub(crate) fn record_session_age(&self, age: Duration) {
+        let Some(ref metrics) = self.metrics else {
+            return;
+        };
+        metrics
+            .session_age
+            .record(age.as_secs_f64(), &self.common_attributes);
+    }
+
+    /// Records that the multiplexed session was replaced with a new session.
+    pub(crate) fn record_session_recreated(&self) {
+        let Some(ref metrics) = self.metrics else {
+            return;
+        };
+        metrics
+            .session_recreation_count
+            .add(1, &self.common_attributes);
+    }
+
+    /// Records a failed attempt to replace the multiplexed session.
+    pub(crate) fn record_session_maintenance_failure(&self) {
+        let Some(ref metrics) = self.metrics else {
+            return;
+        };
+        metrics
+            .session_maintenance_failure_count
+            .add(1, &self.common_attributes);
+    }
+
     pub(crate) fn shutdown(&self) {
         if let Some(ref provider) = self.meter_provider
             && let Err(err) = provider.shutdown()
@@ -618,6 +665,18 @@ impl Observability {
     ) {
     }
 
+    /// No-op stub implementation when the `_experimental-builtin-metrics` feature is disabled.
+    #[inline(always)]
+    pub(crate) fn record_session_age(&self, _age: Duration) {}
+
+    /// No-op stub implementation when the `_experimental-builtin-metrics` feature is disabled.
+    #[inline(always)]
+    pub(crate) fn record_session_recreated(&self) {}
+
+    /// No-op stub implementation when the `_experimental-builtin-metrics` feature is disabled.
+    #[inline(always)]
+    pub(crate) fn record_session_maintenance_failure(&self) {}
+
     #[allow(dead_code)]
     pub(crate) fn shutdown(&self) {}
 }
@@ -647,6 +706,9 @@ mod disabled_tests {
         .await;
         initialized.record_attempt("ExecuteSql", Duration::from_millis(10), None, None);
         initialized.record_operation("ExecuteSql", Duration::from_millis(10), None);
+        initialized.record_session_age(Duration::from_secs(10));
+        initialized.record_session_recreated();
+        initialized.record_session_maintenance_failure();
         let res = initialized
             .trace_operation("ExecuteSql", async { Ok::<_, crate::Error>(42) })
             .await
@@ -765,6 +827,9 @@ mod tests {
 
         o11y.record_operation("ExecuteSql", Duration::from_millis(10), None);
         o11y.record_attempt("ExecuteSql", Duration::from_millis(10), None, None);
+        o11y.record_session_age(Duration::from_secs(10));
+        o11y.record_session_recreated();
+        o11y.record_session_maintenance_failure();
         o11y.shutdown();
     }
 
@@ -835,6 +900,66 @@ mod tests {
         assert!(!finished.is_empty());
     }
 
+    #[test]
+    fn session_metrics() {
+        let exporter = InMemoryMetricExporter::default();
+        let reader = PeriodicReader::builder(exporter.clone()).build();
+        let provider = SdkMeterProvider::builder().with_reader(reader).build();
+        let meter = provider.meter("cloud.google.com/rust");
+        let metrics = SpannerMetrics::new(meter);
+        let o11y = Observability::for_test(metrics, provider.clone());
+
+        o11y.record_session_age(Duration::from_secs(90));
+        o11y.record_session_recreated();
+        o11y.record_session_maintenance_failure();
+        o11y.record_session_maintenance_failure();
+
+        provider.force_flush().expect("force_flush failed");
+        let finished = exporter
+            .get_finished_metrics()
+            .expect("get_finished_metrics");
+
+        let mut ages = Vec::new();
+        let mut counts = HashMap::new();
+        for metric in finished
+            .iter()
+            .flat_map(|r| r.scope_metrics())
+            .flat_map(|s| s.metrics())
+        {
+            match metric.data() {
+                AggregatedMetrics::F64(MetricData::Gauge(gauge)) => {
+                    ages.extend(gauge.data_points().map(|dp| dp.value()));
+                }
+                AggregatedMetrics::U64(MetricData::Sum(sum)) => {
+                    let total: u64 = sum.data_points().map(|dp| dp.value()).sum();
+                    counts.insert(metric.name().to_string(), total);
+                }
+                _ => {}
+            }
+        }
+        assert_eq!(ages, vec![90.0]);
+        assert_eq!(
+            counts
+                .get("spanner.googleapis.com/internal/client/multiplexed_session_recreation_count"),
+            Some(&1)
+        );
+        assert_eq!(
+            counts.get(
+                "spanner.googleapis.com/internal/client/multiplexed_session_maintenance_failure_count"
+            ),
+            Some(&2)
+        );
+
+        let attributes = extract_all_attributes(
+            &finished,
+            "spanner.googleapis.com/internal/client/multiplexed_session_recreation_count",
+        );
+        assert_eq!(
+            attributes[0].get("database").map(String::as_str),
+            Some("test-db")
+        );
+    }
+
     #[tokio::test]
     async fn trace_operation_success() {
         let o11y = Observability::disabled();
@@ -0,0 +1,636 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::batch_read_only_transaction::{BatchReadOnlyTransaction, Partition};
+use crate::database_client::DatabaseClient;
+use crate::model::PartitionOptions;
+use crate::read::ReadRequest;
+use crate::row::Row;
+use crate::statement::Statement;
+use google_cloud_gax::backoff_policy::BackoffPolicyArg;
+use google_cloud_gax::options::RequestOptions as GaxRequestOptions;
+use google_cloud_gax::retry_policy::RetryPolicyArg;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::{JoinHandle, JoinSet};
+
+#[cfg(feature = "unstable-stream")]
+use futures::Stream;
+
+const CHANNEL_CAPACITY: usize = 128;
+
+#[derive(Clone, Debug)]
+enum Operation {
+    Query(Statement),
+    Read(ReadRequest),
+}
+
+/// A builder for executing all the partitions of a query or read in parallel.
+///
+/// # Example
+/// ```
+/// # use google_cloud_spanner::client::DatabaseClient;
+/// # use google_cloud_spanner::statement::Statement;
+/// # async fn run(db_client: DatabaseClient) -> Result<(), google_cloud_spanner::Error> {
+/// let transaction = db_client.batch_read_only_transaction().build().await?;
+/// let mut rows = transaction
+///     .execute_all(Statement::builder("SELECT * FROM Singers").build(), 8)
+///     .set_data_boost(true)
+///     .rows()
+///     .await?;
+/// while let Some(row) = rows.next().await.transpose()? {
+///     // process row
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Created by [BatchReadOnlyTransaction::execute_all] and
+/// [BatchReadOnlyTransaction::execute_all_read].
+#[derive(Debug)]
+pub struct ExecuteAllBuilder<'a> {
+    transaction: &'a BatchReadOnlyTransaction,
+    operation: Operation,
+    concurrency: usize,
+    partition_options: PartitionOptions,
+    data_boost: bool,
+    gax_options: GaxRequestOptions,
+}
+
+impl<'a> ExecuteAllBuilder<'a> {
+    pub(crate) fn query(
+        transaction: &'a BatchReadOnlyTransaction,
+        statement: Statement,
+        concurrency: usize,
+    ) -> Self {
+        Self::new(transaction, Operation::Query(statement), concurrency)
+    }
+
+    pub(crate) fn read(
+        transaction: &'a BatchReadOnlyTransaction,
+        read: ReadRequest,
+        concurrency: usize,
+    ) -> Self {
+        Self::new(transaction, Operation::Read(read), concurrency)
+    }
+
+    fn new(
+        transaction: &'a BatchReadOnlyTransaction,
+        operation: Operation,
+        concurrency: usize,
+    ) -> Self {
+        Self {
+            transaction,
+            operation,
+            concurrency,
+            partition_options: PartitionOptions::default(),
+            data_boost: false,
+            gax_options: GaxRequestOptions::default(),
+        }
+    }
+
+    /// Sets the options used to create the partitions.
+    pub fn with_partition_options(mut self, options: PartitionOptions) -> Self {
+        self.partition_options = options;
+        self
+    }
+
+    /// Sets whether Data Boost is enabled for all partitions.
+    pub fn set_data_boost(mut self, enabled: bool) -> Self {
+        self.data_boost = enabled;
+        self
+    }
+
+    /// Sets the per-attempt timeout for each partition execution.
+    pub fn with_attempt_timeout(mut self, timeout: Duration) -> Self {
+        self.gax_options.set_attempt_timeout(timeout);
+        self
+    }
+
+    /// Sets the retry policy for each partition execution.
+    ///
+    /// Each partition is retried separately. A partition that exhausts its
+    /// retry policy does not restart the partitions that already completed.
+    pub fn with_retry_policy(mut self, policy: impl Into<RetryPolicyArg>) -> Self {
+        self.gax_options.set_retry_policy(policy);
+        self
+    }
+
+    /// Sets the backoff policy for each partition execution.
+    pub fn with_backoff_policy(mut self, policy: impl Into<BackoffPolicyArg>) -> Self {
+        self.gax_options.set_backoff_policy(policy);
+        self
+    }
+
+    /// Creates the partitions and executes them in parallel, returning the
+    /// rows of all partitions in a single stream.
+    ///
+    /// The rows of different partitions are interleaved in no particular
+    /// order. The stream stops after the first error, and cancels the
+    /// partitions that are still running.
+    pub async fn rows(self) -> crate::Result<PartitionedRows> {
+        let (events, executor, _) = self.start().await?;
+        Ok(PartitionedRows {
+            events,
+            executor,
+            done: false,
+        })
+    }
+
+    /// Creates the partitions and executes them in parallel, returning the
+    /// results of each partition as they arrive.
+    ///
+    /// Unlike [rows](Self::rows), a partition that fails does not stop the
+    /// other partitions. Use [PartitionedResults::partitions] to re-execute
+    /// failed partitions, possibly in a different process.
+    pub async fn by_partition(self) -> crate::Result<PartitionedResults> {
+        let (events, executor, partitions) = self.start().await?;
+        Ok(PartitionedResults {
+            events,
+            executor,
+            partitions,
+        })
+    }
+
+    async fn start(
+        self,
+    ) -> crate::Result<(
+        mpsc::Receiver<PartitionEvent>,
+        JoinHandle<()>,
+        Vec<Partition>,
+    )> {
+        let partitions = match self.operation {
+            Operation::Query(statement) => {
+                self.transaction
+                    .partition_query(statement, self.partition_options)
+                    .await?
+            }
+            Operation::Read(read) => {
+                self.transaction
+                    .partition_read(read, self.partition_options)
+                    .await?
+            }
+        };
+        let partitions: Vec<Partition> = partitions
+            .into_iter()
+            .map(|p| {
+                let mut p = p.set_data_boost(self.data_boost);
+                p.gax_options = self.gax_options.clone();
+                p
+            })
+            .collect();
+        let (events, executor) = execute_partitions(
+            self.transaction.client().clone(),
+            partitions.clone(),
+            self.concurrency,
+        );
+        Ok((events, executor, partitions))
+    }
+}
+
+/// An event produced while executing partitions in parallel.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PartitionEvent {
+    /// A row returned by a partition.
+    #[non_exhaustive]
+    Row {
+        /// The index of the partition that returned the row.
+        partition: usize,
+        /// The row.
+        row: Row,
+    },
+    /// A partition returned all its rows.
+    #[non_exhaustive]
+    Finished {
+        /// The index of the partition.
+        partition: usize,
+    },
+    /// A partition failed after exhausting its retry policy. The partition
+    /// does not return any more rows.
+    #[non_exhaustive]
+    Failed {
+        /// The index of the partition.
+        partition: usize,
+        /// The error returned by the partition.
+        error: crate::Error,
+    },
+}
+
+/// The merged rows of partitions executed in parallel.
+///
+/// Returned by [ExecuteAllBuilder::rows]. Dropping this value cancels the
+/// partitions that are still running.
+#[derive(Debug)]
+pub struct PartitionedRows {
+    events: mpsc::Receiver<PartitionEvent>,
+    executor: JoinHandle<()>,
+    done: bool,
+}
+
+impl PartitionedRows {
+    /// Returns the next row of any partition, or `None` when all partitions
+    /// have finished.
+    ///
+    /// After an error, no more rows are returned.
+    pub async fn next(&mut self) -> Option<crate::Result<Row>> {
+        if self.done {
+            return None;
+        }
+        while let Some(event) = self.events.recv().await {
+            match event {
+                PartitionEvent::Row { row, .. } => return Some(Ok(row)),
+                PartitionEvent::Finished { .. } => {}
+                PartitionEvent::Failed { error, .. } => {
+                    self.done = true;
+                    self.executor.abort();
+                    return Some(Err(error));
+                }
+            }
+        }
+        self.done = true;
+        None
+    }
+
+    /// Converts the rows into a [Stream].
+    #[cfg(feature = "unstable-stream")]
+    pub fn into_stream(self) -> impl Stream<Item = crate::Result<Row>> + Unpin {
+        use futures::stream::unfold;
+        Box::pin(unfold(self, |mut rows| async move {
+            rows.next().await.map(|row| (row, rows))
+        }))
+    }
+}
+
+impl Drop for PartitionedRows {
+    fn drop(&mut self) {
+        self.executor.abort();
+    }
+}
+
+/// The per-partition results of partitions executed in parallel.
+///
+/// Returned by [ExecuteAllBuilder::by_partition]. Dropping this value
+/// cancels the partitions that are still running.
+#[derive(Debug)]
+pub struct PartitionedResults {
+    events: mpsc::Receiver<PartitionEvent>,
+    executor: JoinHandle<()>,
+    partitions: Vec<Partition>,
+}
+
+impl PartitionedResults {
+    /// Returns the next event of any partition, or `None` when all partitions
+    /// have finished or failed.
+    pub async fn next(&mut self) -> Option<PartitionEvent> {
+        self.events.recv().await
+    }
+
+    /// Returns the partitions being executed.
+    ///
+    /// The index of each partition matches the `partition` field of
+    /// [PartitionEvent]. Partitions can be serialized and executed by other
+    /// processes with [Partition::execute].
+    pub fn partitions(&self) -> &[Partition] {
+        &self.partitions
+    }
+
+    /// Converts the results into a [Stream].
+    #[cfg(feature = "unstable-stream")]
+    pub fn into_stream(self) -> impl Stream<Item = PartitionEvent> + Unpin {
+        use futures::stream::unfold;
+        Box::pin(unfold(self, |mut results| async move {
+            results.next().await.map(|event| (event, results))
+        }))
+    }
+}
+
+impl Drop for PartitionedResults {
+    fn drop(&mut self) {
+        self.executor.abort();
+    }
+}
+
+/// Executes `partitions` with at most `concurrency` partitions running at
+/// the same time.
+///
+/// Aborting the returned task cancels all running partitions.
+fn execute_partitions(
+    client: DatabaseClient,
+    partitions: Vec<Partition>,
+    concurrency: usize,
+) -> (mpsc::Receiver<PartitionEvent>, JoinHandle<()>) {
+    let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+    let concurrency = concurrency.max(1);
+    let executor = tokio::spawn(async move {
+        let mut running = JoinSet::new();
+        for (index, partition) in partitions.into_iter().enumerate() {
+            if running.len() >= concurrency {
+                running.join_next().await;
+            }
+            running.spawn(execute_partition(
+                client.clone(),
+                index,
+                partition,
+                tx.clone(),
+            ));
+        }
+        while running.join_next().await.is_some() {}
+    });
+    (rx, executor)
+}
+
+async fn execute_partition(
+    client: DatabaseClient,
+    partition: usize,
+    operation: Partition,
+    tx: mpsc::Sender<PartitionEvent>,
+) {
+    // The result set retries the initial RPC and resumes interrupted streams
+    // using the retry policy of the partition.
+    let mut result_set = match operation.execute(&client).await {
+        Ok(rs) => rs,
+        Err(error) => {
+            let _ = tx.send(PartitionEvent::Failed { partition, error }).await;
+            return;
+        }
+    };
+    while let Some(row) = result_set.next().await {
+        let event = match row {
+            Ok(row) => PartitionEvent::Row { partition, row },
+            Err(error) => {
+                let _ = tx.send(PartitionEvent::Failed { partition, error }).await;
+                return;
+            }
+        };
+        if tx.send(event).await.is_err() {
+            return;
+        }
+    }
+    let _ = tx.send(PartitionEvent::Finished { partition }).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::key::KeySet;
+    use crate::read_only_transaction::tests::{create_session_mock, setup_db_client};
+    use crate::result_set::tests::adapt;
+    use gaxi::grpc::tonic::{Response, Status};
+    use google_cloud_test_macros::tokio_test_no_panics;
+    use spanner_grpc_mock::MockSpanner;
+    use spanner_grpc_mock::google::spanner::v1 as mock_v1;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn auto_traits() {
+        static_assertions::assert_impl_all!(ExecuteAllBuilder<'_>: Send, Sync, std::fmt::Debug);
+        static_assertions::assert_impl_all!(PartitionedRows: Send, Sync, std::fmt::Debug);
+        static_assertions::assert_impl_all!(PartitionedResults: Send, Sync, std::fmt::Debug);
+        static_assertions::assert_impl_all!(PartitionEvent: Send, Sync, std::fmt::Debug);
+    }
+
+    fn partition_mock(tokens: &[u8]) -> MockSpanner {
+        let mut mock = create_session_mock();
+        mock.expect_begin_transaction().once().returning(|_| {
+            Ok(Response::new(mock_v1::Transaction {
+                id: vec![1, 2, 3],
+                ..Default::default()
+            }))
+        });
+        let partitions: Vec<_> = tokens
+            .iter()
+            .map(|t| mock_v1::Partition {
+                partition_token: vec![*t],
+            })
+            .collect();
+        let read_partitions = partitions.clone();
+        mock.expect_partition_query().returning(move |_| {
+            Ok(Response::new(mock_v1::PartitionResponse {
+                partitions: partitions.clone(),
+                transaction: None,
+            }))
+        });
+        mock.expect_partition_read().returning(move |_| {
+            Ok(Response::new(mock_v1::PartitionResponse {
+                partitions: read_partitions.clone(),
+                transaction: None,
+            }))
+        });
+        mock
+    }
+
+    /// Returns a result set with a single row containing `value`.
+    fn result_set(value: String) -> mock_v1::PartialResultSet {
+        mock_v1::PartialResultSet {
+            metadata: Some(mock_v1::ResultSetMetadata {
+                row_type: Some(mock_v1::StructType {
+                    fields: vec![mock_v1::struct_type::Field {
+                        name: "Value".to_string(),
+                        r#type: Some(mock_v1::Type {
+                            code: mock_v1::TypeCode::String as i32,
+                            ..Default::default()
+                        }),
+                    }],
+                }),
+                ..Default::default()
+            }),
+            values: vec![prost_types::Value {
+                kind: Some(prost_types::value::Kind::StringValue(value)),
+            }],
+            last: true,
+            ..Default::default()
+        }
+    }
+
+    fn permanent_error() -> Status {
+        Status::permission_denied("partition failed")
+    }
+
+    #[tokio_test_no_panics]
+    async fn rows() -> anyhow::Result<()> {
+        let mut mock = partition_mock(&[10, 20, 30]);
+        mock.expect_execute_streaming_sql()
+            .times(3)
+            .returning(|req| {
+                let req = req.into_inner();
+                assert!(req.data_boost_enabled, "{req:?}");
+                assert_eq!(req.sql, "SELECT Value FROM T");
+                let token = req.partition_token[0];
+                Ok(Response::from(adapt([Ok(result_set(format!(
+                    "row-{token}"
+                )))])))
+            });
+        let (db_client, _server) = setup_db_client(mock).await;
+
+        let transaction = db_client.batch_read_only_transaction().build().await?;
+        let mut rows = transaction
+            .execute_all(Statement::builder("SELECT Value FROM T").build(), 2)
+            .set_data_boost(true)
+            .rows()
+            .await?;
+        let mut got = BTreeSet::new();
+        while let Some(row) = rows.next().await.transpose()? {
+            got.insert(row.get::<String, _>("Value"));
+        }
+        assert_eq!(
+            got,
+            BTreeSet::from(["row-10".to_string(), "row-20".into(), "row-30".into()])
+        );
+        assert!(rows.next().await.is_none());
+        Ok(())
+    }
+
+    #[tokio_test_no_panics]
+    async fn rows_read() -> anyhow::Result<()> {
+        let mut mock = partition_mock(&[10, 20]);
+        mock.expect_streaming_read().times(2).returning(|req| {
+            let req = req.into_inner();
+            assert_eq!(req.table, "T");
+            assert!(!req.data_boost_enabled, "{req:?}");
+            let token = req.partition_token[0];
+            Ok(Response::from(adapt([Ok(result_set(format!(
+                "row-{token}"
+            )))])))
+        });
+        let (db_client, _server) = setup_db_client(mock).await;
+
+        let transaction = db_client.batch_read_only_transaction().build().await?;
+        let read = ReadRequest::builder("T", vec!["Value"])
+            .with_keys(KeySet::all())
+            .build();
+        // A concurrency of zero executes one partition at a time.
+        let mut rows = transaction.execute_all_read(read, 0).rows().await?;
+        let mut got = BTreeSet::new();
+        while let Some(row) = rows.next().await.transpose()? {
+            got.insert(row.get::<String, _>("Value"));
+        }
+        assert_eq!(got, BTreeSet::from(["row-10".to_string(), "row-20".into()]));
+        Ok(())
+    }
+
+    #[tokio_test_no_panics]
+    async fn rows_error() -> anyhow::Result<()> {
+        let mut mock = partition_mock(&[10, 20]);
+        mock.expect_execute_streaming_sql().returning(|req| {
+            let req = req.into_inner();
+            if req.partition_token[0] == 20 {
+                return Err(permanent_error());
+            }
+            Ok(Response::from(adapt([Ok(result_set(
+                "row-10".to_string(),
+            ))])))
+        });
+        let (db_client, _server) = setup_db_client(mock).await;
+
+        let transaction = db_client.batch_read_only_transaction().build().await?;
+        let mut rows = transaction
+            .execute_all(Statement::builder("SELECT Value FROM T").build(), 1)
+            .rows()
+            .await?;
+        let mut errors = 0;
+        while let Some(row) = rows.next().await {
+            if let Err(e) = row {
+                assert_eq!(
+                    e.status().map(|s| s.message.as_str()),
+                    Some("partition failed")
+                );
+                errors += 1;
+            }
+        }
+        assert_eq!(errors, 1);
+        assert!(rows.next().await.is_none());
+        Ok(())
+    }
+
+    #[tokio_test_no_panics]
+    async fn by_partition() -> anyhow::Result<()> {
+        let mut mock = partition_mock(&[10, 20, 30]);
+        mock.expect_execute_streaming_sql()
+            .times(3)
+            .returning(|req| {
+                let req = req.into_inner();
+                match req.partition_token[0] {
+                    20 => Ok(Response::from(adapt([Err(permanent_error())]))),
+                    token => Ok(Response::from(adapt([Ok(result_set(format!(
+                        "row-{token}"
+                    )))]))),
+                }
+            });
+        let (db_client, _server) = setup_db_client(mock).await;
+
+        let transaction = db_client.batch_read_only_transaction().build().await?;
+        let mut results = transaction
+            .execute_all(Statement::builder("SELECT Value FROM T").build(), 3)
+            .with_attempt_timeout(Duration::from_secs(5))
+            .by_partition()
+            .await?;
+        assert_eq!(results.partitions().len(), 3);
+
+        let (mut rows, mut finished, mut failed) = (Vec::new(), Vec::new(), Vec::new());
+        while let Some(event) = results.next().await {
+            match event {
+                PartitionEvent::Row { partition, row } => {
+                    rows.push((partition, row.get::<String, _>("Value")))
+                }
+                PartitionEvent::Finished { partition } => finished.push(partition),
+                PartitionEvent::Failed { partition, error } => failed.push((partition, error)),
+            }
+        }
+        rows.sort();
+        finished.sort();
+        assert_eq!(
+            rows,
+            vec![(0, "row-10".to_string()), (2, "row-30".to_string())]
+        );
+        assert_eq!(finished, vec![0, 2]);
+        assert_eq!(failed.len(), 1, "{failed:?}");
+        assert_eq!(failed[0].0, 1);
+
+        // The failed partition can be serialized and executed elsewhere.
+        let partition = &results.partitions()[failed[0].0];
+        let serialized = serde_json::to_string(partition)?;
+        let got: Partition = serde_json::from_str(&serialized)?;
+        match got.inner {
+            crate::batch_read_only_transaction::PartitionedOperation::Query(req) => {
+                assert_eq!(req.partition_token.as_ref(), &[20]);
+            }
+            _ => panic!("expected a query partition"),
+        }
+        Ok(())
+    }
+
+    #[tokio_test_no_panics]
+    async fn partition_error() -> anyhow::Result<()> {
+        let mut mock = create_session_mock();
+        mock.expect_begin_transaction().once().returning(|_| {
+            Ok(Response::new(mock_v1::Transaction {
+                id: vec![1, 2, 3],
+                ..Default::default()
+            }))
+        });
+        mock.expect_partition_query()
+            .once()
+            .returning(|_| Err(permanent_error()));
+        let (db_client, _server) = setup_db_client(mock).await;
+
+        let transaction = db_client.batch_read_only_transaction().build().await?;
+        let got = transaction
+            .execute_all(Statement::builder("SELECT Value FROM T").build(), 2)
+            .rows()
+            .await;
+        assert!(got.is_err(), "{got:?}");
+        Ok(())
+    }
+}
@@ -0,0 +1,304 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::from_value::{ConvertError, FromValue};
+use crate::to_value::ToValue;
+use crate::types::Type;
+use crate::value::Value;
+
+/// A protobuf message stored in a Spanner `PROTO` column.
+///
+/// # Example
+/// ```
+/// # use google_cloud_spanner::result::Row;
+/// # use google_cloud_spanner::value::Proto;
+/// # fn sample(row: Row) -> Result<(), google_cloud_spanner::Error> {
+/// let Proto(duration) = row.try_get::<Proto<prost_types::Duration>, _>("Duration")?;
+/// println!("{}s", duration.seconds);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Use [StatementBuilder::add_proto_param] to bind a message as a query
+/// parameter with its fully-qualified type name.
+///
+/// [StatementBuilder::add_proto_param]: crate::statement::StatementBuilder::add_proto_param
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Proto<T>(pub T);
+
+impl<T: prost::Message> ToValue for Proto<T> {
+    fn to_value(&self) -> Value {
+        self.0.encode_to_vec().into()
+    }
+}
+
+impl<T: prost::Message> From<Proto<T>> for Value {
+    fn from(p: Proto<T>) -> Self {
+        p.to_value()
+    }
+}
+
+impl<T: prost::Message + Default> FromValue for Proto<T> {
+    fn from_value(value: &Value, type_: &Type) -> Result<Self, ConvertError> {
+        let bytes = Vec::<u8>::from_value(value, type_)?;
+        T::decode(bytes.as_slice())
+            .map(Proto)
+            .map_err(|e| ConvertError::Convert(Box::new(e)))
+    }
+}
+
+/// A protobuf enum stored in a Spanner `ENUM` column.
+///
+/// # Example
+/// ```
+/// # use google_cloud_spanner::result::Row;
+/// # use google_cloud_spanner::value::ProtoEnum;
+/// # fn sample(row: Row) -> Result<(), google_cloud_spanner::Error> {
+/// let ProtoEnum(null) = row.try_get::<ProtoEnum<prost_types::NullValue>, _>("Null")?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Works with the enums generated by prost, which convert to and from `i32`.
+/// Implement [ProtoEnumName] to bind an enum as a query parameter with
+/// [StatementBuilder::add_proto_enum_param].
+///
+/// [StatementBuilder::add_proto_enum_param]: crate::statement::StatementBuilder::add_proto_enum_param
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ProtoEnum<T>(pub T);
+
+impl<T: Copy + Into<i32>> ToValue for ProtoEnum<T> {
+    fn to_value(&self) -> Value {
+        i64::from(self.0.into()).into()
+    }
+}
+
+impl<T: Copy + Into<i32>> From<ProtoEnum<T>> for Value {
+    fn from(e: ProtoEnum<T>) -> Self {
+        e.to_value()
+    }
+}
+
+impl<T> FromValue for ProtoEnum<T>
+where
+    T: TryFrom<i32>,
+    T::Error: std::error::Error + Send + Sync + 'static,
+{
+    fn from_value(value: &Value, type_: &Type) -> Result<Self, ConvertError> {
+        let number = i64::from_value(value, type_)?;
+        let number = i32::try_from(number).map_err(|e| ConvertError::Convert(Box::new(e)))?;
+        T::try_from(number)
+            .map(ProtoEnum)
+            .map_err(|e| ConvertError::Convert(Box::new(e)))
+    }
+}
+
+/// The fully-qualified name of a protobuf enum.
+///
+/// prost generates [prost::Name] for messages, but not for enums. Use the
+/// [proto_enum_name!] macro to implement this trait for the enums generated
+/// by prost, and then bind them as query parameters.
+///
+/// # Example
+/// ```
+/// # use google_cloud_spanner::value::ProtoEnumName;
+/// # #[derive(Clone, Copy)]
+/// # enum Genre { Pop = 0 }
+/// impl ProtoEnumName for Genre {
+///     fn full_name() -> String {
+///         "examples.music.Genre".to_string()
+///     }
+/// }
+/// ```
+///
+/// [proto_enum_name!]: crate::proto_enum_name
+pub trait ProtoEnumName {
+    /// Returns the fully-qualified name of the enum, for example `examples.music.Genre`.
+    fn full_name() -> String;
+}
+
+/// Implements [ProtoEnumName] for one or more prost-generated enums.
+///
+/// The name of each enum is the name of the Rust type, prefixed by the
+/// protobuf package for top-level enums, or by the fully-qualified name of
+/// the enclosing message (as returned by [prost::Name]) for nested enums.
+///
+/// # Example
+/// ```
+/// # mod music {
+/// #   #[derive(Clone, Copy)] pub enum Genre { Pop = 0 }
+/// #   impl From<Genre> for i32 { fn from(g: Genre) -> i32 { g as i32 } }
+/// #   #[derive(Clone, Copy)] pub enum Mood { Happy = 0 }
+/// #   #[derive(Clone, PartialEq, prost::Message)] pub struct Singer {}
+/// #   impl prost::Name for Singer {
+/// #       const NAME: &'static str = "Singer";
+/// #       const PACKAGE: &'static str = "examples.music";
+/// #       fn full_name() -> String { "examples.music.Singer".into() }
+/// #   }
+/// #   pub mod singer { #[derive(Clone, Copy)] pub enum Status { Active = 0 } }
+/// # }
+/// use google_cloud_spanner::proto_enum_name;
+/// use google_cloud_spanner::statement::Statement;
+///
+/// // Top-level enums in the `examples.music` package.
+/// proto_enum_name!(package = "examples.music"; music::Genre, music::Mood);
+/// // Enums nested in the `examples.music.Singer` message.
+/// proto_enum_name!(message = music::Singer; music::singer::Status);
+///
+/// let statement = Statement::builder("SELECT * FROM Songs WHERE Genre = @genre")
+///     .add_proto_enum_param("genre", music::Genre::Pop)
+///     .build();
+/// ```
+#[macro_export]
+macro_rules! proto_enum_name {
+    (package = $package:expr; $($first:ident $(:: $rest:ident)*),+ $(,)?) => {
+        $(
+            impl $crate::value::ProtoEnumName for $first $(:: $rest)* {
+                fn full_name() -> ::std::string::String {
+                    ::std::format!(
+                        "{}.{}",
+                        $package,
+                        $crate::proto_enum_name!(@last $first $(:: $rest)*)
+                    )
+                }
+            }
+        )+
+    };
+    (message = $message:ty; $($first:ident $(:: $rest:ident)*),+ $(,)?) => {
+        $(
+            impl $crate::value::ProtoEnumName for $first $(:: $rest)* {
+                fn full_name() -> ::std::string::String {
+                    ::std::format!(
+                        "{}.{}",
+                        <$message as $crate::prost::Name>::full_name(),
+                        $crate::proto_enum_name!(@last $first $(:: $rest)*)
+                    )
+                }
+            }
+        )+
+    };
+    (@last $last:ident) => {
+        ::std::stringify!($last)
+    };
+    (@last $head:ident :: $($tail:tt)+) => {
+        $crate::proto_enum_name!(@last $($tail)+)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::google::spanner::v1::TypeCode as ProtoTypeCode;
+    use crate::types;
+    use crate::value::Kind;
+    use prost::Name;
+
+    fn duration() -> prost_types::Duration {
+        prost_types::Duration {
+            seconds: 12,
+            nanos: 34,
+        }
+    }
+
+    #[test]
+    fn proto_roundtrip() -> anyhow::Result<()> {
+        let value = Proto(duration()).to_value();
+        assert_eq!(value.kind(), Kind::String);
+        let type_ = types::proto(prost_types::Duration::full_name());
+        let got = Proto::<prost_types::Duration>::from_value(&value, &type_)?;
+        assert_eq!(got, Proto(duration()));
+
+        let value: Value = Proto(duration()).into();
+        let got = Option::<Proto<prost_types::Duration>>::from_value(&value, &type_)?;
+        assert_eq!(got, Some(Proto(duration())));
+        Ok(())
+    }
+
+    #[test]
+    fn proto_from_bytes_column() -> anyhow::Result<()> {
+        use prost::Message;
+        let value = duration().encode_to_vec().to_value();
+        let got = Proto::<prost_types::Duration>::from_value(&value, &types::bytes())?;
+        assert_eq!(got, Proto(duration()));
+        Ok(())
+    }
+
+    #[test]
+    fn proto_errors() {
+        let type_ = types::proto("google.protobuf.Duration");
+        let got = Proto::<prost_types::Duration>::from_value(&Value::null(), &type_);
+        assert!(matches!(got, Err(ConvertError::NotNull)), "{got:?}");
+
+        // 0xff is an invalid tag.
+        let value = vec![0xff_u8].to_value();
+        let got = Proto::<prost_types::Duration>::from_value(&value, &type_);
+        assert!(matches!(got, Err(ConvertError::Convert(_))), "{got:?}");
+
+        let got = Proto::<prost_types::Duration>::from_value(&"abc".to_value(), &types::string());
+        assert!(
+            matches!(got, Err(ConvertError::KindMismatch { .. })),
+            "{got:?}"
+        );
+    }
+
+    mod names {
+        use crate::google::spanner::v1;
+
+        crate::proto_enum_name!(package = "google.spanner.v1"; v1::TypeCode, v1::TypeAnnotationCode);
+        crate::proto_enum_name!(message = v1::RequestOptions; v1::request_options::Priority);
+    }
+
+    #[test]
+    fn enum_names() {
+        use crate::google::spanner::v1;
+        assert_eq!(
+            <v1::TypeCode as ProtoEnumName>::full_name(),
+            "google.spanner.v1.TypeCode"
+        );
+        assert_eq!(
+            <v1::TypeAnnotationCode as ProtoEnumName>::full_name(),
+            "google.spanner.v1.TypeAnnotationCode"
+        );
+        assert_eq!(
+            <v1::request_options::Priority as ProtoEnumName>::full_name(),
+            "google.spanner.v1.RequestOptions.Priority"
+        );
+    }
+
+    #[test]
+    fn enum_roundtrip() -> anyhow::Result<()> {
+        let type_ = types::proto_enum("google.spanner.v1.TypeCode");
+        let value = ProtoEnum(ProtoTypeCode::Json).to_value();
+        assert_eq!(value, 11_i64.to_value());
+        let got = ProtoEnum::<ProtoTypeCode>::from_value(&value, &type_)?;
+        assert_eq!(got, ProtoEnum(ProtoTypeCode::Json));
+
+        let value: Value = ProtoEnum(ProtoTypeCode::Bool).into();
+        assert_eq!(value, 1_i64.to_value());
+        Ok(())
+    }
+
+    #[test]
+    fn enum_errors() {
+        let type_ = types::proto_enum("google.spanner.v1.TypeCode");
+        let got = ProtoEnum::<ProtoTypeCode>::from_value(&1000_i64.to_value(), &type_);
+        assert!(matches!(got, Err(ConvertError::Convert(_))), "{got:?}");
+
+        let got = ProtoEnum::<ProtoTypeCode>::from_value(&i64::MAX.to_value(), &type_);
+        assert!(matches!(got, Err(ConvertError::Convert(_))), "{got:?}");
+
+        let got = ProtoEnum::<ProtoTypeCode>::from_value(&Value::null(), &type_);
+        assert!(matches!(got, Err(ConvertError::NotNull)), "{got:?}");
+    }
+}
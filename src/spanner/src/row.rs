@@ -231,8 +231,11 @@ impl Row {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate as google_cloud_spanner;
+    use crate::model;
     use crate::to_value::ToValue;
     use crate::types;
+    use crate::value::Type;
     use rust_decimal::Decimal;
     use std::sync::Arc;
     use time::{Date, Month, OffsetDateTime};
@@ -355,4 +358,125 @@ mod tests {
         // int64 is encoded as a string, so getting it as a string is also possible.
         assert_eq!(row.get::<String, _>(1), "42");
     }
+
+    #[derive(crate::FromRow, Debug, PartialEq)]
+    struct Singer {
+        #[spanner(rename = "SingerId")]
+        singer_id: i64,
+        name: String,
+        nickname: Option<String>,
+        address: Address,
+        albums: Vec<Album>,
+    }
+
+    #[derive(crate::FromRow, Debug, PartialEq)]
+    struct Address {
+        city: String,
+        #[spanner(rename = "Zip")]
+        zip: Option<i64>,
+    }
+
+    #[derive(crate::FromRow, Debug, PartialEq)]
+    struct Album {
+        title: String,
+    }
+
+    fn struct_type(fields: Vec<(&str, Type)>) -> Type {
+        let mut t = types::create_type(types::TypeCode::Struct);
+        t.0.struct_type = Some(Box::new(model::StructType::new().set_fields(
+            fields.into_iter().map(|(name, t)| {
+                model::struct_type::Field::new()
+                    .set_name(name)
+                    .set_type(model::Type::from(t))
+            }),
+        )));
+        t
+    }
+
+    fn list(values: Vec<Value>) -> Value {
+        Value(prost_types::Value {
+            kind: Some(prost_types::value::Kind::ListValue(
+                prost_types::ListValue {
+                    values: values.into_iter().map(|v| v.0).collect(),
+                },
+            )),
+        })
+    }
+
+    fn singer_row(nickname: Value) -> Row {
+        let album_type = struct_type(vec![("title", types::string())]);
+        Row {
+            values: vec![
+                1_i64.to_value(),
+                "Marc".to_value(),
+                nickname,
+                // The fields are in a different order than in the struct.
+                list(vec![Value::null(), "Paris".to_value()]),
+                list(vec![
+                    list(vec!["Total Junk".to_value()]),
+                    list(vec!["Go, Go, Go".to_value()]),
+                ]),
+            ],
+            metadata: ResultSetMetadata {
+                column_names: Arc::new(
+                    ["SingerId", "name", "nickname", "address", "albums"]
+                        .map(String::from)
+                        .to_vec(),
+                ),
+                column_types: Arc::new(vec![
+                    types::int64(),
+                    types::string(),
+                    types::string(),
+                    struct_type(vec![("Zip", types::int64()), ("city", types::string())]),
+                    types::array(album_type),
+                ]),
+                undeclared_parameters: Arc::new(std::collections::BTreeMap::new()),
+            },
+        }
+    }
+
+    #[test]
+    fn derive_from_row() -> anyhow::Result<()> {
+        let got = Singer::try_from(singer_row("Foo".to_value()))?;
+        let want = Singer {
+            singer_id: 1,
+            name: "Marc".to_string(),
+            nickname: Some("Foo".to_string()),
+            address: Address {
+                city: "Paris".to_string(),
+                zip: None,
+            },
+            albums: vec![
+                Album {
+                    title: "Total Junk".to_string(),
+                },
+                Album {
+                    title: "Go, Go, Go".to_string(),
+                },
+            ],
+        };
+        assert_eq!(got, want);
+
+        let got = Singer::try_from(&singer_row(Value::null()))?;
+        assert_eq!(got.nickname, None);
+        Ok(())
+    }
+
+    #[test]
+    fn derive_from_row_errors() {
+        let mut row = singer_row(Value::null());
+        Arc::make_mut(&mut row.metadata.column_names)[1] = "other".to_string();
+        let err = Singer::try_from(row).expect_err("missing column should fail");
+        assert!(err.is_deserialization(), "{err:?}");
+
+        let mut row = singer_row(Value::null());
+        row.values[3] = "not a struct".to_value();
+        let err = Singer::try_from(row).expect_err("bad struct should fail");
+        assert!(err.is_deserialization(), "{err:?}");
+
+        let mut row = singer_row(Value::null());
+        row.values[3] = Value::null();
+        let err = Singer::try_from(row).expect_err("null struct should fail");
+        assert!(err.is_deserialization(), "{err:?}");
+    }
 }
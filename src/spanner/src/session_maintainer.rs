@@ -16,7 +16,7 @@ use crate::client::Spanner;
 use crate::model::{CreateSessionRequest, Session};
 use crate::observability::Observability;
 use crate::{RequestOptions, Result};
-use std::sync::{Arc, RwLock, Weak};
+use std::sync::{Arc, Mutex, RwLock, Weak};
 use std::time::Duration;
 use tokio::time::{Instant, sleep};
 
@@ -28,6 +28,135 @@ pub(crate) const SESSION_MAINTENANCE_INTERVAL: Duration = Duration::from_secs(36
 /// every 7 days to be safe.
 pub(crate) const SESSION_MAINTENANCE_AGE: Duration = Duration::from_secs(7 * 24 * 3600);
 
+/// A snapshot of the state of the multiplexed session used by a
+/// [DatabaseClient](crate::client::DatabaseClient).
+///
+/// # Example
+/// ```
+/// # use google_cloud_spanner::client::DatabaseClient;
+/// # fn sample(client: &DatabaseClient) -> anyhow::Result<()> {
+/// let health = client.health();
+/// if !health.is_healthy() {
+///     anyhow::bail!(
+///         "cannot obtain a Spanner session after {} attempts: {:?}",
+///         health.consecutive_failures(),
+///         health.last_error()
+///     );
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The client keeps its multiplexed session alive in the background and
+/// periodically replaces it with a new session. Use this snapshot to monitor
+/// that process, for example in a readiness probe.
+#[derive(Clone, Debug)]
+pub struct SessionHealth {
+    session_name: String,
+    age: Duration,
+    recreation_count: u64,
+    maintenance_failures: u64,
+    consecutive_failures: u32,
+    last_error: Option<Arc<crate::Error>>,
+}
+
+impl SessionHealth {
+    /// Returns the name of the current multiplexed session.
+    pub fn session_name(&self) -> &str {
+        &self.session_name
+    }
+
+    /// Returns the time since the current session was created.
+    pub fn age(&self) -> Duration {
+        self.age
+    }
+
+    /// Returns the number of times the session was replaced with a new session.
+    pub fn recreation_count(&self) -> u64 {
+        self.recreation_count
+    }
+
+    /// Returns the total number of failed attempts to replace the session.
+    pub fn maintenance_failures(&self) -> u64 {
+        self.maintenance_failures
+    }
+
+    /// Returns the number of failed attempts to replace the session since the
+    /// last successful attempt.
+    pub fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+
+    /// Returns the error of the most recent failed attempt to replace the session.
+    pub fn last_error(&self) -> Option<&crate::Error> {
+        self.last_error.as_deref()
+    }
+
+    /// Returns `true` if the most recent attempt to obtain a session succeeded.
+    ///
+    /// The current session remains usable for a while after a failed
+    /// replacement, but a failure usually means that the client cannot reach
+    /// the database, or lost the permissions to use it.
+    pub fn is_healthy(&self) -> bool {
+        self.consecutive_failures == 0
+    }
+}
+
+/// An event in the lifecycle of the multiplexed session used by a
+/// [DatabaseClient](crate::client::DatabaseClient).
+///
+/// Register a handler with
+/// [DatabaseClientBuilder::on_session_event](crate::builder::DatabaseClientBuilder::on_session_event).
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum SessionEvent {
+    /// The client created its initial session.
+    #[non_exhaustive]
+    Created {
+        /// The name of the new session.
+        session_name: String,
+    },
+    /// The client replaced its session with a new session.
+    #[non_exhaustive]
+    Replaced {
+        /// The name of the replaced session.
+        old_session_name: String,
+        /// The name of the new session.
+        new_session_name: String,
+        /// The age of the replaced session.
+        age: Duration,
+    },
+    /// The client failed to replace its session. It keeps using the current
+    /// session and tries again later.
+    #[non_exhaustive]
+    MaintenanceFailed {
+        /// The error returned by the service.
+        error: Arc<crate::Error>,
+        /// The number of failed attempts since the last successful attempt.
+        consecutive_failures: u32,
+    },
+}
+
+/// A callback invoked for each [SessionEvent].
+#[derive(Clone)]
+pub(crate) struct SessionEventHandler(Arc<dyn Fn(&SessionEvent) + Send + Sync>);
+
+impl SessionEventHandler {
+    pub(crate) fn new<F>(handler: F) -> Self
+    where
+        F: Fn(&SessionEvent) + Send + Sync + 'static,
+    {
+        Self(Arc::new(handler))
+    }
+}
+
+impl std::fmt::Debug for SessionEventHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SessionEventHandler")
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct ManagedSessionMaintainer {
     pub(crate) spanner: Spanner,
@@ -36,6 +165,16 @@ pub(crate) struct ManagedSessionMaintainer {
     pub(crate) database_role: String,
     pub(crate) options: RequestOptions,
     pub(crate) o11y: Arc<Observability>,
+    stats: Mutex<MaintenanceStats>,
+    on_event: Option<SessionEventHandler>,
+}
+
+#[derive(Debug, Default)]
+struct MaintenanceStats {
+    recreation_count: u64,
+    maintenance_failures: u64,
+    consecutive_failures: u32,
+    last_error: Option<Arc<crate::Error>>,
 }
 
 #[derive(Debug)]
@@ -54,6 +193,23 @@ impl ManagedSessionMaintainer {
             .clone()
     }
 
+    /// Returns a snapshot of the session state and the maintenance statistics.
+    pub(crate) fn health(&self) -> SessionHealth {
+        let (session_name, age) = {
+            let guard = self.session.read().expect("failed to read session");
+            (guard.session.name.clone(), guard.created_at.elapsed())
+        };
+        let stats = self.stats.lock().expect("failed to lock session stats");
+        SessionHealth {
+            session_name,
+            age,
+            recreation_count: stats.recreation_count,
+            maintenance_failures: stats.maintenance_failures,
+            consecutive_failures: stats.consecutive_failures,
+            last_error: stats.last_error.clone(),
+        }
+    }
+
     /// Creates a new `ManagedSessionMaintainer` with an initial session,
     /// and spawns a background task to periodically check and rotate the session.
     pub(crate) async fn create_and_start_maintenance(
@@ -62,9 +218,11 @@ impl ManagedSessionMaintainer {
         database_role: String,
         options: RequestOptions,
         o11y: Arc<Observability>,
+        on_event: Option<SessionEventHandler>,
     ) -> Result<Arc<Self>> {
         let session =
             Self::create_session(&spanner, &database_name, &database_role, &options, &o11y).await?;
+        let session_name = session.name.clone();
 
         let maintainer = Arc::new(ManagedSessionMaintainer {
             spanner,
@@ -76,7 +234,10 @@ impl ManagedSessionMaintainer {
             database_role,
             options,
             o11y,
+            stats: Mutex::new(MaintenanceStats::default()),
+            on_event,
         });
+        maintainer.emit(SessionEvent::Created { session_name });
 
         let weak_maintainer = Arc::downgrade(&maintainer);
         tokio::spawn(async move {
@@ -113,18 +274,58 @@ impl ManagedSessionMaintainer {
         )
         .await?;
 
-        let mut guard = self.session.write().expect("failed to write session");
-        *guard = ManagedSession {
-            session: Arc::new(new_session),
-            created_at: Instant::now(),
+        let new_session_name = new_session.name.clone();
+        let old = {
+            let mut guard = self.session.write().expect("failed to write session");
+            std::mem::replace(
+                &mut *guard,
+                ManagedSession {
+                    session: Arc::new(new_session),
+                    created_at: Instant::now(),
+                },
+            )
         };
         tracing::info!(
             "Successfully replaced multiplexed session for {}",
             self.database_name
         );
+
+        {
+            let mut stats = self.stats.lock().expect("failed to lock session stats");
+            stats.recreation_count += 1;
+            stats.consecutive_failures = 0;
+        }
+        self.o11y.record_session_recreated();
+        self.emit(SessionEvent::Replaced {
+            old_session_name: old.session.name.clone(),
+            new_session_name,
+            age: old.created_at.elapsed(),
+        });
         Ok(())
     }
 
+    fn record_maintenance_failure(&self, error: crate::Error) {
+        let error = Arc::new(error);
+        let consecutive_failures = {
+            let mut stats = self.stats.lock().expect("failed to lock session stats");
+            stats.maintenance_failures += 1;
+            stats.consecutive_failures = stats.consecutive_failures.saturating_add(1);
+            stats.last_error = Some(error.clone());
+            stats.consecutive_failures
+        };
+        self.o11y.record_session_maintenance_failure();
+        self.emit(SessionEvent::MaintenanceFailed {
+            error,
+            consecutive_failures,
+        });
+    }
+
+    fn emit(&self, event: SessionEvent) {
+        if let Some(handler) = &self.on_event {
+            (handler.0)(&event);
+        }
+    }
+
     async fn create_session(
         spanner: &Spanner,
         database_name: &str,
@@ -159,12 +360,18 @@ impl ManagedSessionMaintainer {
 
     /// Performs a single maintenance iteration.
     async fn maintain(maintainer: Arc<ManagedSessionMaintainer>, age: Duration) {
+        let session_age = {
+            let guard = maintainer.session.read().expect("failed to read session");
+            guard.created_at.elapsed()
+        };
+        maintainer.o11y.record_session_age(session_age);
         if let Err(e) = maintainer.check_and_replace_session(age).await {
             tracing::warn!(
                 "Failed to check and replace session for {}: {}. Retrying in 1 hour.",
                 maintainer.database_name,
                 e
             );
+            maintainer.record_maintenance_failure(e);
         }
     }
 }
@@ -225,6 +432,7 @@ mod tests {
             "test-role".to_string(),
             RequestOptions::default(),
             Arc::new(Observability::disabled()),
+            None,
         )
         .await
         .expect("Failed to create ManagedSessionMaintainer");
@@ -296,6 +504,7 @@ mod tests {
             "test-role".to_string(),
             RequestOptions::default(),
             Arc::new(Observability::disabled()),
+            None,
         )
         .await
         .expect("Failed to create ManagedSessionMaintainer");
@@ -339,6 +548,7 @@ mod tests {
             "test-role".to_string(),
             RequestOptions::default(),
             Arc::new(Observability::disabled()),
+            None,
         )
         .await
         .expect("Failed to create ManagedSessionMaintainer");
@@ -404,6 +614,7 @@ mod tests {
             "test-role".to_string(),
             RequestOptions::default(),
             Arc::new(Observability::disabled()),
+            None,
         )
         .await
         .expect("Failed to create ManagedSessionMaintainer");
@@ -431,6 +642,166 @@ mod tests {
         );
     }
 
+    fn session_response(
+        id: u32,
+    ) -> std::result::Result<Response<GrpcSession>, gaxi::grpc::tonic::Status> {
+        Ok(Response::new(GrpcSession {
+            name: format!(
+                "projects/test-project/instances/test-instance/databases/test-db/sessions/{id}"
+            ),
+            multiplexed: true,
+            ..Default::default()
+        }))
+    }
+
+    fn recording_handler() -> (SessionEventHandler, Arc<Mutex<Vec<SessionEvent>>>) {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = events.clone();
+        let handler = SessionEventHandler::new(move |event: &SessionEvent| {
+            recorded
+                .lock()
+                .expect("failed to lock events")
+                .push(event.clone());
+        });
+        (handler, events)
+    }
+
+    #[tokio_test_no_panics]
+    async fn health_and_events() {
+        let mut mock = MockSpanner::new();
+        let mut seq = mockall::Sequence::new();
+        mock.expect_create_session()
+            .once()
+            .in_sequence(&mut seq)
+            .returning(|_| session_response(1));
+        mock.expect_create_session()
+            .once()
+            .in_sequence(&mut seq)
+            .returning(|_| Err(gaxi::grpc::tonic::Status::internal("mock failure")));
+        mock.expect_create_session()
+            .once()
+            .in_sequence(&mut seq)
+            .returning(|_| Err(gaxi::grpc::tonic::Status::internal("mock failure")));
+        mock.expect_create_session()
+            .once()
+            .in_sequence(&mut seq)
+            .returning(|_| session_response(2));
+
+        let (address, _server) = start("0.0.0.0:0", mock)
+            .await
+            .expect("Failed to start mock server");
+        let spanner = Spanner::builder()
+            .with_endpoint(address)
+            .with_credentials(Anonymous::new().build())
+            .build()
+            .await
+            .expect("Failed to build client");
+
+        let (handler, events) = recording_handler();
+        let maintainer = ManagedSessionMaintainer::create_and_start_maintenance(
+            spanner,
+            "projects/test-project/instances/test-instance/databases/test-db".to_string(),
+            "test-role".to_string(),
+            RequestOptions::default(),
+            Arc::new(Observability::disabled()),
+            Some(handler),
+        )
+        .await
+        .expect("Failed to create ManagedSessionMaintainer");
+
+        let health = maintainer.health();
+        assert_eq!(
+            health.session_name(),
+            "projects/test-project/instances/test-instance/databases/test-db/sessions/1"
+        );
+        assert!(health.age() < SESSION_MAINTENANCE_AGE, "{health:?}");
+        assert_eq!(health.recreation_count(), 0);
+        assert_eq!(health.maintenance_failures(), 0);
+        assert!(health.is_healthy(), "{health:?}");
+        assert!(health.last_error().is_none(), "{health:?}");
+
+        let old_age = Duration::from_secs(7 * 24 * 3600 + 3600);
+        maintainer
+            .session
+            .write()
+            .expect("failed to write session")
+            .created_at = Instant::now() - old_age;
+        for _ in 0..2 {
+            ManagedSessionMaintainer::maintain(maintainer.clone(), SESSION_MAINTENANCE_AGE).await;
+        }
+        let health = maintainer.health();
+        assert!(!health.is_healthy(), "{health:?}");
+        assert_eq!(health.consecutive_failures(), 2);
+        assert_eq!(health.maintenance_failures(), 2);
+        assert_eq!(health.recreation_count(), 0);
+        assert!(health.age() >= old_age, "{health:?}");
+        let status = health
+            .last_error()
+            .and_then(|e| e.status())
+            .expect("last error should have a status");
+        assert_eq!(status.message, "mock failure");
+
+        ManagedSessionMaintainer::maintain(maintainer.clone(), SESSION_MAINTENANCE_AGE).await;
+        let health = maintainer.health();
+        assert!(health.is_healthy(), "{health:?}");
+        assert_eq!(health.consecutive_failures(), 0);
+        assert_eq!(health.maintenance_failures(), 2);
+        assert_eq!(health.recreation_count(), 1);
+        assert!(health.age() < SESSION_MAINTENANCE_AGE, "{health:?}");
+        assert_eq!(
+            health.session_name(),
+            "projects/test-project/instances/test-instance/databases/test-db/sessions/2"
+        );
+        // The error of the last failure is kept for diagnostics.
+        assert!(health.last_error().is_some(), "{health:?}");
+
+        let events = events.lock().expect("failed to lock events");
+        assert_eq!(events.len(), 4, "{events:?}");
+        assert!(
+            matches!(&events[0], SessionEvent::Created { session_name } if session_name.ends_with("/sessions/1")),
+            "{events:?}"
+        );
+        assert!(
+            matches!(
+                &events[1],
+                SessionEvent::MaintenanceFailed {
+                    consecutive_failures: 1,
+                    ..
+                }
+            ),
+            "{events:?}"
+        );
+        assert!(
+            matches!(
+                &events[2],
+                SessionEvent::MaintenanceFailed {
+                    consecutive_failures: 2,
+                    ..
+                }
+            ),
+            "{events:?}"
+        );
+        match &events[3] {
+            SessionEvent::Replaced {
+                old_session_name,
+                new_session_name,
+                age,
+            } => {
+                assert!(old_session_name.ends_with("/sessions/1"), "{events:?}");
+                assert!(new_session_name.ends_with("/sessions/2"), "{events:?}");
+                assert!(*age >= old_age, "{events:?}");
+            }
+            e => panic!("expected a Replaced event, got {e:?}"),
+        }
+    }
+
+    #[test]
+    fn session_event_handler_debug() {
+        let (handler, _) = recording_handler();
+        let got = format!("{handler:?}");
+        assert!(got.contains("SessionEventHandler"), "{got}");
+    }
+
     #[tokio_test_no_panics]
     async fn transaction_session_consistency_across_retries() {
         use crate::database_client::DatabaseClient;
@@ -554,6 +925,7 @@ mod tests {
             "test-role".to_string(),
             RequestOptions::default(),
             Arc::new(Observability::disabled()),
+            None,
         )
         .await
         .expect("Failed to create ManagedSessionMaintainer");
@@ -90,6 +90,41 @@ impl StatementBuilder {
         self
     }
 
+    /// Adds a protobuf message parameter value to this Statement.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_spanner::statement::Statement;
+    /// let duration = prost_types::Duration { seconds: 10, nanos: 0 };
+    /// let statement = Statement::builder("SELECT * FROM Jobs WHERE Timeout = @timeout")
+    ///     .add_proto_param("timeout", &duration)
+    ///     .build();
+    /// ```
+    ///
+    /// The parameter is sent as a `PROTO` value with the fully-qualified name
+    /// of the message type, as returned by [prost::Name::full_name].
+    pub fn add_proto_param<T: prost::Name>(self, name: impl Into<String>, value: &T) -> Self {
+        let value: Value = value.encode_to_vec().into();
+        self.add_typed_param(name, value, crate::types::proto(T::full_name()))
+    }
+
+    /// Adds a protobuf enum parameter value to this Statement.
+    ///
+    /// The parameter is sent as an `ENUM` value with the fully-qualified name
+    /// of the enum type, as returned by [ProtoEnumName::full_name].
+    ///
+    /// [ProtoEnumName::full_name]: crate::value::ProtoEnumName::full_name
+    pub fn add_proto_enum_param<T>(self, name: impl Into<String>, value: T) -> Self
+    where
+        T: crate::value::ProtoEnumName + Copy + Into<i32>,
+    {
+        self.add_typed_param(
+            name,
+            crate::value::ProtoEnum(value),
+            crate::types::proto_enum(T::full_name()),
+        )
+    }
+
     /// Sets the request tag to use for this statement.
     ///
     /// # Example
@@ -645,6 +680,43 @@ mod tests {
         assert!(param_types.contains_key("role"));
     }
 
+    #[derive(Clone, Copy)]
+    enum Genre {
+        Jazz = 2,
+    }
+
+    impl From<Genre> for i32 {
+        fn from(g: Genre) -> Self {
+            g as i32
+        }
+    }
+
+    crate::proto_enum_name!(package = "examples.music"; Genre);
+
+    #[test]
+    fn test_proto_params() {
+        use crate::types::TypeCode;
+
+        let duration = prost_types::Duration {
+            seconds: 10,
+            nanos: 0,
+        };
+        let stmt = Statement::builder("SELECT * FROM t WHERE d = @d AND c = @c")
+            .add_proto_param("d", &duration)
+            .add_proto_enum_param("c", Genre::Jazz)
+            .build();
+
+        let d = &stmt.param_types["d"];
+        assert_eq!(d.code(), TypeCode::Proto);
+        assert_eq!(d.proto_type_fqn(), "google.protobuf.Duration");
+        assert_eq!(stmt.params["d"], crate::value::Proto(duration).to_value());
+
+        let c = &stmt.param_types["c"];
+        assert_eq!(c.code(), TypeCode::Enum);
+        assert_eq!(c.proto_type_fqn(), "examples.music.Genre");
+        assert_eq!(stmt.params["c"].as_string(), "2");
+    }
+
     #[test]
     fn with_request_tag() {
         let stmt = Statement::builder("SELECT * FROM users")
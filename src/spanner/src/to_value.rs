@@ -220,6 +220,38 @@ impl From<Date> for Value {
     }
 }
 
+impl ToValue for crate::Interval {
+    fn to_value(&self) -> Value {
+        (*self).into()
+    }
+}
+
+impl From<crate::Interval> for Value {
+    fn from(i: crate::Interval) -> Self {
+        Value(ProtoValue {
+            kind: Some(prost_types::value::Kind::StringValue(i.to_string())),
+        })
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl ToValue for uuid::Uuid {
+    fn to_value(&self) -> Value {
+        (*self).into()
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl From<uuid::Uuid> for Value {
+    fn from(u: uuid::Uuid) -> Self {
+        Value(ProtoValue {
+            kind: Some(prost_types::value::Kind::StringValue(
+                u.hyphenated().to_string(),
+            )),
+        })
+    }
+}
+
 impl ToValue for bool {
     fn to_value(&self) -> Value {
         (*self).into()
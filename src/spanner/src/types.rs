@@ -149,6 +149,12 @@ impl Type {
         self.0.struct_type.as_deref()
     }
 
+    /// Returns the fully-qualified name of the message or enum of a `PROTO`
+    /// or `ENUM` type, or an empty string for other types.
+    pub fn proto_type_fqn(&self) -> &str {
+        &self.0.proto_type_fqn
+    }
+
     /// Safely reinterprets a reference to the inner model type as a reference to Type.
     /// Logical safety is guaranteed by #[repr(transparent)].
     pub(crate) fn from_ref(v: &model::Type) -> &Self {
@@ -278,6 +284,26 @@ pub fn pg_oid() -> Type {
     TYPE_PG_OID.clone()
 }
 
+/// Returns a `Type` representing a `PROTO` column with the given message type.
+///
+/// `full_name` is the fully-qualified name of the message, for example
+/// `examples.music.SingerInfo`.
+pub fn proto(full_name: impl Into<String>) -> Type {
+    let mut t = create_type(TypeCode::Proto);
+    t.0.proto_type_fqn = full_name.into();
+    t
+}
+
+/// Returns a `Type` representing an `ENUM` column with the given enum type.
+///
+/// `full_name` is the fully-qualified name of the enum, for example
+/// `examples.music.Genre`.
+pub fn proto_enum(full_name: impl Into<String>) -> Type {
+    let mut t = create_type(TypeCode::Enum);
+    t.0.proto_type_fqn = full_name.into();
+    t
+}
+
 /// Returns a `Type` representing `ARRAY<t>` (GoogleSQL) or `t[]` (PostgreSQL).
 pub fn array(element_type: Type) -> Type {
     let mut t = create_type(TypeCode::Array);
@@ -434,6 +460,19 @@ mod tests {
         assert_eq!(pg_oid().0.type_annotation, TypeAnnotationCode::PgOid);
     }
 
+    #[test]
+    fn test_proto_types() {
+        let t = proto("examples.music.SingerInfo");
+        assert_eq!(t.code(), TypeCode::Proto);
+        assert_eq!(t.proto_type_fqn(), "examples.music.SingerInfo");
+
+        let t = proto_enum("examples.music.Genre");
+        assert_eq!(t.code(), TypeCode::Enum);
+        assert_eq!(t.proto_type_fqn(), "examples.music.Genre");
+
+        assert_eq!(int64().proto_type_fqn(), "");
+    }
+
     #[test]
     fn test_array_element_type() {
         let arr = array(int64());
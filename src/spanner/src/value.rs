@@ -18,7 +18,8 @@ pub(crate) const SPANNER_TIMESTAMP_FORMAT: &[time::format_description::FormatIte
 pub(crate) const SPANNER_DATE_FORMAT: &[time::format_description::FormatItem<'static>] =
     time::macros::format_description!("[year]-[month]-[day]");
 
-pub use crate::from_value::FromValue;
+pub use crate::from_value::{FromValue, struct_field};
+pub use crate::proto::{Proto, ProtoEnum, ProtoEnumName};
 pub use crate::to_value::ToValue;
 pub use crate::types::{Type, TypeCode};
 
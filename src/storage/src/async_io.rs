@@ -0,0 +1,34 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Adapters to use Cloud Storage objects with [tokio::io].
+//!
+//! Many libraries consume data via the [AsyncRead] and [AsyncWrite] traits,
+//! for example, [tokio::io::copy], compression libraries, or archive
+//! formats. This module provides adapters to read and write objects using
+//! these traits.
+//!
+//! - [ObjectReader] implements [AsyncRead] and [AsyncSeek] using an
+//!   [ObjectDescriptor][crate::object_descriptor::ObjectDescriptor].
+//! - [ObjectWriter] implements [AsyncWrite] using a resumable upload.
+//!
+//! [AsyncRead]: tokio::io::AsyncRead
+//! [AsyncSeek]: tokio::io::AsyncSeek
+//! [AsyncWrite]: tokio::io::AsyncWrite
+
+mod reader;
+mod writer;
+
+pub use reader::ObjectReader;
+pub use writer::{ObjectWriter, ObjectWriterSource};
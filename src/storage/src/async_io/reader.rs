@@ -0,0 +1,396 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model_ext::ReadRange;
+use crate::object_descriptor::ObjectDescriptor;
+use crate::read_object::ReadObjectResponse;
+use std::future::Future;
+use std::io::SeekFrom;
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+use tokio::io::{AsyncRead, AsyncSeek, ReadBuf};
+
+type NextFuture =
+    Pin<Box<dyn Future<Output = (ReadObjectResponse, Option<crate::Result<bytes::Bytes>>)> + Send>>;
+
+/// Reads an object using [AsyncRead] and [AsyncSeek].
+///
+/// # Example
+/// ```
+/// # use google_cloud_storage::client::Storage;
+/// # async fn sample(client: &Storage) -> anyhow::Result<()> {
+/// use google_cloud_storage::async_io::ObjectReader;
+/// use tokio::io::{AsyncReadExt, AsyncSeekExt};
+/// let descriptor = client
+///     .open_object("projects/_/buckets/my-bucket", "my-object")
+///     .send()
+///     .await?;
+/// let mut reader = ObjectReader::new(descriptor);
+/// reader.seek(std::io::SeekFrom::Start(1024)).await?;
+/// let mut header = [0_u8; 16];
+/// reader.read_exact(&mut header).await?;
+/// # Ok(()) }
+/// ```
+///
+/// The reader streams the object data starting at the current position. It
+/// keeps the data received from the service, but not yet consumed by the
+/// application, in a small buffer. Seeking within this buffer does not make
+/// any requests to the service. Seeking outside the buffer discards it, and
+/// the next read starts a new range at the new position.
+///
+/// The reader uses the object size from the
+/// [ObjectDescriptor::object()] metadata. Seeking relative to the end of the
+/// object uses this size. Reading past the end of the object returns 0 bytes.
+///
+/// Errors from the service are returned as [std::io::Error] values with
+/// [std::io::ErrorKind::Other]. Use [std::io::Error::get_ref()] and
+/// `downcast_ref::<google_cloud_storage::Error>()` to examine the original
+/// error.
+///
+/// [AsyncRead]: tokio::io::AsyncRead
+/// [AsyncSeek]: tokio::io::AsyncSeek
+pub struct ObjectReader {
+    descriptor: ObjectDescriptor,
+    size: u64,
+    /// The offset of the next byte returned by `poll_read()`.
+    position: u64,
+    /// Data received from the service starting at `position`.
+    buffer: bytes::Bytes,
+    state: State,
+}
+
+enum State {
+    /// There is no range in progress.
+    Idle,
+    /// The current range is waiting for more data.
+    Reading(NextFuture),
+}
+
+impl ObjectReader {
+    /// Creates a new reader for the object in `descriptor`.
+    pub fn new(descriptor: ObjectDescriptor) -> Self {
+        let size = descriptor.object().size.max(0) as u64;
+        Self {
+            descriptor,
+            size,
+            position: 0,
+            buffer: bytes::Bytes::new(),
+            state: State::Idle,
+        }
+    }
+
+    /// Returns the object size.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the current position in the object.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    fn next(mut response: ReadObjectResponse) -> NextFuture {
+        Box::pin(async move {
+            let data = response.next().await;
+            (response, data)
+        })
+    }
+
+    fn start(&self) -> NextFuture {
+        let descriptor = self.descriptor.clone();
+        let offset = self.position;
+        Box::pin(async move {
+            let mut response = descriptor.read_range(ReadRange::offset(offset)).await;
+            let data = response.next().await;
+            (response, data)
+        })
+    }
+}
+
+impl AsyncRead for ObjectReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            if !this.buffer.is_empty() {
+                let n = std::cmp::min(buf.remaining(), this.buffer.len());
+                buf.put_slice(&this.buffer.split_to(n));
+                this.position += n as u64;
+                return Poll::Ready(Ok(()));
+            }
+            if this.position >= this.size || buf.remaining() == 0 {
+                return Poll::Ready(Ok(()));
+            }
+            let future = match &mut this.state {
+                State::Idle => this.state.insert_reading(this.start()),
+                State::Reading(f) => f,
+            };
+            let (response, data) = ready!(future.as_mut().poll(cx));
+            match data {
+                Some(Ok(data)) => {
+                    this.buffer = data;
+                    this.state = State::Reading(Self::next(response));
+                }
+                Some(Err(e)) => {
+                    this.state = State::Idle;
+                    return Poll::Ready(Err(std::io::Error::other(e)));
+                }
+                None => {
+                    this.state = State::Idle;
+                    return Poll::Ready(Err(std::io::Error::new(
+                        std::io::ErrorKind::UnexpectedEof,
+                        format!(
+                            "the object data ended at {}, expected {} bytes",
+                            this.position, this.size
+                        ),
+                    )));
+                }
+            }
+        }
+    }
+}
+
+impl State {
+    fn insert_reading(&mut self, future: NextFuture) -> &mut NextFuture {
+        *self = State::Reading(future);
+        match self {
+            State::Reading(f) => f,
+            State::Idle => unreachable!("just assigned to Reading"),
+        }
+    }
+}
+
+impl AsyncSeek for ObjectReader {
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> std::io::Result<()> {
+        let this = self.get_mut();
+        let target = match position {
+            SeekFrom::Start(p) => Some(p),
+            SeekFrom::Current(d) => this.position.checked_add_signed(d),
+            SeekFrom::End(d) => this.size.checked_add_signed(d),
+        }
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("invalid seek to a negative or overflowing position: {position:?}"),
+            )
+        })?;
+        match target.checked_sub(this.position) {
+            Some(skip) if skip <= this.buffer.len() as u64 => {
+                let _ = this.buffer.split_to(skip as usize);
+            }
+            _ => {
+                this.buffer = bytes::Bytes::new();
+                this.state = State::Idle;
+            }
+        }
+        this.position = target;
+        Ok(())
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<u64>> {
+        Poll::Ready(Ok(self.position))
+    }
+}
+
+impl std::fmt::Debug for ObjectReader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObjectReader")
+            .field("descriptor", &self.descriptor)
+            .field("size", &self.size)
+            .field("position", &self.position)
+            .field("buffer_len", &self.buffer.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Object;
+    use crate::model_ext::{ObjectHighlights, RequestedRange};
+    use crate::object_descriptor::tests::{MockDescriptor, MockResponse};
+    use crate::{Error, error::ReadError};
+    use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+    type TestResult = anyhow::Result<()>;
+
+    fn contents(size: usize) -> bytes::Bytes {
+        bytes::Bytes::from_owner((0..size).map(|i| (i % 251) as u8).collect::<Vec<_>>())
+    }
+
+    fn start(range: &ReadRange) -> u64 {
+        match range.0 {
+            RequestedRange::Offset(o) => o,
+            _ => panic!("unexpected range {range:?}"),
+        }
+    }
+
+    fn descriptor(size: usize) -> MockDescriptor {
+        let mut mock = MockDescriptor::new();
+        mock.expect_object()
+            .return_const(Object::new().set_size(size as i64));
+        mock
+    }
+
+    /// Returns the data in chunks of 100 bytes.
+    fn chunked(data: bytes::Bytes, offset: u64) -> ReadObjectResponse {
+        let rest = data.slice(offset as usize..);
+        let chunks = (0..rest.len())
+            .step_by(100)
+            .map(|s| rest.slice(s..std::cmp::min(s + 100, rest.len())))
+            .collect::<Vec<_>>();
+        ReadObjectResponse::from_source(ObjectHighlights::default(), chunks)
+    }
+
+    #[tokio::test]
+    async fn read_to_end() -> TestResult {
+        let data = contents(1000);
+        let mut mock = descriptor(data.len());
+        let source = data.clone();
+        mock.expect_read_range()
+            .times(1)
+            .withf(|range| start(range) == 0)
+            .returning(move |range| chunked(source.clone(), start(&range)));
+
+        let mut reader = ObjectReader::new(ObjectDescriptor::new(mock));
+        assert_eq!(reader.size(), 1000);
+        let mut got = Vec::new();
+        reader.read_to_end(&mut got).await?;
+        assert_eq!(got, data);
+        assert_eq!(reader.position(), 1000);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn seek_within_buffer() -> TestResult {
+        let data = contents(1000);
+        let mut mock = descriptor(data.len());
+        let source = data.clone();
+        mock.expect_read_range()
+            .times(1)
+            .returning(move |range| chunked(source.clone(), start(&range)));
+
+        let mut reader = ObjectReader::new(ObjectDescriptor::new(mock));
+        let mut buf = [0_u8; 10];
+        reader.read_exact(&mut buf).await?;
+        assert_eq!(&buf[..], &data[0..10]);
+        // The first chunk is [0, 100), this seek is served from the buffer.
+        let pos = reader.seek(SeekFrom::Current(50)).await?;
+        assert_eq!(pos, 60);
+        reader.read_exact(&mut buf).await?;
+        assert_eq!(&buf[..], &data[60..70]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn seek_new_range() -> TestResult {
+        let data = contents(1000);
+        let mut mock = descriptor(data.len());
+        let mut seq = mockall::Sequence::new();
+        for offset in [0_u64, 500, 990, 20] {
+            let source = data.clone();
+            mock.expect_read_range()
+                .times(1)
+                .in_sequence(&mut seq)
+                .withf(move |range| start(range) == offset)
+                .returning(move |range| chunked(source.clone(), start(&range)));
+        }
+
+        let mut reader = ObjectReader::new(ObjectDescriptor::new(mock));
+        let mut buf = [0_u8; 10];
+        reader.read_exact(&mut buf).await?;
+        assert_eq!(&buf[..], &data[0..10]);
+
+        assert_eq!(reader.seek(SeekFrom::Start(500)).await?, 500);
+        reader.read_exact(&mut buf).await?;
+        assert_eq!(&buf[..], &data[500..510]);
+
+        assert_eq!(reader.seek(SeekFrom::End(-10)).await?, 990);
+        let mut got = Vec::new();
+        reader.read_to_end(&mut got).await?;
+        assert_eq!(got, &data[990..]);
+
+        assert_eq!(reader.seek(SeekFrom::Start(20)).await?, 20);
+        reader.read_exact(&mut buf).await?;
+        assert_eq!(&buf[..], &data[20..30]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn seek_past_end() -> TestResult {
+        let mut mock = descriptor(1000);
+        mock.expect_read_range().never();
+
+        let mut reader = ObjectReader::new(ObjectDescriptor::new(mock));
+        assert_eq!(reader.seek(SeekFrom::End(10)).await?, 1010);
+        let mut buf = [0_u8; 10];
+        let n = reader.read(&mut buf).await?;
+        assert_eq!(n, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn seek_negative() -> TestResult {
+        let mut mock = descriptor(1000);
+        mock.expect_read_range().never();
+
+        let mut reader = ObjectReader::new(ObjectDescriptor::new(mock));
+        let err = reader
+            .seek(SeekFrom::Current(-1))
+            .await
+            .expect_err("negative positions are invalid");
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput, "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_error() -> TestResult {
+        let mut mock = descriptor(1000);
+        mock.expect_read_range().times(1).returning(|_| {
+            let mut response = MockResponse::new();
+            response
+                .expect_next()
+                .returning(|| Some(Err(Error::deser(ReadError::ShortRead(1000)))));
+            ReadObjectResponse::new(Box::new(response))
+        });
+
+        let mut reader = ObjectReader::new(ObjectDescriptor::new(mock));
+        let mut buf = [0_u8; 10];
+        let err = reader.read(&mut buf).await.expect_err("error is returned");
+        let source = err.get_ref().and_then(|e| e.downcast_ref::<Error>());
+        assert!(source.is_some_and(Error::is_deserialization), "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unexpected_eof() -> TestResult {
+        let data = contents(1000);
+        let mut mock = descriptor(2000);
+        mock.expect_read_range()
+            .times(1)
+            .returning(move |range| chunked(data.clone(), start(&range)));
+
+        let mut reader = ObjectReader::new(ObjectDescriptor::new(mock));
+        let mut got = Vec::new();
+        let err = reader
+            .read_to_end(&mut got)
+            .await
+            .expect_err("short objects are detected");
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof, "{err:?}");
+        Ok(())
+    }
+}
@@ -0,0 +1,435 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::builder::storage::WriteObject;
+use crate::model::Object;
+use crate::streaming_source::{SizeHint, StreamingSource};
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use tokio::io::AsyncWrite;
+
+/// The maximum number of bytes queued by the writer before the upload consumes them.
+const MAX_QUEUED_BYTES: usize = 1024 * 1024;
+
+type UploadFuture = Pin<Box<dyn Future<Output = crate::Result<Object>> + Send>>;
+
+/// Writes an object using [AsyncWrite].
+///
+/// # Example
+/// ```
+/// # use google_cloud_storage::client::Storage;
+/// # async fn sample(client: &Storage) -> anyhow::Result<()> {
+/// use tokio::io::AsyncWriteExt;
+/// let mut writer = client
+///     .object_writer("projects/_/buckets/my-bucket", "my-object")
+///     .set_content_type("text/plain")
+///     .into_async_writer();
+/// let mut source = tokio::fs::File::open("my-data").await?;
+/// tokio::io::copy(&mut source, &mut writer).await?;
+/// writer.shutdown().await?;
+/// println!("object={:?}", writer.object());
+/// # Ok(()) }
+/// ```
+///
+/// The writer uses a [resumable upload] with [send_buffered()], see that
+/// function for details about the memory requirements. The writer queues a
+/// small amount of data and makes progress on the upload every time the
+/// application polls it. The upload is finalized when the application calls
+/// [shutdown()]. Dropping the writer without calling [shutdown()] abandons the
+/// upload and no object is created.
+///
+/// Errors from the service are returned as [std::io::Error] values with
+/// [std::io::ErrorKind::Other]. Use [std::io::Error::get_ref()] and
+/// `downcast_ref::<google_cloud_storage::Error>()` to examine the original
+/// error. Once the upload fails all calls return an error.
+///
+/// [AsyncWrite]: tokio::io::AsyncWrite
+/// [resumable upload]: https://cloud.google.com/storage/docs/resumable-uploads
+/// [send_buffered()]: WriteObject::send_buffered
+/// [shutdown()]: tokio::io::AsyncWriteExt::shutdown
+pub struct ObjectWriter {
+    queue: Arc<Mutex<Queue>>,
+    state: State,
+}
+
+enum State {
+    Uploading(UploadFuture),
+    Done(Box<Object>),
+    Failed,
+}
+
+#[derive(Debug, Default)]
+struct Queue {
+    data: VecDeque<bytes::Bytes>,
+    size: usize,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+impl ObjectWriter {
+    pub(crate) fn new<S>(builder: WriteObject<ObjectWriterSource, S>) -> Self
+    where
+        S: crate::storage::stub::Storage + 'static,
+    {
+        let queue = Arc::new(Mutex::new(Queue::default()));
+        let mut builder = builder;
+        builder.payload = ObjectWriterSource {
+            queue: Some(queue.clone()),
+        }
+        .into();
+        // Setting the threshold to 0 forces a resumable upload, even if the
+        // application writes very little data.
+        let builder = builder.with_resumable_upload_threshold(0_usize);
+        Self {
+            queue,
+            state: State::Uploading(Box::pin(builder.send_buffered())),
+        }
+    }
+
+    /// Returns the object metadata, once the upload is finalized.
+    pub fn object(&self) -> Option<&Object> {
+        match &self.state {
+            State::Done(o) => Some(o.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Drives the upload, returns `Ready` if the upload completed.
+    fn poll_upload(&mut self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        let future = match &mut self.state {
+            State::Uploading(f) => f,
+            State::Done(_) => return Poll::Ready(Ok(())),
+            State::Failed => return Poll::Ready(Err(failed())),
+        };
+        match future.as_mut().poll(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Ok(object)) => {
+                self.state = State::Done(Box::new(object));
+                Poll::Ready(Ok(()))
+            }
+            Poll::Ready(Err(e)) => {
+                self.state = State::Failed;
+                Poll::Ready(Err(std::io::Error::other(e)))
+            }
+        }
+    }
+
+    fn poll_queue_drained(&mut self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        if let Poll::Ready(r) = self.poll_upload(cx) {
+            return Poll::Ready(r.and_then(|_| Err(finished_early())));
+        }
+        let mut queue = self.queue.lock().expect("never poisoned");
+        if queue.size == 0 {
+            return Poll::Ready(Ok(()));
+        }
+        queue.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl AsyncWrite for ObjectWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+        if let Poll::Ready(r) = this.poll_upload(cx) {
+            return Poll::Ready(r.and_then(|_| Err(finished_early())));
+        }
+        let mut queue = this.queue.lock().expect("never poisoned");
+        if queue.closed {
+            return Poll::Ready(Err(finished_early()));
+        }
+        if queue.size >= MAX_QUEUED_BYTES {
+            queue.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        let n = std::cmp::min(buf.len(), MAX_QUEUED_BYTES - queue.size);
+        queue
+            .data
+            .push_back(bytes::Bytes::copy_from_slice(&buf[..n]));
+        queue.size += n;
+        // The upload future consumes the data the next time it is polled.
+        if let Some(w) = queue.waker.take() {
+            w.wake();
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        if matches!(this.state, State::Done(_)) {
+            return Poll::Ready(Ok(()));
+        }
+        this.poll_queue_drained(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        {
+            let mut queue = this.queue.lock().expect("never poisoned");
+            if !queue.closed {
+                queue.closed = true;
+                if let Some(w) = queue.waker.take() {
+                    w.wake();
+                }
+            }
+        }
+        this.poll_upload(cx)
+    }
+}
+
+impl std::fmt::Debug for ObjectWriter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = match &self.state {
+            State::Uploading(_) => "Uploading",
+            State::Done(_) => "Done",
+            State::Failed => "Failed",
+        };
+        f.debug_struct("ObjectWriter")
+            .field("queue", &self.queue)
+            .field("state", &state)
+            .finish()
+    }
+}
+
+fn failed() -> std::io::Error {
+    std::io::Error::other("the upload has already failed")
+}
+
+fn finished_early() -> std::io::Error {
+    std::io::Error::other("the upload has already finished")
+}
+
+/// The payload type for [ObjectWriter].
+///
+/// Applications do not create values of this type. Use
+/// [Storage::object_writer()] to create a [WriteObject] builder with this
+/// payload, and [into_async_writer()][WriteObject::into_async_writer] to
+/// create the writer.
+///
+/// Calling `send_buffered()` or `send_unbuffered()` on a builder with this
+/// payload, instead of creating a writer, fails with an error.
+///
+/// [Storage::object_writer()]: crate::client::Storage::object_writer
+#[derive(Debug, Default)]
+pub struct ObjectWriterSource {
+    queue: Option<Arc<Mutex<Queue>>>,
+}
+
+impl StreamingSource for ObjectWriterSource {
+    type Error = std::io::Error;
+
+    async fn next(&mut self) -> Option<Result<bytes::Bytes, Self::Error>> {
+        // Without a writer there is no data to upload. Return an error instead
+        // of silently replacing the object with an empty one.
+        let Some(queue) = self.queue.clone() else {
+            return Some(Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "the object data must be written using `into_async_writer()`",
+            )));
+        };
+        std::future::poll_fn(move |cx| {
+            let mut queue = queue.lock().expect("never poisoned");
+            if let Some(data) = queue.data.pop_front() {
+                queue.size -= data.len();
+                // Wake the writer, it may be waiting for space in the queue.
+                if let Some(w) = queue.waker.take() {
+                    w.wake();
+                }
+                return Poll::Ready(Some(Ok(data)));
+            }
+            if queue.closed {
+                return Poll::Ready(None);
+            }
+            queue.waker = Some(cx.waker().clone());
+            Poll::Pending
+        })
+        .await
+    }
+
+    async fn size_hint(&self) -> Result<SizeHint, Self::Error> {
+        Ok(SizeHint::new())
+    }
+}
+
+impl<S> WriteObject<ObjectWriterSource, S>
+where
+    S: crate::storage::stub::Storage + 'static,
+{
+    /// Creates an [ObjectWriter] to upload the object.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_storage::client::Storage;
+    /// # async fn sample(client: &Storage) -> anyhow::Result<()> {
+    /// use tokio::io::AsyncWriteExt;
+    /// let mut writer = client
+    ///     .object_writer("projects/_/buckets/my-bucket", "my-object")
+    ///     .set_if_generation_match(0)
+    ///     .into_async_writer();
+    /// writer.write_all(b"hello world").await?;
+    /// writer.shutdown().await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn into_async_writer(self) -> ObjectWriter {
+        ObjectWriter::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Storage;
+    use crate::model_ext::WriteObjectRequest;
+    use crate::request_options::RequestOptions;
+    use crate::{Error, Result};
+    use tokio::io::AsyncWriteExt;
+
+    type TestResult = anyhow::Result<()>;
+
+    /// Collects the uploaded data.
+    #[derive(Debug, Default)]
+    struct FakeStorage {
+        fail: bool,
+        uploaded: Mutex<Vec<u8>>,
+    }
+
+    impl crate::stub::Storage for FakeStorage {
+        async fn write_object_buffered<P>(
+            &self,
+            mut payload: P,
+            req: WriteObjectRequest,
+            options: RequestOptions,
+        ) -> Result<Object>
+        where
+            P: StreamingSource + Send + Sync + 'static,
+        {
+            assert_eq!(options.resumable_upload_threshold(), 0);
+            let mut size = 0_i64;
+            while let Some(b) = payload.next().await.transpose().map_err(Error::ser)? {
+                size += b.len() as i64;
+                self.uploaded.lock().unwrap().extend_from_slice(&b);
+                if self.fail {
+                    return Err(Error::io("simulated failure"));
+                }
+            }
+            let resource = req.spec.resource.unwrap();
+            Ok(resource.set_size(size))
+        }
+    }
+
+    #[tokio::test]
+    async fn copy() -> TestResult {
+        let stub = Arc::new(FakeStorage::default());
+        let client = Storage::<FakeStorage>::from_stub(stub.clone());
+        let mut writer = client
+            .object_writer("projects/_/buckets/test-bucket", "test-object")
+            .set_content_type("text/plain")
+            .into_async_writer();
+        assert!(writer.object().is_none(), "{writer:?}");
+
+        let data = (0..3 * MAX_QUEUED_BYTES)
+            .map(|i| (i % 251) as u8)
+            .collect::<Vec<_>>();
+        let mut source = std::io::Cursor::new(data.clone());
+        tokio::io::copy(&mut source, &mut writer).await?;
+        writer.flush().await?;
+        writer.shutdown().await?;
+
+        let object = writer.object().expect("upload is finalized");
+        assert_eq!(object.name, "test-object");
+        assert_eq!(object.content_type, "text/plain");
+        assert_eq!(object.size, data.len() as i64);
+        assert_eq!(*stub.uploaded.lock().unwrap(), data);
+
+        // Calling shutdown() again is harmless.
+        writer.shutdown().await?;
+        let err = writer
+            .write_all(b"more")
+            .await
+            .expect_err("writes after shutdown fail");
+        assert_eq!(err.kind(), std::io::ErrorKind::Other, "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn empty() -> TestResult {
+        let stub = Arc::new(FakeStorage::default());
+        let client = Storage::<FakeStorage>::from_stub(stub.clone());
+        let mut writer = client
+            .object_writer("projects/_/buckets/test-bucket", "test-object")
+            .into_async_writer();
+        writer.shutdown().await?;
+        let object = writer.object().expect("upload is finalized");
+        assert_eq!(object.size, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn upload_error() -> TestResult {
+        let stub = Arc::new(FakeStorage {
+            fail: true,
+            ..FakeStorage::default()
+        });
+        let client = Storage::<FakeStorage>::from_stub(stub.clone());
+        let mut writer = client
+            .object_writer("projects/_/buckets/test-bucket", "test-object")
+            .into_async_writer();
+        writer.write_all(b"hello").await?;
+        let err = writer.shutdown().await.expect_err("upload fails");
+        let source = err.get_ref().and_then(|e| e.downcast_ref::<Error>());
+        assert!(source.is_some_and(Error::is_io), "{err:?}");
+
+        let err = writer
+            .write_all(b"more")
+            .await
+            .expect_err("writes after failures fail");
+        assert!(err.to_string().contains("failed"), "{err:?}");
+        assert!(writer.object().is_none(), "{writer:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn send_without_writer() -> TestResult {
+        let stub = Arc::new(FakeStorage::default());
+        let client = Storage::<FakeStorage>::from_stub(stub.clone());
+        let err = client
+            .object_writer("projects/_/buckets/test-bucket", "test-object")
+            .with_resumable_upload_threshold(0_usize)
+            .send_buffered()
+            .await
+            .expect_err("sending without a writer fails");
+        assert!(err.is_serialization(), "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn empty_source() -> TestResult {
+        let mut source = ObjectWriterSource::default();
+        let err = source
+            .next()
+            .await
+            .expect("sources without a writer return an error")
+            .expect_err("sources without a writer return an error");
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput, "{err:?}");
+        let hint = source.size_hint().await?;
+        assert_eq!(hint.exact(), None);
+        Ok(())
+    }
+}
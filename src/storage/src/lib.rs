@@ -58,12 +58,14 @@ pub(crate) use google_cloud_gax::response::Response;
 #[cfg(google_cloud_unstable_storage_bidi)]
 #[cfg_attr(docsrs, doc(cfg(feature = "unstable-stream")))]
 pub mod appendable_object_writer;
+pub mod async_io;
 pub mod backoff_policy;
 pub mod object_descriptor;
 pub mod read_object;
 pub mod read_resume_policy;
 pub mod retry_policy;
 pub mod signed_url;
+pub mod transfer_manager;
 pub use crate::storage::request_options;
 pub use crate::storage::streaming_source;
 
@@ -101,6 +103,7 @@ pub mod builder {
         #[cfg_attr(docsrs, doc(cfg(feature = "unstable-stream")))]
         pub use crate::storage::open_appendable_object::OpenAppendableObject;
         pub use crate::storage::open_object::OpenObject;
+        pub use crate::storage::parallel_upload::ParallelUpload;
         pub use crate::storage::post_policy::{PostPolicyV4Builder, PostPolicyV4Result};
         pub use crate::storage::read_object::ReadObject;
         #[cfg(google_cloud_unstable_storage_bidi)]
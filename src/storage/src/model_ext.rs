@@ -22,6 +22,10 @@ use sha2::{Digest, Sha256};
 mod open_object_request;
 pub use open_object_request::OpenObjectRequest;
 
+mod upload_session;
+pub use upload_session::UploadSession;
+pub(crate) use upload_session::UploadSessionCallback;
+
 #[cfg(google_cloud_unstable_storage_bidi)]
 mod open_appendable_object_request;
 #[cfg(google_cloud_unstable_storage_bidi)]
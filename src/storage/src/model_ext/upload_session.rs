@@ -0,0 +1,208 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+/// The state of a resumable upload.
+///
+/// Applications can save this value, for example as JSON using `serde_json`,
+/// and use [Storage::resume_upload] to continue the upload after the process
+/// restarts.
+///
+/// # Example
+/// ```
+/// # use google_cloud_storage::client::Storage;
+/// # async fn sample(client: &Storage) -> anyhow::Result<()> {
+/// use google_cloud_storage::model_ext::UploadSession;
+/// let saved = std::fs::read_to_string("my-upload.session.json")?;
+/// let session = serde_json::from_str::<UploadSession>(&saved)?;
+/// let payload = tokio::fs::File::open("my-data").await?;
+/// let object = client
+///     .resume_upload(session, payload)
+///     .send_unbuffered()
+///     .await?;
+/// println!("object={object:?}");
+/// # Ok(()) }
+/// ```
+///
+/// The session contains the upload URL. Anybody with access to this URL can
+/// complete the upload, treat it as a secret.
+///
+/// [Storage::resume_upload]: crate::client::Storage::resume_upload
+#[derive(Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadSession {
+    bucket: String,
+    object: String,
+    upload_url: String,
+    persisted_size: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    crc32c: Option<Crc32cState>,
+}
+
+/// The CRC32C checksum of the first `offset` bytes of the payload.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+struct Crc32cState {
+    checksum: u32,
+    offset: u64,
+}
+
+impl UploadSession {
+    pub(crate) fn new(
+        bucket: String,
+        object: String,
+        upload_url: String,
+        persisted_size: u64,
+        crc32c: Option<(u32, u64)>,
+    ) -> Self {
+        Self {
+            bucket,
+            object,
+            upload_url,
+            persisted_size,
+            crc32c: crc32c.map(|(checksum, offset)| Crc32cState { checksum, offset }),
+        }
+    }
+
+    /// The bucket name. In `projects/_/buckets/{bucket_id}` format.
+    pub fn bucket(&self) -> &str {
+        &self.bucket
+    }
+
+    /// The object name.
+    pub fn object(&self) -> &str {
+        &self.object
+    }
+
+    /// The URL for the upload session.
+    pub fn upload_url(&self) -> &str {
+        &self.upload_url
+    }
+
+    /// The number of bytes persisted by the service when this value was
+    /// created.
+    ///
+    /// The service may have persisted more data since then. When resuming the
+    /// upload, the client library queries the service for the current value.
+    pub fn persisted_size(&self) -> u64 {
+        self.persisted_size
+    }
+
+    pub(crate) fn crc32c_state(&self) -> Option<(u32, u64)> {
+        self.crc32c.map(|c| (c.checksum, c.offset))
+    }
+}
+
+impl std::fmt::Debug for UploadSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UploadSession")
+            .field("bucket", &self.bucket)
+            .field("object", &self.object)
+            .field("upload_url", &"[censored]")
+            .field("persisted_size", &self.persisted_size)
+            .field("crc32c", &self.crc32c)
+            .finish()
+    }
+}
+
+/// Receives the [UploadSession] as a resumable upload makes progress.
+#[derive(Clone)]
+pub(crate) struct UploadSessionCallback(Arc<dyn Fn(&UploadSession) + Send + Sync>);
+
+impl UploadSessionCallback {
+    pub(crate) fn new<F>(f: F) -> Self
+    where
+        F: Fn(&UploadSession) + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    pub(crate) fn call(&self, session: &UploadSession) {
+        (self.0)(session)
+    }
+}
+
+impl std::fmt::Debug for UploadSessionCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("UploadSessionCallback")
+            .field(&"[skipped]")
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn roundtrip() -> anyhow::Result<()> {
+        let session = UploadSession::new(
+            "projects/_/buckets/test-bucket".into(),
+            "test-object".into(),
+            "https://example.com/upload/session-001".into(),
+            256 * 1024,
+            Some((0x01020304, 512 * 1024)),
+        );
+        let value = serde_json::to_value(&session)?;
+        assert_eq!(
+            value,
+            json!({
+                "bucket": "projects/_/buckets/test-bucket",
+                "object": "test-object",
+                "uploadUrl": "https://example.com/upload/session-001",
+                "persistedSize": 256 * 1024,
+                "crc32c": { "checksum": 0x01020304, "offset": 512 * 1024 },
+            })
+        );
+        let got = serde_json::from_value::<UploadSession>(value)?;
+        assert_eq!(got, session);
+        assert_eq!(got.bucket(), "projects/_/buckets/test-bucket");
+        assert_eq!(got.object(), "test-object");
+        assert_eq!(got.upload_url(), "https://example.com/upload/session-001");
+        assert_eq!(got.persisted_size(), 256 * 1024);
+        assert_eq!(got.crc32c_state(), Some((0x01020304, 512 * 1024)));
+        Ok(())
+    }
+
+    #[test]
+    fn without_checksum() -> anyhow::Result<()> {
+        let session = UploadSession::new(
+            "projects/_/buckets/test-bucket".into(),
+            "test-object".into(),
+            "https://example.com/upload/session-001".into(),
+            0,
+            None,
+        );
+        let value = serde_json::to_value(&session)?;
+        assert!(value.get("crc32c").is_none(), "{value:?}");
+        let got = serde_json::from_value::<UploadSession>(value)?;
+        assert_eq!(got, session);
+        Ok(())
+    }
+
+    #[test]
+    fn debug() {
+        let session = UploadSession::new(
+            "projects/_/buckets/test-bucket".into(),
+            "test-object".into(),
+            "https://example.com/upload/session-001".into(),
+            0,
+            None,
+        );
+        let fmt = format!("{session:?}");
+        assert!(!fmt.contains("session-001"), "{fmt}");
+        assert!(fmt.contains("test-object"), "{fmt}");
+    }
+}
@@ -98,6 +98,16 @@ pub struct Crc32c {
 }
 
 impl Crc32c {
+    /// Continue computing a checksum from a previously saved state.
+    pub fn resume(checksum: u32, offset: u64) -> Self {
+        Self { checksum, offset }
+    }
+
+    /// Returns the checksum so far and the number of bytes it covers.
+    pub fn state(&self) -> (u32, u64) {
+        (self.checksum, self.offset)
+    }
+
     fn update(&mut self, offset: u64, data: &bytes::Bytes) {
         self.offset = self::checked_update(self.offset, offset, data, |data| {
             self.checksum = crc32c::crc32c_append(self.checksum, data)
@@ -172,6 +182,25 @@ impl<S> ChecksummedSource<S> {
     pub fn final_checksum(&self) -> ObjectChecksums {
         self.checksum.finalize()
     }
+
+    /// Returns the CRC32C checksum state, if the checksum is computed.
+    pub fn crc32c_state(&self) -> Option<(u32, u64)> {
+        self.checksum.crc32c.as_ref().map(Crc32c::state)
+    }
+
+    /// Returns the number of bytes consumed by all the checksums.
+    ///
+    /// The checksums ignore data past this offset until they see the data
+    /// before it.
+    pub fn checksum_offset(&self) -> u64 {
+        let crc32c = self.checksum.crc32c.as_ref().map(|c| c.offset);
+        let md5 = self.checksum.md5_hash.as_ref().map(|c| c.offset);
+        crc32c.into_iter().chain(md5).min().unwrap_or(u64::MAX)
+    }
+
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
 }
 
 impl<S> StreamingSource for ChecksummedSource<S>
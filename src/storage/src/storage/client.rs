@@ -13,12 +13,14 @@
 // limitations under the License.
 
 use super::request_options::RequestOptions;
+use crate::async_io::ObjectWriterSource;
 #[cfg(google_cloud_unstable_storage_bidi)]
 use crate::builder::storage::OpenAppendableObject;
 use crate::builder::storage::ReadObject;
 #[cfg(google_cloud_unstable_storage_bidi)]
 use crate::builder::storage::ReopenAppendableObject;
 use crate::builder::storage::WriteObject;
+use crate::model_ext::UploadSession;
 use crate::read_resume_policy::ReadResumePolicy;
 use crate::storage::bidi::{GrpcClient, OpenObject};
 use crate::storage::common_options::CommonOptions;
@@ -196,6 +198,100 @@ where
         )
     }
 
+    /// Creates a builder to write an object using [AsyncWrite].
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_storage::client::Storage;
+    /// # async fn sample(client: &Storage) -> anyhow::Result<()> {
+    /// use tokio::io::AsyncWriteExt;
+    /// let mut writer = client
+    ///     .object_writer("projects/_/buckets/my-bucket", "my-object")
+    ///     .into_async_writer();
+    /// writer.write_all(b"hello world").await?;
+    /// writer.shutdown().await?;
+    /// println!("object={:?}", writer.object());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// Use the returned builder to configure the object metadata,
+    /// pre-conditions, and request options. Then call
+    /// [into_async_writer()] to create an [ObjectWriter].
+    /// The builder has no data to upload until you create the writer,
+    /// calling `send_buffered()` or `send_unbuffered()` directly fails.
+    ///
+    /// # Parameters
+    /// * `bucket` - the bucket name containing the object. In
+    ///   `projects/_/buckets/{bucket_id}` format.
+    /// * `object` - the object name.
+    ///
+    /// [AsyncWrite]: tokio::io::AsyncWrite
+    /// [ObjectWriter]: crate::async_io::ObjectWriter
+    /// [into_async_writer()]: crate::builder::storage::WriteObject::into_async_writer
+    pub fn object_writer<B, O>(&self, bucket: B, object: O) -> WriteObject<ObjectWriterSource, S>
+    where
+        B: Into<String>,
+        O: Into<String>,
+    {
+        WriteObject::new(
+            self.stub.clone(),
+            bucket,
+            object,
+            ObjectWriterSource::default(),
+            self.options.clone(),
+        )
+    }
+
+    /// Resumes an upload session started by this or a previous process.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_storage::client::Storage;
+    /// # async fn sample(client: &Storage) -> anyhow::Result<()> {
+    /// use google_cloud_storage::model_ext::UploadSession;
+    /// let saved = std::fs::read_to_string("my-upload.session.json")?;
+    /// let session = serde_json::from_str::<UploadSession>(&saved)?;
+    /// let payload = tokio::fs::File::open("my-data").await?;
+    /// let response = client
+    ///     .resume_upload(session, payload)
+    ///     .send_unbuffered()
+    ///     .await?;
+    /// println!("response details={response:?}");
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// Use [with_upload_session_callback()] to receive the [UploadSession] for
+    /// an upload. If the process restarts, use this function to continue the
+    /// upload. The client library queries the service to find how much data
+    /// was persisted, and then sends the remaining data. The `payload` must
+    /// produce the same data as the payload used to start the upload.
+    ///
+    /// With [send_unbuffered()] the client library seeks the payload to the
+    /// first byte not persisted by the service. With [send_buffered()] the
+    /// client library reads and discards the data persisted by the service.
+    /// In both cases, the client library may read some of the persisted data
+    /// again to compute the checksums of the full object.
+    ///
+    /// The object metadata and pre-conditions are set when the upload session
+    /// is created, and setting them in the returned builder has no effect.
+    /// If the upload uses [customer-supplied encryption keys], you must set
+    /// the same key in the returned builder.
+    ///
+    /// # Parameters
+    /// * `session` - the upload session to resume.
+    /// * `payload` - the object data.
+    ///
+    /// [customer-supplied encryption keys]: https://cloud.google.com/storage/docs/encryption/customer-supplied-keys
+    /// [send_buffered()]: crate::builder::storage::WriteObject::send_buffered
+    /// [send_unbuffered()]: crate::builder::storage::WriteObject::send_unbuffered
+    /// [with_upload_session_callback()]: crate::builder::storage::WriteObject::with_upload_session_callback
+    pub fn resume_upload<T, P>(&self, session: UploadSession, payload: T) -> WriteObject<P, S>
+    where
+        T: Into<Payload<P>>,
+    {
+        WriteObject::resume(self.stub.clone(), session, payload, self.options.clone())
+    }
+
     /// Reads the contents of an object.
     ///
     /// # Example
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::Result;
+use crate::model::Object;
 use crate::model_ext::{KeyAes256, OpenObjectRequest, ReadRange};
 use crate::object_descriptor::ObjectDescriptor;
 use crate::read_object::ReadObjectResponse;
@@ -21,6 +22,8 @@ use crate::request_options::RequestOptions;
 use std::sync::Arc;
 use std::time::Duration;
 
+mod download;
+
 /// A request builder for [Storage::open_object][crate::client::Storage::open_object].
 ///
 /// # Example
@@ -101,6 +104,43 @@ where
         // the code in the library will return an error and close the stream.
         unreachable!("the stub cannot create more readers")
     }
+
+    /// Downloads the object to a local file using concurrent ranged reads.
+    ///
+    /// Example:
+    /// ```ignore
+    /// # use google_cloud_storage::client::Storage;
+    /// # async fn sample(client: &Storage) -> anyhow::Result<()> {
+    /// let object = client
+    ///     .open_object("projects/_/buckets/my-bucket", "my-object")
+    ///     .download_to_file("my-object.data", 8)
+    ///     .await?;
+    /// println!("downloaded {} bytes", object.size);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// This method opens the object, splits it into (at most) `slices` ranges
+    /// of similar size, and reads all the ranges concurrently. Each range is
+    /// written at its offset in the destination file, which is created or
+    /// truncated as needed.
+    ///
+    /// Each range is resumed independently, using the configured
+    /// [ReadResumePolicy]. If any range fails, the remaining ranges are
+    /// cancelled and the method returns the error. Once all the ranges
+    /// complete, the client library
+    /// compares the CRC32C checksum of the full object against the value
+    /// reported by the service, unless disabled via
+    /// [compute_crc32c()][OpenObject::compute_crc32c].
+    ///
+    /// On error, the destination file may contain partial data.
+    pub async fn download_to_file<P>(self, path: P, slices: usize) -> Result<Object>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let validate_crc32c = self.options.checksum.crc32c.is_some();
+        let descriptor = self.send().await?;
+        download::download_to_file(descriptor, path.as_ref(), slices, validate_crc32c).await
+    }
 }
 
 impl<S> OpenObject<S> {
@@ -0,0 +1,336 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implements sliced downloads to a local file.
+
+use crate::error::{ChecksumMismatch, ReadError};
+use crate::model::Object;
+use crate::model_ext::ReadRange;
+use crate::object_descriptor::ObjectDescriptor;
+use crate::{Error, Result};
+use std::path::Path;
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+
+/// Downloads the object in `descriptor` to `path` using `slices` concurrent reads.
+///
+/// Each slice is written at its offset in the destination file, using a
+/// separate file handle. The descriptor already resumes interrupted reads using
+/// the configured `ReadResumePolicy`, so any error from a slice is final. The
+/// first such error cancels all the other slices.
+pub(crate) async fn download_to_file(
+    descriptor: ObjectDescriptor,
+    path: &Path,
+    slices: usize,
+    validate_crc32c: bool,
+) -> Result<Object> {
+    let object = descriptor.object();
+    let size = object.size as u64;
+    let file = tokio::fs::File::create(path).await.map_err(Error::io)?;
+    file.set_len(size).await.map_err(Error::io)?;
+    drop(file);
+
+    let ranges = split(size, slices);
+    let downloads = ranges
+        .iter()
+        .map(|(start, end)| download_slice(&descriptor, path, *start, *end));
+    let results = futures::future::try_join_all(downloads).await?;
+
+    let mut crc32c = 0_u32;
+    for (result, (start, end)) in results.into_iter().zip(ranges) {
+        crc32c = crc32c::crc32c_combine(crc32c, result, (end - start) as usize);
+    }
+    if !validate_crc32c {
+        return Ok(object);
+    }
+    match object.checksums.as_ref().and_then(|c| c.crc32c) {
+        Some(want) if want != crc32c => Err(Error::deser(ReadError::ChecksumMismatch(
+            ChecksumMismatch::Crc32c { got: crc32c, want },
+        ))),
+        _ => Ok(object),
+    }
+}
+
+/// Downloads the `[start, end)` range to the same range in `path`.
+///
+/// Returns the CRC32C checksum of the range.
+async fn download_slice(
+    descriptor: &ObjectDescriptor,
+    path: &Path,
+    start: u64,
+    end: u64,
+) -> Result<u32> {
+    let mut file = tokio::fs::OpenOptions::new()
+        .write(true)
+        .open(path)
+        .await
+        .map_err(Error::io)?;
+    file.seek(std::io::SeekFrom::Start(start))
+        .await
+        .map_err(Error::io)?;
+
+    let mut crc32c = 0_u32;
+    let mut offset = start;
+    let mut reader = descriptor
+        .read_range(ReadRange::segment(start, end - start))
+        .await;
+    while let Some(data) = reader.next().await.transpose()? {
+        let next = offset + data.len() as u64;
+        if next > end {
+            return Err(Error::deser(ReadError::LongRead {
+                got: next - start,
+                expected: end - start,
+            }));
+        }
+        file.write_all(&data).await.map_err(Error::io)?;
+        crc32c = crc32c::crc32c_append(crc32c, &data);
+        offset = next;
+    }
+    if offset < end {
+        return Err(Error::deser(ReadError::ShortRead(end - offset)));
+    }
+    file.flush().await.map_err(Error::io)?;
+    Ok(crc32c)
+}
+
+/// Splits `size` bytes into at most `count` non-empty ranges.
+fn split(size: u64, count: usize) -> Vec<(u64, u64)> {
+    let count = std::cmp::min(count.max(1) as u64, size);
+    if count == 0 {
+        return Vec::new();
+    }
+    let length = size / count;
+    (0..count)
+        .map(|i| {
+            let start = i * length;
+            let end = if i + 1 == count { size } else { start + length };
+            (start, end)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::ObjectChecksums;
+    use crate::model_ext::ObjectHighlights;
+    use crate::model_ext::RequestedRange;
+    use crate::object_descriptor::tests::{MockDescriptor, MockResponse};
+    use crate::read_object::ReadObjectResponse;
+    use tempfile::TempDir;
+    use test_case::test_case;
+
+    type TestResult = anyhow::Result<()>;
+
+    fn contents(size: usize) -> bytes::Bytes {
+        bytes::Bytes::from_owner((0..size).map(|i| (i % 251) as u8).collect::<Vec<_>>())
+    }
+
+    fn segment(range: &ReadRange) -> (u64, u64) {
+        match range.0 {
+            RequestedRange::Segment { offset, limit } => (offset, offset + limit),
+            _ => panic!("unexpected range {range:?}"),
+        }
+    }
+
+    fn descriptor(data: bytes::Bytes, crc32c: u32) -> MockDescriptor {
+        let object = Object::new()
+            .set_name("test-object")
+            .set_size(data.len() as i64)
+            .set_checksums(ObjectChecksums::new().set_crc32c(crc32c));
+        let mut mock = MockDescriptor::new();
+        mock.expect_object().return_const(object);
+        mock
+    }
+
+    #[test_case(100, 4, 4)]
+    #[test_case(3, 4, 3)]
+    #[test_case(0, 4, 0)]
+    #[test_case(103, 0, 1)]
+    fn split_ranges(size: u64, count: usize, want: usize) {
+        let got = split(size, count);
+        assert_eq!(got.len(), want, "{got:?}");
+        assert!(got.iter().all(|(s, e)| s < e), "{got:?}");
+        assert_eq!(got.last().map(|r| r.1).unwrap_or_default(), size, "{got:?}");
+    }
+
+    #[tokio::test]
+    async fn success() -> TestResult {
+        let data = contents(1000);
+        let mut mock = descriptor(data.clone(), crc32c::crc32c(&data));
+        let source = data.clone();
+        mock.expect_read_range().times(4).returning(move |range| {
+            let (start, end) = segment(&range);
+            let chunks = vec![
+                source.slice(start as usize..(start + 10) as usize),
+                source.slice((start + 10) as usize..end as usize),
+            ];
+            ReadObjectResponse::from_source(ObjectHighlights::default(), chunks)
+        });
+
+        let dir = TempDir::new()?;
+        let path = dir.path().join("download");
+        let object = download_to_file(ObjectDescriptor::new(mock), &path, 4, true).await?;
+        assert_eq!(object.name, "test-object");
+        let got = tokio::fs::read(&path).await?;
+        assert_eq!(got, data);
+        Ok(())
+    }
+
+    /// A response that never produces any data.
+    #[derive(Debug)]
+    struct Pending;
+
+    #[async_trait::async_trait]
+    impl crate::read_object::dynamic::ReadObjectResponse for Pending {
+        fn object(&self) -> ObjectHighlights {
+            ObjectHighlights::default()
+        }
+        async fn next(&mut self) -> Option<Result<bytes::Bytes>> {
+            futures::future::pending().await
+        }
+    }
+
+    #[tokio::test]
+    async fn error_cancels_other_slices() -> TestResult {
+        let data = contents(1000);
+        let mut mock = descriptor(data.clone(), crc32c::crc32c(&data));
+        mock.expect_read_range().times(1..=2).returning(|range| {
+            if segment(&range).0 != 0 {
+                return ReadObjectResponse::new(Box::new(Pending));
+            }
+            let mut response = MockResponse::new();
+            response
+                .expect_next()
+                .times(1)
+                .returning(|| Some(Err(Error::io("interrupted"))));
+            ReadObjectResponse::new(Box::new(response))
+        });
+
+        let dir = TempDir::new()?;
+        let path = dir.path().join("download");
+        let err = download_to_file(ObjectDescriptor::new(mock), &path, 2, true)
+            .await
+            .unwrap_err();
+        assert!(err.is_io(), "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn permanent_error() -> TestResult {
+        let data = contents(1000);
+        let mut mock = descriptor(data.clone(), crc32c::crc32c(&data));
+        mock.expect_read_range().times(1).returning(|_| {
+            let mut response = MockResponse::new();
+            response
+                .expect_next()
+                .returning(|| Some(Err(Error::io("interrupted"))));
+            ReadObjectResponse::new(Box::new(response))
+        });
+
+        let dir = TempDir::new()?;
+        let path = dir.path().join("download");
+        let err = download_to_file(ObjectDescriptor::new(mock), &path, 1, true)
+            .await
+            .unwrap_err();
+        assert!(err.is_io(), "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn short_read() -> TestResult {
+        let data = contents(1000);
+        let mut mock = descriptor(data.clone(), crc32c::crc32c(&data));
+        let source = data.clone();
+        mock.expect_read_range().times(1).returning(move |_| {
+            ReadObjectResponse::from_source(ObjectHighlights::default(), source.slice(0..500))
+        });
+
+        let dir = TempDir::new()?;
+        let path = dir.path().join("download");
+        let err = download_to_file(ObjectDescriptor::new(mock), &path, 1, true)
+            .await
+            .unwrap_err();
+        let source = std::error::Error::source(&err).and_then(|e| e.downcast_ref::<ReadError>());
+        assert!(matches!(source, Some(ReadError::ShortRead(500))), "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn long_read() -> TestResult {
+        let data = contents(1000);
+        let mut mock = descriptor(data.clone(), crc32c::crc32c(&data));
+        // The service returns 10 extra bytes for the second slice.
+        let source = contents(1010);
+        mock.expect_read_range().times(2).returning(move |range| {
+            let (start, end) = segment(&range);
+            let end = if start == 0 { end } else { end + 10 };
+            ReadObjectResponse::from_source(
+                ObjectHighlights::default(),
+                source.slice(start as usize..end as usize),
+            )
+        });
+
+        let dir = TempDir::new()?;
+        let path = dir.path().join("download");
+        let err = download_to_file(ObjectDescriptor::new(mock), &path, 2, true)
+            .await
+            .unwrap_err();
+        let source = std::error::Error::source(&err).and_then(|e| e.downcast_ref::<ReadError>());
+        assert!(
+            matches!(
+                source,
+                Some(ReadError::LongRead {
+                    got: 510,
+                    expected: 500
+                })
+            ),
+            "{err:?}"
+        );
+        Ok(())
+    }
+
+    #[test_case(true)]
+    #[test_case(false)]
+    #[tokio::test]
+    async fn checksum_mismatch(validate: bool) -> TestResult {
+        let data = contents(1000);
+        let mut mock = descriptor(data.clone(), 0x01020304_u32);
+        let source = data.clone();
+        mock.expect_read_range().times(2).returning(move |range| {
+            let (start, end) = segment(&range);
+            ReadObjectResponse::from_source(
+                ObjectHighlights::default(),
+                source.slice(start as usize..end as usize),
+            )
+        });
+
+        let dir = TempDir::new()?;
+        let path = dir.path().join("download");
+        let result = download_to_file(ObjectDescriptor::new(mock), &path, 2, validate).await;
+        if !validate {
+            assert!(result.is_ok(), "{result:?}");
+            return Ok(());
+        }
+        let err = result.unwrap_err();
+        let source = std::error::Error::source(&err).and_then(|e| e.downcast_ref::<ReadError>());
+        assert!(
+            matches!(
+                source,
+                Some(ReadError::ChecksumMismatch(ChecksumMismatch::Crc32c { .. }))
+            ),
+            "{err:?}"
+        );
+        Ok(())
+    }
+}
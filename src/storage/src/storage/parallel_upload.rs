@@ -0,0 +1,702 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the request builder for [parallel_upload()] and related types.
+//!
+//! [parallel_upload()]: crate::builder::storage::WriteObject::parallel_upload()
+
+use super::streaming_source::{Payload, Seek, SizeHint, StreamingSource};
+use super::write_object::WriteObject;
+use crate::client::StorageControl;
+use crate::error::WriteError;
+use crate::model::compose_object_request::SourceObject;
+use crate::model::{Object, ObjectChecksums};
+use crate::storage::checksum::details::{update as checksum_update, validate as checksum_validate};
+use crate::{Error, Result};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// The maximum number of source objects in a single `ComposeObject` request.
+const MAX_COMPONENTS: usize = 32;
+
+/// The default number of components in a parallel upload.
+const DEFAULT_COMPONENT_COUNT: usize = 8;
+
+/// The default minimum size for each component.
+const DEFAULT_MINIMUM_COMPONENT_SIZE: u64 = 32 * 1024 * 1024;
+
+/// A request builder for parallel composite uploads.
+///
+/// Parallel composite uploads split the payload into multiple temporary
+/// objects, called components, and upload the components concurrently. Once
+/// all the components are uploaded the client library combines them into the
+/// destination object using [ComposeObject], and then deletes the components.
+/// The client library deletes the components even if the upload or the
+/// composition fail.
+///
+/// Parallel composite uploads can improve throughput for large objects, as
+/// each component uses a separate connection. The payload must implement
+/// [Seek], as each component reads a different range of the payload. The
+/// payload must report its exact size via [StreamingSource::size_hint]. If the
+/// size is unknown, or too small to split, the client library falls back to a
+/// regular upload.
+///
+/// Composite objects do not have an MD5 hash. The client library computes the
+/// CRC32C checksum of the destination object from the component checksums,
+/// sends this value in the `ComposeObject` request, and verifies the checksum
+/// reported by the service matches this value.
+///
+/// # Example
+/// ```
+/// use google_cloud_storage::client::{Storage, StorageControl};
+/// async fn sample(client: &Storage, control: &StorageControl) -> anyhow::Result<()> {
+///     let payload = tokio::fs::File::open("my-large-file").await?;
+///     let object = client
+///         .write_object("projects/_/buckets/my-bucket", "my-object", payload)
+///         .parallel_upload(control)
+///         .with_component_count(16)
+///         .send()
+///         .await?;
+///     println!("object metadata={object:?}");
+///     Ok(())
+/// }
+/// ```
+///
+/// [ComposeObject]: crate::client::StorageControl::compose_object
+pub struct ParallelUpload<T, S = crate::storage::transport::Storage>
+where
+    S: crate::storage::stub::Storage + 'static,
+{
+    inner: WriteObject<T, S>,
+    control: StorageControl,
+    component_count: usize,
+    minimum_component_size: u64,
+    component_prefix: String,
+}
+
+impl<T, S> ParallelUpload<T, S>
+where
+    S: crate::storage::stub::Storage + 'static,
+{
+    pub(crate) fn new(inner: WriteObject<T, S>, control: StorageControl) -> Self {
+        Self {
+            inner,
+            control,
+            component_count: DEFAULT_COMPONENT_COUNT,
+            minimum_component_size: DEFAULT_MINIMUM_COMPONENT_SIZE,
+            component_prefix: String::new(),
+        }
+    }
+
+    /// Sets the maximum number of components.
+    ///
+    /// The client library uploads all the components concurrently. The value
+    /// is clamped to the `[1, 32]` range, as `ComposeObject` accepts at most
+    /// 32 source objects.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_storage::client::{Storage, StorageControl};
+    /// # async fn sample(client: &Storage, control: &StorageControl) -> anyhow::Result<()> {
+    /// let payload = tokio::fs::File::open("my-large-file").await?;
+    /// let object = client
+    ///     .write_object("projects/_/buckets/my-bucket", "my-object", payload)
+    ///     .parallel_upload(control)
+    ///     .with_component_count(4)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn with_component_count(mut self, v: usize) -> Self {
+        self.component_count = v.clamp(1, MAX_COMPONENTS);
+        self
+    }
+
+    /// Sets the minimum size for each component.
+    ///
+    /// The client library uses fewer components if needed to keep every
+    /// component at least this large. If the payload is smaller than two
+    /// components the client library uses a regular upload.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_storage::client::{Storage, StorageControl};
+    /// # async fn sample(client: &Storage, control: &StorageControl) -> anyhow::Result<()> {
+    /// const MIB: u64 = 1024 * 1024;
+    /// let payload = tokio::fs::File::open("my-large-file").await?;
+    /// let object = client
+    ///     .write_object("projects/_/buckets/my-bucket", "my-object", payload)
+    ///     .parallel_upload(control)
+    ///     .with_minimum_component_size(128 * MIB)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn with_minimum_component_size(mut self, v: u64) -> Self {
+        self.minimum_component_size = v.max(1);
+        self
+    }
+
+    /// Sets the prefix for the temporary component names.
+    ///
+    /// By default, components are created next to the destination object.
+    /// Applications may prefer a separate prefix, for example, to configure
+    /// an [Object Lifecycle] rule that removes any components left behind if
+    /// the application crashes.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_storage::client::{Storage, StorageControl};
+    /// # async fn sample(client: &Storage, control: &StorageControl) -> anyhow::Result<()> {
+    /// let payload = tokio::fs::File::open("my-large-file").await?;
+    /// let object = client
+    ///     .write_object("projects/_/buckets/my-bucket", "my-object", payload)
+    ///     .parallel_upload(control)
+    ///     .with_component_prefix("tmp/parallel-uploads/")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [Object Lifecycle]: https://cloud.google.com/storage/docs/lifecycle
+    pub fn with_component_prefix<V: Into<String>>(mut self, v: V) -> Self {
+        self.component_prefix = v.into();
+        self
+    }
+}
+
+impl<T, S> ParallelUpload<T, S>
+where
+    T: StreamingSource + Seek + Send + Sync + 'static,
+    <T as StreamingSource>::Error: std::error::Error + Send + Sync + 'static,
+    <T as Seek>::Error: std::error::Error + Send + Sync + 'static,
+    S: crate::storage::stub::Storage + 'static,
+{
+    /// Uploads the components, composes them, and removes the components.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_storage::client::{Storage, StorageControl};
+    /// # async fn sample(client: &Storage, control: &StorageControl) -> anyhow::Result<()> {
+    /// let payload = tokio::fs::File::open("my-large-file").await?;
+    /// let object = client
+    ///     .write_object("projects/_/buckets/my-bucket", "my-object", payload)
+    ///     .parallel_upload(control)
+    ///     .send()
+    ///     .await?;
+    /// println!("object metadata={object:?}");
+    /// # Ok(()) }
+    /// ```
+    pub async fn send(self) -> Result<Object> {
+        let hint = self.inner.payload.size_hint().await.map_err(Error::ser)?;
+        let Some(size) = hint.exact() else {
+            return self.inner.send_unbuffered().await;
+        };
+        let ranges = split(size, self.component_count, self.minimum_component_size);
+        if ranges.len() < 2 {
+            return self.inner.send_unbuffered().await;
+        }
+
+        let WriteObject {
+            stub,
+            request,
+            payload,
+            options,
+        } = self.inner;
+        let resource = request.spec.resource.clone().unwrap_or_default();
+        let prefix = format!(
+            "{}{}.{}",
+            self.component_prefix,
+            resource.name,
+            uuid::Uuid::new_v4()
+        );
+        let payload = Arc::new(Mutex::new(payload));
+        let uploads = ranges.iter().enumerate().map(|(index, (start, end))| {
+            let source = ComponentSource::new(payload.clone(), *start, *end);
+            let mut builder = WriteObject::new(
+                stub.clone(),
+                resource.bucket.clone(),
+                format!("{prefix}.part-{index:02}"),
+                source,
+                options.clone(),
+            )
+            .set_if_generation_match(0);
+            builder.request.params = request.params.clone();
+            builder.send_unbuffered()
+        });
+        let results = futures::future::join_all(uploads).await;
+
+        let mut components = Vec::new();
+        let mut error = None;
+        for r in results {
+            match r {
+                Ok(o) => components.push(o),
+                Err(e) => {
+                    error.get_or_insert(e);
+                }
+            }
+        }
+        if let Some(e) = error {
+            cleanup(&self.control, &components).await;
+            return Err(e);
+        }
+
+        let result = compose(&self.control, request, resource, &components).await;
+        cleanup(&self.control, &components).await;
+        result
+    }
+}
+
+async fn compose(
+    control: &StorageControl,
+    request: crate::model_ext::WriteObjectRequest,
+    mut destination: Object,
+    components: &[Object],
+) -> Result<Object> {
+    let mut expected = destination.checksums.take().unwrap_or_default();
+    checksum_update(&mut expected, combined_checksums(components));
+    // Composite objects do not have MD5 hashes.
+    expected.md5_hash = bytes::Bytes::new();
+    let kms_key = destination.kms_key.clone();
+    let sources = components.iter().map(|o| {
+        SourceObject::new()
+            .set_name(o.name.clone())
+            .set_generation(o.generation)
+    });
+    let object = control
+        .compose_object()
+        .set_destination(destination)
+        .set_source_objects(sources)
+        .set_destination_predefined_acl(request.spec.predefined_acl)
+        .set_or_clear_if_generation_match(request.spec.if_generation_match)
+        .set_or_clear_if_metageneration_match(request.spec.if_metageneration_match)
+        .set_kms_key(kms_key)
+        .set_or_clear_common_object_request_params(request.params)
+        .set_object_checksums(expected.clone())
+        .send()
+        .await?;
+    if let Err(mismatch) = checksum_validate(&expected, &object.checksums) {
+        return Err(Error::ser(WriteError::ChecksumMismatch {
+            mismatch,
+            object: object.into(),
+        }));
+    }
+    Ok(object)
+}
+
+/// Computes the CRC32C checksum of the concatenated components.
+///
+/// Returns an empty value if any component is missing its CRC32C checksum.
+fn combined_checksums(components: &[Object]) -> ObjectChecksums {
+    let crc32c = components.iter().try_fold(0_u32, |crc, o| {
+        let component = o.checksums.as_ref().and_then(|c| c.crc32c)?;
+        Some(crc32c::crc32c_combine(crc, component, o.size as usize))
+    });
+    ObjectChecksums::new().set_or_clear_crc32c(crc32c)
+}
+
+/// Deletes the components, ignoring any errors.
+///
+/// The components are temporary, the application has no use for them once the
+/// composition succeeds or fails. A failure to delete them should not mask the
+/// result of the upload.
+async fn cleanup(control: &StorageControl, components: &[Object]) {
+    let deletes = components.iter().map(|o| {
+        control
+            .delete_object()
+            .set_bucket(o.bucket.clone())
+            .set_object(o.name.clone())
+            .set_generation(o.generation)
+            .send()
+    });
+    for (o, r) in components
+        .iter()
+        .zip(futures::future::join_all(deletes).await)
+    {
+        if let Err(e) = r {
+            tracing::warn!(
+                "cannot delete temporary component {} in {}: {e:?}",
+                o.name,
+                o.bucket
+            );
+        }
+    }
+}
+
+/// Splits `size` bytes into at most `count` ranges of at least `minimum` bytes.
+fn split(size: u64, count: usize, minimum: u64) -> Vec<(u64, u64)> {
+    let count = std::cmp::min(count as u64, size / minimum).max(1);
+    let length = size / count;
+    (0..count)
+        .map(|i| {
+            let start = i * length;
+            let end = if i + 1 == count { size } else { start + length };
+            (start, end)
+        })
+        .collect()
+}
+
+/// A [StreamingSource] for a range of a shared payload.
+///
+/// Each component seeks the shared payload before reading, so multiple
+/// components can be uploaded concurrently. Reading from the payload is
+/// serialized, but sending data to the service is not.
+struct ComponentSource<T> {
+    payload: Arc<Mutex<Payload<T>>>,
+    start: u64,
+    end: u64,
+    offset: u64,
+}
+
+impl<T> ComponentSource<T> {
+    fn new(payload: Arc<Mutex<Payload<T>>>, start: u64, end: u64) -> Self {
+        Self {
+            payload,
+            start,
+            end,
+            offset: start,
+        }
+    }
+}
+
+impl<T> StreamingSource for ComponentSource<T>
+where
+    T: StreamingSource + Seek + Send + Sync,
+{
+    type Error = std::io::Error;
+
+    async fn next(&mut self) -> Option<std::result::Result<bytes::Bytes, Self::Error>> {
+        if self.offset >= self.end {
+            return None;
+        }
+        let mut payload = self.payload.lock().await;
+        if let Err(e) = payload.seek(self.offset).await {
+            return Some(Err(std::io::Error::other(e)));
+        }
+        let data = match payload.next().await? {
+            Err(e) => return Some(Err(std::io::Error::other(e))),
+            Ok(d) => d,
+        };
+        let remaining = (self.end - self.offset) as usize;
+        let data = data.slice(0..std::cmp::min(remaining, data.len()));
+        self.offset += data.len() as u64;
+        Some(Ok(data))
+    }
+
+    async fn size_hint(&self) -> std::result::Result<SizeHint, Self::Error> {
+        Ok(SizeHint::with_exact(self.end - self.start))
+    }
+}
+
+impl<T> Seek for ComponentSource<T>
+where
+    T: Send + Sync,
+{
+    type Error = std::io::Error;
+
+    async fn seek(&mut self, offset: u64) -> std::result::Result<(), Self::Error> {
+        self.offset = std::cmp::min(self.start + offset, self.end);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Storage;
+    use crate::error::ChecksumMismatch;
+    use crate::model::{ComposeObjectRequest, DeleteObjectRequest};
+    use crate::model_ext::WriteObjectRequest;
+    use crate::request_options::RequestOptions;
+    use google_cloud_gax::error::rpc::{Code, Status};
+    use google_cloud_gax::options::RequestOptions as GaxRequestOptions;
+    use google_cloud_gax::response::Response;
+    use std::collections::BTreeMap;
+    use test_case::test_case;
+
+    type TestResult = anyhow::Result<()>;
+
+    const BUCKET: &str = "projects/_/buckets/test-bucket";
+
+    /// Stores the uploaded objects in memory.
+    #[derive(Debug, Default)]
+    struct FakeStorage {
+        objects: std::sync::Mutex<BTreeMap<String, bytes::Bytes>>,
+        fail: Option<String>,
+    }
+
+    impl crate::stub::Storage for FakeStorage {
+        async fn write_object_unbuffered<P>(
+            &self,
+            mut payload: P,
+            req: WriteObjectRequest,
+            _options: RequestOptions,
+        ) -> Result<Object>
+        where
+            P: StreamingSource + Seek + Send + Sync + 'static,
+        {
+            let resource = req.spec.resource.unwrap();
+            if self
+                .fail
+                .as_ref()
+                .is_some_and(|f| resource.name.ends_with(f))
+            {
+                return Err(Error::service(
+                    Status::default().set_code(Code::PermissionDenied),
+                ));
+            }
+            let mut data = Vec::new();
+            while let Some(b) = payload.next().await.transpose().map_err(Error::ser)? {
+                data.extend_from_slice(&b);
+            }
+            let object = Object::new()
+                .set_bucket(resource.bucket)
+                .set_name(resource.name.clone())
+                .set_generation(1234)
+                .set_size(data.len() as i64)
+                .set_checksums(ObjectChecksums::new().set_crc32c(crc32c::crc32c(&data)));
+            self.objects
+                .lock()
+                .unwrap()
+                .insert(resource.name, bytes::Bytes::from_owner(data));
+            Ok(object)
+        }
+    }
+
+    mockall::mock! {
+        #[derive(Debug)]
+        StorageControl {}
+        impl crate::stub::StorageControl for StorageControl {
+            async fn compose_object(&self, req: ComposeObjectRequest, _options: GaxRequestOptions) -> Result<Response<Object>>;
+            async fn delete_object(&self, req: DeleteObjectRequest, _options: GaxRequestOptions) -> Result<Response<()>>;
+        }
+    }
+
+    fn contents(size: usize) -> bytes::Bytes {
+        bytes::Bytes::from_owner((0..size).map(|i| (i % 251) as u8).collect::<Vec<_>>())
+    }
+
+    #[test_case(100, 4, 10, 4)]
+    #[test_case(100, 4, 30, 3)]
+    #[test_case(100, 4, 60, 1)]
+    #[test_case(100, 4, 200, 1)]
+    #[test_case(103, 4, 1, 4)]
+    fn split_ranges(size: u64, count: usize, minimum: u64, want: usize) {
+        let got = split(size, count, minimum);
+        assert_eq!(got.len(), want, "{got:?}");
+        assert_eq!(got.first().map(|r| r.0), Some(0), "{got:?}");
+        assert_eq!(got.last().map(|r| r.1), Some(size), "{got:?}");
+        for w in got.windows(2) {
+            assert_eq!(w[0].1, w[1].0, "{got:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn component_source() -> TestResult {
+        let data = contents(1000);
+        let payload = Arc::new(Mutex::new(Payload::from(data.clone())));
+        let mut source = ComponentSource::new(payload.clone(), 100, 300);
+        assert_eq!(source.size_hint().await?.exact(), Some(200));
+        let mut got = Vec::new();
+        while let Some(b) = source.next().await.transpose()? {
+            got.extend_from_slice(&b);
+        }
+        assert_eq!(got, data.slice(100..300));
+
+        source.seek(150).await?;
+        let got = source.next().await.transpose()?;
+        assert_eq!(got, Some(data.slice(250..300)));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn success() -> TestResult {
+        let data = contents(1000);
+        let want_crc32c = crc32c::crc32c(&data);
+        let mut control = MockStorageControl::new();
+        control
+            .expect_compose_object()
+            .times(1)
+            .withf(move |req, _| {
+                req.source_objects.len() == 4
+                    && req.destination.as_ref().is_some_and(|d| d.name == "object")
+                    && req.if_generation_match == Some(0)
+                    && req
+                        .object_checksums
+                        .as_ref()
+                        .is_some_and(|c| c.crc32c == Some(want_crc32c))
+            })
+            .returning(move |req, _| {
+                let object = req
+                    .destination
+                    .unwrap()
+                    .set_size(1000)
+                    .set_checksums(ObjectChecksums::new().set_crc32c(want_crc32c));
+                Ok(Response::from(object))
+            });
+        control
+            .expect_delete_object()
+            .times(4)
+            .withf(|req, _| req.bucket == BUCKET && req.generation == 1234)
+            .returning(|_, _| Ok(Response::from(())));
+
+        let stub = Arc::new(FakeStorage::default());
+        let client = Storage::<FakeStorage>::from_stub(stub.clone());
+        let control = StorageControl::from_stub(control);
+        let object = client
+            .write_object(BUCKET, "object", data.clone())
+            .set_if_generation_match(0)
+            .parallel_upload(&control)
+            .with_component_count(4)
+            .with_minimum_component_size(10)
+            .with_component_prefix("tmp/")
+            .send()
+            .await?;
+        assert_eq!(object.checksums.and_then(|c| c.crc32c), Some(want_crc32c));
+
+        let uploaded = stub.objects.lock().unwrap().clone();
+        assert_eq!(uploaded.len(), 4, "{uploaded:?}");
+        assert!(uploaded.keys().all(|k| k.starts_with("tmp/object.")));
+        let got = uploaded.values().fold(Vec::new(), |mut v, b| {
+            v.extend_from_slice(b);
+            v
+        });
+        assert_eq!(got, data);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn small_payload_uses_single_upload() -> TestResult {
+        let control = StorageControl::from_stub(MockStorageControl::new());
+        let stub = Arc::new(FakeStorage::default());
+        let client = Storage::<FakeStorage>::from_stub(stub.clone());
+        let object = client
+            .write_object(BUCKET, "object", contents(100))
+            .parallel_upload(&control)
+            .with_minimum_component_size(64)
+            .send()
+            .await?;
+        assert_eq!(object.name, "object");
+        let uploaded = stub.objects.lock().unwrap().clone();
+        assert_eq!(uploaded.keys().collect::<Vec<_>>(), vec!["object"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn upload_error_deletes_components() -> TestResult {
+        let mut control = MockStorageControl::new();
+        control.expect_compose_object().never();
+        control
+            .expect_delete_object()
+            .times(3)
+            .returning(|_, _| Ok(Response::from(())));
+
+        let stub = Arc::new(FakeStorage {
+            fail: Some(".part-02".to_string()),
+            ..FakeStorage::default()
+        });
+        let client = Storage::<FakeStorage>::from_stub(stub);
+        let control = StorageControl::from_stub(control);
+        let err = client
+            .write_object(BUCKET, "object", contents(1000))
+            .parallel_upload(&control)
+            .with_component_count(4)
+            .with_minimum_component_size(10)
+            .send()
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.status().map(|s| s.code),
+            Some(Code::PermissionDenied),
+            "{err:?}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn compose_error_deletes_components() -> TestResult {
+        let mut control = MockStorageControl::new();
+        control.expect_compose_object().times(1).returning(|_, _| {
+            Err(Error::service(
+                Status::default().set_code(Code::FailedPrecondition),
+            ))
+        });
+        control
+            .expect_delete_object()
+            .times(4)
+            .returning(|_, _| Err(Error::service(Status::default().set_code(Code::NotFound))));
+
+        let client = Storage::<FakeStorage>::from_stub(FakeStorage::default());
+        let control = StorageControl::from_stub(control);
+        let err = client
+            .write_object(BUCKET, "object", contents(1000))
+            .parallel_upload(&control)
+            .with_component_count(4)
+            .with_minimum_component_size(10)
+            .send()
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.status().map(|s| s.code),
+            Some(Code::FailedPrecondition),
+            "{err:?}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn checksum_mismatch() -> TestResult {
+        let mut control = MockStorageControl::new();
+        control
+            .expect_compose_object()
+            .times(1)
+            .returning(|req, _| {
+                let object = req
+                    .destination
+                    .unwrap()
+                    .set_checksums(ObjectChecksums::new().set_crc32c(0x01020304_u32));
+                Ok(Response::from(object))
+            });
+        control
+            .expect_delete_object()
+            .times(2)
+            .returning(|_, _| Ok(Response::from(())));
+
+        let client = Storage::<FakeStorage>::from_stub(FakeStorage::default());
+        let control = StorageControl::from_stub(control);
+        let err = client
+            .write_object(BUCKET, "object", contents(1000))
+            .parallel_upload(&control)
+            .with_component_count(2)
+            .with_minimum_component_size(10)
+            .send()
+            .await
+            .unwrap_err();
+        assert!(err.is_serialization(), "{err:?}");
+        let source = std::error::Error::source(&err)
+            .and_then(|e| e.downcast_ref::<WriteError>())
+            .expect("source is a WriteError");
+        assert!(
+            matches!(
+                source,
+                WriteError::ChecksumMismatch {
+                    mismatch: ChecksumMismatch::Crc32c { .. },
+                    ..
+                }
+            ),
+            "{source:?}"
+        );
+        Ok(())
+    }
+}
@@ -14,8 +14,9 @@
 
 use super::client::{StorageInner, apply_customer_supplied_encryption_headers};
 use crate::model::Object;
+use crate::model_ext::UploadSession;
 use crate::retry_policy::ContinueOn308;
-use crate::storage::checksum::details::ChecksummedSource;
+use crate::storage::checksum::details::{ChecksummedSource, Crc32c};
 use crate::storage::info::X_GOOG_API_CLIENT_HEADER;
 use crate::storage::v1;
 use crate::streaming_source::{IterSource, Seek, SizeHint, StreamingSource};
@@ -53,7 +54,18 @@ impl<S> PerformUpload<S> {
         params: Option<crate::model::CommonObjectRequestParams>,
         options: super::request_options::RequestOptions,
     ) -> Self {
-        let checksum = options.checksum.clone();
+        let mut checksum = options.checksum.clone();
+        // When resuming an upload session, continue computing the CRC32C
+        // checksum from the saved state.
+        if let (Some(crc32c), Some((value, offset))) = (
+            checksum.crc32c.as_mut(),
+            options
+                .upload_session
+                .as_ref()
+                .and_then(UploadSession::crc32c_state),
+        ) {
+            *crc32c = Crc32c::resume(value, offset);
+        }
         Self {
             payload: Arc::new(Mutex::new(ChecksummedSource::new(checksum, payload))),
             inner,
@@ -70,6 +82,30 @@ impl<S> PerformUpload<S> {
             .expect("resource field initialized in `new()`")
     }
 
+    /// The upload URL of the session created by a previous process, if any.
+    fn resumed_upload_url(&self) -> Option<String> {
+        self.options
+            .upload_session
+            .as_ref()
+            .map(|s| s.upload_url().to_string())
+    }
+
+    /// Reports the upload session state to the application, if requested.
+    async fn report_upload_session(&self, upload_url: &str, persisted_size: u64) {
+        let Some(callback) = &self.options.upload_session_callback else {
+            return;
+        };
+        let crc32c = self.payload.lock().await.crc32c_state();
+        let resource = self.resource();
+        callback.call(&UploadSession::new(
+            resource.bucket.clone(),
+            resource.name.clone(),
+            upload_url.to_string(),
+            persisted_size,
+            crc32c,
+        ));
+    }
+
     async fn start_resumable_upload_attempt(&self, attempt_count: u32) -> Result<String> {
         let builder = self.start_resumable_upload_request().await?;
         let options = self.options.gax();
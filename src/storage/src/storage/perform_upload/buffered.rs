@@ -46,7 +46,8 @@ where
             .await
             .map_err(Error::ser)?;
         let threshold = self.options.resumable_upload_threshold() as u64;
-        if hint.upper().is_none_or(|max| max >= threshold) {
+        if self.options.upload_session.is_some() || hint.upper().is_none_or(|max| max >= threshold)
+        {
             self.send_buffered_resumable(hint).await
         } else {
             self.send_buffered_single_shot().await
@@ -55,7 +56,10 @@ where
 
     async fn send_buffered_resumable(self, hint: SizeHint) -> Result<Object> {
         let mut progress = InProgressUpload::new(self.options.resumable_upload_buffer_size(), hint);
-        let mut url = None;
+        let mut url = self.resumed_upload_url();
+        if url.is_some() {
+            progress.resume();
+        }
         let throttler = self.options.retry_throttler.clone();
         let retry = Arc::new(ContinueOn308::new(self.options.retry_policy.clone()));
         let backoff = self.options.backoff_policy.clone();
@@ -90,6 +94,7 @@ where
             u
         } else {
             let u = self.start_resumable_upload_attempt(attempt_count).await?;
+            self.report_upload_session(&u, 0).await;
             url.insert(u).as_str()
         };
 
@@ -101,7 +106,14 @@ where
                     if persisted_size > 0 {
                         is_partial_resume = true;
                     }
-                    progress.handle_partial(persisted_size)?;
+                    if progress.is_resuming() {
+                        progress
+                            .skip_persisted(&mut *self.payload.lock().await, persisted_size)
+                            .await?;
+                    } else {
+                        progress.handle_partial(persisted_size)?;
+                    }
+                    self.report_upload_session(upload_url, persisted_size).await;
                 }
             };
         }
@@ -135,6 +147,7 @@ where
                 }
                 Ok(ResumableUploadStatus::Partial(persisted_size)) => {
                     progress.handle_partial(persisted_size)?;
+                    self.report_upload_session(upload_url, persisted_size).await;
                 }
             };
         }
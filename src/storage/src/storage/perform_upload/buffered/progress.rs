@@ -47,6 +47,11 @@ pub struct InProgressUpload {
     remainder: VecDeque<bytes::Bytes>,
     /// Indicates if the source stream has ended.
     source_ended: bool,
+    /// Indicates if the upload continues a session from a previous process.
+    ///
+    /// In this case the data persisted by the service must be skipped from
+    /// the source stream.
+    resuming: bool,
 }
 
 struct Summary<'a>(&'a VecDeque<bytes::Bytes>);
@@ -79,7 +84,8 @@ impl std::fmt::Debug for InProgressUpload {
             .field("buffer_size", &self.buffer_size)
             // The buffer and remainder can be rather large, just print a summary.
             .field("buffer", &Summary(&self.buffer))
-            .field("remainder", &Summary(&self.remainder));
+            .field("remainder", &Summary(&self.remainder))
+            .field("resuming", &self.resuming);
         fmt.finish()
     }
 }
@@ -100,6 +106,43 @@ impl InProgressUpload {
         }
     }
 
+    /// Prepares to continue an upload session started by a previous process.
+    pub fn resume(&mut self) {
+        self.persisted_size = None;
+        self.resuming = true;
+    }
+
+    pub fn is_resuming(&self) -> bool {
+        self.resuming
+    }
+
+    /// Skips the data already persisted by a session started by a previous process.
+    pub async fn skip_persisted<S>(&mut self, payload: &mut S, persisted_size: u64) -> Result<()>
+    where
+        S: StreamingSource,
+    {
+        let mut skip = persisted_size;
+        while skip > 0 {
+            let Some(mut b) = payload.next().await.transpose().map_err(Error::ser)? else {
+                return Err(Error::ser(WriteError::TooMuchProgress {
+                    sent: persisted_size - skip,
+                    persisted: persisted_size,
+                }));
+            };
+            match b.len() as u64 {
+                n if n <= skip => skip -= n,
+                _ => {
+                    self.remainder.push_back(b.split_off(skip as usize));
+                    skip = 0;
+                }
+            }
+        }
+        self.offset = persisted_size;
+        self.persisted_size = Some(persisted_size);
+        self.resuming = false;
+        Ok(())
+    }
+
     pub fn needs_query(&self) -> bool {
         self.persisted_size.is_none_or(|x| x != self.offset)
     }
@@ -678,6 +721,56 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn skip_persisted() -> Result {
+        let mut upload = InProgressUpload::fake(RESUMABLE_UPLOAD_QUANTUM);
+        upload.resume();
+        assert!(upload.is_resuming(), "{upload:?}");
+        assert!(upload.needs_query(), "{upload:?}");
+
+        let mut payload = Payload::from(IterSource::new(vec![
+            new_line(0, 100),
+            new_line(1, 100),
+            new_line(2, 100),
+        ]));
+        upload.skip_persisted(&mut payload, 150).await?;
+        assert!(!upload.is_resuming(), "{upload:?}");
+        assert!(!upload.needs_query(), "{upload:?}");
+        assert_eq!(upload.offset, 150);
+
+        upload.next_buffer(&mut payload).await?;
+        let got = upload.buffer.iter().fold(Vec::new(), |mut v, b| {
+            v.extend_from_slice(b);
+            v
+        });
+        let want = [new_line(1, 100).slice(50..), new_line(2, 100)].concat();
+        assert_eq!(got, want);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn skip_persisted_too_much_progress() -> Result {
+        let mut upload = InProgressUpload::fake(RESUMABLE_UPLOAD_QUANTUM);
+        upload.resume();
+        let mut payload = Payload::from(IterSource::new(vec![new_line(0, 100)]));
+        let err = upload
+            .skip_persisted(&mut payload, 200)
+            .await
+            .expect_err("not enough data in source");
+        let source = err.source().and_then(|e| e.downcast_ref::<WriteError>());
+        assert!(
+            matches!(
+                source,
+                Some(WriteError::TooMuchProgress {
+                    sent: 100,
+                    persisted: 200
+                })
+            ),
+            "{err:?}"
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn handle_error() -> Result {
         let mut payload = Payload::from("");
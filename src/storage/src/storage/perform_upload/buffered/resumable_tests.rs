@@ -102,6 +102,9 @@
 //! - An upload that succeeds despite a PUT error. The data may arrive to the
 //!   service but the PUT request fails to read the response or otherwise fails.
 //!   The next query returns a finalized upload status.
+//! - An upload that resumes a session created by a previous process. The
+//!   client library must skip the data persisted by the service.
+//! - An upload that reports the upload session state to the application.
 //!
 //! [Seek]: crate::streaming_source::Seek
 
@@ -794,3 +797,121 @@ async fn start_resumable_upload_client_retry_options() -> Result {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn resume_upload_session() -> Result {
+    const LEN: usize = 2 * RESUMABLE_UPLOAD_QUANTUM;
+    let payload = bytes::Bytes::from_owner((0..LEN).map(|i| i as u8).collect::<Vec<_>>());
+    let server = Server::run();
+    let session = server.url("/upload/session/test-only-001");
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("PUT", session.path().to_string()),
+            request::headers(contains(("content-range", "bytes */*"))),
+            request::headers(contains(("content-length", "0"))),
+        ])
+        .respond_with(
+            status_code(308)
+                .append_header("range", format!("bytes=0-{}", RESUMABLE_UPLOAD_QUANTUM - 1)),
+        ),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("PUT", session.path().to_string()),
+            request::headers(contains((
+                "content-range",
+                format!("bytes {RESUMABLE_UPLOAD_QUANTUM}-{}/{LEN}", LEN - 1)
+            ))),
+            request::body(payload.slice(RESUMABLE_UPLOAD_QUANTUM..).to_vec()),
+        ])
+        .respond_with(status_code(200).body(response_body().to_string())),
+    );
+
+    let saved = crate::model_ext::UploadSession::new(
+        "projects/_/buckets/test-bucket".into(),
+        "test-object".into(),
+        session.to_string(),
+        0,
+        None,
+    );
+    let client = test_builder()
+        .with_endpoint(format!("http://{}", server.addr()))
+        .build()
+        .await?;
+    let response = client
+        .resume_upload(saved, BytesSource::new(payload))
+        .send_buffered()
+        .await?;
+    assert_eq!(response.name, "test-object");
+    assert_eq!(response.bucket, "projects/_/buckets/test-bucket");
+    Ok(())
+}
+
+#[tokio::test]
+async fn upload_session_callback() -> Result {
+    const LEN: usize = 2 * RESUMABLE_UPLOAD_QUANTUM;
+    let server = Server::run();
+    let session = server.url("/upload/session/test-only-001");
+    server.expect(
+        Expectation::matching(request::method_path(
+            "POST",
+            "/upload/storage/v1/b/test-bucket/o",
+        ))
+        .respond_with(status_code(200).append_header("location", session.to_string())),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("PUT", session.path().to_string()),
+            request::headers(contains((
+                "content-range",
+                format!("bytes 0-{}/{LEN}", RESUMABLE_UPLOAD_QUANTUM - 1)
+            ))),
+        ])
+        .respond_with(
+            status_code(308)
+                .append_header("range", format!("bytes=0-{}", RESUMABLE_UPLOAD_QUANTUM - 1)),
+        ),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("PUT", session.path().to_string()),
+            request::headers(contains((
+                "content-range",
+                format!("bytes {RESUMABLE_UPLOAD_QUANTUM}-{}/{LEN}", LEN - 1)
+            ))),
+        ])
+        .respond_with(status_code(200).body(response_body().to_string())),
+    );
+
+    let sessions = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let captured = sessions.clone();
+    let client = test_builder()
+        .with_endpoint(format!("http://{}", server.addr()))
+        .with_resumable_upload_threshold(0_usize)
+        .with_resumable_upload_buffer_size(RESUMABLE_UPLOAD_QUANTUM)
+        .build()
+        .await?;
+    let response = client
+        .write_object(
+            "projects/_/buckets/test-bucket",
+            "test-object",
+            BytesSource::new(bytes::Bytes::from_owner(vec![0_u8; LEN])),
+        )
+        .with_upload_session_callback(move |s| captured.lock().unwrap().push(s.clone()))
+        .send_buffered()
+        .await?;
+    assert_eq!(response.name, "test-object");
+
+    let sessions = sessions.lock().unwrap();
+    let persisted = sessions
+        .iter()
+        .map(|s| s.persisted_size())
+        .collect::<Vec<_>>();
+    assert_eq!(persisted, vec![0, RESUMABLE_UPLOAD_QUANTUM as u64]);
+    let want = session.to_string();
+    assert!(
+        sessions.iter().all(|s| s.upload_url() == want),
+        "{sessions:?}"
+    );
+    Ok(())
+}
@@ -39,7 +39,8 @@ where
             .await
             .map_err(Error::deser)?;
         let threshold = self.options.resumable_upload_threshold() as u64;
-        if hint.upper().is_none_or(|max| max >= threshold) {
+        if self.options.upload_session.is_some() || hint.upper().is_none_or(|max| max >= threshold)
+        {
             self.send_unbuffered_resumable(hint).await
         } else {
             self.send_unbuffered_single_shot(hint).await
@@ -47,7 +48,7 @@ where
     }
 
     async fn send_unbuffered_resumable(self, hint: SizeHint) -> Result<Object> {
-        let mut upload_url = None;
+        let mut upload_url = self.resumed_upload_url();
         let throttler = self.options.retry_throttler.clone();
         let retry = Arc::new(ContinueOn308::new(self.options.retry_policy.clone()));
         let backoff = self.options.backoff_policy.clone();
@@ -83,10 +84,15 @@ where
                 ResumableUploadStatus::Finalized(object) => {
                     return Ok(*object);
                 }
-                ResumableUploadStatus::Partial(offset) => (offset, upload_url),
+                ResumableUploadStatus::Partial(offset) => {
+                    self.catch_up_checksums(offset).await?;
+                    self.report_upload_session(upload_url, offset).await;
+                    (offset, upload_url)
+                }
             }
         } else {
             let upload_url = self.start_resumable_upload_attempt(attempt_count).await?;
+            self.report_upload_session(&upload_url, 0).await;
             (0_u64, url.insert(upload_url).as_str())
         };
 
@@ -130,6 +136,32 @@ where
         self.validate_response_object(object).await
     }
 
+    /// Reads any data before `offset` that the checksums have not seen.
+    ///
+    /// This happens when resuming an upload session created by a previous
+    /// process, the checksums must include the data persisted by the service
+    /// before the client library can send the rest of the data.
+    async fn catch_up_checksums(&self, offset: u64) -> Result<()> {
+        let mut payload = self.payload.lock().await;
+        let start = payload.checksum_offset();
+        if start >= offset {
+            return Ok(());
+        }
+        payload.seek(start).await.map_err(Error::ser)?;
+        while payload.offset() < offset {
+            if payload
+                .next()
+                .await
+                .transpose()
+                .map_err(Error::ser)?
+                .is_none()
+            {
+                break;
+            }
+        }
+        Ok(())
+    }
+
     pub(super) async fn send_unbuffered_single_shot(self, hint: SizeHint) -> Result<Object> {
         // Single shot uploads are idempotent only if they have pre-conditions.
         let idempotent = self.options.idempotency.unwrap_or(
@@ -73,6 +73,9 @@
 //! - An upload that succeeds despite a PUT error. The data may arrive to the
 //!   service but the PUT request fails to read the response or otherwise fails.
 //!   The next query returns a finalized upload status.
+//! - An upload that resumes a session created by a previous process. The
+//!   client library must compute the checksums for the full object.
+//! - An upload that reports the upload session state to the application.
 //!
 //! [Seek]: crate::streaming_source::Seek
 
@@ -883,3 +886,202 @@ async fn resumable_upload_handle_response_deser() -> Result {
     assert!(err.is_deserialization(), "{err:?}");
     Ok(())
 }
+
+fn crc32c_base64(data: &[u8]) -> String {
+    use base64::Engine;
+    base64::prelude::BASE64_STANDARD.encode(crc32c::crc32c(data).to_be_bytes())
+}
+
+#[tokio::test]
+async fn resume_upload_session() -> Result {
+    let payload = bytes::Bytes::from_owner((0..1_000).map(|i| i as u8).collect::<Vec<_>>());
+    let server = Server::run();
+    let session = server.url("/upload/session/test-only-001");
+    server.expect(
+        Expectation::matching(request::method_path(
+            "POST",
+            "/upload/storage/v1/b/test-bucket/o",
+        ))
+        .times(0)
+        .respond_with(status_code(500)),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("PUT", session.path().to_string()),
+            request::headers(contains(("content-range", "bytes */*"))),
+            request::headers(contains(("content-length", "0"))),
+        ])
+        .respond_with(status_code(308).append_header("range", "bytes=0-255")),
+    );
+    let mut body = response_body();
+    body["crc32c"] = json!(crc32c_base64(&payload));
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("PUT", session.path().to_string()),
+            request::headers(contains(("content-range", "bytes 256-999/1000"))),
+            request::body(payload.slice(256..).to_vec()),
+        ])
+        .respond_with(status_code(200).body(body.to_string())),
+    );
+
+    // The previous process saved the checksum of the first 100 bytes, the
+    // client library must read the bytes in [100, 256) to compute the
+    // checksum of the full object.
+    let saved = crate::model_ext::UploadSession::new(
+        "projects/_/buckets/test-bucket".into(),
+        "test-object".into(),
+        session.to_string(),
+        0,
+        Some((crc32c::crc32c(&payload[..100]), 100)),
+    );
+    let client = test_builder()
+        .with_endpoint(format!("http://{}", server.addr()))
+        .build()
+        .await?;
+    let response = client
+        .resume_upload(saved, payload.clone())
+        .send_unbuffered()
+        .await?;
+    assert_eq!(response.name, "test-object");
+    assert_eq!(response.bucket, "projects/_/buckets/test-bucket");
+    Ok(())
+}
+
+#[tokio::test]
+async fn resume_upload_session_finalized() -> Result {
+    let server = Server::run();
+    let session = server.url("/upload/session/test-only-001");
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("PUT", session.path().to_string()),
+            request::headers(contains(("content-range", "bytes */*"))),
+        ])
+        .respond_with(status_code(200).body(response_body().to_string())),
+    );
+
+    let saved = crate::model_ext::UploadSession::new(
+        "projects/_/buckets/test-bucket".into(),
+        "test-object".into(),
+        session.to_string(),
+        1_000,
+        None,
+    );
+    let client = test_builder()
+        .with_endpoint(format!("http://{}", server.addr()))
+        .build()
+        .await?;
+    let response = client
+        .resume_upload(saved, bytes::Bytes::from_owner(vec![0_u8; 1_000]))
+        .send_unbuffered()
+        .await?;
+    assert_eq!(response.name, "test-object");
+    Ok(())
+}
+
+#[tokio::test]
+async fn resume_upload_session_checksum_mismatch() -> Result {
+    let payload = bytes::Bytes::from_owner((0..1_000).map(|i| i as u8).collect::<Vec<_>>());
+    let server = Server::run();
+    let session = server.url("/upload/session/test-only-001");
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("PUT", session.path().to_string()),
+            request::headers(contains(("content-range", "bytes */*"))),
+        ])
+        .respond_with(status_code(308).append_header("range", "bytes=0-255")),
+    );
+    let mut body = response_body();
+    body["crc32c"] = json!(crc32c_base64(&payload));
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("PUT", session.path().to_string()),
+            request::headers(contains(("content-range", "bytes 256-999/1000"))),
+        ])
+        .respond_with(status_code(200).body(body.to_string())),
+    );
+
+    // A saved checksum that does not match the data must be detected.
+    let saved = crate::model_ext::UploadSession::new(
+        "projects/_/buckets/test-bucket".into(),
+        "test-object".into(),
+        session.to_string(),
+        256,
+        Some((0x01020304, 256)),
+    );
+    let client = test_builder()
+        .with_endpoint(format!("http://{}", server.addr()))
+        .build()
+        .await?;
+    let err = client
+        .resume_upload(saved, payload)
+        .send_unbuffered()
+        .await
+        .expect_err("checksum mismatch should be detected");
+    assert!(err.is_serialization(), "{err:?}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn upload_session_callback() -> Result {
+    let server = Server::run();
+    let session = server.url("/upload/session/test-only-001");
+    server.expect(
+        Expectation::matching(request::method_path(
+            "POST",
+            "/upload/storage/v1/b/test-bucket/o",
+        ))
+        .respond_with(status_code(200).append_header("location", session.to_string())),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("PUT", session.path().to_string()),
+            request::headers(contains(("content-range", "bytes 0-999/1000")))
+        ])
+        .respond_with(status_code(429).body("try-again")),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("PUT", session.path().to_string()),
+            request::headers(contains(("content-range", "bytes */*"))),
+        ])
+        .respond_with(status_code(308).append_header("range", "bytes=0-255")),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("PUT", session.path().to_string()),
+            request::headers(contains(("content-range", "bytes 256-999/1000")))
+        ])
+        .respond_with(status_code(200).body(response_body().to_string())),
+    );
+
+    let sessions = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let captured = sessions.clone();
+    let client = test_builder()
+        .with_endpoint(format!("http://{}", server.addr()))
+        .with_resumable_upload_threshold(0_usize)
+        .build()
+        .await?;
+    let response = client
+        .write_object(
+            "projects/_/buckets/test-bucket",
+            "test-object",
+            bytes::Bytes::from_owner(vec![0_u8; 1_000]),
+        )
+        .with_upload_session_callback(move |s| captured.lock().unwrap().push(s.clone()))
+        .send_unbuffered()
+        .await?;
+    assert_eq!(response.name, "test-object");
+
+    let sessions = sessions.lock().unwrap();
+    let persisted = sessions
+        .iter()
+        .map(|s| s.persisted_size())
+        .collect::<Vec<_>>();
+    assert_eq!(persisted, vec![0, 256]);
+    for s in sessions.iter() {
+        assert_eq!(s.bucket(), "projects/_/buckets/test-bucket");
+        assert_eq!(s.object(), "test-object");
+        assert_eq!(s.upload_url(), session.to_string());
+    }
+    Ok(())
+}
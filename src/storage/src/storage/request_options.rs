@@ -16,6 +16,7 @@
 
 use super::common_options::CommonOptions;
 use crate::{
+    model_ext::{UploadSession, UploadSessionCallback},
     read_resume_policy::ReadResumePolicy,
     storage::checksum::details::{Checksum, Crc32c},
 };
@@ -47,6 +48,8 @@ pub struct RequestOptions {
     pub(crate) bidi_attempt_timeout: Duration,
     pub(crate) user_agent: Option<String>,
     pub(crate) quota_project: Option<String>,
+    pub(crate) upload_session: Option<UploadSession>,
+    pub(crate) upload_session_callback: Option<UploadSessionCallback>,
 }
 
 impl RequestOptions {
@@ -148,6 +151,8 @@ impl RequestOptions {
             bidi_attempt_timeout: DEFAULT_BIDI_ATTEMPT_TIMEOUT,
             user_agent: None,
             quota_project: None,
+            upload_session: None,
+            upload_session_callback: None,
         }
     }
 
@@ -18,7 +18,7 @@
 
 use super::streaming_source::{Seek, StreamingSource};
 use super::*;
-use crate::model_ext::KeyAes256;
+use crate::model_ext::{KeyAes256, UploadSession, UploadSessionCallback};
 use crate::storage::checksum::details::update as checksum_update;
 use crate::storage::checksum::details::{Checksum, Md5};
 use crate::storage::request_options::RequestOptions;
@@ -76,7 +76,7 @@ pub struct WriteObject<T, S = crate::storage::transport::Storage>
 where
     S: crate::storage::stub::Storage + 'static,
 {
-    stub: std::sync::Arc<S>,
+    pub(crate) stub: std::sync::Arc<S>,
     pub(crate) request: crate::model_ext::WriteObjectRequest,
     pub(crate) payload: Payload<T>,
     pub(crate) options: RequestOptions,
@@ -848,6 +848,52 @@ where
         self
     }
 
+    /// Receive the state of the upload session as the upload makes progress.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_storage::client::Storage;
+    /// # async fn sample(client: &Storage) -> anyhow::Result<()> {
+    /// let payload = tokio::fs::File::open("my-data").await?;
+    /// let response = client
+    ///     .write_object("projects/_/buckets/my-bucket", "my-object", payload)
+    ///     .with_upload_session_callback(|session| {
+    ///         let saved = serde_json::to_string(session).expect("sessions are serializable");
+    ///         let _ = std::fs::write("my-upload.session.json", saved);
+    ///     })
+    ///     .send_buffered()
+    ///     .await?;
+    /// println!("response details={response:?}");
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// For [resumable uploads] the client library calls this function when the
+    /// upload session is created, and every time the service reports how much
+    /// data it has persisted. Applications can save the [UploadSession] and
+    /// use [Storage::resume_upload] to continue the upload if the process
+    /// restarts.
+    ///
+    /// With [send_buffered()] the service reports its progress after each
+    /// chunk of [with_resumable_upload_buffer_size()] bytes. With
+    /// [send_unbuffered()] the service reports its progress only when the
+    /// upload is interrupted, the application can still resume these uploads
+    /// from the last persisted byte.
+    ///
+    /// The function is never called for single-shot uploads.
+    ///
+    /// [resumable uploads]: https://cloud.google.com/storage/docs/resumable-uploads
+    /// [Storage::resume_upload]: crate::client::Storage::resume_upload
+    /// [send_buffered()]: WriteObject::send_buffered
+    /// [send_unbuffered()]: WriteObject::send_unbuffered
+    /// [with_resumable_upload_buffer_size()]: WriteObject::with_resumable_upload_buffer_size
+    pub fn with_upload_session_callback<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&UploadSession) + Send + Sync + 'static,
+    {
+        self.options.upload_session_callback = Some(UploadSessionCallback::new(f));
+        self
+    }
+
     fn mut_resource(&mut self) -> &mut crate::model::Object {
         self.request
             .spec
@@ -996,6 +1042,20 @@ where
             options,
         }
     }
+
+    pub(crate) fn resume<P>(
+        stub: std::sync::Arc<S>,
+        session: UploadSession,
+        payload: P,
+        options: RequestOptions,
+    ) -> Self
+    where
+        P: Into<Payload<T>>,
+    {
+        let mut builder = Self::new(stub, session.bucket(), session.object(), payload, options);
+        builder.options.upload_session = Some(session);
+        builder
+    }
 }
 
 impl<T, S> WriteObject<T, S>
@@ -1073,6 +1133,37 @@ where
         };
         Ok(self)
     }
+
+    /// Upload the object using a parallel composite upload.
+    ///
+    /// Returns a builder to configure and send the upload. The client library
+    /// uploads separate ranges of the payload as temporary objects
+    /// concurrently, and then uses `control` to compose them into the
+    /// destination object and to delete the temporary objects.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_storage::client::{Storage, StorageControl};
+    /// # async fn sample(client: &Storage, control: &StorageControl) -> anyhow::Result<()> {
+    /// let payload = tokio::fs::File::open("my-large-file").await?;
+    /// let response = client
+    ///     .write_object("projects/_/buckets/my-bucket", "my-object", payload)
+    ///     .parallel_upload(control)
+    ///     .with_component_count(16)
+    ///     .send()
+    ///     .await?;
+    /// println!("response details={response:?}");
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// See [ParallelUpload][crate::builder::storage::ParallelUpload] for more
+    /// details, including the limitations of composite objects.
+    pub fn parallel_upload(
+        self,
+        control: &crate::client::StorageControl,
+    ) -> crate::builder::storage::ParallelUpload<T, S> {
+        crate::builder::storage::ParallelUpload::new(self, control.clone())
+    }
 }
 
 impl<T, S> WriteObject<T, S>
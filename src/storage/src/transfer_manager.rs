@@ -0,0 +1,1011 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Transfer directories to and from Cloud Storage.
+//!
+//! The [TransferManager] uploads a local directory tree to a prefix in a
+//! bucket, and downloads all the objects under a prefix to a local directory.
+//! The transfers run with bounded concurrency, can be filtered using glob
+//! patterns, and can skip files that are unchanged. Each transfer returns a
+//! [TransferReport] with the result for each file.
+//!
+//! # Example
+//! ```
+//! # use google_cloud_storage::client::{Storage, StorageControl};
+//! use google_cloud_storage::transfer_manager::TransferManager;
+//! # async fn sample(client: Storage, control: StorageControl) -> anyhow::Result<()> {
+//! let manager = TransferManager::new(client, control).with_concurrency(16);
+//! let report = manager
+//!     .upload_directory("build/artifacts", "projects/_/buckets/my-bucket", "artifacts/")
+//!     .with_exclude("**/*.tmp")
+//!     .with_skip_if_unchanged(true)
+//!     .send()
+//!     .await?;
+//! for result in report.failed() {
+//!     println!("{result:?}");
+//! }
+//! # Ok(()) }
+//! ```
+
+use crate::client::{Storage, StorageControl};
+use crate::model::{Object, ObjectChecksums};
+use crate::storage::checksum::details::{Checksum, Crc32c, Md5, validate as checksum_validate};
+use crate::{Error, Result};
+use futures::StreamExt;
+use google_cloud_gax::backoff_policy::BackoffPolicyArg;
+use google_cloud_gax::paginator::ItemPaginator as _;
+use google_cloud_gax::retry_policy::RetryPolicyArg;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+mod glob;
+
+/// The default number of concurrent transfers.
+const DEFAULT_CONCURRENCY: usize = 8;
+
+/// The buffer size to compute checksums of local files.
+const READ_SIZE: usize = 256 * 1024;
+
+/// Uploads and downloads directories.
+///
+/// # Example
+/// ```
+/// # use google_cloud_storage::client::{Storage, StorageControl};
+/// use google_cloud_storage::transfer_manager::TransferManager;
+/// # async fn sample() -> anyhow::Result<()> {
+/// let client = Storage::builder().build().await?;
+/// let control = StorageControl::builder().build().await?;
+/// let manager = TransferManager::new(client, control);
+/// let report = manager
+///     .download_directory("projects/_/buckets/my-bucket", "artifacts/", "downloads")
+///     .send()
+///     .await?;
+/// println!("downloaded {} files", report.transferred().count());
+/// # Ok(()) }
+/// ```
+///
+/// The transfer manager uses the [Storage] client to upload and download the
+/// data, and the [StorageControl] client to list the objects. The uploads and
+/// downloads use the retry, backoff, and read resume policies configured in
+/// the clients, unless overridden in the transfer manager.
+#[derive(Clone, Debug)]
+pub struct TransferManager<S = crate::storage::transport::Storage>
+where
+    S: crate::storage::stub::Storage + 'static,
+{
+    client: Storage<S>,
+    control: StorageControl,
+    concurrency: usize,
+    retry_policy: Option<RetryPolicyArg>,
+    backoff_policy: Option<BackoffPolicyArg>,
+}
+
+impl<S> TransferManager<S>
+where
+    S: crate::storage::stub::Storage + Clone + 'static,
+{
+    /// Creates a new transfer manager using the provided clients.
+    pub fn new(client: Storage<S>, control: StorageControl) -> Self {
+        Self {
+            client,
+            control,
+            concurrency: DEFAULT_CONCURRENCY,
+            retry_policy: None,
+            backoff_policy: None,
+        }
+    }
+
+    /// Sets the maximum number of concurrent file transfers.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_storage::client::{Storage, StorageControl};
+    /// # use google_cloud_storage::transfer_manager::TransferManager;
+    /// # fn sample(client: Storage, control: StorageControl) {
+    /// let manager = TransferManager::new(client, control).with_concurrency(32);
+    /// # }
+    /// ```
+    pub fn with_concurrency(mut self, v: usize) -> Self {
+        self.concurrency = v.max(1);
+        self
+    }
+
+    /// Sets the retry policy for each file transfer.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_storage::client::{Storage, StorageControl};
+    /// # use google_cloud_storage::transfer_manager::TransferManager;
+    /// # fn sample(client: Storage, control: StorageControl) {
+    /// use google_cloud_storage::retry_policy::RetryableErrors;
+    /// use google_cloud_gax::retry_policy::RetryPolicyExt;
+    /// let manager = TransferManager::new(client, control)
+    ///     .with_retry_policy(RetryableErrors.with_attempt_limit(5));
+    /// # }
+    /// ```
+    pub fn with_retry_policy<V: Into<RetryPolicyArg>>(mut self, v: V) -> Self {
+        self.retry_policy = Some(v.into());
+        self
+    }
+
+    /// Sets the backoff policy for each file transfer.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_storage::client::{Storage, StorageControl};
+    /// # use google_cloud_storage::transfer_manager::TransferManager;
+    /// # fn sample(client: Storage, control: StorageControl) {
+    /// use google_cloud_gax::exponential_backoff::ExponentialBackoff;
+    /// let manager = TransferManager::new(client, control)
+    ///     .with_backoff_policy(ExponentialBackoff::default());
+    /// # }
+    /// ```
+    pub fn with_backoff_policy<V: Into<BackoffPolicyArg>>(mut self, v: V) -> Self {
+        self.backoff_policy = Some(v.into());
+        self
+    }
+
+    /// Uploads the files in `directory` to objects under `prefix` in `bucket`.
+    ///
+    /// The object name for each file is `prefix` followed by the path of the
+    /// file relative to `directory`, using `/` as the separator.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_storage::transfer_manager::TransferManager;
+    /// # async fn sample(manager: &TransferManager) -> anyhow::Result<()> {
+    /// let report = manager
+    ///     .upload_directory("build/artifacts", "projects/_/buckets/my-bucket", "artifacts/")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Parameters
+    /// * `directory` - the local directory.
+    /// * `bucket` - the bucket name. In `projects/_/buckets/{bucket_id}` format.
+    /// * `prefix` - the prefix for the object names.
+    pub fn upload_directory<D, B, P>(
+        &self,
+        directory: D,
+        bucket: B,
+        prefix: P,
+    ) -> UploadDirectory<S>
+    where
+        D: Into<PathBuf>,
+        B: Into<String>,
+        P: Into<String>,
+    {
+        UploadDirectory {
+            manager: self.clone(),
+            spec: TransferSpec::new(directory.into(), bucket.into(), prefix.into()),
+        }
+    }
+
+    /// Downloads the objects under `prefix` in `bucket` to `directory`.
+    ///
+    /// The local path for each object is `directory` followed by the object
+    /// name without `prefix`. The transfer manager creates any missing
+    /// directories. Objects with names that end in `/`, often used as
+    /// placeholders for folders, are ignored. Objects with names that would
+    /// create files outside `directory` are reported as failures.
+    ///
+    /// # Example
+    /// ```
+    /// # use google_cloud_storage::transfer_manager::TransferManager;
+    /// # async fn sample(manager: &TransferManager) -> anyhow::Result<()> {
+    /// let report = manager
+    ///     .download_directory("projects/_/buckets/my-bucket", "artifacts/", "downloads")
+    ///     .with_include("**/*.tar.gz")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Parameters
+    /// * `bucket` - the bucket name. In `projects/_/buckets/{bucket_id}` format.
+    /// * `prefix` - the prefix for the object names.
+    /// * `directory` - the local directory.
+    pub fn download_directory<B, P, D>(
+        &self,
+        bucket: B,
+        prefix: P,
+        directory: D,
+    ) -> DownloadDirectory<S>
+    where
+        B: Into<String>,
+        P: Into<String>,
+        D: Into<PathBuf>,
+    {
+        DownloadDirectory {
+            manager: self.clone(),
+            spec: TransferSpec::new(directory.into(), bucket.into(), prefix.into()),
+        }
+    }
+}
+
+/// The configuration shared by uploads and downloads.
+#[derive(Clone, Debug)]
+struct TransferSpec {
+    directory: PathBuf,
+    bucket: String,
+    prefix: String,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    skip_if_unchanged: bool,
+}
+
+impl TransferSpec {
+    fn new(directory: PathBuf, bucket: String, prefix: String) -> Self {
+        Self {
+            directory,
+            bucket,
+            prefix,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            skip_if_unchanged: false,
+        }
+    }
+
+    /// Returns true if the relative path passes the include and exclude filters.
+    fn selected(&self, relative: &str) -> bool {
+        let included =
+            self.include.is_empty() || self.include.iter().any(|p| glob::matches(p, relative));
+        included && !self.exclude.iter().any(|p| glob::matches(p, relative))
+    }
+}
+
+macro_rules! filter_methods {
+    ($builder:ident) => {
+        impl<S> $builder<S>
+        where
+            S: crate::storage::stub::Storage + 'static,
+        {
+            /// Only transfer files matching this glob pattern.
+            ///
+            /// Patterns are matched against the path relative to the directory
+            /// (or prefix), using `/` as the separator. `?` matches any single
+            /// character, `*` matches any sequence of characters within a path
+            /// segment, and `**` matches across path segments. If called
+            /// multiple times, files matching any of the patterns are included.
+            /// By default, all files are included.
+            pub fn with_include<V: Into<String>>(mut self, v: V) -> Self {
+                self.spec.include.push(v.into());
+                self
+            }
+
+            /// Do not transfer files matching this glob pattern.
+            ///
+            /// Exclusions take precedence over inclusions. See
+            /// [with_include()][Self::with_include] for the pattern syntax.
+            pub fn with_exclude<V: Into<String>>(mut self, v: V) -> Self {
+                self.spec.exclude.push(v.into());
+                self
+            }
+
+            /// Skip files that are unchanged.
+            ///
+            /// A file is unchanged if the source and destination have the same
+            /// size, and the same CRC32C checksum or MD5 hash. The transfer
+            /// manager must read the local file to compute its checksums, but
+            /// only if the sizes match.
+            pub fn with_skip_if_unchanged(mut self, v: bool) -> Self {
+                self.spec.skip_if_unchanged = v;
+                self
+            }
+        }
+    };
+}
+
+/// A request builder for [TransferManager::upload_directory].
+#[derive(Debug)]
+pub struct UploadDirectory<S = crate::storage::transport::Storage>
+where
+    S: crate::storage::stub::Storage + 'static,
+{
+    manager: TransferManager<S>,
+    spec: TransferSpec,
+}
+
+filter_methods!(UploadDirectory);
+
+impl<S> UploadDirectory<S>
+where
+    S: crate::storage::stub::Storage + 'static,
+{
+    /// Uploads the files.
+    ///
+    /// Returns an error if the local directory or the existing objects cannot
+    /// be listed. Errors uploading individual files are included in the
+    /// report.
+    pub async fn send(self) -> Result<TransferReport> {
+        let files = list_files(&self.spec.directory).await?;
+        let existing = if self.spec.skip_if_unchanged {
+            list_objects(&self.manager.control, &self.spec.bucket, &self.spec.prefix).await?
+        } else {
+            BTreeMap::new()
+        };
+        let manager = &self.manager;
+        let spec = &self.spec;
+        let existing = &existing;
+        let results = futures::stream::iter(
+            files
+                .into_iter()
+                .filter(|relative| spec.selected(relative))
+                .map(|relative| async move {
+                    let path = to_local_path(&spec.directory, &relative);
+                    let object = format!("{}{relative}", spec.prefix);
+                    let outcome = manager
+                        .upload_file(spec, &path, &object, existing.get(&object))
+                        .await;
+                    TransferResult {
+                        path,
+                        object,
+                        outcome,
+                    }
+                }),
+        )
+        .buffer_unordered(manager.concurrency)
+        .collect::<Vec<_>>()
+        .await;
+        Ok(TransferReport::new(results))
+    }
+}
+
+/// A request builder for [TransferManager::download_directory].
+#[derive(Debug)]
+pub struct DownloadDirectory<S = crate::storage::transport::Storage>
+where
+    S: crate::storage::stub::Storage + 'static,
+{
+    manager: TransferManager<S>,
+    spec: TransferSpec,
+}
+
+filter_methods!(DownloadDirectory);
+
+impl<S> DownloadDirectory<S>
+where
+    S: crate::storage::stub::Storage + 'static,
+{
+    /// Downloads the objects.
+    ///
+    /// Returns an error if the objects cannot be listed. Errors downloading
+    /// individual objects are included in the report.
+    pub async fn send(self) -> Result<TransferReport> {
+        let objects =
+            list_objects(&self.manager.control, &self.spec.bucket, &self.spec.prefix).await?;
+        let manager = &self.manager;
+        let spec = &self.spec;
+        let results = futures::stream::iter(
+            objects
+                .into_values()
+                .filter_map(|object| {
+                    let relative = object.name.strip_prefix(&spec.prefix)?.to_string();
+                    (!relative.is_empty() && !relative.ends_with('/') && spec.selected(&relative))
+                        .then_some((relative, object))
+                })
+                .map(|(relative, object)| async move {
+                    let path = to_local_path(&spec.directory, &relative);
+                    let name = object.name.clone();
+                    let outcome = if is_safe_relative(&relative) {
+                        manager.download_file(spec, &path, object).await
+                    } else {
+                        TransferOutcome::Failed(Error::binding(format!(
+                            "object name {name} would create a file outside {}",
+                            spec.directory.display()
+                        )))
+                    };
+                    TransferResult {
+                        path,
+                        object: name,
+                        outcome,
+                    }
+                }),
+        )
+        .buffer_unordered(manager.concurrency)
+        .collect::<Vec<_>>()
+        .await;
+        Ok(TransferReport::new(results))
+    }
+}
+
+impl<S> TransferManager<S>
+where
+    S: crate::storage::stub::Storage + 'static,
+{
+    async fn upload_file(
+        &self,
+        spec: &TransferSpec,
+        path: &Path,
+        object: &str,
+        existing: Option<&Object>,
+    ) -> TransferOutcome {
+        if let Some(existing) = existing {
+            match is_unchanged(path, existing).await {
+                Ok(true) => return TransferOutcome::Skipped(existing.clone()),
+                Ok(false) => {}
+                Err(e) => return TransferOutcome::Failed(e),
+            }
+        }
+        let file = match tokio::fs::File::open(path).await {
+            Ok(f) => f,
+            Err(e) => return TransferOutcome::Failed(Error::io(e)),
+        };
+        let mut builder = self.client.write_object(&spec.bucket, object, file);
+        if let Some(p) = &self.retry_policy {
+            builder = builder.with_retry_policy(p.clone());
+        }
+        if let Some(p) = &self.backoff_policy {
+            builder = builder.with_backoff_policy(p.clone());
+        }
+        match builder.send_unbuffered().await {
+            Ok(o) => TransferOutcome::Transferred(o),
+            Err(e) => TransferOutcome::Failed(e),
+        }
+    }
+
+    async fn download_file(
+        &self,
+        spec: &TransferSpec,
+        path: &Path,
+        object: Object,
+    ) -> TransferOutcome {
+        if spec.skip_if_unchanged {
+            match is_unchanged(path, &object).await {
+                Ok(true) => return TransferOutcome::Skipped(object),
+                Ok(false) => {}
+                Err(e) => return TransferOutcome::Failed(e),
+            }
+        }
+        match self.download_file_impl(spec, path, &object).await {
+            Ok(()) => TransferOutcome::Transferred(object),
+            Err(e) => TransferOutcome::Failed(e),
+        }
+    }
+
+    async fn download_file_impl(
+        &self,
+        spec: &TransferSpec,
+        path: &Path,
+        object: &Object,
+    ) -> Result<()> {
+        let mut builder = self
+            .client
+            .read_object(&spec.bucket, &object.name)
+            .set_generation(object.generation);
+        if let Some(p) = &self.retry_policy {
+            builder = builder.with_retry_policy(p.clone());
+        }
+        if let Some(p) = &self.backoff_policy {
+            builder = builder.with_backoff_policy(p.clone());
+        }
+        let mut response = builder.send().await?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(Error::io)?;
+        }
+        // Download to a temporary file, and only move it into place after the
+        // download (including any checksum validation) completes. Otherwise a
+        // failed download leaves a truncated file that looks complete. The
+        // temporary file is removed when `temp` is dropped, including on
+        // errors.
+        let (mut file, temp) = temp_file(path).await?;
+        while let Some(data) = response.next().await.transpose()? {
+            file.write_all(&data).await.map_err(Error::io)?;
+        }
+        file.flush().await.map_err(Error::io)?;
+        drop(file);
+        tokio::fs::rename(&temp, path).await.map_err(Error::io)?;
+        // The file was moved, there is nothing left to remove.
+        let _ = temp.keep();
+        Ok(())
+    }
+}
+
+/// Creates a uniquely named temporary file in the directory of `path`.
+///
+/// The name is unique, so the download never overwrites an existing file
+/// before it completes, and concurrent downloads do not share files.
+async fn temp_file(path: &Path) -> Result<(tokio::fs::File, tempfile::TempPath)> {
+    let dir = match path.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    let mut prefix = std::ffi::OsString::from(".");
+    prefix.push(path.file_name().unwrap_or_default());
+    prefix.push(".");
+    let temp = tokio::task::spawn_blocking(move || {
+        let mut builder = tempfile::Builder::new();
+        builder.prefix(&prefix).suffix(".download");
+        // Temporary files are private by default, use the same permissions as
+        // `File::create()` instead.
+        #[cfg(unix)]
+        builder.permissions(std::os::unix::fs::PermissionsExt::from_mode(0o666));
+        builder.tempfile_in(dir)
+    })
+    .await
+    .map_err(Error::io)?
+    .map_err(Error::io)?;
+    let (file, temp) = temp.into_parts();
+    Ok((tokio::fs::File::from_std(file), temp))
+}
+
+/// The result of a directory transfer.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct TransferReport {
+    /// The result for each file, in no particular order.
+    pub results: Vec<TransferResult>,
+}
+
+impl TransferReport {
+    fn new(results: Vec<TransferResult>) -> Self {
+        Self { results }
+    }
+
+    /// Returns true if no file transfer failed.
+    pub fn is_success(&self) -> bool {
+        self.failed().next().is_none()
+    }
+
+    /// Returns the results for files that were transferred.
+    pub fn transferred(&self) -> impl Iterator<Item = &TransferResult> {
+        self.results
+            .iter()
+            .filter(|r| matches!(r.outcome, TransferOutcome::Transferred(_)))
+    }
+
+    /// Returns the results for files that were skipped because they were unchanged.
+    pub fn skipped(&self) -> impl Iterator<Item = &TransferResult> {
+        self.results
+            .iter()
+            .filter(|r| matches!(r.outcome, TransferOutcome::Skipped(_)))
+    }
+
+    /// Returns the results for files that could not be transferred.
+    pub fn failed(&self) -> impl Iterator<Item = &TransferResult> {
+        self.results
+            .iter()
+            .filter(|r| matches!(r.outcome, TransferOutcome::Failed(_)))
+    }
+}
+
+/// The result of transferring a single file.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct TransferResult {
+    /// The path of the local file.
+    pub path: PathBuf,
+    /// The name of the object.
+    pub object: String,
+    /// The outcome of the transfer.
+    pub outcome: TransferOutcome,
+}
+
+/// The outcome of transferring a single file.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TransferOutcome {
+    /// The file was transferred, contains the object metadata.
+    Transferred(Object),
+    /// The file was unchanged, contains the existing object metadata.
+    Skipped(Object),
+    /// The transfer failed.
+    Failed(Error),
+}
+
+/// Lists the regular files in `directory`, returning their relative paths.
+///
+/// The paths use `/` as the separator. Symbolic links to directories are not
+/// followed.
+async fn list_files(directory: &Path) -> Result<Vec<String>> {
+    let mut files = Vec::new();
+    let mut pending = vec![(directory.to_path_buf(), String::new())];
+    while let Some((dir, relative)) = pending.pop() {
+        let mut entries = tokio::fs::read_dir(&dir).await.map_err(Error::io)?;
+        while let Some(entry) = entries.next_entry().await.map_err(Error::io)? {
+            let Some(name) = entry.file_name().to_str().map(str::to_string) else {
+                continue;
+            };
+            let child = format!("{relative}{name}");
+            let file_type = entry.file_type().await.map_err(Error::io)?;
+            if file_type.is_dir() {
+                pending.push((entry.path(), format!("{child}/")));
+            } else if file_type.is_file()
+                || tokio::fs::metadata(entry.path())
+                    .await
+                    .is_ok_and(|m| m.is_file())
+            {
+                files.push(child);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Lists the objects under `prefix`, keyed by name.
+async fn list_objects(
+    control: &StorageControl,
+    bucket: &str,
+    prefix: &str,
+) -> Result<BTreeMap<String, Object>> {
+    let mut objects = control
+        .list_objects()
+        .set_parent(bucket)
+        .set_prefix(prefix)
+        .by_item();
+    let mut result = BTreeMap::new();
+    while let Some(object) = objects.next().await.transpose()? {
+        result.insert(object.name.clone(), object);
+    }
+    Ok(result)
+}
+
+fn to_local_path(directory: &Path, relative: &str) -> PathBuf {
+    relative
+        .split('/')
+        .fold(directory.to_path_buf(), |path, segment| path.join(segment))
+}
+
+/// Returns false if `relative` could refer to a path outside the directory.
+fn is_safe_relative(relative: &str) -> bool {
+    !relative.starts_with('/')
+        && relative
+            .split('/')
+            .all(|s| !s.is_empty() && s != "." && s != ".." && !s.contains('\\'))
+}
+
+/// Returns true if the local file has the same size and checksums as `object`.
+async fn is_unchanged(path: &Path, object: &Object) -> Result<bool> {
+    let metadata = match tokio::fs::metadata(path).await {
+        Ok(m) => m,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(Error::io(e)),
+    };
+    if metadata.len() != object.size as u64 {
+        return Ok(false);
+    }
+    let Some(remote) = object.checksums.as_ref() else {
+        return Ok(false);
+    };
+    if remote.crc32c.is_none() && remote.md5_hash.is_empty() {
+        return Ok(false);
+    }
+    let local = local_checksums(path).await?;
+    Ok(checksum_validate(&local, &Some(remote.clone())).is_ok())
+}
+
+async fn local_checksums(path: &Path) -> Result<ObjectChecksums> {
+    let mut file = tokio::fs::File::open(path).await.map_err(Error::io)?;
+    let mut checksum = Checksum {
+        crc32c: Some(Crc32c::default()),
+        md5_hash: Some(Md5::default()),
+    };
+    let mut offset = 0_u64;
+    let mut buffer = vec![0_u8; READ_SIZE];
+    loop {
+        let n = file.read(&mut buffer).await.map_err(Error::io)?;
+        if n == 0 {
+            break;
+        }
+        checksum.update(offset, &bytes::Bytes::copy_from_slice(&buffer[..n]));
+        offset += n as u64;
+    }
+    Ok(checksum.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{ListObjectsRequest, ListObjectsResponse, ReadObjectRequest};
+    use crate::model_ext::{ObjectHighlights, WriteObjectRequest};
+    use crate::read_object::ReadObjectResponse;
+    use crate::request_options::RequestOptions;
+    use crate::streaming_source::{Seek, StreamingSource};
+    use google_cloud_gax::error::rpc::{Code, Status};
+    use google_cloud_gax::options::RequestOptions as GaxRequestOptions;
+    use google_cloud_gax::response::Response;
+    use std::sync::{Arc, Mutex};
+    use tempfile::TempDir;
+
+    type TestResult = anyhow::Result<()>;
+
+    const BUCKET: &str = "projects/_/buckets/test-bucket";
+
+    /// Stores objects in memory.
+    #[derive(Clone, Debug, Default)]
+    struct FakeStorage {
+        objects: Arc<Mutex<BTreeMap<String, bytes::Bytes>>>,
+    }
+
+    impl crate::stub::Storage for FakeStorage {
+        async fn read_object(
+            &self,
+            req: ReadObjectRequest,
+            _options: RequestOptions,
+        ) -> Result<ReadObjectResponse> {
+            if req.object.ends_with("broken.txt") {
+                let mut response = crate::object_descriptor::tests::MockResponse::new();
+                let mut seq = mockall::Sequence::new();
+                response
+                    .expect_next()
+                    .times(1)
+                    .in_sequence(&mut seq)
+                    .returning(|| Some(Ok(bytes::Bytes::from_static(b"partial"))));
+                response
+                    .expect_next()
+                    .in_sequence(&mut seq)
+                    .returning(|| Some(Err(Error::io("interrupted"))));
+                return Ok(ReadObjectResponse::new(Box::new(response)));
+            }
+            let data = self.objects.lock().unwrap().get(&req.object).cloned();
+            match data {
+                None => Err(Error::service(Status::default().set_code(Code::NotFound))),
+                Some(d) => Ok(ReadObjectResponse::from_source(
+                    ObjectHighlights::default(),
+                    d,
+                )),
+            }
+        }
+
+        async fn write_object_unbuffered<P>(
+            &self,
+            mut payload: P,
+            req: WriteObjectRequest,
+            _options: RequestOptions,
+        ) -> Result<Object>
+        where
+            P: StreamingSource + Seek + Send + Sync + 'static,
+        {
+            let resource = req.spec.resource.unwrap();
+            let mut data = Vec::new();
+            while let Some(b) = payload.next().await.transpose().map_err(Error::ser)? {
+                data.extend_from_slice(&b);
+            }
+            let data = bytes::Bytes::from_owner(data);
+            let object = to_object(&resource.name, &data);
+            self.objects.lock().unwrap().insert(resource.name, data);
+            Ok(object)
+        }
+    }
+
+    fn to_object(name: &str, data: &bytes::Bytes) -> Object {
+        Object::new()
+            .set_bucket(BUCKET)
+            .set_name(name)
+            .set_generation(1234)
+            .set_size(data.len() as i64)
+            .set_checksums(ObjectChecksums::new().set_crc32c(crc32c::crc32c(data)))
+    }
+
+    mockall::mock! {
+        #[derive(Debug)]
+        StorageControl {}
+        impl crate::stub::StorageControl for StorageControl {
+            async fn list_objects(&self, req: ListObjectsRequest, _options: GaxRequestOptions) -> Result<Response<ListObjectsResponse>>;
+        }
+    }
+
+    fn control_with(objects: Vec<Object>) -> StorageControl {
+        let mut mock = MockStorageControl::new();
+        mock.expect_list_objects().returning(move |req, _| {
+            let objects = objects
+                .iter()
+                .filter(|o| o.name.starts_with(&req.prefix))
+                .cloned();
+            Ok(Response::from(
+                ListObjectsResponse::new().set_objects(objects),
+            ))
+        });
+        StorageControl::from_stub(mock)
+    }
+
+    async fn create_tree(dir: &Path) -> anyhow::Result<()> {
+        tokio::fs::create_dir_all(dir.join("sub/deep")).await?;
+        tokio::fs::write(dir.join("a.txt"), "aaa").await?;
+        tokio::fs::write(dir.join("b.tmp"), "bbb").await?;
+        tokio::fs::write(dir.join("sub/c.txt"), "ccc").await?;
+        tokio::fs::write(dir.join("sub/deep/d.txt"), "ddd").await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn upload_directory() -> TestResult {
+        let dir = TempDir::new()?;
+        create_tree(dir.path()).await?;
+        let stub = Arc::new(FakeStorage::default());
+        let manager = TransferManager::new(
+            Storage::<FakeStorage>::from_stub(stub.clone()),
+            control_with(Vec::new()),
+        )
+        .with_concurrency(2);
+        let report = manager
+            .upload_directory(dir.path(), BUCKET, "prefix/")
+            .with_exclude("**/*.tmp")
+            .send()
+            .await?;
+        assert!(report.is_success(), "{report:?}");
+        assert_eq!(report.transferred().count(), 3, "{report:?}");
+
+        let names = stub
+            .objects
+            .lock()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec!["prefix/a.txt", "prefix/sub/c.txt", "prefix/sub/deep/d.txt"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn upload_directory_skip_unchanged() -> TestResult {
+        let dir = TempDir::new()?;
+        create_tree(dir.path()).await?;
+        let existing = vec![
+            to_object("prefix/a.txt", &bytes::Bytes::from_static(b"aaa")),
+            to_object("prefix/sub/c.txt", &bytes::Bytes::from_static(b"xxx")),
+        ];
+        let stub = Arc::new(FakeStorage::default());
+        let manager = TransferManager::new(
+            Storage::<FakeStorage>::from_stub(stub.clone()),
+            control_with(existing),
+        );
+        let report = manager
+            .upload_directory(dir.path(), BUCKET, "prefix/")
+            .with_include("**/*.txt")
+            .with_skip_if_unchanged(true)
+            .send()
+            .await?;
+        assert!(report.is_success(), "{report:?}");
+        let skipped = report
+            .skipped()
+            .map(|r| r.object.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(skipped, vec!["prefix/a.txt"]);
+        let names = stub
+            .objects
+            .lock()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["prefix/sub/c.txt", "prefix/sub/deep/d.txt"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn download_directory() -> TestResult {
+        let stub = Arc::new(FakeStorage::default());
+        let mut listed = Vec::new();
+        for (name, contents) in [
+            ("prefix/a.txt", "aaa"),
+            ("prefix/sub/c.txt", "ccc"),
+            ("prefix/sub/", ""),
+            ("prefix/../escape.txt", "eee"),
+            ("other/z.txt", "zzz"),
+        ] {
+            let data = bytes::Bytes::from_static(contents.as_bytes());
+            listed.push(to_object(name, &data));
+            stub.objects.lock().unwrap().insert(name.to_string(), data);
+        }
+        let dir = TempDir::new()?;
+        tokio::fs::write(dir.path().join("a.txt"), "aaa").await?;
+        // Files that look like temporary files are not overwritten.
+        tokio::fs::create_dir(dir.path().join("sub")).await?;
+        tokio::fs::write(dir.path().join("sub/c.txt.download"), "keep").await?;
+        let manager = TransferManager::new(
+            Storage::<FakeStorage>::from_stub(stub.clone()),
+            control_with(listed),
+        );
+        let report = manager
+            .download_directory(BUCKET, "prefix/", dir.path())
+            .with_skip_if_unchanged(true)
+            .send()
+            .await?;
+        let skipped = report
+            .skipped()
+            .map(|r| r.object.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(skipped, vec!["prefix/a.txt"], "{report:?}");
+        let transferred = report
+            .transferred()
+            .map(|r| r.object.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(transferred, vec!["prefix/sub/c.txt"], "{report:?}");
+        let failed = report
+            .failed()
+            .map(|r| r.object.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(failed, vec!["prefix/../escape.txt"], "{report:?}");
+
+        let got = tokio::fs::read_to_string(dir.path().join("sub/c.txt")).await?;
+        assert_eq!(got, "ccc");
+        let got = tokio::fs::read_to_string(dir.path().join("sub/c.txt.download")).await?;
+        assert_eq!(got, "keep");
+        assert!(!dir.path().join("../escape.txt").exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn download_directory_errors() -> TestResult {
+        let stub = Arc::new(FakeStorage::default());
+        let listed = vec![to_object("prefix/missing.txt", &bytes::Bytes::new())];
+        let dir = TempDir::new()?;
+        let manager = TransferManager::new(
+            Storage::<FakeStorage>::from_stub(stub),
+            control_with(listed),
+        );
+        let report = manager
+            .download_directory(BUCKET, "prefix/", dir.path())
+            .send()
+            .await?;
+        assert!(!report.is_success(), "{report:?}");
+        let failed = report.failed().next().expect("one failure");
+        assert!(
+            matches!(&failed.outcome, TransferOutcome::Failed(e) if e.status().is_some_and(|s| s.code == Code::NotFound)),
+            "{report:?}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn download_directory_keeps_existing_file_on_error() -> TestResult {
+        let stub = Arc::new(FakeStorage::default());
+        let listed = vec![to_object(
+            "prefix/broken.txt",
+            &bytes::Bytes::from_static(b"new contents"),
+        )];
+        let dir = TempDir::new()?;
+        tokio::fs::write(dir.path().join("broken.txt"), "old").await?;
+        let manager = TransferManager::new(
+            Storage::<FakeStorage>::from_stub(stub),
+            control_with(listed),
+        );
+        let report = manager
+            .download_directory(BUCKET, "prefix/", dir.path())
+            .send()
+            .await?;
+        assert!(!report.is_success(), "{report:?}");
+
+        let got = tokio::fs::read_to_string(dir.path().join("broken.txt")).await?;
+        assert_eq!(got, "old");
+        // The temporary file is removed.
+        assert_eq!(list_files(dir.path()).await?, vec!["broken.txt"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_files_missing_directory() -> TestResult {
+        let dir = TempDir::new()?;
+        let err = list_files(&dir.path().join("missing")).await.unwrap_err();
+        assert!(err.is_io(), "{err:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn safe_relative() {
+        assert!(is_safe_relative("a/b.txt"));
+        assert!(!is_safe_relative("../a.txt"));
+        assert!(!is_safe_relative("a/../../b.txt"));
+        assert!(!is_safe_relative("/etc/passwd"));
+        assert!(!is_safe_relative("a//b"));
+        assert!(!is_safe_relative("a\\..\\b"));
+    }
+}
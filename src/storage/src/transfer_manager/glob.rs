@@ -0,0 +1,142 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A minimal glob matcher for relative paths.
+//!
+//! Paths always use `/` as the separator. The patterns support:
+//! - `?`: matches any single character, except `/`.
+//! - `*`: matches any sequence of characters, except `/`.
+//! - `**`: matches any sequence of characters, including `/`. A `**/` prefix
+//!   also matches zero directories, so `**/*.txt` matches `a.txt`.
+//! - any other character matches itself.
+
+/// Returns true if `path` matches `pattern`.
+///
+/// The implementation simulates the pattern as a non-deterministic automaton,
+/// tracking every pattern position that can match the path so far. This runs
+/// in `O(pattern.len() * path.len())` time, without backtracking.
+pub(crate) fn matches(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let tokens = tokenize(&pattern);
+    let mut current = vec![false; tokens.len() + 1];
+    current[0] = true;
+    closure(&tokens, &mut current);
+    for c in path.chars() {
+        let mut next = vec![false; tokens.len() + 1];
+        for (i, token) in tokens.iter().enumerate() {
+            if !current[i] {
+                continue;
+            }
+            match token {
+                Token::Literal(p) if *p == c => next[i + 1] = true,
+                Token::Any if c != '/' => next[i + 1] = true,
+                Token::Star if c != '/' => next[i] = true,
+                Token::GlobStar => next[i] = true,
+                Token::GlobStarDir => {
+                    next[i] = true;
+                    if c == '/' {
+                        next[i + 1] = true;
+                    }
+                }
+                _ => {}
+            }
+        }
+        closure(&tokens, &mut next);
+        if !next.iter().any(|s| *s) {
+            return false;
+        }
+        current = next;
+    }
+    current[tokens.len()]
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    /// Any character other than a wildcard.
+    Literal(char),
+    /// `?`
+    Any,
+    /// `*`
+    Star,
+    /// `**`
+    GlobStar,
+    /// `**/`, which also matches zero directories.
+    GlobStarDir,
+}
+
+fn tokenize(mut pattern: &[char]) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    while !pattern.is_empty() {
+        let (token, rest) = match pattern {
+            ['*', '*', '/', rest @ ..] => (Token::GlobStarDir, rest),
+            ['*', '*', rest @ ..] => (Token::GlobStar, rest),
+            ['*', rest @ ..] => (Token::Star, rest),
+            ['?', rest @ ..] => (Token::Any, rest),
+            [c, rest @ ..] => (Token::Literal(*c), rest),
+            [] => unreachable!("loop condition checks for empty patterns"),
+        };
+        tokens.push(token);
+        pattern = rest;
+    }
+    tokens
+}
+
+/// Adds the states reachable without consuming any characters.
+fn closure(tokens: &[Token], states: &mut [bool]) {
+    for (i, token) in tokens.iter().enumerate() {
+        if states[i] && matches!(token, Token::Star | Token::GlobStar | Token::GlobStarDir) {
+            states[i + 1] = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("a.txt", "a.txt", true; "literal")]
+    #[test_case("a.txt", "b.txt", false; "literal_mismatch")]
+    #[test_case("*.txt", "a.txt", true; "star")]
+    #[test_case("*.txt", "dir/a.txt", false; "star_no_separator")]
+    #[test_case("dir/*.txt", "dir/a.txt", true; "star_in_dir")]
+    #[test_case("**/*.txt", "a.txt", true; "globstar_zero_dirs")]
+    #[test_case("**/*.txt", "dir/a.txt", true; "globstar_one_dir")]
+    #[test_case("**/*.txt", "dir/sub/a.txt", true; "globstar_two_dirs")]
+    #[test_case("**/*.txt", "dir/sub/a.bin", false; "globstar_mismatch")]
+    #[test_case("dir/**", "dir/sub/a.bin", true; "trailing_globstar")]
+    #[test_case("dir/**", "other/a.bin", false; "trailing_globstar_mismatch")]
+    #[test_case("?.txt", "a.txt", true; "question")]
+    #[test_case("?.txt", "ab.txt", false; "question_too_long")]
+    #[test_case("?", "/", false; "question_separator")]
+    #[test_case("?.txt", "é.txt", true; "question_multibyte")]
+    #[test_case("??.txt", "é.txt", false; "question_multibyte_one_char")]
+    #[test_case("*é.txt", "aé.txt", true; "literal_multibyte")]
+    #[test_case("*", "", true; "star_empty")]
+    #[test_case("", "a", false; "empty_pattern")]
+    #[test_case("**/a/**/b", "a/x/y/b", true; "globstar_twice")]
+    #[test_case("**", "a/b/c", true; "globstar_only")]
+    #[test_case("a/**/b", "a/b", true; "globstar_dir_zero_dirs")]
+    #[test_case("*/*.txt", "a/b/c.txt", false; "star_per_segment")]
+    fn glob(pattern: &str, path: &str, want: bool) {
+        assert_eq!(matches(pattern, path), want, "{pattern} vs. {path}");
+    }
+
+    #[test]
+    fn no_exponential_backtracking() {
+        let path = "a".repeat(10_000);
+        assert!(!matches("*a*a*a*a*a*a*a*a*b", &path));
+        assert!(!matches("**a**a**a**a**a**a**a**a**b", &path));
+    }
+}